@@ -0,0 +1,110 @@
+//! backend/src/authentication/guard.rs
+//! Role based authorization for routes behind `AuthCookie`.
+//! Routes take a `CurrentUser` and ask it whether the user may do something,
+//! instead of comparing author IDs themselves.
+use crate::{authentication::LocalBoxFuture, error_chain_helper, repository::RoleLookUp};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{
+    dev::Payload, web, FromRequest, HttpMessage, HttpRequest, HttpResponse, ResponseError,
+};
+use anyhow::Context;
+use models::{
+    roles::{Permission, Role},
+    UserID,
+};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum GuardError {
+    #[error("{0}")]
+    AuthorizationError(String),
+    #[error("{0}")]
+    PermissionError(String),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for GuardError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for GuardError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            GuardError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            GuardError::AuthorizationError(msg) => HttpResponse::build(StatusCode::UNAUTHORIZED)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": msg })),
+            GuardError::PermissionError(msg) => HttpResponse::build(StatusCode::FORBIDDEN)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": msg })),
+        }
+    }
+}
+
+/// The logged in user and their role.
+/// The role is read from the database on every request, so role changes apply straight away.
+#[derive(Debug, Clone)]
+pub struct CurrentUser {
+    pub user_id: String,
+    pub role: Role,
+}
+
+impl CurrentUser {
+    /// Fails with 403 unless the user's role grants the permission.
+    pub fn require(&self, permission: Permission) -> Result<(), GuardError> {
+        if self.role.can(permission) {
+            Ok(())
+        } else {
+            Err(GuardError::PermissionError(format!(
+                "A {} cannot do that",
+                self.role
+            )))
+        }
+    }
+
+    /// Fails with 403 unless the user may change or delete content made by `author_id`.
+    pub fn require_manage(&self, author_id: &str) -> Result<(), GuardError> {
+        if self.role.may_manage(&self.user_id, author_id) {
+            Ok(())
+        } else {
+            Err(GuardError::PermissionError(String::from(
+                "User does not own that",
+            )))
+        }
+    }
+}
+
+/// Needs the `UserID` that `AuthCookie` puts in the request, so only use behind it.
+impl FromRequest for CurrentUser {
+    type Error = GuardError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let user_id: Option<UserID> = req.extensions().get::<UserID>().cloned();
+        let db: Option<web::Data<dyn RoleLookUp>> =
+            req.app_data::<web::Data<dyn RoleLookUp>>().cloned();
+        Box::pin(async move {
+            let Some(UserID(user_id)) = user_id else {
+                return Err(GuardError::AuthorizationError(String::from(
+                    "Session Token not found",
+                )));
+            };
+            let db: web::Data<dyn RoleLookUp> = db.context("Database missing from app data")?;
+            let role: Role = db
+                .get_user_role(user_id.clone())
+                .await
+                .context("Failed to look up user role")?
+                // The account was deleted while the session was still alive
+                .ok_or_else(|| GuardError::AuthorizationError(String::from("User not found")))?;
+            Ok(CurrentUser { user_id, role })
+        })
+    }
+}
@@ -1,8 +1,10 @@
 //! backend/src/authentication/mod.rs
 //! backend/src/authentication/mod.rs
 //! Holds logic and helpers related to authenticating users.
+pub mod guard;
 pub mod middleware;
 pub mod password;
 
+pub use guard::*;
 pub use middleware::*;
 pub use password::*;
@@ -1,10 +1,7 @@
 //! backend/src/authentication/password.rs
 //! File to hold logic for encrypting passwords.
 //! Passwords will use the Argon2 encryption method
-use crate::{
-    surrealdb_repo::{Database, LookUpUser},
-    telemetry::spawn_blocking_and_tracing,
-};
+use crate::{repository::LookUpUser, telemetry::spawn_blocking_and_tracing};
 use actix_web::web;
 use anyhow::Context;
 use argon2::{
@@ -36,9 +33,9 @@ pub struct UserCredentials {
 /// The hard coded credentials will fail but help secure against
 /// attacks that check difference in response times to target victims.
 #[tracing::instrument(name = "Validate Credentials", skip_all)]
-pub async fn validate_credentials(
+pub async fn validate_credentials<R: LookUpUser>(
     credentials: UserCredentials,
-    db: web::Data<Database>,
+    db: web::Data<R>,
 ) -> Result<uuid::Uuid, AuthError> {
     // To keep return time consistent
     let mut db_user_id: Option<Uuid> = None;
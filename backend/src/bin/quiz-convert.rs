@@ -0,0 +1,71 @@
+//! backend/src/bin/quiz-convert.rs
+//! Converts quiz bundles between JSON, YAML, GIFT and Moodle XML without a server.
+//! Formats come from the file extensions, e.g. `quiz-convert questions.gift quiz.json`
+use anyhow::Context;
+use backend::bundle_format::{decode_bundle, encode_bundle, Converted};
+use models::{
+    bundle::{BundleFormat, ImportReport, QuizBundle},
+    interchange::Unmapped,
+};
+use std::path::Path;
+use surrealdb::sql::Thing;
+
+const USAGE: &str = "Usage: quiz-convert <input> <output>
+Formats are picked from the extensions: .json, .yaml/.yml, .gift/.txt, .xml";
+
+fn format_of(path: &Path) -> anyhow::Result<BundleFormat> {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(BundleFormat::from_extension)
+        .with_context(|| format!("Unknown format for {}\n{}", path.display(), USAGE))
+}
+
+fn report_unmapped(stage: &str, unmapped: &[Unmapped]) {
+    for part in unmapped {
+        eprintln!("{}: {}", stage, part);
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [input, output] = args.as_slice() else {
+        anyhow::bail!(USAGE);
+    };
+    let (input, output): (&Path, &Path) = (Path::new(input), Path::new(output));
+    let (in_format, out_format) = (format_of(input)?, format_of(output)?);
+
+    let text: String = std::fs::read_to_string(input)
+        .with_context(|| format!("Unable to read {}", input.display()))?;
+    let read: Converted<QuizBundle> = decode_bundle(&text, in_format)?;
+    report_unmapped("read", &read.unmapped);
+
+    let bundle: QuizBundle = read.value;
+    bundle.validate_header()?;
+    let written: Converted<String> = encode_bundle(&bundle, out_format)?;
+    report_unmapped("write", &written.unmapped);
+    std::fs::write(output, written.value)
+        .with_context(|| format!("Unable to write {}", output.display()))?;
+    println!(
+        "Converted {} questions from {} to {}",
+        bundle.questions.len(),
+        in_format.describe(),
+        out_format.describe()
+    );
+
+    // Same checks as an import, so the output can be uploaded as is
+    let (quiz, packages) = bundle.into_packages(&Thing::from(("quizzes", "convert")));
+    let report: ImportReport = ImportReport::check(true, &quiz, &packages);
+    if let Some(err) = &report.quiz_error {
+        eprintln!("quiz: {}", err);
+    }
+    for item in report.items.iter() {
+        if let Some(err) = &item.error {
+            eprintln!("question {}: {}", item.index + 1, err);
+        }
+    }
+    if report.is_valid() {
+        Ok(())
+    } else {
+        anyhow::bail!("Some questions will not pass an import, see above")
+    }
+}
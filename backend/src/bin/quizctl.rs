@@ -0,0 +1,361 @@
+//! backend/src/bin/quizctl.rs
+//! Admin tool for running the quiz app, using the same configuration as the server.
+//! Select the environment the usual way, e.g. `QUIZAPP_ENVIRONMENT=production quizctl stats`
+use anyhow::Context;
+use backend::{
+    authentication::create_password_hash,
+    bundle_format::{decode_bundle, encode_bundle, Converted},
+    configuration::{get_configuration, AllSettings},
+    migrations::{applied_version, run_migrations, MIGRATIONS},
+    repository::{LookUpUser, QuestionRepo, QuizRepo, SessionRepo},
+    surrealdb_repo::Database,
+};
+use models::{
+    bundle::{BundleFormat, ImportReport, QuizBundle},
+    questions::{AllQuestions, QuestionJsonPkg, QUESTION_TABLES},
+    quiz::{Quiz, QuizJsonPkg, SurrealQuiz},
+    roles::Role,
+    GeneralUser,
+};
+use pico_args::Arguments;
+use secrecy::{ExposeSecret, Secret};
+use std::path::PathBuf;
+use surrealdb::sql::{thing, Id, Thing};
+
+const USAGE: &str = "\
+Usage: quizctl <command>
+
+Commands:
+  users list
+  users delete <username> [--yes]           Deletes the user and everything they made
+  users reset-password <username>           Reads the new password from stdin
+  users set-role <username> <role>          Roles: student, instructor, admin
+  sessions purge                            Deletes expired sessions
+  quizzes export <quiz-id> [--format F] [--output FILE]
+  quizzes import <FILE> --owner <username> [--format F] [--dry-run]
+  stats                                     Record counts per table
+  migrate                                   Applies pending schema migrations
+
+Formats: json, yaml, gift, xml. Without --format, the file extension is used.";
+
+/// Shortest password `create-user` accepts.
+const MIN_PASSWORD_LEN: usize = 6;
+
+/// A parsed command line, checked before connecting to the database.
+enum Command {
+    ListUsers,
+    DeleteUser {
+        username: String,
+        confirmed: bool,
+    },
+    ResetPassword {
+        username: String,
+    },
+    SetRole {
+        username: String,
+        role: String,
+    },
+    PurgeSessions,
+    ExportQuiz {
+        quiz_id: String,
+        format: Option<String>,
+        output: Option<PathBuf>,
+    },
+    ImportQuiz {
+        path: PathBuf,
+        owner: String,
+        format: Option<String>,
+        dry_run: bool,
+    },
+    Stats,
+    Migrate,
+}
+
+impl Command {
+    fn parse(mut args: Arguments) -> anyhow::Result<Self> {
+        let group: Option<String> = args.subcommand()?;
+        let command: Option<String> = match group.as_deref() {
+            Some("users" | "sessions" | "quizzes") => args.subcommand()?,
+            _ => None,
+        };
+        let parsed: Command = match (group.as_deref(), command.as_deref()) {
+            (Some("users"), Some("list")) => Command::ListUsers,
+            (Some("users"), Some("delete")) => Command::DeleteUser {
+                confirmed: args.contains("--yes"),
+                username: args.free_from_str()?,
+            },
+            (Some("users"), Some("reset-password")) => Command::ResetPassword {
+                username: args.free_from_str()?,
+            },
+            (Some("users"), Some("set-role")) => Command::SetRole {
+                username: args.free_from_str()?,
+                role: args.free_from_str()?,
+            },
+            (Some("sessions"), Some("purge")) => Command::PurgeSessions,
+            (Some("quizzes"), Some("export")) => Command::ExportQuiz {
+                format: args.opt_value_from_str("--format")?,
+                output: args.opt_value_from_str("--output")?,
+                quiz_id: args.free_from_str()?,
+            },
+            (Some("quizzes"), Some("import")) => Command::ImportQuiz {
+                format: args.opt_value_from_str("--format")?,
+                owner: args.value_from_str("--owner")?,
+                dry_run: args.contains("--dry-run"),
+                path: args.free_from_str()?,
+            },
+            (Some("stats"), None) => Command::Stats,
+            (Some("migrate"), None) => Command::Migrate,
+            _ => anyhow::bail!(USAGE),
+        };
+        let unused: Vec<std::ffi::OsString> = args.finish();
+        if !unused.is_empty() {
+            anyhow::bail!("Unexpected arguments {:?}\n{}", unused, USAGE);
+        }
+        Ok(parsed)
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let mut args: Arguments = Arguments::from_env();
+    if args.contains(["-h", "--help"]) {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    let command: Command = Command::parse(args)?;
+
+    let config: AllSettings = get_configuration().context("Failed to read configuration")?;
+    let db: Database = Database::from_config(config.database)
+        .await
+        .context("Failed to connect to database")?;
+
+    match command {
+        Command::ListUsers => list_users(&db).await,
+        Command::DeleteUser {
+            username,
+            confirmed,
+        } => delete_user(&db, username, confirmed).await,
+        Command::ResetPassword { username } => reset_password(&db, username).await,
+        Command::SetRole { username, role } => set_role(&db, username, role).await,
+        Command::PurgeSessions => {
+            let purged: usize = db.purge_expired_sessions().await?;
+            println!("Purged {} expired sessions", purged);
+            Ok(())
+        }
+        Command::ExportQuiz {
+            quiz_id,
+            format,
+            output,
+        } => export_quiz(&db, quiz_id, format, output).await,
+        Command::ImportQuiz {
+            path,
+            owner,
+            format,
+            dry_run,
+        } => import_quiz(&db, path, owner, format, dry_run).await,
+        Command::Stats => print_stats(&db).await,
+        Command::Migrate => {
+            let before: u32 = applied_version(&db).await?;
+            let after: u32 = run_migrations(&db).await?;
+            println!(
+                "Schema at version {} of {}, {} applied",
+                after,
+                MIGRATIONS.len(),
+                after.saturating_sub(before)
+            );
+            Ok(())
+        }
+    }
+}
+
+async fn find_user(db: &Database, username: String) -> anyhow::Result<GeneralUser> {
+    db.get_user_by_username(username.clone())
+        .await?
+        .with_context(|| format!("No user named {}", username))
+}
+
+/// Uses `--format` when given, else the file extension, else JSON.
+fn pick_format(format: Option<String>, path: Option<&PathBuf>) -> anyhow::Result<BundleFormat> {
+    if let Some(format) = format {
+        return BundleFormat::from_extension(&format)
+            .with_context(|| format!("Unknown format {}", format));
+    }
+    Ok(path
+        .and_then(|path| path.extension())
+        .and_then(|ext| ext.to_str())
+        .and_then(BundleFormat::from_extension)
+        .unwrap_or_default())
+}
+
+async fn list_users(db: &Database) -> anyhow::Result<()> {
+    let mut users: Vec<GeneralUser> = db
+        .get_all_general_users()
+        .await
+        .context("Failed to fetch users")?;
+    users.sort_by(|a, b| a.username.cmp(&b.username));
+    println!("{:<36}  {:<24}  {:<10}  NAME", "UUID", "USERNAME", "ROLE");
+    for user in users.iter() {
+        println!(
+            "{:<36}  {:<24}  {:<10}  {}",
+            user.uuid,
+            user.username,
+            user.role.to_string(),
+            user.name
+        );
+    }
+    println!("{} users", users.len());
+    Ok(())
+}
+
+async fn delete_user(db: &Database, username: String, confirmed: bool) -> anyhow::Result<()> {
+    let user: GeneralUser = find_user(db, username).await?;
+    if !confirmed {
+        println!(
+            "Would delete {} ({}) and all of their quizzes, questions, attempts, tags and likes.",
+            user.username, user.uuid
+        );
+        println!("Re-run with --yes to delete.");
+        return Ok(());
+    }
+    db.delete_user(&user.uuid).await?;
+    println!("Deleted {} ({})", user.username, user.uuid);
+    Ok(())
+}
+
+async fn reset_password(db: &Database, username: String) -> anyhow::Result<()> {
+    let user: GeneralUser = find_user(db, username).await?;
+
+    // Read from stdin, so the password stays out of shell history
+    eprintln!("New password for {}:", user.username);
+    let mut line: String = String::new();
+    std::io::stdin()
+        .read_line(&mut line)
+        .context("Failed to read password")?;
+    let password: Secret<String> = Secret::new(line.trim_end_matches(['\r', '\n']).to_string());
+    if password.expose_secret().len() < MIN_PASSWORD_LEN {
+        anyhow::bail!("Password must be at least {} characters", MIN_PASSWORD_LEN);
+    }
+
+    let password_hash: Secret<String> = create_password_hash(password)?;
+    db.client
+        .query("UPDATE $user SET password_hash = $password_hash")
+        .bind(("user", Thing::from(("general_user", user.uuid.as_str()))))
+        .bind(("password_hash", password_hash.expose_secret()))
+        .await?
+        .check()?;
+    // Anyone holding the old password is logged out
+    let logged_out: usize = db.delete_user_sessions(&user.uuid).await?;
+    println!(
+        "Password reset for {}, {} sessions logged out",
+        user.username, logged_out
+    );
+    Ok(())
+}
+
+async fn set_role(db: &Database, username: String, role: String) -> anyhow::Result<()> {
+    let role: Role = Role::from_name(&role)
+        .with_context(|| format!("Unknown role {}, use student, instructor or admin", role))?;
+    let user: GeneralUser = find_user(db, username).await?;
+    db.set_user_role(&user.uuid, role)
+        .await?
+        .context("User went missing")?;
+    println!("{} is now {} (was {})", user.username, role, user.role);
+    Ok(())
+}
+
+async fn export_quiz(
+    db: &Database,
+    quiz_id: String,
+    format: Option<String>,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let format: BundleFormat = pick_format(format, output.as_ref())?;
+    let quiz_id: Thing = thing(&quiz_id).context("Unable to parse quiz ID")?;
+    let quiz: SurrealQuiz = db
+        .client
+        .select(&quiz_id)
+        .await?
+        .context("Quiz does not exist")?;
+    let all_questions: AllQuestions = db.get_quiz_questions(&quiz.id).await?;
+
+    let bundle: QuizBundle = QuizBundle::new(&quiz, all_questions);
+    let written: Converted<String> = encode_bundle(&bundle, format)?;
+    for part in written.unmapped.iter() {
+        eprintln!("Not exported: {}", part);
+    }
+    match output {
+        Some(path) => {
+            std::fs::write(&path, written.value)
+                .with_context(|| format!("Unable to write {}", path.display()))?;
+            eprintln!("Exported {} to {}", quiz.name, path.display());
+        }
+        None => print!("{}", written.value),
+    }
+    Ok(())
+}
+
+async fn import_quiz(
+    db: &Database,
+    path: PathBuf,
+    owner: String,
+    format: Option<String>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let owner: GeneralUser = find_user(db, owner).await?;
+    let format: BundleFormat = pick_format(format, Some(&path))?;
+    let text: String = std::fs::read_to_string(&path)
+        .with_context(|| format!("Unable to read {}", path.display()))?;
+
+    let Converted {
+        value: bundle,
+        unmapped,
+    }: Converted<QuizBundle> = decode_bundle(&text, format)?;
+    bundle.validate_header()?;
+    for part in unmapped.iter() {
+        eprintln!("Not imported: {}", part);
+    }
+
+    // Same checks as the import endpoint
+    let quiz_id: Thing = Thing::from(("quizzes", Id::rand()));
+    let (quiz_pkg, packages): (QuizJsonPkg, Vec<QuestionJsonPkg>) = bundle.into_packages(&quiz_id);
+    let report: ImportReport = ImportReport::check(dry_run, &quiz_pkg, &packages);
+    if let Some(err) = &report.quiz_error {
+        eprintln!("Quiz: {}", err);
+    }
+    for item in report.items.iter() {
+        if let Some(err) = &item.error {
+            eprintln!("Question {} ({}): {}", item.index + 1, item.question, err);
+        }
+    }
+    if !report.is_valid() {
+        anyhow::bail!("Nothing imported, fix the problems above first");
+    }
+    if dry_run {
+        println!("All {} questions are ready to import", report.items.len());
+        return Ok(());
+    }
+
+    let quiz: Quiz = quiz_pkg.into_record(owner.uuid);
+    db.create_quiz_with_questions(&quiz_id, &quiz, packages)
+        .await?;
+    println!(
+        "Imported {} as {} with {} questions for {}",
+        quiz.name,
+        quiz_id,
+        report.items.len(),
+        owner.username
+    );
+    Ok(())
+}
+
+async fn print_stats(db: &Database) -> anyhow::Result<()> {
+    let tables = ["general_user", "sessions", "quizzes"]
+        .into_iter()
+        .chain(QUESTION_TABLES)
+        .chain(["attempts", "review_cards", "tags", "tagged", "likes"])
+        .chain(["groups", "memberships", "assignments"]);
+    for table in tables {
+        println!("{:<14} {:>8}", table, db.count_records(table).await?);
+    }
+    Ok(())
+}
@@ -0,0 +1,255 @@
+//! backend/src/bundle_format.rs
+//! Reading and writing quiz bundles as JSON, YAML, GIFT or Moodle XML.
+//! YAML goes through `serde_json::Value`, so both formats share the serde layout of `QuizBundle`.
+//! GIFT and Moodle XML go through `models::interchange`, which reports what they cannot hold.
+use anyhow::Context;
+use models::{
+    bundle::{BundleFormat, QuizBundle, BUNDLE_VERSION},
+    interchange::{gift, moodle_xml, QuestionBank, Unmapped, WrittenBank},
+    quiz::QuizJsonPkg,
+};
+use serde_json::{Map, Number, Value};
+use yaml_rust::{yaml::Hash, Yaml, YamlEmitter, YamlLoader};
+
+/// Quiz name for question banks that do not name a category.
+pub const DEFAULT_IMPORT_NAME: &str = "Imported Questions";
+
+/// A converted bundle, with anything the other format could not hold.
+#[derive(Debug)]
+pub struct Converted<T> {
+    pub value: T,
+    pub unmapped: Vec<Unmapped>,
+}
+
+impl<T> Converted<T> {
+    fn complete(value: T) -> Self {
+        Self {
+            value,
+            unmapped: Vec::new(),
+        }
+    }
+}
+
+/// Writes a bundle out in the chosen format.
+pub fn encode_bundle(
+    bundle: &QuizBundle,
+    format: BundleFormat,
+) -> anyhow::Result<Converted<String>> {
+    let written: WrittenBank = match format {
+        BundleFormat::Json => {
+            return serde_json::to_string_pretty(bundle)
+                .map(Converted::complete)
+                .context("Unable to write bundle as JSON")
+        }
+        BundleFormat::Yaml => {
+            let value: Value = serde_json::to_value(bundle).context("Unable to read bundle")?;
+            let mut out: String = String::new();
+            YamlEmitter::new(&mut out)
+                .dump(&json_to_yaml(value))
+                .context("Unable to write bundle as YAML")?;
+            out.push('\n');
+            return Ok(Converted::complete(out));
+        }
+        BundleFormat::Gift => gift::write(&bundle.quiz.name, &bundle.questions),
+        BundleFormat::MoodleXml => moodle_xml::write(&bundle.quiz.name, &bundle.questions),
+    };
+    Ok(Converted {
+        value: written.text,
+        unmapped: written.unmapped,
+    })
+}
+
+/// Reads a bundle written in the chosen format.
+pub fn decode_bundle(text: &str, format: BundleFormat) -> anyhow::Result<Converted<QuizBundle>> {
+    let value: Value = match format {
+        BundleFormat::Json => serde_json::from_str(text).context("Bundle is not valid JSON")?,
+        BundleFormat::Yaml => {
+            let mut docs: Vec<Yaml> =
+                YamlLoader::load_from_str(text).context("Bundle is not valid YAML")?;
+            if docs.len() != 1 {
+                anyhow::bail!("Bundle must be a single YAML document");
+            }
+            yaml_to_json(docs.remove(0))?
+        }
+        BundleFormat::Gift | BundleFormat::MoodleXml => {
+            let bank: QuestionBank = if format == BundleFormat::Gift {
+                gift::read(text)
+            } else {
+                moodle_xml::read(text)
+            }
+            .map_err(|err| anyhow::anyhow!(err.to_string()))
+            .with_context(|| format!("Bundle is not valid {}", format.describe()))?;
+            return Ok(Converted {
+                value: QuizBundle {
+                    version: BUNDLE_VERSION,
+                    quiz: QuizJsonPkg {
+                        name: bank
+                            .category
+                            .unwrap_or_else(|| String::from(DEFAULT_IMPORT_NAME)),
+                        description: format!("Imported from {}", format.describe()),
+                        visibility: None,
+                        time_limit_secs: None,
+                        time_per_question_secs: None,
+                        pools: None,
+                        hint_penalty: None,
+                    },
+                    questions: bank.questions,
+                },
+                unmapped: bank.unmapped,
+            });
+        }
+    };
+    serde_json::from_value(value)
+        .map(Converted::complete)
+        .context("Bundle does not match the bundle format")
+}
+
+fn json_to_yaml(value: Value) -> Yaml {
+    match value {
+        Value::Null => Yaml::Null,
+        Value::Bool(b) => Yaml::Boolean(b),
+        Value::Number(num) => match num.as_i64() {
+            Some(int) => Yaml::Integer(int),
+            None => Yaml::Real(num.to_string()),
+        },
+        Value::String(text) => Yaml::String(text),
+        Value::Array(list) => Yaml::Array(list.into_iter().map(json_to_yaml).collect()),
+        Value::Object(map) => {
+            let mut hash: Hash = Hash::new();
+            for (key, val) in map {
+                hash.insert(Yaml::String(key), json_to_yaml(val));
+            }
+            Yaml::Hash(hash)
+        }
+    }
+}
+
+fn yaml_to_json(yaml: Yaml) -> anyhow::Result<Value> {
+    Ok(match yaml {
+        Yaml::Null => Value::Null,
+        Yaml::Boolean(b) => Value::Bool(b),
+        Yaml::Integer(int) => Value::Number(int.into()),
+        Yaml::Real(text) => {
+            let real: f64 = text
+                .parse()
+                .with_context(|| format!("Unable to read number {}", text))?;
+            Number::from_f64(real)
+                .map(Value::Number)
+                .with_context(|| format!("Number {} is out of range", text))?
+        }
+        Yaml::String(text) => Value::String(text),
+        Yaml::Array(list) => Value::Array(
+            list.into_iter()
+                .map(yaml_to_json)
+                .collect::<anyhow::Result<Vec<Value>>>()?,
+        ),
+        Yaml::Hash(hash) => {
+            let mut map: Map<String, Value> = Map::new();
+            for (key, val) in hash {
+                let key: String = match key {
+                    Yaml::String(text) | Yaml::Real(text) => text,
+                    Yaml::Integer(int) => int.to_string(),
+                    Yaml::Boolean(b) => b.to_string(),
+                    _ => anyhow::bail!("YAML keys must be plain text"),
+                };
+                map.insert(key, yaml_to_json(val)?);
+            }
+            Value::Object(map)
+        }
+        Yaml::Alias(_) | Yaml::BadValue => anyhow::bail!("Unsupported YAML value"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle_json() -> Value {
+        serde_json::json!({
+            "version": 1,
+            "quiz": {
+                "name": "Algorithms",
+                "description": "Sorting: the basics",
+                "visibility": "Public"
+            },
+            "questions": [
+                { "ShortAnswer": {
+                    "question": "What is 6 x 7?",
+                    "hint": null,
+                    "answers": ["42", "true", "forty two"]
+                }},
+                { "TrueFalse": {
+                    "question": "Merge sort is stable\nin every case",
+                    "hint": "Think about ties",
+                    "answer": true
+                }}
+            ]
+        })
+    }
+
+    #[test]
+    fn yaml_round_trip_keeps_every_field() {
+        // Assign
+        let bundle: QuizBundle = serde_json::from_value(bundle_json()).unwrap();
+
+        // Act
+        let yaml: String = encode_bundle(&bundle, BundleFormat::Yaml).unwrap().value;
+        let read_back: QuizBundle = decode_bundle(&yaml, BundleFormat::Yaml).unwrap().value;
+
+        // Assert
+        assert_eq!(serde_json::to_value(&read_back).unwrap(), bundle_json());
+    }
+
+    #[test]
+    fn hand_written_yaml_is_read() {
+        // Assign
+        let yaml: &str = "version: 1
+quiz:
+  name: Graphs
+  description: Paths and trees
+questions:
+  - MultipleChoice:
+      question: Shortest path with negative edges?
+      hint: ~
+      answer: Bellman-Ford
+      choices:
+        - Dijkstra
+";
+
+        // Act
+        let bundle: QuizBundle = decode_bundle(yaml, BundleFormat::Yaml).unwrap().value;
+
+        // Assert
+        assert_eq!(bundle.quiz.name, "Graphs");
+        assert!(bundle.quiz.visibility.is_none());
+        assert_eq!(bundle.questions[0].table_name(), "questions_mc");
+    }
+
+    #[test]
+    fn gift_bundles_named_after_category() {
+        // Assign
+        let gift_text: &str = "$CATEGORY: Sorting\n\nMerge sort is stable {T}\n\nMatch {=a -> b}\n";
+
+        // Act
+        let converted: Converted<QuizBundle> =
+            decode_bundle(gift_text, BundleFormat::Gift).unwrap();
+        let written: Converted<String> =
+            encode_bundle(&converted.value, BundleFormat::MoodleXml).unwrap();
+
+        // Assert
+        assert_eq!(converted.value.quiz.name, "Sorting");
+        assert_eq!(converted.value.questions.len(), 1);
+        assert_eq!(converted.unmapped.len(), 1);
+        assert!(converted.unmapped[0].skipped);
+        assert!(written.value.contains("<question type=\"truefalse\">"));
+        assert!(written.unmapped.is_empty());
+    }
+
+    #[test]
+    fn malformed_bundles_rejected() {
+        assert!(decode_bundle("{ not json", BundleFormat::Json).is_err());
+        assert!(decode_bundle("version: [1", BundleFormat::Yaml).is_err());
+        assert!(decode_bundle("version: 1\n", BundleFormat::Yaml).is_err());
+        assert!(decode_bundle("<quiz>", BundleFormat::MoodleXml).is_err());
+    }
+}
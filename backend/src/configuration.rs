@@ -52,6 +52,19 @@ pub struct ApplicationSettings {
     pub hmac_secret: Secret<String>,
 }
 
+/// Where SurrealDB keeps its data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseEngine {
+    /// A SurrealDB server, reached over WebSocket at `host` and `port`
+    #[default]
+    Remote,
+    /// Embedded in the backend, stored with RocksDB at `path`
+    RocksDb,
+    /// Embedded in the backend, stored with SurrealKV at `path`
+    SurrealKv,
+}
+
 /// Struct to hold information regarding the database
 #[derive(Debug, Clone, Deserialize)]
 pub struct DatabaseSettings {
@@ -62,6 +75,52 @@ pub struct DatabaseSettings {
     pub password: String,
     pub namespace: String,
     pub name: String,
+    // keeps everything in memory instead, nothing is saved when the server stops
+    #[serde(default)]
+    pub in_memory: bool,
+    // servers from before embedding connect remotely
+    #[serde(default)]
+    pub engine: DatabaseEngine,
+    // folder the embedded engines store data in, unused by a remote server
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+}
+
+impl DatabaseSettings {
+    /// The address SurrealDB is opened at, e.g. `ws://127.0.0.1:8000` or `surrealkv://data/quiz_app`.
+    pub fn endpoint(&self) -> Result<String, ConfigError> {
+        let scheme: &str = match self.engine {
+            DatabaseEngine::Remote => return Ok(format!("ws://{}:{}", self.host, self.port)),
+            DatabaseEngine::RocksDb => "rocksdb",
+            DatabaseEngine::SurrealKv => "surrealkv",
+        };
+        let path: &PathBuf = self.path.as_ref().ok_or_else(|| {
+            ConfigError::NotFound(String::from(
+                "`database.path` is required by an embedded engine",
+            ))
+        })?;
+        Ok(format!("{}://{}", scheme, path.display()))
+    }
+}
+
+/// How long deleted quizzes and questions wait in the trash before they are purged.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TrashSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub retention_days: u32,
+    // how often to look for anything past retention
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub purge_interval_mins: u64,
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        Self {
+            retention_days: 30,
+            purge_interval_mins: 60,
+        }
+    }
 }
 
 /// Struct for holding all settings for a convenient means of passing
@@ -70,6 +129,9 @@ pub struct DatabaseSettings {
 pub struct AllSettings {
     pub application: ApplicationSettings,
     pub database: DatabaseSettings,
+    // configuration files from before the trash use the defaults
+    #[serde(default)]
+    pub trash: TrashSettings,
 }
 
 /// Function to read from configuration files and create a `Settings` struct
@@ -134,3 +196,37 @@ pub fn get_configuration() -> Result<AllSettings, ConfigError> {
     // Try deserialize values into struct
     settings.try_deserialize::<AllSettings>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(engine: DatabaseEngine, path: Option<&str>) -> DatabaseSettings {
+        DatabaseSettings {
+            port: 8000,
+            host: String::from("127.0.0.1"),
+            username: String::from("user"),
+            password: String::from("password"),
+            namespace: String::from("surreal"),
+            name: String::from("quiz_app"),
+            in_memory: false,
+            engine,
+            path: path.map(PathBuf::from),
+        }
+    }
+
+    #[test]
+    fn engine_picks_endpoint() {
+        // Assign
+        let remote = settings(DatabaseEngine::Remote, Some("ignored"));
+        let embedded = settings(DatabaseEngine::SurrealKv, Some("data/quiz_app"));
+        let rocks = settings(DatabaseEngine::RocksDb, Some("data/quiz_app"));
+        let no_path = settings(DatabaseEngine::SurrealKv, None);
+
+        // Act & Assert
+        assert_eq!(remote.endpoint().unwrap(), "ws://127.0.0.1:8000");
+        assert_eq!(embedded.endpoint().unwrap(), "surrealkv://data/quiz_app");
+        assert_eq!(rocks.endpoint().unwrap(), "rocksdb://data/quiz_app");
+        assert!(no_path.endpoint().is_err());
+    }
+}
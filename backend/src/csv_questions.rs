@@ -0,0 +1,220 @@
+//! backend/src/csv_questions.rs
+//! Reading multiple choice questions from a CSV spreadsheet.
+//! Columns are matched by header name, ignoring case, so they may come in any order.
+use models::{
+    csv_upload::{CsvRowError, CSV_COLUMNS, MAX_CSV_ROWS},
+    questions::{JsonQuestion, JsonQuestionMC},
+};
+
+/// Where each column sits in a row.
+struct Columns {
+    question: usize,
+    answer: usize,
+    hint: Option<usize>,
+    // sorted by choice number
+    choices: Vec<usize>,
+    width: usize,
+}
+
+impl Columns {
+    fn from_headers(headers: &csv::StringRecord) -> Result<Self, CsvRowError> {
+        let header_error = |error: String| CsvRowError { row: 1, error };
+        let (mut question, mut answer, mut hint) = (None, None, None);
+        let mut choices: Vec<(u32, usize)> = Vec::new();
+
+        for (at, name) in headers.iter().enumerate() {
+            let name: String = name.trim().to_lowercase();
+            let slot: &mut Option<usize> = match name.as_str() {
+                "question" => &mut question,
+                "answer" => &mut answer,
+                "hint" => &mut hint,
+                _ => {
+                    match name
+                        .strip_prefix("choice")
+                        .and_then(|num| num.parse::<u32>().ok())
+                    {
+                        Some(num) if choices.iter().any(|(seen, _)| *seen == num) => {
+                            return Err(header_error(format!("Column {} appears twice", name)));
+                        }
+                        Some(num) => choices.push((num, at)),
+                        None => {
+                            return Err(header_error(format!(
+                                "Unknown column '{}', expected {} or choice1, choice2, ...",
+                                name,
+                                CSV_COLUMNS.join(", ")
+                            )))
+                        }
+                    }
+                    continue;
+                }
+            };
+            if slot.replace(at).is_some() {
+                return Err(header_error(format!("Column {} appears twice", name)));
+            }
+        }
+
+        choices.sort_unstable();
+        match (question, answer) {
+            (Some(question), Some(answer)) if !choices.is_empty() => Ok(Self {
+                question,
+                answer,
+                hint,
+                choices: choices.into_iter().map(|(_, at)| at).collect(),
+                width: headers.len(),
+            }),
+            (Some(_), Some(_)) => Err(header_error(String::from(
+                "Needs at least one choice column, e.g. choice1",
+            ))),
+            _ => Err(header_error(String::from(
+                "Needs both a question and an answer column",
+            ))),
+        }
+    }
+
+    fn question(&self, record: &csv::StringRecord) -> JsonQuestion {
+        let cell = |at: usize| record.get(at).unwrap_or_default().trim().to_string();
+        JsonQuestion::MultipleChoice(JsonQuestionMC {
+            question: cell(self.question),
+            hint: self.hint.map(cell).filter(|hint| !hint.is_empty()),
+            answer: cell(self.answer),
+            // Blank cells are expected when rows have different numbers of choices
+            choices: self
+                .choices
+                .iter()
+                .map(|at| cell(*at))
+                .filter(|choice| !choice.is_empty())
+                .collect(),
+        })
+    }
+}
+
+/// Reads every row as a multiple choice question.
+/// Any problem fails the whole batch, with every row that has a problem listed.
+pub fn read_csv_questions(text: &str) -> Result<Vec<JsonQuestion>, Vec<CsvRowError>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(text.as_bytes());
+    let columns: Columns = reader
+        .headers()
+        .map_err(|err| {
+            vec![CsvRowError {
+                row: 1,
+                error: err.to_string(),
+            }]
+        })
+        .and_then(|headers| Columns::from_headers(headers).map_err(|err| vec![err]))?;
+
+    let mut questions: Vec<JsonQuestion> = Vec::new();
+    let mut errors: Vec<CsvRowError> = Vec::new();
+    for result in reader.records() {
+        let record: csv::StringRecord = match result {
+            Ok(record) => record,
+            Err(err) => {
+                let row: u64 = err.position().map(|pos| pos.line()).unwrap_or_default();
+                errors.push(CsvRowError {
+                    row,
+                    error: err.to_string(),
+                });
+                continue;
+            }
+        };
+        // Spreadsheets often save trailing rows of empty cells
+        if record.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        let row: u64 = record.position().map(|pos| pos.line()).unwrap_or_default();
+        if record.len() > columns.width {
+            errors.push(CsvRowError {
+                row,
+                error: String::from("Row has more cells than there are columns"),
+            });
+            continue;
+        }
+        let question: JsonQuestion = columns.question(&record);
+        match question.validate_fields() {
+            Ok(()) => questions.push(question),
+            Err(err) => errors.push(CsvRowError {
+                row,
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    if questions.is_empty() && errors.is_empty() {
+        errors.push(CsvRowError {
+            row: 2,
+            error: String::from("No questions found below the header"),
+        });
+    } else if questions.len() + errors.len() > MAX_CSV_ROWS {
+        errors.push(CsvRowError {
+            row: MAX_CSV_ROWS as u64 + 2,
+            error: format!("Uploads cannot have more than {} rows", MAX_CSV_ROWS),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(questions)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_read_in_any_order() {
+        // Assign
+        let text: &str = "Hint,Choice2,question,choice1,answer
+,Dijkstra,Shortest path with negative edges?,,Bellman-Ford
+\"Think of ties, and order\",Quick,Which sort is stable?,Heap,Merge
+,,,,
+";
+
+        // Act
+        let questions: Vec<JsonQuestion> = read_csv_questions(text).unwrap();
+
+        // Assert
+        assert_eq!(questions.len(), 2);
+        let JsonQuestion::MultipleChoice(second) = &questions[1] else {
+            panic!("Expected a multiple choice question");
+        };
+        assert_eq!(second.answer, "Merge");
+        assert_eq!(second.choices, vec!["Heap", "Quick"]);
+        assert_eq!(second.hint.as_deref(), Some("Think of ties, and order"));
+        let JsonQuestion::MultipleChoice(first) = &questions[0] else {
+            panic!("Expected a multiple choice question");
+        };
+        assert_eq!(first.choices, vec!["Dijkstra"]);
+        assert!(first.hint.is_none());
+    }
+
+    #[test]
+    fn every_bad_row_reported() {
+        // Assign
+        let text: &str = "question,answer,choice1,choice2,hint
+What is 6 x 7?,42,41,43,
+,Merge,Heap,,
+Largest planet?,,Mars,Venus,
+";
+
+        // Act
+        let errors: Vec<CsvRowError> = read_csv_questions(text).unwrap_err();
+
+        // Assert
+        let rows: Vec<u64> = errors.iter().map(|err| err.row).collect();
+        assert_eq!(rows, vec![3, 4]);
+    }
+
+    #[test]
+    fn bad_headers_rejected() {
+        assert_eq!(
+            read_csv_questions("question,answer,choice1,notes\n").unwrap_err()[0].row,
+            1
+        );
+        assert!(read_csv_questions("question,choice1\nA?,B\n").is_err());
+        assert!(read_csv_questions("question,answer\nA?,B\n").is_err());
+        assert!(read_csv_questions("question,answer,choice1\n").is_err());
+    }
+}
@@ -1,6 +1,11 @@
 //! backend/src/lib.rs
 pub mod authentication;
+pub mod bundle_format;
 pub mod configuration;
+pub mod csv_questions;
+pub mod memory_repo;
+pub mod migrations;
+pub mod repository;
 pub mod routes;
 pub mod session_wrapper;
 pub mod startup;
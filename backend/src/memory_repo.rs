@@ -0,0 +1,1719 @@
+//! backend/src/memory_repo.rs
+//! A storage backend that keeps everything in memory, for running and testing without SurrealDB.
+//! Records are kept as JSON under their SurrealDB style IDs, so they come back in the same shapes.
+//! Nothing is saved when the server stops.
+use crate::{
+    repository::{
+        AttemptRepo, GroupRepo, LookUpUser, QuestionRepo, QuizRepo, RevisionRepo, SessionRepo,
+        StudyRepo, TagRepo, TrashRepo,
+    },
+    surrealdb_repo::{generate_session_key, generate_time_stamp, SessionState},
+};
+use actix_session::storage::{LoadError, SaveError, SessionKey, SessionStore, UpdateError};
+use actix_web::cookie::time::Duration;
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use models::{
+    attempts::{Attempt, QuestionTiming, SurrealAttempt},
+    groups::{
+        AssignedQuiz, Assignment, Group, Membership, SurrealAssignment, SurrealGroup,
+        SurrealMembership,
+    },
+    likes::{LikeCount, LikeTally},
+    questions::{
+        AllQuestions, JsonQuestion, QuestionJsonPkg, SurrealGenericQuestionData, SurrealQuestionLA,
+        SurrealQuestionMC, SurrealQuestionMS, SurrealQuestionSA, SurrealQuestionTF,
+        QUESTION_TABLES,
+    },
+    quiz::{Quiz, QuizDeletion, QuizJsonPkg, SurrealQuiz, Visibility},
+    revisions::{Edit, Pin, Revision, Snapshot, SurrealRevision},
+    roles::Role,
+    search::{snippet, SearchHit, SearchKind, HIGHLIGHT_END, HIGHLIGHT_START, SNIPPET_CONTEXT},
+    study::{ReviewCard, SurrealReviewCard},
+    tags::{SurrealTag, Tag, TagIndex, TagJsonPkg, TagLink, TagSummary},
+    trash::{TrashItem, TrashPurge},
+    GeneralUser, PartialUser,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use surrealdb::sql::{Datetime, Id, Thing};
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryDatabase {
+    tables: Arc<RwLock<MemoryTables>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryTables {
+    // every record, ordered by table then ID
+    records: BTreeMap<Thing, Value>,
+    sessions: HashMap<String, MemorySession>,
+}
+
+#[derive(Debug, Clone)]
+struct MemorySession {
+    state: SessionState,
+    expiry: DateTime<Utc>,
+}
+
+impl MemoryDatabase {
+    fn read(&self) -> anyhow::Result<RwLockReadGuard<'_, MemoryTables>> {
+        self.tables
+            .read()
+            .map_err(|_| anyhow::anyhow!("Memory database lock poisoned"))
+    }
+
+    fn write(&self) -> anyhow::Result<RwLockWriteGuard<'_, MemoryTables>> {
+        self.tables
+            .write()
+            .map_err(|_| anyhow::anyhow!("Memory database lock poisoned"))
+    }
+
+    /// helper function to save a record under a new random ID in `table`.
+    fn create<T, R>(&self, table: &str, content: &T) -> anyhow::Result<R>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        let id: Thing = Thing::from((table, Id::rand()));
+        self.create_with_id(id, content)
+    }
+
+    /// helper function to save a record under a known ID, replacing any record already there.
+    fn create_with_id<T, R>(&self, id: Thing, content: &T) -> anyhow::Result<R>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        let record: Value = record_of(&id, content)?;
+        self.write()?.records.insert(id, record.clone());
+        serde_json::from_value(record).context("Failed to deserialize record")
+    }
+
+    /// helper function to overwrite a record that passes `keep`, leaving its ID alone.
+    /// `None` when there is no such record, or it was not kept.
+    fn replace_if<T, R>(
+        &self,
+        id: &Thing,
+        content: &T,
+        keep: impl Fn(&Value) -> bool,
+    ) -> anyhow::Result<Option<R>>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        let replacement: Value = record_of(id, content)?;
+        let mut tables = self.write()?;
+        let Some(record) = tables.records.get_mut(id).filter(|record| keep(record)) else {
+            return Ok(None);
+        };
+        *record = replacement;
+        serde_json::from_value(record.clone())
+            .map(Some)
+            .context("Failed to deserialize record")
+    }
+
+    /// helper function to fetch a single record, unless it is in the trash.
+    fn select<R: DeserializeOwned>(&self, id: &Thing) -> anyhow::Result<Option<R>> {
+        self.read()?
+            .records
+            .get(id)
+            .filter(|record| !is_trashed(record))
+            .cloned()
+            .map(|record| serde_json::from_value(record).context("Failed to deserialize record"))
+            .transpose()
+    }
+
+    /// helper function to fetch every record of a table that is not in the trash.
+    fn select_table<R: DeserializeOwned>(&self, table: &str) -> anyhow::Result<Vec<R>> {
+        self.read()?
+            .records
+            .iter()
+            .filter(|(id, record)| id.tb == table && !is_trashed(record))
+            .map(|(_, record)| {
+                serde_json::from_value(record.clone()).context("Failed to deserialize record")
+            })
+            .collect()
+    }
+
+    /// helper function to overwrite the fields in `changes`, leaving the rest of the record alone.
+    fn merge<T, R>(&self, id: &Thing, changes: &T) -> anyhow::Result<Option<R>>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        self.merge_revised(id, changes, None)
+    }
+
+    /// `merge` that also saves `revision` as the record's next revision, under the same write lock.
+    fn merge_revised<T, R>(
+        &self,
+        id: &Thing,
+        changes: &T,
+        revision: Option<Edit>,
+    ) -> anyhow::Result<Option<R>>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        let changes: Value = serde_json::to_value(changes).context("Failed to serialize")?;
+        let changes = changes.as_object().context("Changes must be objects")?;
+        let mut tables = self.write()?;
+        let Some(record) = tables.records.get_mut(id) else {
+            return Ok(None);
+        };
+        let fields = record.as_object_mut().context("Records must be objects")?;
+        for (field, value) in changes {
+            fields.insert(field.clone(), value.clone());
+        }
+        let merged: Value = record.clone();
+        if let Some(edit) = revision {
+            add_revision(&mut tables, id, &edit.author_id, edit.snapshot)?;
+        }
+        serde_json::from_value(merged)
+            .map(Some)
+            .context("Failed to deserialize record")
+    }
+
+    /// helper function to delete a single record, returning what was there.
+    fn delete<R: DeserializeOwned>(&self, id: &Thing) -> anyhow::Result<Option<R>> {
+        self.write()?
+            .records
+            .remove(id)
+            .map(|record| serde_json::from_value(record).context("Failed to deserialize record"))
+            .transpose()
+    }
+
+    /// helper function to mark a record as trashed, handing back the record.
+    /// `None` when there is no such record, or it is already trashed.
+    fn trash(&self, id: &Thing) -> anyhow::Result<Option<Value>> {
+        let deleted_at: Value =
+            serde_json::to_value(Datetime::default()).context("Failed to serialize time")?;
+        let mut tables = self.write()?;
+        let Some(record) = tables.records.get_mut(id) else {
+            return Ok(None);
+        };
+        if is_trashed(record) {
+            return Ok(None);
+        }
+        let fields = record.as_object_mut().context("Records must be objects")?;
+        fields.insert(String::from("deleted_at"), deleted_at);
+        Ok(Some(record.clone()))
+    }
+
+    /// helper function to relate `from` to `to` through `table`, or to unrelate them.
+    /// Holds the write lock while checking, so the two are never related twice.
+    fn set_relation(
+        &self,
+        table: &str,
+        from: &Thing,
+        to: &Thing,
+        related: bool,
+    ) -> anyhow::Result<()> {
+        let from_at: Value = serde_json::to_value(from).context("Failed to serialize ID")?;
+        let to_at: Value = serde_json::to_value(to).context("Failed to serialize ID")?;
+        let mut tables = self.write()?;
+        let existing: Vec<Thing> = tables
+            .records
+            .iter()
+            .filter(|(id, record)| {
+                id.tb == table
+                    && record.get("in") == Some(&from_at)
+                    && record.get("out") == Some(&to_at)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        if !related {
+            for id in existing {
+                tables.records.remove(&id);
+            }
+        } else if existing.is_empty() {
+            let id: Thing = Thing::from((table, Id::rand()));
+            let relation: Relation = Relation {
+                from: from.clone(),
+                to: to.clone(),
+                related_at: Datetime::default(),
+            };
+            let record: Value = record_of(&id, &relation)?;
+            tables.records.insert(id, record);
+        }
+        Ok(())
+    }
+
+    /// helper function to fetch the questions of every type that pass `keep`,
+    /// which is given each question's ID and quiz. Trashed questions are only kept `with_trashed`.
+    fn select_questions(
+        &self,
+        with_trashed: bool,
+        keep: impl Fn(&Thing, &Thing) -> bool,
+    ) -> anyhow::Result<AllQuestions> {
+        fn of_type<Q: DeserializeOwned>(
+            tables: &MemoryTables,
+            table: &str,
+            with_trashed: bool,
+            keep: &impl Fn(&Thing, &Thing) -> bool,
+        ) -> anyhow::Result<Vec<Q>> {
+            let mut questions: Vec<Q> = Vec::new();
+            for (id, record) in tables
+                .records
+                .iter()
+                .filter(|(id, record)| id.tb == table && (with_trashed || !is_trashed(record)))
+            {
+                let generic: SurrealGenericQuestionData = serde_json::from_value(record.clone())
+                    .context("Failed to deserialize record")?;
+                if keep(id, &generic.parent_quiz) {
+                    questions.push(
+                        serde_json::from_value(record.clone())
+                            .context("Failed to deserialize record")?,
+                    );
+                }
+            }
+            Ok(questions)
+        }
+
+        let tables = self.read()?;
+        Ok(AllQuestions {
+            mc: of_type(&tables, "questions_mc", with_trashed, &keep)?,
+            sa: of_type(&tables, "questions_sa", with_trashed, &keep)?,
+            la: of_type(&tables, "questions_la", with_trashed, &keep)?,
+            tf: of_type(&tables, "questions_tf", with_trashed, &keep)?,
+            ms: of_type(&tables, "questions_ms", with_trashed, &keep)?,
+        })
+    }
+}
+
+/// Trashed records keep their fields, with the time they were trashed alongside.
+fn is_trashed(record: &Value) -> bool {
+    record.get("deleted_at").is_some_and(|at| !at.is_null())
+}
+
+/// helper function to turn `content` into a record stored under `id`.
+fn record_of<T: Serialize>(id: &Thing, content: &T) -> anyhow::Result<Value> {
+    let mut record: Value = serde_json::to_value(content).context("Failed to serialize")?;
+    let fields = record.as_object_mut().context("Records must be objects")?;
+    fields.insert(
+        String::from("id"),
+        serde_json::to_value(id).context("Failed to serialize ID")?,
+    );
+    Ok(record)
+}
+
+/// helper function to save `snapshot` as the next revision of `target`, numbered after its latest.
+fn add_revision(
+    tables: &mut MemoryTables,
+    target: &Thing,
+    author_id: &str,
+    snapshot: Snapshot,
+) -> anyhow::Result<Value> {
+    let at: Value = serde_json::to_value(target).context("Failed to serialize ID")?;
+    let latest: u64 = tables
+        .records
+        .iter()
+        .filter(|(id, record)| id.tb == "revisions" && record.get("target") == Some(&at))
+        .filter_map(|(_, record)| record.get("number").and_then(Value::as_u64))
+        .max()
+        .unwrap_or_default();
+
+    let revision: Revision = Revision {
+        target: target.clone(),
+        number: latest as u32 + 1,
+        author_id: String::from(author_id),
+        created_at: Datetime::default(),
+        snapshot,
+    };
+    let id: Thing = Thing::from(("revisions", Id::rand()));
+    let record: Value = record_of(&id, &revision)?;
+    tables.records.insert(id, record.clone());
+    Ok(record)
+}
+
+/// helper function to list a trashed quiz or question, `None` when the record is not in the trash.
+fn trash_item(record: &Value) -> anyhow::Result<Option<TrashItem>> {
+    if !is_trashed(record) {
+        return Ok(None);
+    }
+    // Quizzes have a name, questions have the question
+    let title: Option<&Value> = record.get("name").or_else(|| record.get("question"));
+    let item: Value = serde_json::json!({
+        "id": record.get("id"),
+        "title": title,
+        "author_id": record.get("author_id"),
+        "parent_quiz": record.get("parent_quiz"),
+        "deleted_at": record.get("deleted_at"),
+    });
+    serde_json::from_value(item)
+        .map(Some)
+        .context("Failed to deserialize trash item")
+}
+
+/// helper function to delete the records of `table` whose `field` points at any of `targets`,
+/// returning how many went.
+fn remove_pointing(
+    tables: &mut MemoryTables,
+    table: &str,
+    field: &str,
+    targets: &[Thing],
+) -> anyhow::Result<usize> {
+    let targets: Vec<Value> = targets
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<_, _>>()
+        .context("Failed to serialize ID")?;
+    let before: usize = tables.records.len();
+    tables.records.retain(|id, record| {
+        id.tb != table || !record.get(field).is_some_and(|at| targets.contains(at))
+    });
+    Ok(before - tables.records.len())
+}
+
+/// A `likes` or `tagged` relation, stored the way SurrealDB relates records.
+#[derive(Debug, Serialize, Deserialize)]
+struct Relation {
+    #[serde(rename = "in")]
+    from: Thing,
+    #[serde(rename = "out")]
+    to: Thing,
+    related_at: Datetime,
+}
+
+/// Quizzes and questions both record their author.
+#[derive(Debug, Deserialize)]
+struct Authored {
+    author_id: String,
+}
+
+/// helper function to wrap every match of the search words in highlight markers, ignoring ASCII case.
+/// `None` when no word matched, otherwise also hands back how many matches there were.
+fn highlight(text: &str, words: &[String]) -> Option<(String, usize)> {
+    let lowered: String = text.to_ascii_lowercase();
+    let mut hits: Vec<(usize, usize)> = Vec::new();
+    for word in words {
+        hits.extend(
+            lowered
+                .match_indices(word.as_str())
+                .map(|(start, found)| (start, start + found.len())),
+        );
+    }
+    if hits.is_empty() {
+        return None;
+    }
+    hits.sort();
+
+    let mut highlighted: String = String::with_capacity(text.len());
+    let mut done: usize = 0;
+    for (start, end) in hits.iter() {
+        // overlapping matches are marked once
+        if *start < done {
+            continue;
+        }
+        highlighted.push_str(&text[done..*start]);
+        highlighted.push_str(HIGHLIGHT_START);
+        highlighted.push_str(&text[*start..*end]);
+        highlighted.push_str(HIGHLIGHT_END);
+        done = *end;
+    }
+    highlighted.push_str(&text[done..]);
+    Some((highlighted, hits.len()))
+}
+
+impl LookUpUser for MemoryDatabase {
+    async fn get_user_by_username(
+        &self,
+        username: String,
+    ) -> Result<Option<GeneralUser>, anyhow::Error> {
+        let users: Vec<GeneralUser> = self.select_table("general_user")?;
+        Ok(users.into_iter().find(|user| user.username == username))
+    }
+
+    async fn get_user(&self, user_id: &str) -> Result<Option<GeneralUser>, anyhow::Error> {
+        self.select(&Thing::from(("general_user", user_id)))
+    }
+
+    async fn count_users(&self, username: &str) -> Result<i64, anyhow::Error> {
+        let users: Vec<GeneralUser> = self.select_table("general_user")?;
+        Ok(users
+            .iter()
+            .filter(|user| user.username == username)
+            .count() as i64)
+    }
+
+    async fn add_general_user(
+        &self,
+        new_general_user: GeneralUser,
+    ) -> Result<Option<GeneralUser>, anyhow::Error> {
+        let id: Thing = Thing::from(("general_user", new_general_user.uuid.as_str()));
+        self.create_with_id(id, &new_general_user).map(Some)
+    }
+
+    async fn set_user_role(
+        &self,
+        user_id: &str,
+        role: Role,
+    ) -> Result<Option<GeneralUser>, anyhow::Error> {
+        let id: Thing = Thing::from(("general_user", user_id));
+        self.merge(&id, &serde_json::json!({ "role": role }))
+    }
+}
+
+impl QuizRepo for MemoryDatabase {
+    async fn get_quiz(&self, quiz_id: &Thing) -> Result<Option<SurrealQuiz>, anyhow::Error> {
+        self.select(quiz_id)
+    }
+
+    async fn get_author_quizzes(
+        &self,
+        author_id: &str,
+        tag: Option<&Thing>,
+    ) -> Result<Vec<SurrealQuiz>, anyhow::Error> {
+        let tagged: Option<Vec<Thing>> = match tag {
+            None => None,
+            Some(tag) => Some(
+                self.select_table::<Relation>("tagged")?
+                    .into_iter()
+                    .filter(|link| &link.to == tag)
+                    .map(|link| link.from)
+                    .collect(),
+            ),
+        };
+        let quizzes: Vec<SurrealQuiz> = self.select_table("quizzes")?;
+        Ok(quizzes
+            .into_iter()
+            .filter(|qz| qz.author_id == author_id)
+            .filter(|qz| tagged.as_ref().is_none_or(|ids| ids.contains(&qz.id)))
+            .collect())
+    }
+
+    async fn create_quiz(&self, quiz: &Quiz) -> Result<SurrealQuiz, anyhow::Error> {
+        self.create("quizzes", quiz)
+    }
+
+    /// Every record is built before any is stored, then all go in under one write lock.
+    async fn create_quiz_with_questions(
+        &self,
+        quiz_id: &Thing,
+        quiz: &Quiz,
+        packages: Vec<QuestionJsonPkg>,
+    ) -> Result<SurrealQuiz, anyhow::Error> {
+        let mut records: Vec<(Thing, Value)> = vec![(quiz_id.clone(), record_of(quiz_id, quiz)?)];
+        for pkg in packages {
+            let id: Thing = Thing::from((pkg.question.table_name(), Id::rand()));
+            let question = pkg
+                .question
+                .into_record(quiz.author_id.clone(), pkg.quiz_id);
+            records.push((id.clone(), record_of(&id, &question)?));
+        }
+
+        let created: Value = records[0].1.clone();
+        self.write()?.records.extend(records);
+        serde_json::from_value(created).context("Failed to deserialize record")
+    }
+
+    async fn get_public_quizzes(
+        &self,
+        search: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<SurrealQuiz>, u64), anyhow::Error> {
+        let search: String = search.to_lowercase();
+        let mut quizzes: Vec<SurrealQuiz> = self.select_table("quizzes")?;
+        quizzes.retain(|qz| {
+            qz.visibility == Visibility::Public
+                && (qz.name.to_lowercase().contains(&search)
+                    || qz.description.to_lowercase().contains(&search))
+        });
+        quizzes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let total: u64 = quizzes.len() as u64;
+        let start: usize = ((page.max(1) - 1) * per_page) as usize;
+        Ok((
+            quizzes
+                .into_iter()
+                .skip(start)
+                .take(per_page as usize)
+                .collect(),
+            total,
+        ))
+    }
+
+    /// Matches each word of the query ignoring case, scoring by how many matches there were.
+    /// Only multiple choice and multi-select questions have choices.
+    async fn search(&self, query: &str, user_id: &str) -> Result<Vec<SearchHit>, anyhow::Error> {
+        let words: Vec<String> = query
+            .split_whitespace()
+            .map(|word| word.to_ascii_lowercase())
+            .collect();
+        let tables = self.read()?;
+        let mut hits: Vec<SearchHit> = Vec::new();
+
+        for (id, record) in tables.records.iter() {
+            if is_trashed(record) {
+                continue;
+            }
+            if id.tb == "quizzes" {
+                let quiz: SurrealQuiz = serde_json::from_value(record.clone())
+                    .context("Failed to deserialize record")?;
+                if quiz.author_id != user_id && quiz.visibility != Visibility::Public {
+                    continue;
+                }
+                let title = highlight(&quiz.name, &words);
+                let description = highlight(&quiz.description, &words);
+                if title.is_none() && description.is_none() {
+                    continue;
+                }
+                hits.push(SearchHit {
+                    id: quiz.id.clone(),
+                    kind: SearchKind::Quiz,
+                    quiz_id: quiz.id,
+                    quiz_name: quiz.name.clone(),
+                    score: (title.as_ref().map_or(0, |(_, n)| *n)
+                        + description.as_ref().map_or(0, |(_, n)| *n))
+                        as f64,
+                    title: snippet(&title.map_or(quiz.name, |(text, _)| text), SNIPPET_CONTEXT),
+                    snippet: description
+                        .map(|(text, _)| snippet(&text, SNIPPET_CONTEXT))
+                        .unwrap_or_default(),
+                });
+            } else if QUESTION_TABLES.contains(&id.tb.as_str()) {
+                let question: SurrealGenericQuestionData =
+                    serde_json::from_value(record.clone())
+                        .context("Failed to deserialize record")?;
+                let parent: Option<&Value> = tables.records.get(&question.parent_quiz);
+                if parent.is_some_and(is_trashed) {
+                    continue;
+                }
+                let parent: Option<SurrealQuiz> = parent
+                    .map(|quiz| serde_json::from_value(quiz.clone()))
+                    .transpose()
+                    .context("Failed to deserialize record")?;
+                let public: bool = parent
+                    .as_ref()
+                    .is_some_and(|quiz| quiz.visibility == Visibility::Public);
+                if question.author_id != user_id && !public {
+                    continue;
+                }
+
+                let choices: Option<String> = record
+                    .get("choices")
+                    .map(|choices| serde_json::from_value::<Vec<String>>(choices.clone()))
+                    .transpose()
+                    .context("Failed to deserialize choices")?
+                    .map(|choices| choices.join(" · "));
+                let title = highlight(&question.question, &words);
+                let choices = choices.and_then(|choices| highlight(&choices, &words));
+                if title.is_none() && choices.is_none() {
+                    continue;
+                }
+                hits.push(SearchHit {
+                    id: id.clone(),
+                    kind: SearchKind::Question,
+                    quiz_id: question.parent_quiz,
+                    quiz_name: parent.map_or_else(|| String::from("Deleted quiz"), |qz| qz.name),
+                    score: (title.as_ref().map_or(0, |(_, n)| *n)
+                        + choices.as_ref().map_or(0, |(_, n)| *n))
+                        as f64,
+                    title: snippet(
+                        &title.map_or(question.question, |(text, _)| text),
+                        SNIPPET_CONTEXT,
+                    ),
+                    snippet: choices
+                        .map(|(text, _)| snippet(&text, SNIPPET_CONTEXT))
+                        .unwrap_or_default(),
+                });
+            }
+        }
+        Ok(hits)
+    }
+
+    async fn update_quiz(
+        &self,
+        quiz_id: &Thing,
+        changes: &QuizJsonPkg,
+        revision: Option<Edit>,
+    ) -> Result<Option<SurrealQuiz>, anyhow::Error> {
+        self.merge_revised(quiz_id, changes, revision)
+    }
+
+    /// Holds the write lock throughout, so nothing sees the quiz half deleted.
+    async fn delete_quiz(&self, quiz_id: &Thing) -> Result<Option<QuizDeletion>, anyhow::Error> {
+        let parent_quiz: Value = serde_json::to_value(quiz_id).context("Failed to serialize ID")?;
+        let mut tables = self.write()?;
+        let Some(record) = tables.records.get(quiz_id) else {
+            return Ok(None);
+        };
+        let quiz: SurrealQuiz =
+            serde_json::from_value(record.clone()).context("Failed to deserialize record")?;
+
+        let mut removed: Vec<Thing> = tables
+            .records
+            .iter()
+            .filter(|(id, record)| {
+                QUESTION_TABLES.contains(&id.tb.as_str())
+                    && record.get("parent_quiz") == Some(&parent_quiz)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+        let questions: usize = removed.len();
+        removed.push(quiz_id.clone());
+        for id in removed.iter() {
+            tables.records.remove(id);
+        }
+        let only_quiz: &[Thing] = std::slice::from_ref(quiz_id);
+
+        Ok(Some(QuizDeletion {
+            quiz,
+            questions,
+            attempts: remove_pointing(&mut tables, "attempts", "quiz_id", only_quiz)?,
+            review_cards: remove_pointing(&mut tables, "review_cards", "quiz_id", only_quiz)?,
+            likes: remove_pointing(&mut tables, "likes", "out", &removed)?,
+            tag_links: remove_pointing(&mut tables, "tagged", "in", &removed)?,
+            assignments: remove_pointing(&mut tables, "assignments", "quiz_id", only_quiz)?,
+            revisions: remove_pointing(&mut tables, "revisions", "target", &removed)?,
+        }))
+    }
+
+    async fn get_user_tags(&self, user_id: &str) -> Result<Vec<SurrealTag>, anyhow::Error> {
+        let tags: Vec<SurrealTag> = self.select_table("tags")?;
+        Ok(tags
+            .into_iter()
+            .filter(|tg| tg.author_id == user_id)
+            .collect())
+    }
+
+    async fn get_tags(&self, targets: &[Thing]) -> Result<TagIndex, anyhow::Error> {
+        let mut links: Vec<TagLink> = Vec::new();
+        for link in self.select_table::<Relation>("tagged")? {
+            if targets.contains(&link.from) {
+                links.push(TagLink {
+                    target: link.from,
+                    tag: self.select(&link.to)?,
+                });
+            }
+        }
+        Ok(TagIndex { links })
+    }
+
+    async fn get_likes(
+        &self,
+        targets: &[Thing],
+        user_id: &str,
+    ) -> Result<LikeTally, anyhow::Error> {
+        let user: Thing = Thing::from(("general_user", user_id));
+        let mut tally: LikeTally = LikeTally {
+            counts: Vec::new(),
+            mine: Vec::new(),
+        };
+        for like in self.select_table::<Relation>("likes")? {
+            if !targets.contains(&like.to) {
+                continue;
+            }
+            match tally.counts.iter_mut().find(|lc| lc.target == like.to) {
+                Some(count) => count.likes += 1,
+                None => tally.counts.push(LikeCount {
+                    target: like.to.clone(),
+                    likes: 1,
+                }),
+            }
+            if like.from == user {
+                tally.mine.push(like.to);
+            }
+        }
+        Ok(tally)
+    }
+
+    async fn set_like(
+        &self,
+        user_id: &str,
+        target: &Thing,
+        liked: bool,
+    ) -> Result<(), anyhow::Error> {
+        self.set_relation(
+            "likes",
+            &Thing::from(("general_user", user_id)),
+            target,
+            liked,
+        )
+    }
+
+    async fn get_author_of(&self, target: &Thing) -> Result<Option<String>, anyhow::Error> {
+        if target.tb != "quizzes" && !QUESTION_TABLES.contains(&target.tb.as_str()) {
+            return Ok(None);
+        }
+        let authored: Option<Authored> = self.select(target)?;
+        Ok(authored.map(|authored| authored.author_id))
+    }
+}
+
+impl QuestionRepo for MemoryDatabase {
+    async fn get_quiz_questions(&self, quiz_id: &Thing) -> Result<AllQuestions, anyhow::Error> {
+        self.select_questions(false, |_, parent_quiz| parent_quiz == quiz_id)
+    }
+
+    async fn get_questions_by_ids(&self, ids: &[Thing]) -> Result<AllQuestions, anyhow::Error> {
+        self.select_questions(true, |id, _| ids.contains(id))
+    }
+
+    async fn get_question(
+        &self,
+        question_id: &Thing,
+    ) -> Result<Option<SurrealGenericQuestionData>, anyhow::Error> {
+        if !QUESTION_TABLES.contains(&question_id.tb.as_str()) {
+            return Ok(None);
+        }
+        self.select(question_id)
+    }
+
+    async fn create_question(
+        &self,
+        question: JsonQuestion,
+        author_id: String,
+        quiz_id: Thing,
+    ) -> Result<Value, anyhow::Error> {
+        let table: &str = question.table_name();
+        self.create(table, &question.into_record(author_id, quiz_id))
+    }
+
+    /// Every record is built before any is stored, then all go in under one write lock.
+    async fn create_questions(
+        &self,
+        questions: Vec<JsonQuestion>,
+        author_id: String,
+        quiz_id: Thing,
+    ) -> Result<Vec<Value>, anyhow::Error> {
+        let mut records: Vec<(Thing, Value)> = Vec::with_capacity(questions.len());
+        for question in questions {
+            let id: Thing = Thing::from((question.table_name(), Id::rand()));
+            let question = question.into_record(author_id.clone(), quiz_id.clone());
+            records.push((id.clone(), record_of(&id, &question)?));
+        }
+
+        let created: Vec<Value> = records.iter().map(|(_, record)| record.clone()).collect();
+        self.write()?.records.extend(records);
+        Ok(created)
+    }
+
+    async fn update_question(
+        &self,
+        question_id: &Thing,
+        question: JsonQuestion,
+        revision: Option<Edit>,
+    ) -> Result<Option<Value>, anyhow::Error> {
+        match question {
+            JsonQuestion::MultipleChoice(what) => self
+                .merge_revised::<_, SurrealQuestionMC>(question_id, &what, revision)?
+                .map(serde_json::to_value),
+            JsonQuestion::ShortAnswer(what) => self
+                .merge_revised::<_, SurrealQuestionSA>(question_id, &what, revision)?
+                .map(serde_json::to_value),
+            JsonQuestion::LongAnswer(what) => self
+                .merge_revised::<_, SurrealQuestionLA>(question_id, &what, revision)?
+                .map(serde_json::to_value),
+            JsonQuestion::TrueFalse(what) => self
+                .merge_revised::<_, SurrealQuestionTF>(question_id, &what, revision)?
+                .map(serde_json::to_value),
+            JsonQuestion::MultiSelect(what) => self
+                .merge_revised::<_, SurrealQuestionMS>(question_id, &what, revision)?
+                .map(serde_json::to_value),
+        }
+        .transpose()
+        .context("Failed to serialize question")
+    }
+
+    async fn delete_question(&self, question_id: &Thing) -> Result<Option<Value>, anyhow::Error> {
+        if !QUESTION_TABLES.contains(&question_id.tb.as_str()) {
+            return Ok(None);
+        }
+        self.delete(question_id)
+    }
+}
+
+impl TrashRepo for MemoryDatabase {
+    async fn trash_quiz(&self, quiz_id: &Thing) -> Result<Option<SurrealQuiz>, anyhow::Error> {
+        self.trash(quiz_id)?
+            .map(|record| serde_json::from_value(record).context("Failed to deserialize record"))
+            .transpose()
+    }
+
+    async fn trash_question(&self, question_id: &Thing) -> Result<Option<Value>, anyhow::Error> {
+        if !QUESTION_TABLES.contains(&question_id.tb.as_str()) {
+            return Ok(None);
+        }
+        self.trash(question_id)
+    }
+
+    async fn get_trash(&self, author_id: &str) -> Result<Vec<TrashItem>, anyhow::Error> {
+        let tables = self.read()?;
+        let mut trash: Vec<TrashItem> = Vec::new();
+        for (id, record) in tables.records.iter() {
+            if id.tb != "quizzes" && !QUESTION_TABLES.contains(&id.tb.as_str()) {
+                continue;
+            }
+            if let Some(item) = trash_item(record)? {
+                if item.author_id == author_id {
+                    trash.push(item);
+                }
+            }
+        }
+        trash.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+        Ok(trash)
+    }
+
+    async fn get_trash_item(&self, id: &Thing) -> Result<Option<TrashItem>, anyhow::Error> {
+        match self.read()?.records.get(id) {
+            Some(record) => trash_item(record),
+            None => Ok(None),
+        }
+    }
+
+    async fn restore(&self, id: &Thing) -> Result<Option<TrashItem>, anyhow::Error> {
+        let mut tables = self.write()?;
+        let Some(record) = tables.records.get_mut(id) else {
+            return Ok(None);
+        };
+        let item: Option<TrashItem> = trash_item(record)?;
+        if item.is_some() {
+            let fields = record.as_object_mut().context("Records must be objects")?;
+            fields.remove("deleted_at");
+        }
+        Ok(item)
+    }
+
+    async fn purge_trash(&self, cutoff: DateTime<Utc>) -> Result<TrashPurge, anyhow::Error> {
+        let mut expired: Vec<Thing> = Vec::new();
+        for record in self.read()?.records.values() {
+            if let Some(item) = trash_item(record)? {
+                if *item.deleted_at < cutoff {
+                    expired.push(item.id);
+                }
+            }
+        }
+
+        let mut purged: TrashPurge = TrashPurge::default();
+        for id in expired {
+            if id.tb == "quizzes" {
+                if self.delete_quiz(&id).await?.is_some() {
+                    purged.quizzes += 1;
+                }
+            } else {
+                let mut tables = self.write()?;
+                // may already be gone with its quiz
+                if tables.records.remove(&id).is_some() {
+                    let only_question: &[Thing] = std::slice::from_ref(&id);
+                    remove_pointing(&mut tables, "likes", "out", only_question)?;
+                    remove_pointing(&mut tables, "tagged", "in", only_question)?;
+                    remove_pointing(&mut tables, "review_cards", "question_id", only_question)?;
+                    remove_pointing(&mut tables, "revisions", "target", only_question)?;
+                    purged.questions += 1;
+                }
+            }
+        }
+        Ok(purged)
+    }
+}
+
+impl RevisionRepo for MemoryDatabase {
+    async fn get_revisions(&self, target: &Thing) -> Result<Vec<SurrealRevision>, anyhow::Error> {
+        let mut revisions: Vec<SurrealRevision> = self.select_table("revisions")?;
+        revisions.retain(|revision| &revision.target == target);
+        revisions.sort_by_key(|revision| std::cmp::Reverse(revision.number));
+        Ok(revisions)
+    }
+
+    async fn get_revisions_by_ids(
+        &self,
+        ids: &[Thing],
+    ) -> Result<Vec<SurrealRevision>, anyhow::Error> {
+        let mut revisions: Vec<SurrealRevision> = self.select_table("revisions")?;
+        revisions.retain(|revision| ids.contains(&revision.id));
+        Ok(revisions)
+    }
+
+    async fn get_revision(
+        &self,
+        target: &Thing,
+        number: u32,
+    ) -> Result<Option<SurrealRevision>, anyhow::Error> {
+        let revisions: Vec<SurrealRevision> = self.get_revisions(target).await?;
+        Ok(revisions
+            .into_iter()
+            .find(|revision| revision.number == number))
+    }
+
+    /// Holds the write lock while numbering, so two saves never take the same number.
+    async fn save_revision(
+        &self,
+        target: &Thing,
+        author_id: &str,
+        snapshot: Snapshot,
+    ) -> Result<SurrealRevision, anyhow::Error> {
+        let mut tables = self.write()?;
+        let record: Value = add_revision(&mut tables, target, author_id, snapshot)?;
+        serde_json::from_value(record).context("Failed to deserialize record")
+    }
+
+    async fn pin_revision(
+        &self,
+        target: &Thing,
+        author_id: &str,
+        current: Snapshot,
+    ) -> Result<SurrealRevision, anyhow::Error> {
+        let latest: Option<SurrealRevision> = self.get_revisions(target).await?.into_iter().next();
+        match latest {
+            Some(revision) => Ok(revision),
+            None => self.save_revision(target, author_id, current).await,
+        }
+    }
+
+    async fn pin_revisions(&self, pins: Vec<Pin>) -> Result<Vec<SurrealRevision>, anyhow::Error> {
+        let mut pinned: Vec<SurrealRevision> = Vec::with_capacity(pins.len());
+        for pin in pins {
+            pinned.push(
+                self.pin_revision(&pin.target, &pin.author_id, pin.current)
+                    .await?,
+            );
+        }
+        Ok(pinned)
+    }
+}
+
+impl AttemptRepo for MemoryDatabase {
+    async fn get_attempt(
+        &self,
+        attempt_id: &Thing,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        if attempt_id.tb != "attempts" {
+            return Ok(None);
+        }
+        self.select(attempt_id)
+    }
+
+    async fn create_attempt(&self, attempt: &Attempt) -> Result<SurrealAttempt, anyhow::Error> {
+        self.create("attempts", attempt)
+    }
+
+    async fn submit_attempt(
+        &self,
+        attempt_id: &Thing,
+        attempt: &Attempt,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        self.replace_if(attempt_id, attempt, |record| {
+            record.get("submitted_at").is_none_or(Value::is_null)
+        })
+    }
+
+    async fn reveal_hint(
+        &self,
+        attempt_id: &Thing,
+        question_id: &Thing,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        let question: Value =
+            serde_json::to_value(question_id).context("Failed to serialize ID")?;
+        let mut tables = self.write()?;
+        let Some(record) = tables.records.get_mut(attempt_id) else {
+            return Ok(None);
+        };
+        if !record.get("submitted_at").is_none_or(Value::is_null) {
+            return Ok(None);
+        }
+        let fields = record.as_object_mut().context("Records must be objects")?;
+        let hints_used = fields
+            .entry("hints_used")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .context("Hints used must be a list")?;
+        if !hints_used.contains(&question) {
+            hints_used.push(question);
+        }
+        serde_json::from_value(record.clone())
+            .map(Some)
+            .context("Failed to deserialize record")
+    }
+
+    async fn serve_question(
+        &self,
+        attempt_id: &Thing,
+        before: &[QuestionTiming],
+        timings: &[QuestionTiming],
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        let before: Value = serde_json::to_value(before).context("Failed to serialize timings")?;
+        let timings: Value =
+            serde_json::to_value(timings).context("Failed to serialize timings")?;
+        let mut tables = self.write()?;
+        let Some(record) = tables.records.get_mut(attempt_id) else {
+            return Ok(None);
+        };
+        let unchanged: bool = record
+            .get("timings")
+            .map_or(before.as_array().is_some_and(Vec::is_empty), |now| {
+                now == &before
+            });
+        if !record.get("submitted_at").is_none_or(Value::is_null) || !unchanged {
+            return Ok(None);
+        }
+        let fields = record.as_object_mut().context("Records must be objects")?;
+        fields.insert(String::from("timings"), timings);
+        serde_json::from_value(record.clone())
+            .map(Some)
+            .context("Failed to deserialize record")
+    }
+
+    async fn update_attempt(
+        &self,
+        attempt_id: &Thing,
+        attempt: &Attempt,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        self.replace_if(attempt_id, attempt, |_| true)
+    }
+
+    async fn get_submitted_attempts(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<SurrealAttempt>, anyhow::Error> {
+        let mut attempts: Vec<SurrealAttempt> = self.select_table("attempts")?;
+        attempts.retain(|at| at.user_id == user_id && at.submitted_at.is_some());
+        attempts.sort_by(|a, b| a.submitted_at.cmp(&b.submitted_at));
+        Ok(attempts)
+    }
+
+    async fn get_quiz_attempts(
+        &self,
+        quiz_id: &Thing,
+        user_ids: &[String],
+    ) -> Result<Vec<SurrealAttempt>, anyhow::Error> {
+        let mut attempts: Vec<SurrealAttempt> = self.select_table("attempts")?;
+        attempts.retain(|at| &at.quiz_id == quiz_id && user_ids.contains(&at.user_id));
+        Ok(attempts)
+    }
+}
+
+impl StudyRepo for MemoryDatabase {
+    async fn get_review_cards(
+        &self,
+        user_id: &str,
+        quiz_id: &Thing,
+    ) -> Result<Vec<SurrealReviewCard>, anyhow::Error> {
+        let mut cards: Vec<SurrealReviewCard> = self.select_table("review_cards")?;
+        cards.retain(|card| card.user_id == user_id && &card.quiz_id == quiz_id);
+        Ok(cards)
+    }
+
+    async fn get_review_card(
+        &self,
+        user_id: &str,
+        question_id: &Thing,
+    ) -> Result<Option<SurrealReviewCard>, anyhow::Error> {
+        let cards: Vec<SurrealReviewCard> = self.select_table("review_cards")?;
+        Ok(cards
+            .into_iter()
+            .find(|card| card.user_id == user_id && &card.question_id == question_id))
+    }
+
+    async fn create_review_card(
+        &self,
+        card: &ReviewCard,
+    ) -> Result<SurrealReviewCard, anyhow::Error> {
+        self.create("review_cards", card)
+    }
+
+    async fn update_review_card(
+        &self,
+        card_id: &Thing,
+        card: &ReviewCard,
+    ) -> Result<Option<SurrealReviewCard>, anyhow::Error> {
+        self.replace_if(card_id, card, |_| true)
+    }
+}
+
+impl TagRepo for MemoryDatabase {
+    async fn get_tag(&self, tag_id: &Thing) -> Result<Option<SurrealTag>, anyhow::Error> {
+        if tag_id.tb != "tags" {
+            return Ok(None);
+        }
+        self.select(tag_id)
+    }
+
+    /// Anything tagged that is not a quiz is a question.
+    async fn get_tag_summaries(&self, user_id: &str) -> Result<Vec<TagSummary>, anyhow::Error> {
+        let links: Vec<Relation> = self.select_table("tagged")?;
+        let mut summaries: Vec<TagSummary> = self
+            .get_user_tags(user_id)
+            .await?
+            .into_iter()
+            .map(|tag| {
+                let (quizzes, questions) = links
+                    .iter()
+                    .filter(|link| link.to == tag.id)
+                    .partition::<Vec<&Relation>, _>(|link| link.from.tb == "quizzes");
+                TagSummary {
+                    tag,
+                    quizzes: quizzes.len() as u64,
+                    questions: questions.len() as u64,
+                }
+            })
+            .collect();
+        summaries.sort_by(|a, b| a.tag.name.cmp(&b.tag.name));
+        Ok(summaries)
+    }
+
+    async fn create_tag(&self, tag: &Tag) -> Result<SurrealTag, anyhow::Error> {
+        self.create("tags", tag)
+    }
+
+    async fn rename_tag(
+        &self,
+        tag_id: &Thing,
+        name: &str,
+    ) -> Result<Option<SurrealTag>, anyhow::Error> {
+        self.merge(
+            tag_id,
+            &TagJsonPkg {
+                name: name.to_string(),
+            },
+        )
+    }
+
+    /// Holds the write lock throughout, so no link is left pointing at a missing tag.
+    async fn delete_tag(&self, tag_id: &Thing) -> Result<(), anyhow::Error> {
+        let mut tables = self.write()?;
+        remove_pointing(&mut tables, "tagged", "out", std::slice::from_ref(tag_id))?;
+        tables.records.remove(tag_id);
+        Ok(())
+    }
+
+    async fn set_tagged(
+        &self,
+        target: &Thing,
+        tag_id: &Thing,
+        tagged: bool,
+    ) -> Result<(), anyhow::Error> {
+        self.set_relation("tagged", target, tag_id, tagged)
+    }
+
+    async fn get_pool_question_ids(
+        &self,
+        author_id: &str,
+        tag_id: &Thing,
+    ) -> Result<Vec<Thing>, anyhow::Error> {
+        let tables = self.read()?;
+        let mut ids: Vec<Thing> = Vec::new();
+        for record in tables
+            .records
+            .iter()
+            .filter(|(id, _)| id.tb == "tagged")
+            .map(|(_, record)| record)
+        {
+            let link: Relation =
+                serde_json::from_value(record.clone()).context("Failed to deserialize record")?;
+            // quizzes share tags with questions
+            if &link.to != tag_id || !QUESTION_TABLES.contains(&link.from.tb.as_str()) {
+                continue;
+            }
+            let Some(record) = tables.records.get(&link.from).filter(|rd| !is_trashed(rd)) else {
+                continue;
+            };
+            let question: SurrealGenericQuestionData =
+                serde_json::from_value(record.clone()).context("Failed to deserialize record")?;
+            let quiz_trashed: bool = tables
+                .records
+                .get(&question.parent_quiz)
+                .is_some_and(is_trashed);
+            if question.author_id == author_id && !quiz_trashed {
+                ids.push(link.from);
+            }
+        }
+        Ok(ids)
+    }
+}
+
+impl GroupRepo for MemoryDatabase {
+    async fn get_group(&self, group_id: &Thing) -> Result<Option<SurrealGroup>, anyhow::Error> {
+        if group_id.tb != "groups" {
+            return Ok(None);
+        }
+        self.select(group_id)
+    }
+
+    async fn get_group_by_code(
+        &self,
+        join_code: &str,
+    ) -> Result<Option<SurrealGroup>, anyhow::Error> {
+        let groups: Vec<SurrealGroup> = self.select_table("groups")?;
+        Ok(groups.into_iter().find(|gp| gp.join_code == join_code))
+    }
+
+    async fn get_user_groups(&self, user_id: &str) -> Result<Vec<SurrealGroup>, anyhow::Error> {
+        let memberships: Vec<SurrealMembership> = self.select_table("memberships")?;
+        let mut groups: Vec<SurrealGroup> = self.select_table("groups")?;
+        groups.retain(|gp| {
+            gp.owner_id == user_id
+                || memberships
+                    .iter()
+                    .any(|ms| ms.group_id == gp.id && ms.user_id == user_id)
+        });
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(groups)
+    }
+
+    async fn get_group_members(&self, group_id: &Thing) -> Result<Vec<PartialUser>, anyhow::Error> {
+        let memberships: Vec<SurrealMembership> = self.select_table("memberships")?;
+        let mut members: Vec<PartialUser> = Vec::new();
+        for membership in memberships.iter().filter(|ms| &ms.group_id == group_id) {
+            let user_id: Thing = Thing::from(("general_user", membership.user_id.as_str()));
+            if let Some(user) = self.select(&user_id)? {
+                members.push(user);
+            }
+        }
+        members.sort_by(|a, b| a.username.cmp(&b.username));
+        Ok(members)
+    }
+
+    async fn is_member(&self, group_id: &Thing, user_id: &str) -> Result<bool, anyhow::Error> {
+        let memberships: Vec<SurrealMembership> = self.select_table("memberships")?;
+        Ok(memberships
+            .iter()
+            .any(|ms| &ms.group_id == group_id && ms.user_id == user_id))
+    }
+
+    async fn create_group(&self, group: &Group) -> Result<SurrealGroup, anyhow::Error> {
+        self.create("groups", group)
+    }
+
+    async fn add_member(
+        &self,
+        membership: &Membership,
+    ) -> Result<SurrealMembership, anyhow::Error> {
+        self.create("memberships", membership)
+    }
+
+    async fn get_assignment(
+        &self,
+        assignment_id: &Thing,
+    ) -> Result<Option<SurrealAssignment>, anyhow::Error> {
+        if assignment_id.tb != "assignments" {
+            return Ok(None);
+        }
+        self.select(assignment_id)
+    }
+
+    async fn create_assignment(
+        &self,
+        assignment: &Assignment,
+    ) -> Result<SurrealAssignment, anyhow::Error> {
+        self.create("assignments", assignment)
+    }
+
+    async fn get_group_assignments(
+        &self,
+        group_id: &Thing,
+    ) -> Result<Vec<SurrealAssignment>, anyhow::Error> {
+        let mut assignments: Vec<SurrealAssignment> = self.select_table("assignments")?;
+        assignments.retain(|asg| &asg.group_id == group_id);
+        assignments.sort_by(|a, b| a.closes_at.cmp(&b.closes_at));
+        Ok(assignments)
+    }
+
+    async fn get_member_assignments(
+        &self,
+        user_id: &str,
+        quiz_id: &Thing,
+    ) -> Result<Vec<SurrealAssignment>, anyhow::Error> {
+        let memberships: Vec<SurrealMembership> = self.select_table("memberships")?;
+        let mut assignments: Vec<SurrealAssignment> = self.select_table("assignments")?;
+        assignments.retain(|asg| {
+            &asg.quiz_id == quiz_id
+                && memberships
+                    .iter()
+                    .any(|ms| ms.group_id == asg.group_id && ms.user_id == user_id)
+        });
+        Ok(assignments)
+    }
+
+    /// Assignments whose quiz or group is gone are left out.
+    async fn get_assigned_quizzes(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<AssignedQuiz>, anyhow::Error> {
+        let memberships: Vec<SurrealMembership> = self.select_table("memberships")?;
+        let mut assigned: Vec<AssignedQuiz> = Vec::new();
+        for assignment in self.select_table::<SurrealAssignment>("assignments")? {
+            if !memberships
+                .iter()
+                .any(|ms| ms.group_id == assignment.group_id && ms.user_id == user_id)
+            {
+                continue;
+            }
+            let quiz: Option<SurrealQuiz> = self.select(&assignment.quiz_id)?;
+            let group: Option<SurrealGroup> = self.select(&assignment.group_id)?;
+            if let (Some(quiz), Some(group)) = (quiz, group) {
+                assigned.push(AssignedQuiz {
+                    assignment,
+                    quiz,
+                    group_name: group.name,
+                });
+            }
+        }
+        assigned.sort_by(|a, b| a.assignment.closes_at.cmp(&b.assignment.closes_at));
+        Ok(assigned)
+    }
+}
+
+impl SessionRepo for MemoryDatabase {
+    async fn delete_user_sessions(&self, user_id: &str) -> Result<usize, anyhow::Error> {
+        let mut tables = self.write()?;
+        let before: usize = tables.sessions.len();
+        tables
+            .sessions
+            .retain(|_, session| !session.state.values().any(|value| value.contains(user_id)));
+        Ok(before - tables.sessions.len())
+    }
+
+    async fn purge_expired_sessions(&self) -> Result<usize, anyhow::Error> {
+        let now: DateTime<Utc> = Utc::now();
+        let mut tables = self.write()?;
+        let before: usize = tables.sessions.len();
+        tables.sessions.retain(|_, session| session.expiry >= now);
+        Ok(before - tables.sessions.len())
+    }
+}
+
+/// Same behaviour as the SurrealDB session store, expired sessions are removed when loaded.
+impl SessionStore for MemoryDatabase {
+    async fn load(&self, session_key: &SessionKey) -> Result<Option<SessionState>, LoadError> {
+        let mut tables = self.write().map_err(LoadError::Other)?;
+        let Some(session) = tables.sessions.get(session_key.as_ref()).cloned() else {
+            return Ok(None);
+        };
+        if session.expiry < Utc::now() {
+            tables.sessions.remove(session_key.as_ref());
+            return Ok(None);
+        }
+        Ok(Some(session.state))
+    }
+
+    async fn save(
+        &self,
+        session_state: SessionState,
+        time_to_live: &Duration,
+    ) -> Result<SessionKey, SaveError> {
+        let session_key: SessionKey = generate_session_key().map_err(SaveError::Other)?;
+        let expiry: DateTime<Utc> = generate_time_stamp(time_to_live).map_err(SaveError::Other)?;
+
+        self.write().map_err(SaveError::Other)?.sessions.insert(
+            session_key.as_ref().to_owned(),
+            MemorySession {
+                state: session_state,
+                expiry,
+            },
+        );
+        Ok(session_key)
+    }
+
+    async fn update(
+        &self,
+        session_key: SessionKey,
+        session_state: SessionState,
+        time_to_live: &Duration,
+    ) -> Result<SessionKey, UpdateError> {
+        let expiry: DateTime<Utc> =
+            generate_time_stamp(time_to_live).map_err(UpdateError::Other)?;
+
+        self.write().map_err(UpdateError::Other)?.sessions.insert(
+            session_key.as_ref().to_owned(),
+            MemorySession {
+                state: session_state,
+                expiry,
+            },
+        );
+        Ok(session_key)
+    }
+
+    async fn update_ttl(
+        &self,
+        session_key: &SessionKey,
+        time_to_live: &Duration,
+    ) -> Result<(), anyhow::Error> {
+        let mut tables = self.write()?;
+        if !time_to_live.is_positive() {
+            // If duration is non-positive we force remove cookie.
+            tables.sessions.remove(session_key.as_ref());
+        } else if let Some(session) = tables.sessions.get_mut(session_key.as_ref()) {
+            session.expiry = generate_time_stamp(time_to_live)?;
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, session_key: &SessionKey) -> Result<(), anyhow::Error> {
+        self.write()?.sessions.remove(session_key.as_ref());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::questions::{JsonQuestionMC, JsonQuestionTF};
+    use models::quiz::Visibility;
+
+    fn quiz(author_id: &str) -> Quiz {
+        Quiz::new(
+            String::from("Sorting"),
+            String::from("Comparison sorts"),
+            String::from(author_id),
+            Visibility::Private,
+            None,
+            None,
+        )
+    }
+
+    fn true_false(question: &str) -> JsonQuestion {
+        JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from(question),
+            hint: None,
+            answer: true,
+        })
+    }
+
+    #[tokio::test]
+    async fn deleting_quiz_deletes_its_questions() {
+        // Assign
+        let db: MemoryDatabase = MemoryDatabase::default();
+        let kept: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        let doomed: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        for quiz_id in [&kept.id, &doomed.id] {
+            db.create_question(
+                true_false("Merge sort is stable"),
+                String::from("author"),
+                quiz_id.clone(),
+            )
+            .await
+            .unwrap();
+        }
+
+        // Act
+        let deleted: Option<QuizDeletion> = db.delete_quiz(&doomed.id).await.unwrap();
+
+        // Assert
+        assert_eq!(deleted.map(|deleted| deleted.questions), Some(1));
+        assert!(db.get_quiz(&doomed.id).await.unwrap().is_none());
+        assert!(db
+            .get_quiz_questions(&doomed.id)
+            .await
+            .unwrap()
+            .tf
+            .is_empty());
+        assert_eq!(db.get_quiz_questions(&kept.id).await.unwrap().tf.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn deleting_quiz_removes_likes_and_tags_on_it_and_its_questions() {
+        // Assign
+        let db: MemoryDatabase = MemoryDatabase::default();
+        let doomed: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        let kept: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        let question: Value = db
+            .create_question(
+                true_false("Merge sort is stable"),
+                String::from("author"),
+                doomed.id.clone(),
+            )
+            .await
+            .unwrap();
+        let question_id: Thing = serde_json::from_value(question["id"].clone()).unwrap();
+        let tag: SurrealTag = db
+            .create_tag(&Tag::new(String::from("Exam"), String::from("author")))
+            .await
+            .unwrap();
+        for target in [&doomed.id, &question_id, &kept.id] {
+            db.set_like("reader", target, true).await.unwrap();
+            db.set_tagged(target, &tag.id, true).await.unwrap();
+        }
+        // liking twice is still one like
+        db.set_like("reader", &doomed.id, true).await.unwrap();
+
+        // Act
+        let deleted: QuizDeletion = db.delete_quiz(&doomed.id).await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(deleted.likes, 2);
+        assert_eq!(deleted.tag_links, 2);
+        let summaries: Vec<TagSummary> = db.get_tag_summaries("author").await.unwrap();
+        assert_eq!(summaries[0].quizzes, 1);
+        assert_eq!(summaries[0].questions, 0);
+        let tally: LikeTally = db
+            .get_likes(std::slice::from_ref(&kept.id), "reader")
+            .await
+            .unwrap();
+        assert_eq!(tally.count(&kept.id), 1);
+    }
+
+    #[tokio::test]
+    async fn trash_is_purged_after_cutoff() {
+        // Assign
+        let db: MemoryDatabase = MemoryDatabase::default();
+        let restored: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        let purged: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        db.create_question(
+            true_false("Merge sort is stable"),
+            String::from("author"),
+            purged.id.clone(),
+        )
+        .await
+        .unwrap();
+        for quiz_id in [&restored.id, &purged.id] {
+            assert!(db.trash_quiz(quiz_id).await.unwrap().is_some());
+        }
+
+        // Act
+        let hidden: Option<SurrealQuiz> = db.get_quiz(&purged.id).await.unwrap();
+        let trash: Vec<TrashItem> = db.get_trash("author").await.unwrap();
+        db.restore(&restored.id).await.unwrap();
+        let early: TrashPurge = db
+            .purge_trash(Utc::now() - chrono::Duration::days(1))
+            .await
+            .unwrap();
+        let late: TrashPurge = db
+            .purge_trash(Utc::now() + chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(hidden.is_none());
+        assert_eq!(trash.len(), 2);
+        assert_eq!(early, TrashPurge::default());
+        assert_eq!(late.quizzes, 1);
+        assert!(db.get_quiz(&restored.id).await.unwrap().is_some());
+        assert!(db.get_trash_item(&purged.id).await.unwrap().is_none());
+        assert!(db
+            .read()
+            .unwrap()
+            .records
+            .keys()
+            .all(|id| id.tb == "quizzes"));
+    }
+
+    #[tokio::test]
+    async fn revisions_are_numbered_and_go_with_their_quiz() {
+        // Assign
+        let db: MemoryDatabase = MemoryDatabase::default();
+        let created: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        let question: Value = db
+            .create_question(
+                true_false("Merge sort is stable"),
+                String::from("author"),
+                created.id.clone(),
+            )
+            .await
+            .unwrap();
+        let question_id: Thing = serde_json::from_value(question["id"].clone()).unwrap();
+
+        // Act
+        let first: SurrealRevision = db
+            .pin_revision(&created.id, "author", Snapshot::of_quiz(&created))
+            .await
+            .unwrap();
+        let pinned: SurrealRevision = db
+            .pin_revision(&created.id, "editor", Snapshot::of_quiz(&created))
+            .await
+            .unwrap();
+        let second: SurrealRevision = db
+            .save_revision(&created.id, "editor", Snapshot::of_quiz(&created))
+            .await
+            .unwrap();
+        db.pin_revision(
+            &question_id,
+            "author",
+            Snapshot::Question(true_false("Merge sort is stable")),
+        )
+        .await
+        .unwrap();
+        let listed: Vec<SurrealRevision> = db.get_revisions(&created.id).await.unwrap();
+        let deleted: Option<QuizDeletion> = db.delete_quiz(&created.id).await.unwrap();
+
+        // Assert
+        assert_eq!(first.number, 1);
+        assert_eq!(pinned.id, first.id);
+        assert_eq!(second.number, 2);
+        assert_eq!(second.author_id, "editor");
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].number, 2);
+        assert_eq!(deleted.map(|deleted| deleted.revisions), Some(3));
+        assert!(db.get_revisions(&question_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn edits_only_change_given_fields() {
+        // Assign
+        let db: MemoryDatabase = MemoryDatabase::default();
+        let created: SurrealQuiz = db.create_quiz(&quiz("author")).await.unwrap();
+        let question: Value = db
+            .create_question(
+                JsonQuestion::MultipleChoice(JsonQuestionMC {
+                    question: String::from("Fastest average sort?"),
+                    hint: Some(String::from("Pivot")),
+                    answer: String::from("Quick sort"),
+                    choices: vec![String::from("Bubble sort")],
+                }),
+                String::from("author"),
+                created.id.clone(),
+            )
+            .await
+            .unwrap();
+        let question_id: Thing = serde_json::from_value(question["id"].clone()).unwrap();
+
+        // Act
+        let updated: SurrealQuiz = db
+            .update_quiz(
+                &created.id,
+                &QuizJsonPkg {
+                    name: String::from("Sorting, again"),
+                    description: String::from("Comparison sorts"),
+                    visibility: Some(Visibility::Public),
+                    time_limit_secs: None,
+                    time_per_question_secs: None,
+                    pools: None,
+                    hint_penalty: None,
+                },
+                None,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        // only the question tables hold questions
+        let found: Option<SurrealGenericQuestionData> = db
+            .get_question(&Thing::from(("quizzes", "nope")))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(updated.name, "Sorting, again");
+        assert_eq!(updated.visibility, Visibility::Public);
+        assert_eq!(updated.author_id, "author");
+        assert!(found.is_none());
+        let all: AllQuestions = db.get_questions_by_ids(&[question_id]).await.unwrap();
+        assert_eq!(all.mc[0].hint.as_deref(), Some("Pivot"));
+    }
+
+    #[tokio::test]
+    async fn sessions_expire_and_log_out() {
+        // Assign
+        let db: MemoryDatabase = MemoryDatabase::default();
+        let state: SessionState =
+            HashMap::from([(String::from("user_id"), String::from("\"user-1\""))]);
+        let alive: SessionKey = db.save(state.clone(), &Duration::days(1)).await.unwrap();
+        let expired: SessionKey = db.save(state.clone(), &Duration::days(-1)).await.unwrap();
+
+        // Act
+        let purged: usize = db.purge_expired_sessions().await.unwrap();
+        let loaded: Option<SessionState> = db.load(&alive).await.unwrap();
+        let logged_out: usize = db.delete_user_sessions("user-1").await.unwrap();
+
+        // Assert
+        assert_eq!(purged, 1);
+        assert!(db.load(&expired).await.unwrap().is_none());
+        assert_eq!(loaded, Some(state));
+        assert_eq!(logged_out, 1);
+        assert!(db.load(&alive).await.unwrap().is_none());
+    }
+}
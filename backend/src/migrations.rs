@@ -0,0 +1,168 @@
+//! backend/src/migrations.rs
+//! Versioned schema migrations, applied in order when the application starts.
+//! Scripts live in `backend/migrations` and each one runs once, in a transaction
+//! that also records its version in the `schema_migrations` table.
+use crate::surrealdb_repo::Database;
+use anyhow::Context;
+
+/// A numbered script of `DEFINE TABLE/FIELD/INDEX` statements.
+#[derive(Debug)]
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub script: &'static str,
+}
+
+impl Migration {
+    /// The script and its version record, so either both are saved or neither is.
+    fn transaction(&self) -> String {
+        format!(
+            "BEGIN TRANSACTION;\n{}\nCREATE type::thing('schema_migrations', $version) \
+            CONTENT {{ version: $version, name: $name, applied_at: time::now() }};\n\
+            COMMIT TRANSACTION;",
+            self.script
+        )
+    }
+}
+
+/// Every migration, oldest first. Applied scripts are never edited, add a new version instead.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "users_and_sessions",
+        script: include_str!("../migrations/0001_users_and_sessions.surql"),
+    },
+    Migration {
+        version: 2,
+        name: "quizzes_and_questions",
+        script: include_str!("../migrations/0002_quizzes_and_questions.surql"),
+    },
+    Migration {
+        version: 3,
+        name: "attempts_and_study",
+        script: include_str!("../migrations/0003_attempts_and_study.surql"),
+    },
+    Migration {
+        version: 4,
+        name: "search_indexes",
+        script: include_str!("../migrations/0004_search_indexes.surql"),
+    },
+    Migration {
+        version: 5,
+        name: "user_roles",
+        script: include_str!("../migrations/0005_user_roles.surql"),
+    },
+    Migration {
+        version: 6,
+        name: "groups",
+        script: include_str!("../migrations/0006_groups.surql"),
+    },
+    Migration {
+        version: 7,
+        name: "time_limits",
+        script: include_str!("../migrations/0007_time_limits.surql"),
+    },
+    Migration {
+        version: 8,
+        name: "question_pools",
+        script: include_str!("../migrations/0008_question_pools.surql"),
+    },
+    Migration {
+        version: 9,
+        name: "hints",
+        script: include_str!("../migrations/0009_hints.surql"),
+    },
+    Migration {
+        version: 10,
+        name: "trash",
+        script: include_str!("../migrations/0010_trash.surql"),
+    },
+    Migration {
+        version: 11,
+        name: "revisions",
+        script: include_str!("../migrations/0011_revisions.surql"),
+    },
+    Migration {
+        version: 12,
+        name: "assigned_attempts",
+        script: include_str!("../migrations/0012_assigned_attempts.surql"),
+    },
+    Migration {
+        version: 13,
+        name: "question_timings",
+        script: include_str!("../migrations/0013_question_timings.surql"),
+    },
+];
+
+/// The latest version applied to the database, 0 when nothing has been.
+pub async fn applied_version(db: &Database) -> surrealdb::Result<u32> {
+    let version: Option<u32> = db
+        .client
+        .query("SELECT VALUE version FROM schema_migrations ORDER BY version DESC LIMIT 1")
+        .await?
+        .check()?
+        .take(0)?;
+    Ok(version.unwrap_or_default())
+}
+
+/// Applies every migration newer than the database, returning the version it ends on.
+pub async fn run_migrations(db: &Database) -> anyhow::Result<u32> {
+    let start: u32 = applied_version(db)
+        .await
+        .context("Failed to read schema version")?;
+    let mut current: u32 = start;
+    for migration in MIGRATIONS.iter().filter(|m| m.version > start) {
+        let applied: surrealdb::Result<surrealdb::Response> = db
+            .client
+            .query(migration.transaction())
+            .bind(("version", migration.version))
+            .bind(("name", migration.name))
+            .await
+            .and_then(surrealdb::Response::check);
+        match applied {
+            Ok(_) => println!("Applied migration {} {}", migration.version, migration.name),
+            // Another server starting at the same time may have applied it first
+            Err(_) if applied_version(db).await? >= migration.version => {}
+            Err(err) => {
+                return Err(anyhow::anyhow!(err)).with_context(|| {
+                    format!("Migration {} {} failed", migration.version, migration.name)
+                })
+            }
+        }
+        current = migration.version;
+    }
+    Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_count_up_from_one() {
+        for (at, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version as usize, at + 1, "{}", migration.name);
+        }
+    }
+
+    #[test]
+    fn scripts_only_define() {
+        // Transactions are added by the runner, and data changes belong elsewhere
+        for migration in MIGRATIONS {
+            let statements: Vec<String> = migration
+                .script
+                .lines()
+                .filter(|line| !line.trim_start().starts_with("--"))
+                .collect::<Vec<&str>>()
+                .join("\n")
+                .split(';')
+                .map(|part| part.trim().to_string())
+                .filter(|part| !part.is_empty())
+                .collect();
+            assert!(!statements.is_empty(), "{} is empty", migration.name);
+            for statement in statements {
+                assert!(statement.starts_with("DEFINE "), "{}", statement);
+            }
+        }
+    }
+}
@@ -0,0 +1,554 @@
+//! backend/src/repository.rs
+//! Storage traits the routes are written against, so the backend is not tied to one database.
+//! `Database` implements them over SurrealDB and `MemoryDatabase` keeps everything in memory.
+//! Every route is written against these, so both backends serve the whole API.
+use crate::authentication::LocalBoxFuture;
+use actix_session::storage::SessionStore;
+use chrono::{DateTime, Utc};
+use models::{
+    attempts::{Attempt, QuestionTiming, SurrealAttempt},
+    groups::{
+        AssignedQuiz, Assignment, Group, Membership, SurrealAssignment, SurrealGroup,
+        SurrealMembership,
+    },
+    likes::LikeTally,
+    questions::{AllQuestions, JsonQuestion, QuestionJsonPkg, SurrealGenericQuestionData},
+    quiz::{Quiz, QuizDeletion, QuizJsonPkg, SurrealQuiz},
+    revisions::{Edit, Pin, Snapshot, SurrealRevision},
+    roles::Role,
+    search::SearchHit,
+    study::{ReviewCard, SurrealReviewCard},
+    tags::{SurrealTag, Tag, TagIndex, TagSummary},
+    trash::{TrashItem, TrashPurge},
+    GeneralUser, PartialUser,
+};
+use std::future::Future;
+use surrealdb::sql::Thing;
+
+// Compiler suggest not making public async trait...
+pub trait LookUpUser {
+    fn get_user_by_username(
+        &self,
+        username: String,
+    ) -> impl Future<Output = Result<Option<GeneralUser>, anyhow::Error>> + Send;
+
+    /// `None` when the user no longer exists.
+    fn get_user(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Option<GeneralUser>, anyhow::Error>> + Send;
+
+    fn count_users(
+        &self,
+        username: &str,
+    ) -> impl Future<Output = Result<i64, anyhow::Error>> + Send;
+
+    fn add_general_user(
+        &self,
+        new_general_user: GeneralUser,
+    ) -> impl Future<Output = Result<Option<GeneralUser>, anyhow::Error>> + Send;
+
+    /// The user with their new role, `None` when the user does not exist.
+    fn set_user_role(
+        &self,
+        user_id: &str,
+        role: Role,
+    ) -> impl Future<Output = Result<Option<GeneralUser>, anyhow::Error>> + Send;
+}
+
+/// Quizzes, along with the tags and likes listed next to quizzes and questions.
+pub trait QuizRepo {
+    /// `None` when the quiz does not exist or is in the trash.
+    fn get_quiz(
+        &self,
+        quiz_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealQuiz>, anyhow::Error>> + Send;
+
+    /// Quizzes written by a user, only those with the tag when one is given.
+    fn get_author_quizzes(
+        &self,
+        author_id: &str,
+        tag: Option<&Thing>,
+    ) -> impl Future<Output = Result<Vec<SurrealQuiz>, anyhow::Error>> + Send;
+
+    fn create_quiz(
+        &self,
+        quiz: &Quiz,
+    ) -> impl Future<Output = Result<SurrealQuiz, anyhow::Error>> + Send;
+
+    /// Saves a new quiz under `quiz_id` along with all of its questions, all or nothing,
+    /// so a failed question leaves no half imported quiz behind.
+    fn create_quiz_with_questions(
+        &self,
+        quiz_id: &Thing,
+        quiz: &Quiz,
+        packages: Vec<QuestionJsonPkg>,
+    ) -> impl Future<Output = Result<SurrealQuiz, anyhow::Error>> + Send;
+
+    /// Public quizzes with `search` in their name or description, ignoring case,
+    /// a page at a time sorted by name. Also returns how many match across every page.
+    fn get_public_quizzes(
+        &self,
+        search: &str,
+        page: u32,
+        per_page: u32,
+    ) -> impl Future<Output = Result<(Vec<SurrealQuiz>, u64), anyhow::Error>> + Send;
+
+    /// Quizzes and questions matching `query` by their text, from the user's own quizzes and public ones.
+    /// Nothing in the trash is found, and hits come back unranked.
+    fn search(
+        &self,
+        query: &str,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<SearchHit>, anyhow::Error>> + Send;
+
+    /// Only the fields present in `changes` are updated.
+    /// `revision` is saved as the quiz's next revision in the same transaction, nothing is saved without it.
+    fn update_quiz(
+        &self,
+        quiz_id: &Thing,
+        changes: &QuizJsonPkg,
+        revision: Option<Edit>,
+    ) -> impl Future<Output = Result<Option<SurrealQuiz>, anyhow::Error>> + Send;
+
+    /// Deletes the quiz along with its questions and anything pointing at it, all or nothing.
+    /// `None` when the quiz did not exist.
+    fn delete_quiz(
+        &self,
+        quiz_id: &Thing,
+    ) -> impl Future<Output = Result<Option<QuizDeletion>, anyhow::Error>> + Send;
+
+    fn get_user_tags(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<SurrealTag>, anyhow::Error>> + Send;
+
+    fn get_tags(
+        &self,
+        targets: &[Thing],
+    ) -> impl Future<Output = Result<TagIndex, anyhow::Error>> + Send;
+
+    fn get_likes(
+        &self,
+        targets: &[Thing],
+        user_id: &str,
+    ) -> impl Future<Output = Result<LikeTally, anyhow::Error>> + Send;
+
+    /// Sets whether the user likes a quiz or question, a user never likes the same thing twice.
+    fn set_like(
+        &self,
+        user_id: &str,
+        target: &Thing,
+        liked: bool,
+    ) -> impl Future<Output = Result<(), anyhow::Error>> + Send;
+
+    /// Who wrote a quiz or question, `None` when it does not exist or is in the trash.
+    fn get_author_of(
+        &self,
+        target: &Thing,
+    ) -> impl Future<Output = Result<Option<String>, anyhow::Error>> + Send;
+}
+
+/// Questions of every type. Single questions are handed back as JSON in the shape of their own type.
+pub trait QuestionRepo {
+    fn get_quiz_questions(
+        &self,
+        quiz_id: &Thing,
+    ) -> impl Future<Output = Result<AllQuestions, anyhow::Error>> + Send;
+
+    /// Trashed questions are included, so attempts started before a question was trashed still grade.
+    fn get_questions_by_ids(
+        &self,
+        ids: &[Thing],
+    ) -> impl Future<Output = Result<AllQuestions, anyhow::Error>> + Send;
+
+    /// `None` when the question does not exist or is in the trash.
+    fn get_question(
+        &self,
+        question_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealGenericQuestionData>, anyhow::Error>> + Send;
+
+    fn create_question(
+        &self,
+        question: JsonQuestion,
+        author_id: String,
+        quiz_id: Thing,
+    ) -> impl Future<Output = Result<serde_json::Value, anyhow::Error>> + Send;
+
+    /// Adds several questions to a quiz, all or nothing, handed back in the order given.
+    fn create_questions(
+        &self,
+        questions: Vec<JsonQuestion>,
+        author_id: String,
+        quiz_id: Thing,
+    ) -> impl Future<Output = Result<Vec<serde_json::Value>, anyhow::Error>> + Send;
+
+    /// The question keeps its type, callers check it matches the table first.
+    /// `revision` is saved as the question's next revision in the same transaction, like `update_quiz`.
+    fn update_question(
+        &self,
+        question_id: &Thing,
+        question: JsonQuestion,
+        revision: Option<Edit>,
+    ) -> impl Future<Output = Result<Option<serde_json::Value>, anyhow::Error>> + Send;
+
+    fn delete_question(
+        &self,
+        question_id: &Thing,
+    ) -> impl Future<Output = Result<Option<serde_json::Value>, anyhow::Error>> + Send;
+}
+
+/// Deleting a quiz or question only trashes it, trashed records are left out of the queries above
+/// until they are restored. Purging deletes them for good.
+pub trait TrashRepo {
+    /// `None` when there is no such quiz, or it is already trashed.
+    fn trash_quiz(
+        &self,
+        quiz_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealQuiz>, anyhow::Error>> + Send;
+
+    /// Handed back in the shape of its own type, `None` when there is no such question.
+    fn trash_question(
+        &self,
+        question_id: &Thing,
+    ) -> impl Future<Output = Result<Option<serde_json::Value>, anyhow::Error>> + Send;
+
+    /// Quizzes and questions a user wrote that are in the trash, most recently trashed first.
+    fn get_trash(
+        &self,
+        author_id: &str,
+    ) -> impl Future<Output = Result<Vec<TrashItem>, anyhow::Error>> + Send;
+
+    /// `None` unless the quiz or question is in the trash.
+    fn get_trash_item(
+        &self,
+        id: &Thing,
+    ) -> impl Future<Output = Result<Option<TrashItem>, anyhow::Error>> + Send;
+
+    /// Takes a quiz or question back out of the trash, `None` when it was not in there.
+    fn restore(
+        &self,
+        id: &Thing,
+    ) -> impl Future<Output = Result<Option<TrashItem>, anyhow::Error>> + Send;
+
+    /// Deletes everything trashed before `cutoff` for good, quizzes along with all they hold.
+    fn purge_trash(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> impl Future<Output = Result<TrashPurge, anyhow::Error>> + Send;
+}
+
+/// Every saved version of a quiz or question, numbered from 1. Revisions never change once saved,
+/// and go when their quiz or question is deleted for good.
+pub trait RevisionRepo {
+    /// Revisions of a quiz or question, newest first.
+    fn get_revisions(
+        &self,
+        target: &Thing,
+    ) -> impl Future<Output = Result<Vec<SurrealRevision>, anyhow::Error>> + Send;
+
+    /// Revisions by their IDs, in no particular order. IDs without a revision are skipped.
+    fn get_revisions_by_ids(
+        &self,
+        ids: &[Thing],
+    ) -> impl Future<Output = Result<Vec<SurrealRevision>, anyhow::Error>> + Send;
+
+    /// `None` when the quiz or question has no revision with that number.
+    fn get_revision(
+        &self,
+        target: &Thing,
+        number: u32,
+    ) -> impl Future<Output = Result<Option<SurrealRevision>, anyhow::Error>> + Send;
+
+    /// Saves `snapshot` as the next revision of the quiz or question.
+    fn save_revision(
+        &self,
+        target: &Thing,
+        author_id: &str,
+        snapshot: Snapshot,
+    ) -> impl Future<Output = Result<SurrealRevision, anyhow::Error>> + Send;
+
+    /// The latest revision of the quiz or question, saving `current` as its first when it has none.
+    /// Records made before revisions existed get their first one the first time they are pinned.
+    fn pin_revision(
+        &self,
+        target: &Thing,
+        author_id: &str,
+        current: Snapshot,
+    ) -> impl Future<Output = Result<SurrealRevision, anyhow::Error>> + Send;
+
+    /// `pin_revision` for many quizzes and questions at once, in the order they are given.
+    fn pin_revisions(
+        &self,
+        pins: Vec<Pin>,
+    ) -> impl Future<Output = Result<Vec<SurrealRevision>, anyhow::Error>> + Send;
+}
+
+/// Attempts at quizzes. An attempt is submitted once, and only self-marked after that.
+pub trait AttemptRepo {
+    /// `None` when there is no such attempt.
+    fn get_attempt(
+        &self,
+        attempt_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealAttempt>, anyhow::Error>> + Send;
+
+    fn create_attempt(
+        &self,
+        attempt: &Attempt,
+    ) -> impl Future<Output = Result<SurrealAttempt, anyhow::Error>> + Send;
+
+    /// Saves a graded attempt, only if it has not been submitted already.
+    /// `None` when another submission got there first.
+    fn submit_attempt(
+        &self,
+        attempt_id: &Thing,
+        attempt: &Attempt,
+    ) -> impl Future<Output = Result<Option<SurrealAttempt>, anyhow::Error>> + Send;
+
+    /// Records a revealed hint, only while the attempt is unsubmitted.
+    /// `None` when the attempt was submitted first.
+    fn reveal_hint(
+        &self,
+        attempt_id: &Thing,
+        question_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealAttempt>, anyhow::Error>> + Send;
+
+    /// Saves the questions served to an attempt timed per question, only while it is unsubmitted
+    /// and no question was served or answered since `before` was read.
+    /// `None` when the attempt was submitted or moved on first.
+    fn serve_question(
+        &self,
+        attempt_id: &Thing,
+        before: &[QuestionTiming],
+        timings: &[QuestionTiming],
+    ) -> impl Future<Output = Result<Option<SurrealAttempt>, anyhow::Error>> + Send;
+
+    /// Replaces a submitted attempt, for self-marking.
+    fn update_attempt(
+        &self,
+        attempt_id: &Thing,
+        attempt: &Attempt,
+    ) -> impl Future<Output = Result<Option<SurrealAttempt>, anyhow::Error>> + Send;
+
+    /// A user's submitted attempts, oldest first.
+    fn get_submitted_attempts(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<SurrealAttempt>, anyhow::Error>> + Send;
+
+    /// Attempts at a quiz by any of the given users.
+    fn get_quiz_attempts(
+        &self,
+        quiz_id: &Thing,
+        user_ids: &[String],
+    ) -> impl Future<Output = Result<Vec<SurrealAttempt>, anyhow::Error>> + Send;
+}
+
+/// Review cards for study mode, at most one per user and question.
+pub trait StudyRepo {
+    /// A user's cards for the questions of a quiz.
+    fn get_review_cards(
+        &self,
+        user_id: &str,
+        quiz_id: &Thing,
+    ) -> impl Future<Output = Result<Vec<SurrealReviewCard>, anyhow::Error>> + Send;
+
+    /// `None` when the user has never studied the question.
+    fn get_review_card(
+        &self,
+        user_id: &str,
+        question_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealReviewCard>, anyhow::Error>> + Send;
+
+    fn create_review_card(
+        &self,
+        card: &ReviewCard,
+    ) -> impl Future<Output = Result<SurrealReviewCard, anyhow::Error>> + Send;
+
+    fn update_review_card(
+        &self,
+        card_id: &Thing,
+        card: &ReviewCard,
+    ) -> impl Future<Output = Result<Option<SurrealReviewCard>, anyhow::Error>> + Send;
+}
+
+/// Managing tags, and putting them on quizzes and questions.
+/// Reading the tags on a record is done through `QuizRepo`.
+pub trait TagRepo {
+    /// `None` when there is no such tag.
+    fn get_tag(
+        &self,
+        tag_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealTag>, anyhow::Error>> + Send;
+
+    /// A user's tags sorted by name, with how many quizzes and questions have each.
+    fn get_tag_summaries(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<TagSummary>, anyhow::Error>> + Send;
+
+    fn create_tag(
+        &self,
+        tag: &Tag,
+    ) -> impl Future<Output = Result<SurrealTag, anyhow::Error>> + Send;
+
+    fn rename_tag(
+        &self,
+        tag_id: &Thing,
+        name: &str,
+    ) -> impl Future<Output = Result<Option<SurrealTag>, anyhow::Error>> + Send;
+
+    /// Deletes the tag along with its links, so nothing is left pointing at it.
+    fn delete_tag(&self, tag_id: &Thing) -> impl Future<Output = Result<(), anyhow::Error>> + Send;
+
+    /// Sets whether a quiz or question has the tag, a target never has the same tag twice.
+    fn set_tagged(
+        &self,
+        target: &Thing,
+        tag_id: &Thing,
+        tagged: bool,
+    ) -> impl Future<Output = Result<(), anyhow::Error>> + Send;
+
+    /// IDs of an author's questions with the tag, from any of their quizzes, leaving out the trash.
+    fn get_pool_question_ids(
+        &self,
+        author_id: &str,
+        tag_id: &Thing,
+    ) -> impl Future<Output = Result<Vec<Thing>, anyhow::Error>> + Send;
+}
+
+/// Groups of users, their members, and the quizzes assigned to them.
+pub trait GroupRepo {
+    /// `None` when there is no such group.
+    fn get_group(
+        &self,
+        group_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealGroup>, anyhow::Error>> + Send;
+
+    fn get_group_by_code(
+        &self,
+        join_code: &str,
+    ) -> impl Future<Output = Result<Option<SurrealGroup>, anyhow::Error>> + Send;
+
+    /// Groups a user owns or belongs to, sorted by name.
+    fn get_user_groups(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<SurrealGroup>, anyhow::Error>> + Send;
+
+    /// Members of a group, sorted by username.
+    fn get_group_members(
+        &self,
+        group_id: &Thing,
+    ) -> impl Future<Output = Result<Vec<PartialUser>, anyhow::Error>> + Send;
+
+    fn is_member(
+        &self,
+        group_id: &Thing,
+        user_id: &str,
+    ) -> impl Future<Output = Result<bool, anyhow::Error>> + Send;
+
+    fn create_group(
+        &self,
+        group: &Group,
+    ) -> impl Future<Output = Result<SurrealGroup, anyhow::Error>> + Send;
+
+    fn add_member(
+        &self,
+        membership: &Membership,
+    ) -> impl Future<Output = Result<SurrealMembership, anyhow::Error>> + Send;
+
+    /// `None` when there is no such assignment.
+    fn get_assignment(
+        &self,
+        assignment_id: &Thing,
+    ) -> impl Future<Output = Result<Option<SurrealAssignment>, anyhow::Error>> + Send;
+
+    fn create_assignment(
+        &self,
+        assignment: &Assignment,
+    ) -> impl Future<Output = Result<SurrealAssignment, anyhow::Error>> + Send;
+
+    /// Assignments of a group, soonest to close first.
+    fn get_group_assignments(
+        &self,
+        group_id: &Thing,
+    ) -> impl Future<Output = Result<Vec<SurrealAssignment>, anyhow::Error>> + Send;
+
+    /// A quiz's assignments to groups the user belongs to.
+    fn get_member_assignments(
+        &self,
+        user_id: &str,
+        quiz_id: &Thing,
+    ) -> impl Future<Output = Result<Vec<SurrealAssignment>, anyhow::Error>> + Send;
+
+    /// Every assignment for a user with its quiz and group name, soonest to close first.
+    fn get_assigned_quizzes(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<AssignedQuiz>, anyhow::Error>> + Send;
+}
+
+/// Session cookies are stored through `SessionStore`, these are the extras for admin work.
+pub trait SessionRepo: SessionStore {
+    /// Logs a user out everywhere, returning how many sessions were removed.
+    fn delete_user_sessions(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<usize, anyhow::Error>> + Send;
+
+    /// Returns how many sessions were past their expiry.
+    fn purge_expired_sessions(&self) -> impl Future<Output = Result<usize, anyhow::Error>> + Send;
+}
+
+/// Everything the server needs from a storage backend.
+pub trait Repository:
+    LookUpUser
+    + QuizRepo
+    + QuestionRepo
+    + TrashRepo
+    + RevisionRepo
+    + AttemptRepo
+    + StudyRepo
+    + TagRepo
+    + GroupRepo
+    + SessionRepo
+    + Clone
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl<R> Repository for R where
+    R: LookUpUser
+        + QuizRepo
+        + QuestionRepo
+        + TrashRepo
+        + RevisionRepo
+        + AttemptRepo
+        + StudyRepo
+        + TagRepo
+        + GroupRepo
+        + SessionRepo
+        + Clone
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// Lets `CurrentUser` find roles without knowing which backend the server runs on.
+pub trait RoleLookUp: Send + Sync {
+    /// `None` when the user no longer exists.
+    fn get_user_role(&self, user_id: String) -> LocalBoxFuture<'_, anyhow::Result<Option<Role>>>;
+}
+
+impl<R: LookUpUser + Send + Sync> RoleLookUp for R {
+    fn get_user_role(&self, user_id: String) -> LocalBoxFuture<'_, anyhow::Result<Option<Role>>> {
+        Box::pin(async move {
+            let user: Option<GeneralUser> = self.get_user(&user_id).await?;
+            Ok(user.map(|user| user.role))
+        })
+    }
+}
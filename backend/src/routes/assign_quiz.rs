@@ -0,0 +1,122 @@
+//! backend/src/routes/assign_quiz.rs
+//! To assign a quiz to a group, open between two times.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{GroupRepo, QuizRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    groups::{AssignmentJsonPkg, SurrealAssignment, SurrealGroup},
+    model_errors::ModelErrors,
+    quiz::SurrealQuiz,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum AssignQuizError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    PackageError(#[from] ModelErrors),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for AssignQuizError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for AssignQuizError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            AssignQuizError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            AssignQuizError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            AssignQuizError::PackageError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": err.to_string() })),
+            AssignQuizError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AssignQueryString {
+    group: String,
+}
+
+// --- EndPoint ---
+/// Route handler for a group owner assigning one of their quizzes to the group.
+#[tracing::instrument(name = "Request to Assign Quiz", skip(db))]
+pub async fn assign_quiz<R: GroupRepo + QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    group_qp: web::Query<AssignQueryString>,
+    assignment_pt: web::Json<AssignmentJsonPkg>,
+) -> Result<HttpResponse, AssignQuizError> {
+    let assignment_data: AssignmentJsonPkg = assignment_pt.into_inner();
+    assignment_data.validate_fields()?;
+
+    // Decode Query String
+    let group_query_str: String = group_qp.into_inner().group;
+    let decoded_query_str: String = urlencoding::decode(&group_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let group_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(AssignQuizError::ValidationError)?;
+
+    let surreal_group: Option<SurrealGroup> = db
+        .get_group(&group_id)
+        .await
+        .map_err(AssignQuizError::UnexpectedError)?;
+    match &surreal_group {
+        None => {
+            return Err(AssignQuizError::ValidationError(anyhow::anyhow!(
+                "Group does not exist"
+            )));
+        }
+        Some(grp) => user.require_manage(&grp.owner_id)?,
+    }
+
+    // Only quizzes the user could edit can be handed out
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&assignment_data.quiz_id)
+        .await
+        .map_err(AssignQuizError::ValidationError)?;
+    match &surreal_quiz {
+        None => {
+            return Err(AssignQuizError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) => user.require_manage(&qz.author_id)?,
+    }
+
+    let created: SurrealAssignment = db
+        .create_assignment(&assignment_data.into_record(group_id))
+        .await
+        .map_err(AssignQuizError::UnexpectedError)?;
+
+    Ok(HttpResponse::Created().json(created))
+}
@@ -0,0 +1,76 @@
+//! backend/src/routes/create_group.rs
+//! To create a group that quizzes can be assigned to.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::GroupRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::{
+    groups::{Group, GroupJsonPkg, SurrealGroup, JOIN_CODE_LEN},
+    model_errors::ModelErrors,
+    roles::Permission,
+};
+use rand::distributions::{Alphanumeric, DistString};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum CreateGroupError {
+    #[error(transparent)]
+    ValidationError(#[from] ModelErrors),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for CreateGroupError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for CreateGroupError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            CreateGroupError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            CreateGroupError::ValidationError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": err.to_string() })),
+            CreateGroupError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for creating a group, the creator owns it and is given its join code.
+#[tracing::instrument(name = "Request to Create Group", skip(db))]
+pub async fn create_group<R: GroupRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    group_pkg_pt: web::Json<GroupJsonPkg>,
+) -> Result<HttpResponse, CreateGroupError> {
+    user.require(Permission::AuthorQuizzes)?;
+    let group_data: GroupJsonPkg = group_pkg_pt.into_inner();
+    group_data.validate_field()?;
+
+    let join_code: String = Alphanumeric.sample_string(&mut rand::thread_rng(), JOIN_CODE_LEN);
+
+    let created: SurrealGroup = db
+        .create_group(&Group::new(
+            group_data.name.trim().to_string(),
+            user.user_id,
+            join_code,
+        ))
+        .await
+        .map_err(CreateGroupError::UnexpectedError)?;
+
+    Ok(HttpResponse::Created().json(created))
+}
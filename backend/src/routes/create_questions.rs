@@ -1,23 +1,28 @@
 //! backend/src/routes/create_questions.rs
 //! To handle logic for creating questions for user.
-use crate::{error_chain_helper, session_wrapper::SessionWrapper, surrealdb_repo::Database};
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{QuestionRepo, QuizRepo},
+};
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
-use models::questions::SurrealQuestionMC;
+use anyhow::Context;
 use models::{
     model_errors::ModelErrors,
-    questions::{JsonQuestion, QuestionJsonPkg, QuestionMC},
+    questions::{JsonQuestion, QuestionJsonPkg},
+    quiz::SurrealQuiz,
+    roles::Permission,
 };
 use surrealdb::sql::Thing;
-use uuid::Uuid;
 
 // -- Errors --
 #[derive(thiserror::Error)]
 pub enum CreateQuestionError {
     #[error(transparent)]
     ValidationError(#[from] ModelErrors),
-    #[error("{0}")]
-    AuthorizationError(String),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
@@ -42,11 +47,7 @@ impl ResponseError for CreateQuestionError {
                     .insert_header(ContentType::json())
                     .json(serde_json::json!({ "msg": msg }))
             }
-            CreateQuestionError::AuthorizationError(msg) => {
-                HttpResponse::build(StatusCode::UNAUTHORIZED)
-                    .insert_header(ContentType::json())
-                    .json(serde_json::json!({ "msg": msg }))
-            }
+            CreateQuestionError::AccessError(err) => err.error_response(),
         }
     }
 }
@@ -55,14 +56,15 @@ impl ResponseError for CreateQuestionError {
 /// Route handler for creating a new question
 #[tracing::instrument(
     name = "Request to Create Questions"
-    skip(db, session)
+    skip(db)
 )]
-pub async fn create_new_questions(
+pub async fn create_new_questions<R: QuizRepo + QuestionRepo>(
     req: HttpRequest,
-    session: SessionWrapper,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
     question_pkg_pt: web::Json<QuestionJsonPkg>,
 ) -> Result<HttpResponse, CreateQuestionError> {
+    user.require(Permission::AuthorQuizzes)?;
     let question_data: QuestionJsonPkg = question_pkg_pt.into_inner();
     question_data.validate_fields()?;
 
@@ -70,50 +72,20 @@ pub async fn create_new_questions(
     let question: JsonQuestion = question_data.question;
     // `question_data` is no longer valid FYI
 
-    let some_user_id: Option<Uuid> = session
-        .get_user_id()
-        .map_err(|_| CreateQuestionError::UnexpectedError(anyhow::anyhow!("A SessionGetError")))?;
-    dbg!(&some_user_id);
+    let surreal_quiz: SurrealQuiz = db
+        .get_quiz(&quiz_id)
+        .await
+        .context("Failed to fetch quiz")?
+        .ok_or_else(|| ModelErrors::JsonValidation(String::from("Quiz does not exist")))?;
+    user.require_manage(&surreal_quiz.author_id)?;
+    // Questions belong to the quiz author, even when a moderator adds them
+    let user_id: String = surreal_quiz.author_id;
 
-    // Middleware should catch unauthorized users, but just in case
-    let user_id: String = if let Some(id) = some_user_id {
-        id.to_string()
-    } else {
-        return Err(CreateQuestionError::AuthorizationError(
-            "Session Token not found".to_string(),
-        ));
-    };
-
-    // When more types become available, this match will fill out.
-    let json_val: serde_json::Value = match question {
-        JsonQuestion::MultipleChoice(what) => {
-            // -- Save Question into Database
-            let res: Vec<SurrealQuestionMC> = db
-                .client
-                .create("questions_mc")
-                .content(QuestionMC {
-                    question: what.question,
-                    hint: what.hint,
-                    author_id: user_id.clone(),
-                    parent_quiz: quiz_id.clone(),
-                    answer: what.answer,
-                    choices: what.choices,
-                })
-                .await
-                .map_err(|e| CreateQuestionError::UnexpectedError(anyhow::anyhow!(e)))?;
-            // Check it returned correctly
-            if res.len() < 1 {
-                return Err(CreateQuestionError::UnexpectedError(anyhow::anyhow!(
-                    "No values returned"
-                )));
-            }
-
-            let it: &SurrealQuestionMC = &res[0];
-
-            serde_json::to_value(it)
-                .map_err(|e| CreateQuestionError::UnexpectedError(anyhow::anyhow!(e)))?
-        }
-    };
+    // Saved in the table for its type, and returned in that shape
+    let json_val: serde_json::Value = db
+        .create_question(question, user_id, quiz_id)
+        .await
+        .context("Failed to save question")?;
 
     Ok(HttpResponse::Created().json(json_val))
 }
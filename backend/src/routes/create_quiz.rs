@@ -1,22 +1,29 @@
 //! backend/src/routes/create_quiz.rs
 //! To handle logic for creating quizzes for user.
-use crate::{error_chain_helper, session_wrapper::SessionWrapper, surrealdb_repo::Database};
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::QuizRepo,
+};
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
 use models::{
     model_errors::ModelErrors,
+    pools::{validate_pools, PoolDraw},
     quiz::{Quiz, QuizJsonPkg, SurrealQuiz},
+    roles::Permission,
+    tags::SurrealTag,
 };
 use surrealdb::sql::Id;
-use uuid::Uuid;
 
 // Errors
 #[derive(thiserror::Error)]
 pub enum CreateQuizError {
     #[error(transparent)]
     ValidationError(#[from] ModelErrors),
-    #[error("{0}")]
-    AuthorizationError(String),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
@@ -39,11 +46,7 @@ impl ResponseError for CreateQuizError {
             CreateQuizError::ValidationError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
                 .insert_header(ContentType::json())
                 .json(serde_json::json!({ "msg": err.to_string() })),
-            CreateQuizError::AuthorizationError(msg) => {
-                HttpResponse::build(StatusCode::UNAUTHORIZED)
-                    .insert_header(ContentType::json())
-                    .json(serde_json::json!({ "msg": msg }))
-            }
+            CreateQuizError::AccessError(err) => err.error_response(),
         }
     }
 }
@@ -52,47 +55,36 @@ impl ResponseError for CreateQuizError {
 /// Route handler for creating a new quizzes
 #[tracing::instrument(
     name = "Request to Create Quiz"
-    skip(db, session)
+    skip(db)
 )]
-pub async fn create_new_quiz(
+pub async fn create_new_quiz<R: QuizRepo>(
     req: HttpRequest,
-    session: SessionWrapper,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
     quiz_pkg_pt: web::Json<QuizJsonPkg>,
 ) -> Result<HttpResponse, CreateQuizError> {
+    user.require(Permission::AuthorQuizzes)?;
     let quiz_data: QuizJsonPkg = quiz_pkg_pt.into_inner();
     quiz_data.validate_field()?;
 
-    let some_user_id: Option<Uuid> = session
-        .get_user_id()
-        .map_err(|_| CreateQuizError::UnexpectedError(anyhow::anyhow!("A SessionGetError")))?;
-    dbg!(&some_user_id);
-
-    // Middleware should catch unauthorized users, but just in case
-    let user_id: String = if let Some(id) = some_user_id {
-        id.to_string()
-    } else {
-        return Err(CreateQuizError::AuthorizationError(
-            "Session Token not found".to_string(),
-        ));
-    };
+    let pools: Vec<PoolDraw> = quiz_data.pools.clone().unwrap_or_default();
+    if !pools.is_empty() {
+        let tags: Vec<SurrealTag> = db
+            .get_user_tags(&user.user_id)
+            .await
+            .context("Failed to fetch tags")?;
+        validate_pools(&pools, &tags)?;
+    }
 
-    let quiz_to_save: Quiz = Quiz::new(quiz_data.name, quiz_data.description, user_id);
+    let mut quiz_to_save: Quiz = quiz_data.into_record(user.user_id);
+    quiz_to_save.pools = pools;
     dbg!(&quiz_to_save);
     dbg!(Id::uuid().to_string());
 
-    let created: Vec<SurrealQuiz> = db
-        .client
-        .create("quizzes")
-        .content(&quiz_to_save)
+    let created: SurrealQuiz = db
+        .create_quiz(&quiz_to_save)
         .await
-        .map_err(|e| CreateQuizError::UnexpectedError(anyhow::anyhow!(e)))?;
+        .context("Failed to save quiz")?;
 
-    if created.len() == 1 {
-        Ok(HttpResponse::Ok().json(&created[0]))
-    } else {
-        Err(CreateQuizError::UnexpectedError(anyhow::anyhow!(
-            "Unsure what happened in Database"
-        )))
-    }
+    Ok(HttpResponse::Ok().json(&created))
 }
@@ -0,0 +1,82 @@
+//! backend/src/routes/create_tag.rs
+//! To create a tag for organising quizzes and questions.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{QuizRepo, TagRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::{
+    model_errors::ModelErrors,
+    roles::Permission,
+    tags::{SurrealTag, Tag, TagJsonPkg},
+};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum CreateTagError {
+    #[error(transparent)]
+    ValidationError(#[from] ModelErrors),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for CreateTagError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for CreateTagError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            CreateTagError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            CreateTagError::ValidationError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": err.to_string() })),
+            CreateTagError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for creating a new tag, names are unique per user ignoring case.
+/// Only users who can author quizzes have anything to tag.
+#[tracing::instrument(name = "Request to Create Tag", skip(db))]
+pub async fn create_tag<R: QuizRepo + TagRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    tag_pkg_pt: web::Json<TagJsonPkg>,
+) -> Result<HttpResponse, CreateTagError> {
+    user.require(Permission::AuthorQuizzes)?;
+    let tag_data: TagJsonPkg = tag_pkg_pt.into_inner();
+    tag_data.validate_field()?;
+
+    let user_id: String = user.user_id.clone();
+
+    let existing: Vec<SurrealTag> = db
+        .get_user_tags(&user_id)
+        .await
+        .map_err(|err| CreateTagError::UnexpectedError(anyhow::anyhow!(err)))?;
+    if existing.iter().any(|tg| tg.same_name(&tag_data.name)) {
+        return Err(CreateTagError::ValidationError(
+            ModelErrors::JsonValidation(String::from("Tag already exists")),
+        ));
+    }
+
+    let created: SurrealTag = db
+        .create_tag(&Tag::new(tag_data.name.trim().to_string(), user_id))
+        .await
+        .map_err(CreateTagError::UnexpectedError)?;
+
+    Ok(HttpResponse::Created().json(created))
+}
@@ -2,12 +2,12 @@
 //! To handle logic for creating user.
 use crate::authentication::create_password_hash;
 use crate::error_chain_helper;
-use crate::surrealdb_repo::Database;
+use crate::repository::LookUpUser;
 use actix_web::http::header::ContentType;
 use actix_web::http::StatusCode;
 use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
 use anyhow::Context;
-use models::GeneralUser;
+use models::{roles::Role, GeneralUser};
 use secrecy::{ExposeSecret, Secret};
 use serde::Deserialize;
 use uuid::Uuid;
@@ -50,6 +50,9 @@ pub struct CreateUserPayload {
     username: String,
     // Need secrect to hid password in logs
     password: Secret<String>,
+    // only a student may be asked for, other roles are given by an admin
+    #[serde(default)]
+    role: Option<Role>,
 }
 
 /// Allowing GeneralUser to be derived from CreateUserPayload
@@ -66,6 +69,7 @@ impl From<CreateUserPayload> for GeneralUser {
             value.name,
             value.username,
             password_hash.expose_secret().to_string(),
+            Role::SIGN_UP,
         )
     }
 }
@@ -86,6 +90,10 @@ impl CreateUserPayload {
             Err(CreateUserError::ValidationError(
                 "Password must be at least 6 characters long".to_string(),
             ))
+        } else if self.role.is_some_and(|role| role != Role::SIGN_UP) {
+            Err(CreateUserError::ValidationError(String::from(
+                "Accounts start as students, an admin can make you an instructor",
+            )))
         } else {
             Ok(())
         }
@@ -100,9 +108,9 @@ impl CreateUserPayload {
 /// Takes in JSON with user information and stores in database.
 /// If successful, returns 201 CREATED.
 #[tracing::instrument(name = "Request to Create User", skip(db))]
-pub async fn create_user(
+pub async fn create_user<R: LookUpUser>(
     req: HttpRequest, // for tracing
-    db: web::Data<Database>,
+    db: web::Data<R>,
     user_info_pt: web::Json<CreateUserPayload>,
 ) -> Result<HttpResponse, CreateUserError> {
     let user_data = user_info_pt.into_inner();
@@ -113,7 +121,10 @@ pub async fn create_user(
     let _ = unique_username(&db, &user_data.username).await?;
 
     // Do not return, General User has hashed password
-    let _: Option<GeneralUser> = db.add_general_user(user_data.into()).await;
+    let _: Option<GeneralUser> = db
+        .add_general_user(user_data.into())
+        .await
+        .context("Failed to create user")?;
 
     // Unless Something comes up, no good reason to return JSON information
     Ok(HttpResponse::Created()
@@ -122,8 +133,8 @@ pub async fn create_user(
 }
 
 /// Helper function to check if the username already exists
-async fn unique_username(
-    db: &web::Data<Database>,
+async fn unique_username<R: LookUpUser>(
+    db: &web::Data<R>,
     username: &str,
 ) -> Result<bool, CreateUserError> {
     let users = db
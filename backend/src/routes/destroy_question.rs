@@ -1,27 +1,24 @@
 //! backend/src/routes/destroy_question.rs
-//! to delete a question from the database.
+//! to move a question to the trash, where it can be restored until it is purged.
+use crate::authentication::{CurrentUser, GuardError};
 use crate::error_chain_helper;
-use crate::session_wrapper::SessionWrapper;
-use crate::surrealdb_repo::Database;
+use crate::repository::{QuestionRepo, TrashRepo};
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::web;
 use actix_web::{HttpRequest, HttpResponse, ResponseError};
 use anyhow::Context;
-use models::questions::{SurrealGenericQuestionData, SurrealQuestionMC};
+use models::questions::SurrealGenericQuestionData;
 use serde::Deserialize;
 use surrealdb::sql::{thing, Thing};
-use uuid::Uuid;
 
 // Errors
 #[derive(thiserror::Error)]
 pub enum DestroyQuestError {
-    #[error("{0}")]
-    AuthorizationError(String),
-    #[error("{0}")]
-    OwnershipError(#[source] anyhow::Error),
     #[error("{0}")]
     ValidationError(#[source] anyhow::Error),
     #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
 
@@ -40,14 +37,7 @@ impl ResponseError for DestroyQuestError {
                     .insert_header(ContentType::json())
                     .json(serde_json::json!({"msg": "Unknown Error"}))
             }
-            DestroyQuestError::AuthorizationError(msg) => {
-                HttpResponse::build(StatusCode::UNAUTHORIZED)
-                    .insert_header(ContentType::json())
-                    .json(serde_json::json!({ "msg": msg }))
-            }
-            DestroyQuestError::OwnershipError(anywho) => HttpResponse::build(StatusCode::FORBIDDEN)
-                .insert_header(ContentType::json())
-                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            DestroyQuestError::AccessError(err) => err.error_response(),
             DestroyQuestError::ValidationError(anywho) => {
                 HttpResponse::build(StatusCode::BAD_REQUEST)
                     .insert_header(ContentType::json())
@@ -63,27 +53,14 @@ pub struct QuestDestroyerQueryString {
 }
 
 // --- EndPoint ---
-/// Route handler for deleting questions from database.
-#[tracing::instrument(name = "Request to Destroy User's Quiz by User", skip(db, session))]
-pub async fn destroy_my_quest(
+/// Route handler for deleting questions, which trashes them until they are purged.
+#[tracing::instrument(name = "Request to Destroy User's Quiz by User", skip(db))]
+pub async fn destroy_my_quest<R: QuestionRepo + TrashRepo>(
     req: HttpRequest,
-    session: SessionWrapper,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
     quest_qp: web::Query<QuestDestroyerQueryString>,
 ) -> Result<HttpResponse, DestroyQuestError> {
-    let some_user_id: Option<Uuid> = session
-        .get_user_id()
-        .map_err(|_| DestroyQuestError::UnexpectedError(anyhow::anyhow!("A SessionGetError")))?;
-
-    // Middleware should catch unauthorized users, but just in case
-    let user_id: String = if let Some(id) = some_user_id {
-        id.to_string()
-    } else {
-        return Err(DestroyQuestError::AuthorizationError(
-            "Session Token not found".to_string(),
-        ));
-    };
-
     // Decode Query String
     let quest_query_string: String = quest_qp.into_inner().quest;
     let decoded_query_str: String = urlencoding::decode(&quest_query_string)
@@ -97,10 +74,9 @@ pub async fn destroy_my_quest(
 
     // Checking  -- Error returned from database indicates no ID exists.
     let surreal_quest: Option<SurrealGenericQuestionData> = db
-        .client
-        .select(&quest_id)
+        .get_question(&quest_id)
         .await
-        .map_err(|err| DestroyQuestError::ValidationError(anyhow::anyhow!(err)))?;
+        .map_err(DestroyQuestError::ValidationError)?;
 
     // Sanity checks
     match &surreal_quest {
@@ -110,20 +86,15 @@ pub async fn destroy_my_quest(
             )));
         }
         Some(qz) => {
-            if qz.author_id != user_id {
-                return Err(DestroyQuestError::OwnershipError(anyhow::anyhow!(
-                    "User does not own question"
-                )));
-            }
+            user.require_manage(&qz.author_id)?;
         }
     }
 
-    // Delete Quiz
-    let deleted_quest: Option<SurrealQuestionMC> = db
-        .client
-        .delete(&quest_id)
+    // Trash Question - returned in the shape of its own type
+    let deleted_quest: Option<serde_json::Value> = db
+        .trash_question(&quest_id)
         .await
-        .map_err(|err| DestroyQuestError::UnexpectedError(anyhow::anyhow!(err)))?;
+        .context("Failed to trash question")?;
 
     // After removing vector to track questions on Quiz, nothing more to do
     Ok(HttpResponse::Ok().json(deleted_quest))
@@ -1,8 +1,8 @@
 //! backend/src/routes/destroy_quiz.rs
-//! To delete a quiz and related questions from database.
+//! To move a quiz to the trash, where it can be restored until it is purged.
+use crate::authentication::{CurrentUser, GuardError};
 use crate::error_chain_helper;
-use crate::session_wrapper::SessionWrapper;
-use crate::surrealdb_repo::Database;
+use crate::repository::{QuizRepo, TrashRepo};
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::web;
 use actix_web::{HttpRequest, HttpResponse, ResponseError};
@@ -10,18 +10,15 @@ use anyhow::Context;
 use models::quiz::SurrealQuiz;
 use serde::Deserialize;
 use surrealdb::sql::{thing, Thing};
-use uuid::Uuid;
 
 // Errors
 #[derive(thiserror::Error)]
 pub enum DestroyQuizError {
-    #[error("{0}")]
-    AuthorizationError(String),
-    #[error("{0}")]
-    OwnershipError(#[source] anyhow::Error),
     #[error("{0}")]
     ValidationError(#[source] anyhow::Error),
     #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
 
@@ -40,14 +37,7 @@ impl ResponseError for DestroyQuizError {
                     .insert_header(ContentType::json())
                     .json(serde_json::json!({"msg": "errUnknown Error"}))
             }
-            DestroyQuizError::AuthorizationError(msg) => {
-                HttpResponse::build(StatusCode::UNAUTHORIZED)
-                    .insert_header(ContentType::json())
-                    .json(serde_json::json!({ "msg": msg }))
-            }
-            DestroyQuizError::OwnershipError(anywho) => HttpResponse::build(StatusCode::FORBIDDEN)
-                .insert_header(ContentType::json())
-                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            DestroyQuizError::AccessError(err) => err.error_response(),
             DestroyQuizError::ValidationError(anywho) => {
                 HttpResponse::build(StatusCode::BAD_REQUEST)
                     .insert_header(ContentType::json())
@@ -63,27 +53,15 @@ pub struct QuizDestroyerQueryString {
 }
 
 // --- EndPoint ---
-/// Route handler for deleting a quiz and associated questions from database.
-#[tracing::instrument(name = "Request to Destroy User's Quiz by User", skip(db, session))]
-pub async fn destroy_my_quiz(
+/// Route handler for deleting a quiz, which trashes it along with its questions.
+/// Everything is purged for good once it has been in the trash past the retention window.
+#[tracing::instrument(name = "Request to Destroy User's Quiz by User", skip(db))]
+pub async fn destroy_my_quiz<R: QuizRepo + TrashRepo>(
     req: HttpRequest,
-    session: SessionWrapper,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
     quiz: web::Query<QuizDestroyerQueryString>,
 ) -> Result<HttpResponse, DestroyQuizError> {
-    let some_user_id: Option<Uuid> = session
-        .get_user_id()
-        .map_err(|_| DestroyQuizError::UnexpectedError(anyhow::anyhow!("A SessionGetError")))?;
-
-    // Middleware should catch unauthorized users, but just in case
-    let user_id: String = if let Some(id) = some_user_id {
-        id.to_string()
-    } else {
-        return Err(DestroyQuizError::AuthorizationError(
-            "Session Token not found".to_string(),
-        ));
-    };
-
     // Decode Query String
     let quiz_query_str: String = quiz.into_inner().quiz;
     let decoded_query_str: String = urlencoding::decode(&quiz_query_str)
@@ -97,10 +75,9 @@ pub async fn destroy_my_quiz(
 
     // Checking -- Error returned from database indicates no ID exists.
     let surreal_quiz: Option<SurrealQuiz> = db
-        .client
-        .select(&quiz_id)
+        .get_quiz(&quiz_id)
         .await
-        .map_err(|err| DestroyQuizError::ValidationError(anyhow::anyhow!(err)))?;
+        .map_err(DestroyQuizError::ValidationError)?;
 
     // Sanity checks
     match &surreal_quiz {
@@ -110,34 +87,15 @@ pub async fn destroy_my_quiz(
             )));
         }
         Some(qz) => {
-            if qz.author_id != user_id {
-                return Err(DestroyQuizError::OwnershipError(anyhow::anyhow!(
-                    "User does not own quiz"
-                )));
-            }
+            user.require_manage(&qz.author_id)?;
         }
     }
 
-    // Delete Quiz
-    let deleted_quiz: Option<SurrealQuiz> = db
-        .client
-        .delete(&quiz_id)
-        .await
-        .map_err(|err| DestroyQuizError::UnexpectedError(anyhow::anyhow!(err)))?;
-    // Delete related questions
-
-    // Delete from MC table
-    let surreal_ql = r#"DELETE type::table($table)
-    WHERE author_id = $user_id
-    AND parent_quiz = $quiz_id"#;
-    let _surreal_response: surrealdb::Response = db
-        .client
-        .query(surreal_ql)
-        .bind(("table", "questions_mc"))
-        .bind(("user_id", user_id))
-        .bind(("quiz_id", &quiz_id))
+    // Trash Quiz, its questions are hidden with it
+    let trashed_quiz: Option<SurrealQuiz> = db
+        .trash_quiz(&quiz_id)
         .await
-        .map_err(|err| DestroyQuizError::UnexpectedError(anyhow::anyhow!(err)))?;
+        .context("Failed to trash quiz")?;
 
-    Ok(HttpResponse::Ok().json(deleted_quiz))
+    Ok(HttpResponse::Ok().json(trashed_quiz))
 }
@@ -0,0 +1,95 @@
+//! backend/src/routes/destroy_tag.rs
+//! To delete a tag, removing it from every quiz and question.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::TagRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::tags::SurrealTag;
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum DestroyTagError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for DestroyTagError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for DestroyTagError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            DestroyTagError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            DestroyTagError::ValidationError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": err.to_string() })),
+            DestroyTagError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DestroyTagQueryString {
+    tag: String,
+}
+
+// --- EndPoint ---
+/// Route handler for deleting a tag, the quizzes and questions it was on are kept.
+/// Moderators may delete anyone's tag.
+#[tracing::instrument(name = "Request to Destroy Tag", skip(db))]
+pub async fn destroy_tag<R: TagRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    tag_qp: web::Query<DestroyTagQueryString>,
+) -> Result<HttpResponse, DestroyTagError> {
+    // Decode Query String
+    let decoded_query_str: String = urlencoding::decode(&tag_qp.into_inner().tag)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let tag_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(DestroyTagError::ValidationError)?;
+
+    let surreal_tag: Option<SurrealTag> = db
+        .get_tag(&tag_id)
+        .await
+        .map_err(DestroyTagError::UnexpectedError)?;
+
+    let tag: SurrealTag = match surreal_tag {
+        None => {
+            return Err(DestroyTagError::ValidationError(anyhow::anyhow!(
+                "Tag does not exist"
+            )));
+        }
+        Some(tg) => tg,
+    };
+    user.require_manage(&tag.author_id)?;
+
+    // Relations go with the tag, so nothing is left pointing at it
+    db.delete_tag(&tag_id)
+        .await
+        .map_err(DestroyTagError::UnexpectedError)?;
+
+    Ok(HttpResponse::Ok().json(tag))
+}
@@ -0,0 +1,107 @@
+//! backend/src/routes/diff_revisions.rs
+//! To compare two revisions of a quiz or question.
+use crate::authentication::{CurrentUser, GuardError};
+use crate::error_chain_helper;
+use crate::repository::{QuestionRepo, QuizRepo, RevisionRepo};
+use crate::routes::target_author;
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::web;
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::revisions::{RevisionDiff, SurrealRevision};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum DiffRevisionsError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for DiffRevisionsError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for DiffRevisionsError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            DiffRevisionsError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            DiffRevisionsError::AccessError(err) => err.error_response(),
+            DiffRevisionsError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DiffQueryString {
+    target: String,
+    from: u32,
+    to: u32,
+}
+
+// --- EndPoint ---
+/// Route handler for the fields that changed going from one revision to another.
+/// Either may be the older one, going backwards lists the changes the other way round.
+#[tracing::instrument(name = "Request to Diff Revisions", skip(db))]
+pub async fn diff_revisions<R: QuizRepo + QuestionRepo + RevisionRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    diff_qp: web::Query<DiffQueryString>,
+) -> Result<HttpResponse, DiffRevisionsError> {
+    let diff_query: DiffQueryString = diff_qp.into_inner();
+
+    // Decode Query String
+    let decoded_query_str: String = urlencoding::decode(&diff_query.target)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let target_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(DiffRevisionsError::ValidationError)?;
+
+    let author_id: Option<String> = target_author(db.get_ref(), &target_id)
+        .await
+        .map_err(DiffRevisionsError::ValidationError)?;
+    match author_id {
+        None => {
+            return Err(DiffRevisionsError::ValidationError(anyhow::anyhow!(
+                "Quiz or question does not exist"
+            )));
+        }
+        Some(author_id) => user.require_manage(&author_id)?,
+    }
+
+    let from: Option<SurrealRevision> = db
+        .get_revision(&target_id, diff_query.from)
+        .await
+        .context("Failed to fetch revision")?;
+    let to: Option<SurrealRevision> = db
+        .get_revision(&target_id, diff_query.to)
+        .await
+        .context("Failed to fetch revision")?;
+
+    match (from, to) {
+        (Some(from), Some(to)) => Ok(HttpResponse::Ok().json(RevisionDiff::new(&from, &to))),
+        _ => Err(DiffRevisionsError::ValidationError(anyhow::anyhow!(
+            "Revision does not exist"
+        ))),
+    }
+}
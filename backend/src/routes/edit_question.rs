@@ -1,25 +1,27 @@
 //! backend/src/routes/create_quesstions.rs
 //! To handle logic for editing questions.
-use crate::{error_chain_helper, session_wrapper::SessionWrapper, surrealdb_repo::Database};
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{QuestionRepo, RevisionRepo},
+};
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
 use anyhow::Context;
-use models::questions::{
-    EditQuestionJsonPkg, JsonQuestion, SurrealGenericQuestionData, SurrealQuestionMC,
+use models::{
+    questions::{EditQuestionJsonPkg, JsonQuestion, SurrealGenericQuestionData},
+    revisions::{diff_snapshots, Edit, Snapshot, SurrealRevision, WrittenQuestion},
 };
 use serde::Deserialize;
 use surrealdb::sql::{thing, Thing};
-use uuid::Uuid;
 
 // -- Errors --
 #[derive(thiserror::Error)]
 pub enum EditQuestionError {
     #[error("{0}")]
     ValidationError(#[source] anyhow::Error),
-    #[error("{0}")]
-    OwnershipError(#[source] anyhow::Error),
-    #[error("{0}")]
-    AuthorizationError(String),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
@@ -44,14 +46,7 @@ impl ResponseError for EditQuestionError {
                     .insert_header(ContentType::json())
                     .json(serde_json::json!({ "msg": anywho.to_string() }))
             }
-            EditQuestionError::OwnershipError(anywho) => HttpResponse::build(StatusCode::FORBIDDEN)
-                .insert_header(ContentType::json())
-                .json(serde_json::json!({ "msg": anywho.to_string() })),
-            EditQuestionError::AuthorizationError(msg) => {
-                HttpResponse::build(StatusCode::UNAUTHORIZED)
-                    .insert_header(ContentType::json())
-                    .json(serde_json::json!({ "msg": msg }))
-            }
+            EditQuestionError::AccessError(err) => err.error_response(),
         }
     }
 }
@@ -63,14 +58,15 @@ pub struct EditQuestQueryString {
 
 // --- EndPoint ---
 /// Route handler for editing questions.
+/// The question before and after the edit are both kept as revisions.
 #[tracing::instrument(
     name = "Request to Edit Questions"
-    skip(db, session)
+    skip(db)
 )]
-pub async fn edit_question(
+pub async fn edit_question<R: QuestionRepo + RevisionRepo>(
     req: HttpRequest,
-    session: SessionWrapper,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
     quest_qp: web::Query<EditQuestQueryString>,
     question_pkg_pt: web::Json<EditQuestionJsonPkg>,
 ) -> Result<HttpResponse, EditQuestionError> {
@@ -83,19 +79,6 @@ pub async fn edit_question(
     let question: JsonQuestion = question_data.question;
 
     // Get User Session ID
-    let some_user_id: Option<Uuid> = session
-        .get_user_id()
-        .map_err(|_| EditQuestionError::UnexpectedError(anyhow::anyhow!("A SessionGetError")))?;
-
-    // Middleware should catch unauthorized users, but just in case
-    let user_id: String = if let Some(id) = some_user_id {
-        id.to_string()
-    } else {
-        return Err(EditQuestionError::AuthorizationError(
-            "Session Token not found".to_string(),
-        ));
-    };
-
     // Decode Query String
     let quest_query_string: String = quest_qp.into_inner().quest;
     let decoded_query_string: String = urlencoding::decode(&quest_query_string)
@@ -109,10 +92,9 @@ pub async fn edit_question(
 
     // Checking  -- Error returned from database indicates no ID exists.
     let surreal_quest: Option<SurrealGenericQuestionData> = db
-        .client
-        .select(&quest_id)
+        .get_question(&quest_id)
         .await
-        .map_err(|err| EditQuestionError::ValidationError(anyhow::anyhow!(err)))?;
+        .map_err(EditQuestionError::ValidationError)?;
 
     // Sanity checks
     match &surreal_quest {
@@ -122,32 +104,53 @@ pub async fn edit_question(
             )));
         }
         Some(qz) => {
-            if qz.author_id != user_id {
-                return Err(EditQuestionError::OwnershipError(anyhow::anyhow!(
-                    "User does not own question"
-                )));
-            }
+            user.require_manage(&qz.author_id)?;
         }
     }
 
-    // As more question types are added, this match return statement will fill out.
-    match question {
-        JsonQuestion::MultipleChoice(what) => {
-            // -- Save Question into Database
-            let updated: Option<SurrealQuestionMC> = db
-                .client
-                .update(quest_id)
-                .merge(&what)
-                .await
-                .map_err(|e| EditQuestionError::UnexpectedError(anyhow::anyhow!(e)))?;
-            // Check it returned correctly
-            if let Some(qst) = updated {
-                Ok(HttpResponse::Ok().json(&qst))
-            } else {
-                Err(EditQuestionError::UnexpectedError(anyhow::anyhow!(
-                    "Error updating question"
-                )))
-            }
-        }
+    // A question cannot change type, it would have to move tables
+    if quest_id.tb != question.table_name() {
+        return Err(EditQuestionError::ValidationError(anyhow::anyhow!(
+            "Question type does not match existing question"
+        )));
+    }
+
+    // Questions from before revisions get their first one now, as the author left it
+    let current: WrittenQuestion = db
+        .get_questions_by_ids(std::slice::from_ref(&quest_id))
+        .await
+        .context("Failed to fetch question")?
+        .written()
+        .into_iter()
+        .next()
+        .context("Question went missing")?;
+    let pinned: SurrealRevision = db
+        .pin_revision(
+            &quest_id,
+            &current.author_id,
+            Snapshot::Question(current.question),
+        )
+        .await
+        .context("Failed to save revision")?;
+    let snapshot: Snapshot = Snapshot::Question(question.clone());
+    // Edits that change nothing do not make a revision
+    let revision: Option<Edit> =
+        (!diff_snapshots(&pinned.snapshot, &snapshot).is_empty()).then(|| Edit {
+            author_id: user.user_id.clone(),
+            snapshot,
+        });
+
+    // Saved along with its revision, or not at all
+    let updated: Option<serde_json::Value> = db
+        .update_question(&quest_id, question, revision)
+        .await
+        .context("Failed to update question")?;
+    // Check it returned correctly
+    if let Some(qst) = updated {
+        Ok(HttpResponse::Ok().json(&qst))
+    } else {
+        Err(EditQuestionError::UnexpectedError(anyhow::anyhow!(
+            "Error updating question"
+        )))
     }
 }
@@ -1,23 +1,29 @@
 //! backend/src/routes/edit_quiz.rs
 //! Endpoint to edit quiz information.
-use crate::{error_chain_helper, session_wrapper::SessionWrapper, surrealdb_repo::Database};
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{QuizRepo, RevisionRepo},
+};
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
 use anyhow::Context;
-use models::quiz::{QuizJsonPkg, SurrealQuiz};
+use models::{
+    pools::validate_pools,
+    quiz::{QuizJsonPkg, SurrealQuiz},
+    revisions::{diff_snapshots, Edit, Snapshot, SurrealRevision},
+    tags::SurrealTag,
+};
 use serde::Deserialize;
 use surrealdb::sql::{thing, Thing};
-use uuid::Uuid;
 
 // Errors
 #[derive(thiserror::Error)]
 pub enum EditQuizError {
     #[error("{0}")]
     ValidationError(#[source] anyhow::Error),
-    #[error("{0}")]
-    AuthorizationError(String),
-    #[error("{0}")]
-    OwnershipError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
@@ -40,12 +46,7 @@ impl ResponseError for EditQuizError {
             EditQuizError::ValidationError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
                 .insert_header(ContentType::json())
                 .json(serde_json::json!({ "msg": err.to_string() })),
-            EditQuizError::OwnershipError(anywho) => HttpResponse::build(StatusCode::FORBIDDEN)
-                .insert_header(ContentType::json())
-                .json(serde_json::json!({ "msg": anywho.to_string() })),
-            EditQuizError::AuthorizationError(msg) => HttpResponse::build(StatusCode::UNAUTHORIZED)
-                .insert_header(ContentType::json())
-                .json(serde_json::json!({ "msg": msg })),
+            EditQuizError::AccessError(err) => err.error_response(),
         }
     }
 }
@@ -56,15 +57,16 @@ pub struct QuizEditorQueryString {
 }
 
 // --- EndPoint ---
-/// Route handler for editing quizzes
+/// Route handler for editing quizzes.
+/// The quiz before and after the edit are both kept as revisions.
 #[tracing::instrument(
     name = "Request to Edit Quiz"
-    skip(db, session)
+    skip(db)
 )]
-pub async fn edit_quiz(
+pub async fn edit_quiz<R: QuizRepo + RevisionRepo>(
     req: HttpRequest,
-    session: SessionWrapper,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
     quiz: web::Query<QuizEditorQueryString>,
     quiz_pkg_pt: web::Json<QuizJsonPkg>,
 ) -> Result<HttpResponse, EditQuizError> {
@@ -75,20 +77,6 @@ pub async fn edit_quiz(
         .context("Validation error")
         .map_err(|err| EditQuizError::ValidationError(err))?;
 
-    let some_user_id: Option<Uuid> = session
-        .get_user_id()
-        .map_err(|_| EditQuizError::UnexpectedError(anyhow::anyhow!("A SessionGetError")))?;
-    dbg!(&some_user_id);
-
-    // Middleware should catch unauthorized users, but just in case
-    let user_id: String = if let Some(id) = some_user_id {
-        id.to_string()
-    } else {
-        return Err(EditQuizError::AuthorizationError(
-            "Session Token not found".to_string(),
-        ));
-    };
-
     // Decode Query String
     let quiz_query_string: String = quiz.into_inner().quiz;
     let decoded_query_string: String = urlencoding::decode(&quiz_query_string)
@@ -101,32 +89,50 @@ pub async fn edit_quiz(
         .map_err(|err| EditQuizError::ValidationError(err))?;
 
     let surreal_quiz: Option<SurrealQuiz> = db
-        .client
-        .select(&quiz_id)
+        .get_quiz(&quiz_id)
         .await
-        .map_err(|err| EditQuizError::ValidationError(anyhow::anyhow!(err)))?;
+        .map_err(EditQuizError::ValidationError)?;
 
-    match &surreal_quiz {
+    let (current, pinned): (&SurrealQuiz, SurrealRevision) = match &surreal_quiz {
         None => {
             return Err(EditQuizError::ValidationError(anyhow::anyhow!(
                 "Quiz does not exist"
             )));
         }
         Some(qz) => {
-            if qz.author_id != user_id {
-                return Err(EditQuizError::OwnershipError(anyhow::anyhow!(
-                    "User does not own quiz"
-                )));
+            user.require_manage(&qz.author_id)?;
+            // Pools draw from the author's tags, even when a moderator edits the quiz
+            if let Some(pools) = &quiz_data.pools {
+                let tags: Vec<SurrealTag> = db
+                    .get_user_tags(&qz.author_id)
+                    .await
+                    .context("Failed to fetch tags")?;
+                validate_pools(pools, &tags)
+                    .context("Validation error")
+                    .map_err(EditQuizError::ValidationError)?;
             }
+            // Quizzes from before revisions get their first one now, as the author left it
+            let pinned: SurrealRevision = db
+                .pin_revision(&quiz_id, &qz.author_id, Snapshot::of_quiz(qz))
+                .await
+                .context("Failed to save revision")?;
+            (qz, pinned)
         }
-    }
+    };
+
+    let snapshot: Snapshot = Snapshot::of_edited_quiz(current, &quiz_data);
+    // Edits that change nothing do not make a revision
+    let revision: Option<Edit> =
+        (!diff_snapshots(&pinned.snapshot, &snapshot).is_empty()).then(|| Edit {
+            author_id: user.user_id.clone(),
+            snapshot,
+        });
 
+    // Saved along with its revision, or not at all
     let created: Option<SurrealQuiz> = db
-        .client
-        .update(quiz_id)
-        .merge(&quiz_data)
+        .update_quiz(&quiz_id, &quiz_data, revision)
         .await
-        .map_err(|e| EditQuizError::UnexpectedError(anyhow::anyhow!(e)))?;
+        .context("Failed to update quiz")?;
 
     if let Some(qz) = created {
         Ok(HttpResponse::Ok().json(&qz))
@@ -0,0 +1,122 @@
+//! backend/src/routes/edit_tag.rs
+//! Endpoint to rename a tag.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{QuizRepo, TagRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::tags::{SurrealTag, TagJsonPkg};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum EditTagError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for EditTagError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for EditTagError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            EditTagError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            EditTagError::ValidationError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": err.to_string() })),
+            EditTagError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TagQueryString {
+    tag: String,
+}
+
+// --- EndPoint ---
+/// Route handler for renaming a tag, tagged quizzes and questions keep it.
+/// Moderators may rename anyone's tag.
+#[tracing::instrument(name = "Request to Edit Tag", skip(db))]
+pub async fn edit_tag<R: QuizRepo + TagRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    tag_qp: web::Query<TagQueryString>,
+    tag_pkg_pt: web::Json<TagJsonPkg>,
+) -> Result<HttpResponse, EditTagError> {
+    let tag_data: TagJsonPkg = tag_pkg_pt.into_inner();
+    tag_data
+        .validate_field()
+        .context("Validation error")
+        .map_err(EditTagError::ValidationError)?;
+
+    // Decode Query String
+    let decoded_query_str: String = urlencoding::decode(&tag_qp.into_inner().tag)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let tag_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(EditTagError::ValidationError)?;
+
+    let surreal_tag: Option<SurrealTag> = db
+        .get_tag(&tag_id)
+        .await
+        .map_err(EditTagError::UnexpectedError)?;
+
+    let tag: SurrealTag = match surreal_tag {
+        None => {
+            return Err(EditTagError::ValidationError(anyhow::anyhow!(
+                "Tag does not exist"
+            )));
+        }
+        Some(tg) => tg,
+    };
+    user.require_manage(&tag.author_id)?;
+
+    // Names are unique among the author's tags, whoever renames it
+    let existing: Vec<SurrealTag> = db
+        .get_user_tags(&tag.author_id)
+        .await
+        .map_err(|err| EditTagError::UnexpectedError(anyhow::anyhow!(err)))?;
+    // Changing only the case of a tag's own name is fine
+    if existing
+        .iter()
+        .any(|tg| tg.id != tag_id && tg.same_name(&tag_data.name))
+    {
+        return Err(EditTagError::ValidationError(anyhow::anyhow!(
+            "Tag already exists"
+        )));
+    }
+
+    let updated: Option<SurrealTag> = db
+        .rename_tag(&tag_id, tag_data.name.trim())
+        .await
+        .map_err(EditTagError::UnexpectedError)?;
+
+    match updated {
+        Some(tg) => Ok(HttpResponse::Ok().json(tg)),
+        None => Err(EditTagError::UnexpectedError(anyhow::anyhow!(
+            "Unsure what happened in Database"
+        ))),
+    }
+}
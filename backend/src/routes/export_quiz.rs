@@ -0,0 +1,135 @@
+//! backend/src/routes/export_quiz.rs
+//! To download a quiz and all of its questions as a portable bundle.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    bundle_format::encode_bundle,
+    error_chain_helper,
+    repository::{QuestionRepo, QuizRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    bundle::{BundleFormat, QuizBundle},
+    questions::AllQuestions,
+    quiz::SurrealQuiz,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum ExportQuizError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for ExportQuizError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for ExportQuizError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            ExportQuizError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            ExportQuizError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            ExportQuizError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ExportQuizQueryString {
+    quiz: String,
+    #[serde(default)]
+    format: BundleFormat,
+}
+
+/// File name for the download, anything unusual in the quiz name becomes a dash.
+fn bundle_file_name(quiz_name: &str, format: BundleFormat) -> String {
+    let stem: String = quiz_name
+        .trim()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '_' {
+                ch
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let stem: &str = if stem.is_empty() { "quiz" } else { &stem };
+    format!("{}.{}", stem, format.extension())
+}
+
+// --- EndPoint ---
+/// Route handler for exporting one of the user's quizzes, answers included.
+#[tracing::instrument(name = "Request to Export Quiz", skip(db))]
+pub async fn export_quiz<R: QuestionRepo + QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    export_qp: web::Query<ExportQuizQueryString>,
+) -> Result<HttpResponse, ExportQuizError> {
+    // Decode Query String
+    let ExportQuizQueryString { quiz, format } = export_qp.into_inner();
+    let decoded_query_str: String = urlencoding::decode(&quiz).expect("UTF-8").into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let quiz_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(ExportQuizError::ValidationError)?;
+
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&quiz_id)
+        .await
+        .map_err(ExportQuizError::ValidationError)?;
+
+    // Bundles include answers, so only the author may export
+    let quiz: SurrealQuiz = match surreal_quiz {
+        None => {
+            return Err(ExportQuizError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) => {
+            user.require_manage(&qz.author_id)?;
+            qz
+        }
+    };
+
+    let all_questions: AllQuestions = db
+        .get_quiz_questions(&quiz.id)
+        .await
+        .map_err(|err| ExportQuizError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    let bundle: QuizBundle = QuizBundle::new(&quiz, all_questions);
+    // Anything GIFT or Moodle XML cannot hold is noted in comments in the file itself
+    let body: String = encode_bundle(&bundle, format)?.value;
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header((
+            "Content-Disposition",
+            format!(
+                "attachment; filename=\"{}\"",
+                bundle_file_name(&quiz.name, format)
+            ),
+        ))
+        .body(body))
+}
@@ -0,0 +1,112 @@
+//! backend/src/routes/get_assignment_progress.rs
+//! To show a group owner how each member is doing on an assignment.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, GroupRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    attempts::SurrealAttempt,
+    groups::{AssignmentProgress, SurrealAssignment, SurrealGroup},
+    PartialUser,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum AssignmentProgressError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for AssignmentProgressError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for AssignmentProgressError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            AssignmentProgressError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            AssignmentProgressError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            AssignmentProgressError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProgressQueryString {
+    assignment: String,
+}
+
+// --- EndPoint ---
+/// Route handler for the group owner to see each member's completion and best score.
+#[tracing::instrument(name = "Request to Get Assignment Progress", skip(db))]
+pub async fn get_assignment_progress<R: AttemptRepo + GroupRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    assignment_qp: web::Query<ProgressQueryString>,
+) -> Result<HttpResponse, AssignmentProgressError> {
+    // Decode Query String
+    let assignment_query_str: String = assignment_qp.into_inner().assignment;
+    let decoded_query_str: String = urlencoding::decode(&assignment_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let assignment_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(AssignmentProgressError::ValidationError)?;
+
+    let assignment: SurrealAssignment = db
+        .get_assignment(&assignment_id)
+        .await
+        .map_err(AssignmentProgressError::UnexpectedError)?
+        .ok_or_else(|| {
+            AssignmentProgressError::ValidationError(anyhow::anyhow!("Assignment does not exist"))
+        })?;
+
+    let group: Option<SurrealGroup> = db
+        .get_group(&assignment.group_id)
+        .await
+        .map_err(AssignmentProgressError::UnexpectedError)?;
+    match &group {
+        None => {
+            return Err(AssignmentProgressError::UnexpectedError(anyhow::anyhow!(
+                "Assignment has no group"
+            )));
+        }
+        Some(grp) => user.require_manage(&grp.owner_id)?,
+    }
+
+    let members: Vec<PartialUser> = db
+        .get_group_members(&assignment.group_id)
+        .await
+        .map_err(|err| AssignmentProgressError::UnexpectedError(anyhow::anyhow!(err)))?;
+    let member_ids: Vec<String> = members.iter().map(|mbr| mbr.uuid.clone()).collect();
+    let attempts: Vec<SurrealAttempt> = db
+        .get_quiz_attempts(&assignment.quiz_id, &member_ids)
+        .await
+        .map_err(|err| AssignmentProgressError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    Ok(HttpResponse::Ok().json(AssignmentProgress::tally(assignment, members, &attempts)))
+}
@@ -0,0 +1,55 @@
+//! backend/src/routes/get_assignments.rs
+//! To list the quizzes assigned to a user through their groups.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::GroupRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::groups::AssignedQuiz;
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum GetAssignmentsError {
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for GetAssignmentsError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for GetAssignmentsError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            GetAssignmentsError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            GetAssignmentsError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for listing a member's assignments, soonest to close first.
+#[tracing::instrument(name = "Request to Get Assignments", skip(db))]
+pub async fn get_my_assignments<R: GroupRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+) -> Result<HttpResponse, GetAssignmentsError> {
+    let assigned: Vec<AssignedQuiz> = db
+        .get_assigned_quizzes(&user.user_id)
+        .await
+        .map_err(|err| GetAssignmentsError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    Ok(HttpResponse::Ok().json(assigned))
+}
@@ -0,0 +1,137 @@
+//! backend/src/routes/get_attempt_history.rs
+//! To list a user's submitted attempts, summarised per quiz.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, QuizRepo, TrashRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    attempts::{QuizProgress, SurrealAttempt},
+    quiz::SurrealQuiz,
+    trash::TrashItem,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum AttemptHistoryError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for AttemptHistoryError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for AttemptHistoryError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            AttemptHistoryError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            AttemptHistoryError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            AttemptHistoryError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AttemptHistoryQueryString {
+    quiz: Option<String>,
+}
+
+// --- EndPoint ---
+/// Route handler for a user's attempt history, grouped by quiz.
+/// Optionally limited to a single quiz with `?quiz=`.
+#[tracing::instrument(name = "Request to Get Attempt History", skip(db))]
+pub async fn get_attempt_history<R: AttemptRepo + QuizRepo + TrashRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    quiz_qp: web::Query<AttemptHistoryQueryString>,
+) -> Result<HttpResponse, AttemptHistoryError> {
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String, if any
+    let quiz_filter: Option<Thing> = match quiz_qp.into_inner().quiz {
+        None => None,
+        Some(quiz_query_str) => {
+            let decoded_query_str: String = urlencoding::decode(&quiz_query_str)
+                .expect("UTF-8")
+                .into_owned();
+            Some(
+                thing(&decoded_query_str)
+                    .context("Unable to parse query")
+                    .map_err(AttemptHistoryError::ValidationError)?,
+            )
+        }
+    };
+
+    // Fetch Data, oldest first so the latest attempt is last
+    let attempts: Vec<SurrealAttempt> = db
+        .get_submitted_attempts(&user_id)
+        .await
+        .map_err(AttemptHistoryError::UnexpectedError)?;
+
+    // Group by quiz, keeping the order each quiz was first attempted in
+    let mut grouped: Vec<(Thing, Vec<SurrealAttempt>)> = Vec::new();
+    for att in attempts {
+        if quiz_filter.as_ref().is_some_and(|qf| qf != &att.quiz_id) {
+            continue;
+        }
+        match grouped
+            .iter_mut()
+            .find(|(quiz_id, _)| quiz_id == &att.quiz_id)
+        {
+            Some((_, quiz_attempts)) => quiz_attempts.push(att),
+            None => grouped.push((att.quiz_id.clone(), vec![att])),
+        }
+    }
+
+    let mut progress: Vec<QuizProgress> = Vec::with_capacity(grouped.len());
+    for (quiz_id, quiz_attempts) in grouped {
+        let surreal_quiz: Option<SurrealQuiz> = db
+            .get_quiz(&quiz_id)
+            .await
+            .map_err(AttemptHistoryError::UnexpectedError)?;
+        // Trashed quizzes keep their name, they may yet be restored
+        let trashed: Option<TrashItem> = match surreal_quiz {
+            Some(_) => None,
+            None => db
+                .get_trash_item(&quiz_id)
+                .await
+                .map_err(AttemptHistoryError::UnexpectedError)?,
+        };
+
+        // Attempts outlive the quiz they were taken on
+        let quiz_name: String = surreal_quiz
+            .map(|quiz| quiz.name)
+            .or_else(|| trashed.map(|item| item.title))
+            .unwrap_or_else(|| String::from("Deleted quiz"));
+
+        progress.push(QuizProgress::from_attempts(
+            quiz_id,
+            quiz_name,
+            &quiz_attempts,
+        ));
+    }
+
+    Ok(HttpResponse::Ok().json(progress))
+}
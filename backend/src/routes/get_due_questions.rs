@@ -0,0 +1,160 @@
+//! backend/src/routes/get_due_questions.rs
+//! To hand out the questions of a quiz a user is scheduled to review today.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, QuestionRepo, QuizRepo, StudyRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use models::{
+    attempts::{AnswerKey, Attempt, ExamQuestion, SurrealAttempt},
+    questions::AllQuestions,
+    quiz::SurrealQuiz,
+    study::{ReviewCard, SurrealReviewCard},
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum DueQuestionsError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for DueQuestionsError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for DueQuestionsError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            DueQuestionsError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            DueQuestionsError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            DueQuestionsError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DueQuestionsQueryString {
+    quiz: String,
+}
+
+/// Whether the user is part way through an attempt at the quiz.
+/// Studying hands out hints and answers, so it waits until the attempt is submitted.
+pub(crate) async fn taking_quiz<R: AttemptRepo>(
+    db: &R,
+    user_id: &str,
+    quiz_id: &Thing,
+) -> Result<bool, anyhow::Error> {
+    let attempts: Vec<SurrealAttempt> = db
+        .get_quiz_attempts(quiz_id, &[user_id.to_string()])
+        .await?;
+    let now: DateTime<Utc> = Utc::now();
+    Ok(attempts
+        .into_iter()
+        .map(Attempt::from)
+        .any(|att| att.in_progress(now)))
+}
+
+// --- EndPoint ---
+/// Route handler for the questions of a quiz due for review today.
+/// Questions the user has never studied are always due.
+/// Hints are held back like in an attempt, and nothing is handed out while an attempt at the quiz is in progress.
+#[tracing::instrument(name = "Request to Get Due Questions", skip(db))]
+pub async fn get_due_questions<R: AttemptRepo + QuestionRepo + QuizRepo + StudyRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    quiz_qp: web::Query<DueQuestionsQueryString>,
+) -> Result<HttpResponse, DueQuestionsError> {
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String
+    let quiz_query_str: String = quiz_qp.into_inner().quiz;
+    let decoded_query_str: String = urlencoding::decode(&quiz_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let quiz_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(DueQuestionsError::ValidationError)?;
+
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&quiz_id)
+        .await
+        .map_err(DueQuestionsError::ValidationError)?;
+
+    match &surreal_quiz {
+        None => {
+            return Err(DueQuestionsError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) if !qz.viewable_by(&user_id) => {
+            return Err(DueQuestionsError::AccessError(GuardError::PermissionError(
+                String::from("Quiz is private"),
+            )));
+        }
+        Some(_) => {}
+    }
+
+    if taking_quiz(db.get_ref(), &user_id, &quiz_id)
+        .await
+        .map_err(DueQuestionsError::UnexpectedError)?
+    {
+        return Err(DueQuestionsError::ValidationError(anyhow::anyhow!(
+            "Submit your attempt at this quiz before studying it"
+        )));
+    }
+
+    let all_questions: AllQuestions = db
+        .get_quiz_questions(&quiz_id)
+        .await
+        .map_err(|err| DueQuestionsError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    let cards: Vec<SurrealReviewCard> = db
+        .get_review_cards(&user_id, &quiz_id)
+        .await
+        .map_err(DueQuestionsError::UnexpectedError)?;
+
+    // Cards due at any time today are served, so a day's reviews can be done in one sitting
+    let now: DateTime<Utc> = Utc::now();
+    let not_due: Vec<Thing> = cards
+        .into_iter()
+        .map(ReviewCard::from)
+        .filter(|card| !card.is_due(now))
+        .map(|card| card.question_id)
+        .collect();
+
+    // Answer keys are dropped here, they are only revealed once reviewed.
+    // Hints are asked for one at a time through `/study-hall/hint`.
+    let due: Vec<ExamQuestion> = all_questions
+        .into_exam()
+        .into_iter()
+        .map(|(eq, _): (ExamQuestion, AnswerKey)| eq.withhold_hint())
+        .filter(|eq| !not_due.contains(&eq.id))
+        .collect();
+
+    Ok(HttpResponse::Ok().json(due))
+}
@@ -0,0 +1,84 @@
+//! backend/src/routes/get_groups.rs
+//! To list the groups a user owns or belongs to.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::GroupRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::{
+    groups::{GroupSummary, SurrealAssignment, SurrealGroup},
+    PartialUser,
+};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum GetGroupsError {
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for GetGroupsError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for GetGroupsError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            GetGroupsError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            GetGroupsError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for listing the user's groups, sorted by name.
+/// Only owners see the join code, members and assignments.
+#[tracing::instrument(name = "Request to Get Groups", skip(db))]
+pub async fn get_my_groups<R: GroupRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+) -> Result<HttpResponse, GetGroupsError> {
+    let groups: Vec<SurrealGroup> = db
+        .get_user_groups(&user.user_id)
+        .await
+        .map_err(|err| GetGroupsError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    let mut summaries: Vec<GroupSummary> = Vec::with_capacity(groups.len());
+    for mut group in groups {
+        let owned: bool = group.owner_id == user.user_id;
+        let (members, assignments): (Vec<PartialUser>, Vec<SurrealAssignment>) = if owned {
+            let members: Vec<PartialUser> = db
+                .get_group_members(&group.id)
+                .await
+                .map_err(|err| GetGroupsError::UnexpectedError(anyhow::anyhow!(err)))?;
+            let assignments: Vec<SurrealAssignment> = db
+                .get_group_assignments(&group.id)
+                .await
+                .map_err(|err| GetGroupsError::UnexpectedError(anyhow::anyhow!(err)))?;
+            (members, assignments)
+        } else {
+            group.join_code.clear();
+            (Vec::new(), Vec::new())
+        };
+        summaries.push(GroupSummary {
+            group,
+            owned,
+            members,
+            assignments,
+        });
+    }
+
+    Ok(HttpResponse::Ok().json(summaries))
+}
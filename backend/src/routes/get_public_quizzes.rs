@@ -0,0 +1,115 @@
+//! backend/src/routes/get_public_quizzes.rs
+//! To browse, and search, quizzes other users have made public.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::QuizRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::{
+    likes::LikeTally,
+    quiz::{PublicQuizPage, SurrealQuiz, MAX_PAGE_SIZE},
+};
+use serde::Deserialize;
+use surrealdb::sql::Thing;
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum PublicQuizzesError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for PublicQuizzesError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for PublicQuizzesError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            PublicQuizzesError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            PublicQuizzesError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            PublicQuizzesError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PublicQuizzesQueryString {
+    search: Option<String>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+// --- EndPoint ---
+/// Route handler for browsing public quizzes, a page at a time.
+/// An optional `search` matches the quiz name or description, ignoring case.
+/// Pages start at 1.
+#[tracing::instrument(name = "Request to Browse Public Quizzes", skip(db))]
+pub async fn get_public_quizzes<R: QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    browse_qp: web::Query<PublicQuizzesQueryString>,
+) -> Result<HttpResponse, PublicQuizzesError> {
+    let browse: PublicQuizzesQueryString = browse_qp.into_inner();
+
+    let user_id: String = user.user_id.clone();
+
+    let page: u32 = browse.page.unwrap_or(1);
+    let per_page: u32 = browse.per_page.unwrap_or(20);
+    if page < 1 || !(1..=MAX_PAGE_SIZE).contains(&per_page) {
+        return Err(PublicQuizzesError::ValidationError(anyhow::anyhow!(
+            "Pages start at 1, and hold between 1 and {} quizzes",
+            MAX_PAGE_SIZE
+        )));
+    }
+
+    let search: String = browse
+        .search
+        .map(|raw| {
+            urlencoding::decode(&raw)
+                .expect("UTF-8")
+                .trim()
+                .to_lowercase()
+        })
+        .unwrap_or_default();
+
+    // Fetch Data
+    let (quizzes, total): (Vec<SurrealQuiz>, u64) = db
+        .get_public_quizzes(&search, page, per_page)
+        .await
+        .map_err(PublicQuizzesError::UnexpectedError)?;
+
+    let quiz_ids: Vec<Thing> = quizzes.iter().map(|qz| qz.id.clone()).collect();
+    let tally: LikeTally = db
+        .get_likes(&quiz_ids, &user_id)
+        .await
+        .map_err(|err| PublicQuizzesError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    Ok(HttpResponse::Ok().json(PublicQuizPage {
+        quizzes: quizzes
+            .into_iter()
+            .map(|qz| tally.wrap(&qz.id.clone(), qz))
+            .collect(),
+        page,
+        per_page,
+        total,
+    }))
+}
@@ -1,22 +1,22 @@
 //! backend/src/routes/get_question.rs
 //! To fetch questions to a quiz.
-//! This endpoint is only designed currently to handle the only one type of question, multiple
-//! choice. A Breaking API changes will come in future when adding other questions types.
+//! Each question type lives in its own table and is returned in its own list of `AllQuestions`.
+use crate::authentication::{CurrentUser, GuardError};
 use crate::error_chain_helper;
-use crate::surrealdb_repo::Database;
+use crate::repository::{QuestionRepo, QuizRepo};
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::web;
 use actix_web::{HttpRequest, HttpResponse, ResponseError};
 use anyhow::Context;
-use models::questions::{AllQuestions, SurrealQuestionMC};
+use models::{likes::LikeTally, questions::AllQuestions, quiz::SurrealQuiz};
 use serde::Deserialize;
 use surrealdb::sql::{thing, Thing};
 
 // -- Errors --
 #[derive(thiserror::Error)]
 pub enum GetQuestionError {
-    #[error("{0}")]
-    AuthorizationError(String),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
@@ -36,11 +36,7 @@ impl ResponseError for GetQuestionError {
                     .insert_header(ContentType::json())
                     .json(serde_json::json!({"msg": "Unknown Error"}))
             }
-            GetQuestionError::AuthorizationError(msg) => {
-                HttpResponse::build(StatusCode::UNAUTHORIZED)
-                    .insert_header(ContentType::json())
-                    .json(serde_json::json!({ "msg": msg }))
-            }
+            GetQuestionError::AccessError(err) => err.error_response(),
         }
     }
 }
@@ -52,11 +48,14 @@ pub struct QuestionsQueryString {
 
 // --- EndPoint ---
 /// Route handler for fetching questions for a given quiz from the database.
+/// Questions include their answers, so only the quiz author may fetch them.
+/// Anyone else takes the quiz through an attempt, see `start_attempt`.
 /// Per documentation, 400 response returned if cannot serialize query.
 #[tracing::instrument(name = "Request to Get Quizzes by User", skip(db))]
-pub async fn get_questions(
+pub async fn get_questions<R: QuizRepo + QuestionRepo>(
     req: HttpRequest,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
     quiz: web::Query<QuestionsQueryString>,
 ) -> Result<HttpResponse, GetQuestionError> {
     let quiz_query_str: String = quiz.into_inner().quiz;
@@ -65,19 +64,29 @@ pub async fn get_questions(
         .into_owned();
     let qid: Thing = thing(&decoded_query_str).context("Unable to parse query string")?;
 
-    let surreal_ql: &str = "SELECT * FROM questions_mc WHERE parent_quiz = $quiz_id";
-    let mut surreal_response: surrealdb::Response = db
-        .client
-        .query(surreal_ql)
-        .bind(("quiz_id", qid))
-        .await
-        .map_err(|err| GetQuestionError::UnexpectedError(anyhow::anyhow!(err)))?;
+    let surreal_quiz: Option<SurrealQuiz> =
+        db.get_quiz(&qid).await.context("Failed to fetch quiz")?;
+
+    match &surreal_quiz {
+        None => {
+            return Err(GetQuestionError::AccessError(GuardError::PermissionError(
+                String::from("Quiz does not exist"),
+            )));
+        }
+        Some(qz) => {
+            user.require_manage(&qz.author_id)?;
+        }
+    }
 
-    let mc_questions: Vec<SurrealQuestionMC> = surreal_response
-        .take(0)
-        .map_err(|err| GetQuestionError::UnexpectedError(anyhow::anyhow!(err)))?;
+    let all_questions: AllQuestions = db
+        .get_quiz_questions(&qid)
+        .await
+        .context("Failed to fetch questions")?;
 
-    let all_questions: AllQuestions = AllQuestions { mc: mc_questions };
+    let tally: LikeTally = db
+        .get_likes(&all_questions.ids(), &user.user_id)
+        .await
+        .context("Failed to count likes")?;
 
-    Ok(HttpResponse::Ok().json(all_questions))
+    Ok(HttpResponse::Ok().json(all_questions.with_likes(&tally)))
 }
@@ -1,19 +1,27 @@
 //! backend/src/routes/get_quiz.rs
 //! To fetch quizzes for a user.
+use crate::authentication::{CurrentUser, GuardError};
 use crate::error_chain_helper;
-use crate::session_wrapper::SessionWrapper;
-use crate::surrealdb_repo::Database;
+use crate::repository::QuizRepo;
 use actix_web::http::{header::ContentType, StatusCode};
 use actix_web::web;
 use actix_web::{HttpRequest, HttpResponse, ResponseError};
-use models::quiz::SurrealQuiz;
-use uuid::Uuid;
+use anyhow::Context;
+use models::{
+    likes::{LikeTally, Liked},
+    quiz::SurrealQuiz,
+    tags::{TagIndex, Tagged},
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
 
 // Errors
 #[derive(thiserror::Error)]
 pub enum GetQuizError {
     #[error("{0}")]
-    AuthorizationError(String),
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
@@ -33,47 +41,69 @@ impl ResponseError for GetQuizError {
                     .insert_header(ContentType::json())
                     .json(serde_json::json!({"msg": "Unknown Error"}))
             }
-            GetQuizError::AuthorizationError(msg) => HttpResponse::build(StatusCode::UNAUTHORIZED)
+            GetQuizError::ValidationError(err) => HttpResponse::build(StatusCode::BAD_REQUEST)
                 .insert_header(ContentType::json())
-                .json(serde_json::json!({ "msg": msg })),
+                .json(serde_json::json!({ "msg": err.to_string() })),
+            GetQuizError::AccessError(err) => err.error_response(),
         }
     }
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct GetQuizQueryString {
+    tag: Option<String>,
+}
+
 // --- EndPoint ---
 /// Route handler for fetching quizzes for a specific user.
-#[tracing::instrument(name = "Request to Get Quizzes by User", skip(db, session))]
-pub async fn get_my_quizzes(
+/// Optionally limited to quizzes with a tag, using `?tag=`.
+#[tracing::instrument(name = "Request to Get Quizzes by User", skip(db))]
+pub async fn get_my_quizzes<R: QuizRepo>(
     req: HttpRequest,
-    session: SessionWrapper,
-    db: web::Data<Database>,
+    user: CurrentUser,
+    db: web::Data<R>,
+    tag_qp: web::Query<GetQuizQueryString>,
 ) -> Result<HttpResponse, GetQuizError> {
-    let some_user_id: Option<Uuid> = session
-        .get_user_id()
-        .map_err(|_| GetQuizError::UnexpectedError(anyhow::anyhow!("A SessionGetError")))?;
-    dbg!(&some_user_id);
+    let user_id: String = user.user_id.clone();
 
-    // Middleware should catch unauthorized users, but just in case
-    let user_id: String = if let Some(id) = some_user_id {
-        id.to_string()
-    } else {
-        return Err(GetQuizError::AuthorizationError(
-            "Session Token not found".to_string(),
-        ));
+    // Decode Query String, if any
+    let tag_filter: Option<Thing> = match tag_qp.into_inner().tag {
+        None => None,
+        Some(tag_query_str) => {
+            let decoded_query_str: String = urlencoding::decode(&tag_query_str)
+                .expect("UTF-8")
+                .into_owned();
+            Some(
+                thing(&decoded_query_str)
+                    .context("Unable to parse query")
+                    .map_err(GetQuizError::ValidationError)?,
+            )
+        }
     };
 
     // Fetch Data
-    let surreal_ql = "SELECT * FROM quizzes WHERE author_id = $user_id";
-    let mut surreal_response: surrealdb::Response = db
-        .client
-        .query(surreal_ql)
-        .bind(("user_id", user_id))
+    let quizzes: Vec<SurrealQuiz> = db
+        .get_author_quizzes(&user_id, tag_filter.as_ref())
+        .await
+        .context("Failed to fetch quizzes")?;
+
+    let quiz_ids: Vec<Thing> = quizzes.iter().map(|qz| qz.id.clone()).collect();
+    let tally: LikeTally = db
+        .get_likes(&quiz_ids, &user_id)
+        .await
+        .context("Failed to count likes")?;
+    let index: TagIndex = db
+        .get_tags(&quiz_ids)
         .await
-        .map_err(|err| GetQuizError::UnexpectedError(anyhow::anyhow!(err)))?;
+        .context("Failed to fetch tags")?;
 
-    let quizzes: Vec<SurrealQuiz> = surreal_response
-        .take(0)
-        .map_err(|err| GetQuizError::UnexpectedError(anyhow::anyhow!(err)))?;
+    let liked_quizzes: Vec<Liked<Tagged<SurrealQuiz>>> = quizzes
+        .into_iter()
+        .map(|qz| {
+            let quiz_id: Thing = qz.id.clone();
+            tally.wrap(&quiz_id, index.wrap(&quiz_id, qz))
+        })
+        .collect();
 
-    Ok(HttpResponse::Ok().json(quizzes))
+    Ok(HttpResponse::Ok().json(liked_quizzes))
 }
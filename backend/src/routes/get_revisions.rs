@@ -0,0 +1,106 @@
+//! backend/src/routes/get_revisions.rs
+//! To list the revisions of a quiz or question.
+use crate::authentication::{CurrentUser, GuardError};
+use crate::error_chain_helper;
+use crate::repository::{QuestionRepo, QuizRepo, RevisionRepo};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::web;
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::revisions::SurrealRevision;
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum GetRevisionsError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for GetRevisionsError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for GetRevisionsError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            GetRevisionsError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            GetRevisionsError::AccessError(err) => err.error_response(),
+            GetRevisionsError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RevisionsQueryString {
+    target: String,
+}
+
+/// Who wrote a quiz or question, `None` when it does not exist or is in the trash.
+/// The revision routes all check against the author, whoever made the edits.
+pub(crate) async fn target_author<R: QuizRepo + QuestionRepo>(
+    db: &R,
+    target: &Thing,
+) -> anyhow::Result<Option<String>> {
+    if target.tb == "quizzes" {
+        Ok(db.get_quiz(target).await?.map(|qz| qz.author_id))
+    } else {
+        Ok(db.get_question(target).await?.map(|qs| qs.author_id))
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for listing the revisions of a quiz or question, newest first.
+#[tracing::instrument(name = "Request to Get Revisions", skip(db))]
+pub async fn get_target_revisions<R: QuizRepo + QuestionRepo + RevisionRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    target_qp: web::Query<RevisionsQueryString>,
+) -> Result<HttpResponse, GetRevisionsError> {
+    // Decode Query String
+    let target_query_str: String = target_qp.into_inner().target;
+    let decoded_query_str: String = urlencoding::decode(&target_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let target_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(GetRevisionsError::ValidationError)?;
+
+    let author_id: Option<String> = target_author(db.get_ref(), &target_id)
+        .await
+        .map_err(GetRevisionsError::ValidationError)?;
+    match author_id {
+        None => {
+            return Err(GetRevisionsError::ValidationError(anyhow::anyhow!(
+                "Quiz or question does not exist"
+            )));
+        }
+        Some(author_id) => user.require_manage(&author_id)?,
+    }
+
+    let revisions: Vec<SurrealRevision> = db
+        .get_revisions(&target_id)
+        .await
+        .context("Failed to fetch revisions")?;
+
+    Ok(HttpResponse::Ok().json(revisions))
+}
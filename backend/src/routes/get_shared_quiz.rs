@@ -0,0 +1,104 @@
+//! backend/src/routes/get_shared_quiz.rs
+//! To fetch a single quiz shared by link, such as another user's public or unlisted quiz.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::QuizRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{likes::LikeTally, quiz::SurrealQuiz};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum SharedQuizError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for SharedQuizError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for SharedQuizError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            SharedQuizError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            SharedQuizError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            SharedQuizError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SharedQuizQueryString {
+    quiz: String,
+}
+
+// --- EndPoint ---
+/// Route handler for fetching a quiz by its ID, as long as the user may see it.
+/// Private quizzes are only returned to their author.
+#[tracing::instrument(name = "Request to Get Shared Quiz", skip(db))]
+pub async fn get_shared_quiz<R: QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    quiz_qp: web::Query<SharedQuizQueryString>,
+) -> Result<HttpResponse, SharedQuizError> {
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String
+    let quiz_query_str: String = quiz_qp.into_inner().quiz;
+    let decoded_query_str: String = urlencoding::decode(&quiz_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let quiz_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(SharedQuizError::ValidationError)?;
+
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&quiz_id)
+        .await
+        .map_err(SharedQuizError::ValidationError)?;
+
+    let quiz: SurrealQuiz = match surreal_quiz {
+        None => {
+            return Err(SharedQuizError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) if !qz.viewable_by(&user_id) => {
+            return Err(SharedQuizError::AccessError(GuardError::PermissionError(
+                String::from("Quiz is private"),
+            )));
+        }
+        Some(qz) => qz,
+    };
+
+    let tally: LikeTally = db
+        .get_likes(std::slice::from_ref(&quiz.id), &user_id)
+        .await
+        .map_err(|err| SharedQuizError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    Ok(HttpResponse::Ok().json(tally.wrap(&quiz.id.clone(), quiz)))
+}
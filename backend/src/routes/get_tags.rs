@@ -0,0 +1,58 @@
+//! backend/src/routes/get_tags.rs
+//! To list a user's tags, with how often each is used.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::TagRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::tags::TagSummary;
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum GetTagsError {
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for GetTagsError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for GetTagsError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            GetTagsError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            GetTagsError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for listing the user's tags, sorted by name.
+#[tracing::instrument(name = "Request to Get Tags", skip(db))]
+pub async fn get_my_tags<R: TagRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+) -> Result<HttpResponse, GetTagsError> {
+    let user_id: String = user.user_id.clone();
+
+    // Fetch Data
+    let summaries: Vec<TagSummary> = db
+        .get_tag_summaries(&user_id)
+        .await
+        .map_err(GetTagsError::UnexpectedError)?;
+
+    Ok(HttpResponse::Ok().json(summaries))
+}
@@ -0,0 +1,52 @@
+//! backend/src/routes/get_trash.rs
+//! To list the quizzes and questions a user has in the trash.
+use crate::authentication::CurrentUser;
+use crate::error_chain_helper;
+use crate::repository::TrashRepo;
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::web;
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::trash::TrashItem;
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum GetTrashError {
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for GetTrashError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for GetTrashError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            GetTrashError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for listing the user's trashed quizzes and questions, most recently trashed first.
+#[tracing::instrument(name = "Request to Get Trash by User", skip(db))]
+pub async fn get_my_trash<R: TrashRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+) -> Result<HttpResponse, GetTrashError> {
+    let trash: Vec<TrashItem> = db
+        .get_trash(&user.user_id)
+        .await
+        .context("Failed to fetch trash")?;
+
+    Ok(HttpResponse::Ok().json(trash))
+}
@@ -0,0 +1,113 @@
+//! backend/src/routes/import_quiz.rs
+//! To create a quiz and its questions from a portable bundle.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    bundle_format::{decode_bundle, Converted},
+    error_chain_helper,
+    repository::QuizRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::{
+    bundle::{BundleFormat, ImportReport, QuizBundle},
+    questions::QuestionJsonPkg,
+    quiz::{Quiz, QuizJsonPkg, SurrealQuiz},
+    roles::Permission,
+};
+use serde::Deserialize;
+use surrealdb::sql::{Id, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum ImportQuizError {
+    #[error("{0:#}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error("Bundle has invalid items")]
+    InvalidBundle(Box<ImportReport>),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for ImportQuizError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for ImportQuizError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            ImportQuizError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            ImportQuizError::ValidationError(_) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": self.to_string() })),
+            // The report says which items to fix
+            ImportQuizError::InvalidBundle(report) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(report),
+            ImportQuizError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ImportQuizQueryString {
+    #[serde(default)]
+    format: BundleFormat,
+    #[serde(default)]
+    dry_run: bool,
+}
+
+// --- EndPoint ---
+/// Route handler for importing a bundle as a new quiz owned by the user.
+/// A dry run checks every item and reports back without saving anything.
+#[tracing::instrument(name = "Request to Import Quiz", skip(db, body))]
+pub async fn import_quiz<R: QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    import_qp: web::Query<ImportQuizQueryString>,
+    body: String,
+) -> Result<HttpResponse, ImportQuizError> {
+    user.require(Permission::AuthorQuizzes)?;
+
+    let ImportQuizQueryString { format, dry_run } = import_qp.into_inner();
+    let Converted {
+        value: bundle,
+        unmapped,
+    }: Converted<QuizBundle> =
+        decode_bundle(&body, format).map_err(ImportQuizError::ValidationError)?;
+    bundle
+        .validate_header()
+        .map_err(|err| ImportQuizError::ValidationError(anyhow::anyhow!(err)))?;
+
+    // The ID is made up front so every question can point at the quiz before it exists
+    let quiz_id: Thing = Thing::from(("quizzes", Id::rand()));
+    let (quiz_pkg, packages): (QuizJsonPkg, Vec<QuestionJsonPkg>) = bundle.into_packages(&quiz_id);
+
+    let mut report: ImportReport = ImportReport::check(dry_run, &quiz_pkg, &packages);
+    report.unmapped = unmapped;
+    if dry_run {
+        return Ok(HttpResponse::Ok().json(report));
+    }
+    if !report.is_valid() {
+        return Err(ImportQuizError::InvalidBundle(Box::new(report)));
+    }
+
+    let quiz_to_save: Quiz = quiz_pkg.into_record(user.user_id);
+
+    let created: SurrealQuiz = db
+        .create_quiz_with_questions(&quiz_id, &quiz_to_save, packages)
+        .await
+        .map_err(ImportQuizError::UnexpectedError)?;
+    report.quiz = Some(created);
+
+    Ok(HttpResponse::Created().json(report))
+}
@@ -0,0 +1,117 @@
+//! backend/src/routes/invite_member.rs
+//! To add a user to a group by their username.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{GroupRepo, LookUpUser},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    groups::{InvitePkg, Membership, SurrealGroup, SurrealMembership},
+    GeneralUser,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum InviteMemberError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for InviteMemberError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for InviteMemberError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            InviteMemberError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            InviteMemberError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            InviteMemberError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GroupQueryString {
+    group: String,
+}
+
+// --- EndPoint ---
+/// Route handler for a group owner adding a user by username.
+#[tracing::instrument(name = "Request to Invite Group Member", skip(db))]
+pub async fn invite_member<R: GroupRepo + LookUpUser>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    group_qp: web::Query<GroupQueryString>,
+    invite_pt: web::Json<InvitePkg>,
+) -> Result<HttpResponse, InviteMemberError> {
+    // Decode Query String
+    let group_query_str: String = group_qp.into_inner().group;
+    let decoded_query_str: String = urlencoding::decode(&group_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let group_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(InviteMemberError::ValidationError)?;
+
+    let surreal_group: Option<SurrealGroup> = db
+        .get_group(&group_id)
+        .await
+        .map_err(InviteMemberError::UnexpectedError)?;
+
+    match &surreal_group {
+        None => {
+            return Err(InviteMemberError::ValidationError(anyhow::anyhow!(
+                "Group does not exist"
+            )));
+        }
+        Some(grp) => user.require_manage(&grp.owner_id)?,
+    }
+
+    let invited: GeneralUser = db
+        .get_user_by_username(invite_pt.into_inner().username.trim().to_string())
+        .await?
+        .ok_or_else(|| {
+            InviteMemberError::ValidationError(anyhow::anyhow!("User does not exist"))
+        })?;
+
+    let already: bool = db
+        .is_member(&group_id, &invited.uuid)
+        .await
+        .map_err(|err| InviteMemberError::UnexpectedError(anyhow::anyhow!(err)))?;
+    if already {
+        return Err(InviteMemberError::ValidationError(anyhow::anyhow!(
+            "User is already a member"
+        )));
+    }
+
+    let created: SurrealMembership = db
+        .add_member(&Membership::new(group_id, invited.uuid))
+        .await
+        .map_err(InviteMemberError::UnexpectedError)?;
+
+    Ok(HttpResponse::Created().json(created))
+}
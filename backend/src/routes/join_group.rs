@@ -0,0 +1,79 @@
+//! backend/src/routes/join_group.rs
+//! To join a group with the code its owner shared.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::GroupRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::groups::{JoinPkg, Membership, SurrealGroup, SurrealMembership};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum JoinGroupError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for JoinGroupError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for JoinGroupError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            JoinGroupError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            JoinGroupError::ValidationError(anywho) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            JoinGroupError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for joining a group by its join code.
+#[tracing::instrument(name = "Request to Join Group", skip(db))]
+pub async fn join_group<R: GroupRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    join_pt: web::Json<JoinPkg>,
+) -> Result<HttpResponse, JoinGroupError> {
+    let join_code: String = join_pt.into_inner().join_code.trim().to_string();
+
+    let group: SurrealGroup = db
+        .get_group_by_code(&join_code)
+        .await
+        .map_err(JoinGroupError::UnexpectedError)?
+        .ok_or_else(|| JoinGroupError::ValidationError(anyhow::anyhow!("Join code not found")))?;
+
+    let already: bool = db
+        .is_member(&group.id, &user.user_id)
+        .await
+        .map_err(|err| JoinGroupError::UnexpectedError(anyhow::anyhow!(err)))?;
+    if already || group.owner_id == user.user_id {
+        return Err(JoinGroupError::ValidationError(anyhow::anyhow!(
+            "Already in this group"
+        )));
+    }
+
+    let created: SurrealMembership = db
+        .add_member(&Membership::new(group.id, user.user_id))
+        .await
+        .map_err(JoinGroupError::UnexpectedError)?;
+
+    Ok(HttpResponse::Created().json(created))
+}
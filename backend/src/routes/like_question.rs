@@ -1,10 +1,123 @@
 //! backend/src/routes/like_question.rs
-//! endpoint to toggle like status on a question.
-use actix_web::{HttpRequest, HttpResponse};
-
-// TODO: Finish Implementation
-/// Feature did not make it into initial release.
-#[tracing::instrument(name = "Request to Toggle Question Like Status")]
-pub async fn toggle_like(_req: HttpRequest) -> HttpResponse {
-    HttpResponse::Ok().finish()
+//! endpoint to like, or unlike, a quiz or a question.
+//! Likes are `general_user->likes->target` relations, at most one per user and target.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{QuestionRepo, QuizRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::{
+    likes::{likeable_table, LikeJsonPkg, LikeStatus, LikeTally},
+    quiz::SurrealQuiz,
+};
+use surrealdb::sql::Thing;
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum ToggleLikeError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for ToggleLikeError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for ToggleLikeError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            ToggleLikeError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            ToggleLikeError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            ToggleLikeError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+/// The quiz a liked target belongs to, the target itself for quizzes.
+/// `None` when the target, or its quiz, does not exist or is in the trash.
+async fn liked_quiz<R: QuizRepo + QuestionRepo>(
+    db: &R,
+    target: &Thing,
+) -> anyhow::Result<Option<SurrealQuiz>> {
+    let quiz_id: Thing = if target.tb == "quizzes" {
+        target.clone()
+    } else {
+        match db.get_question(target).await? {
+            Some(qs) => qs.parent_quiz,
+            None => return Ok(None),
+        }
+    };
+    db.get_quiz(&quiz_id).await
+}
+
+// --- EndPoint ---
+/// Route handler for liking or unliking a quiz or question.
+/// The request says whether the user should like the target, so repeating it changes nothing.
+/// Only quizzes the user can view, and their questions, can be liked.
+#[tracing::instrument(name = "Request to Toggle Like Status", skip(db))]
+pub async fn toggle_like<R: QuizRepo + QuestionRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    like_pt: web::Json<LikeJsonPkg>,
+) -> Result<HttpResponse, ToggleLikeError> {
+    let like: LikeJsonPkg = like_pt.into_inner();
+
+    let user_id: String = user.user_id.clone();
+
+    if !likeable_table(&like.target.tb) {
+        return Err(ToggleLikeError::ValidationError(anyhow::anyhow!(
+            "Only quizzes and questions can be liked"
+        )));
+    }
+
+    let surreal_quiz: Option<SurrealQuiz> = liked_quiz(db.get_ref(), &like.target)
+        .await
+        .map_err(ToggleLikeError::UnexpectedError)?;
+
+    match surreal_quiz {
+        None => {
+            return Err(ToggleLikeError::ValidationError(anyhow::anyhow!(
+                "Nothing to like, record does not exist"
+            )));
+        }
+        Some(qz) if !qz.viewable_by(&user_id) => {
+            return Err(ToggleLikeError::AccessError(GuardError::PermissionError(
+                String::from("Quiz is private"),
+            )));
+        }
+        Some(_) => {}
+    }
+
+    db.set_like(&user_id, &like.target, like.liked)
+        .await
+        .map_err(ToggleLikeError::UnexpectedError)?;
+
+    let tally: LikeTally = db
+        .get_likes(std::slice::from_ref(&like.target), &user_id)
+        .await
+        .map_err(|err| ToggleLikeError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    Ok(HttpResponse::Ok().json(LikeStatus {
+        likes: tally.count(&like.target),
+        liked: tally.mine.contains(&like.target),
+        target: like.target,
+    }))
 }
@@ -5,15 +5,15 @@ use crate::authentication::UserCredentials;
 use crate::{
     authentication::{validate_credentials, AuthError},
     error_chain_helper,
+    repository::LookUpUser,
     session_wrapper::SessionWrapper,
-    surrealdb_repo::Database,
 };
 use actix_web::{
     http::{header::ContentType, StatusCode},
     web, HttpMessage, HttpRequest, HttpResponse, ResponseError,
 };
 use anyhow::Context;
-use models::{PartialUser, UserID};
+use models::{GeneralUser, PartialUser, UserID};
 
 #[derive(thiserror::Error)]
 pub enum UserLoginError {
@@ -58,9 +58,9 @@ impl ResponseError for UserLoginError {
     name = "User Login"
     skip(db, session)
 )]
-pub async fn user_login(
+pub async fn user_login<R: LookUpUser>(
     req: HttpRequest, // for tracing
-    db: web::Data<Database>,
+    db: web::Data<R>,
     user_info_ptr: web::Json<UserCredentials>,
     session: SessionWrapper,
 ) -> Result<HttpResponse, UserLoginError> {
@@ -96,18 +96,22 @@ pub async fn user_login(
     name = "Check If Logged In"
     skip(db)
 )]
-pub async fn check_login(
+pub async fn check_login<R: LookUpUser>(
     req: HttpRequest,
-    db: web::Data<Database>,
+    db: web::Data<R>,
 ) -> Result<HttpResponse, UserLoginError> {
-    let user: Option<PartialUser> = if let Some(user_id) = req.extensions().get::<UserID>() {
-        db.client
-            .select(("general_user", &user_id.0))
-            .await
-            .context("Error")?
-    } else {
-        None
+    let user_id: Option<UserID> = req.extensions().get::<UserID>().cloned();
+    let user: Option<GeneralUser> = match user_id {
+        Some(user_id) => db.get_user(&user_id.0).await.context("Error")?,
+        None => None,
     };
+    // Leave the password hash behind
+    let user: Option<PartialUser> = user.map(|user| PartialUser {
+        uuid: user.uuid,
+        name: user.name,
+        username: user.username,
+        role: user.role,
+    });
 
     match user {
         Some(user) => Ok(HttpResponse::Ok().json(user)),
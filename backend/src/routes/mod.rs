@@ -1,28 +1,94 @@
 //! backend/src/routes/mod.rs
+mod assign_quiz;
+mod create_group;
 mod create_questions;
 mod create_quiz;
+mod create_tag;
 mod create_user;
 mod destroy_question;
 mod destroy_quiz;
+mod destroy_tag;
+mod diff_revisions;
 mod edit_question;
 mod edit_quiz;
+mod edit_tag;
+mod export_quiz;
+mod get_assignment_progress;
+mod get_assignments;
+mod get_attempt_history;
+mod get_due_questions;
+mod get_groups;
+mod get_public_quizzes;
 mod get_question;
 mod get_quiz;
+mod get_revisions;
+mod get_shared_quiz;
+mod get_tags;
+mod get_trash;
 mod health_check;
+mod import_quiz;
+mod invite_member;
+mod join_group;
 mod like_question;
 mod login_user;
+mod next_question;
+mod restore_trash;
+mod reveal_hint;
+mod reveal_study_hint;
+mod review_question;
+mod rollback_revision;
+mod search_quizzes;
+mod self_mark_attempt;
+mod set_user_role;
+mod start_attempt;
+mod submit_attempt;
+mod tag_target;
+mod upload_csv_questions;
 mod user_logout;
 
+pub use assign_quiz::*;
+pub use create_group::*;
 pub use create_questions::*;
 pub use create_quiz::*;
+pub use create_tag::*;
 pub use create_user::*;
 pub use destroy_question::*;
 pub use destroy_quiz::*;
+pub use destroy_tag::*;
+pub use diff_revisions::*;
 pub use edit_question::*;
 pub use edit_quiz::*;
+pub use edit_tag::*;
+pub use export_quiz::*;
+pub use get_assignment_progress::*;
+pub use get_assignments::*;
+pub use get_attempt_history::*;
+pub use get_due_questions::*;
+pub use get_groups::*;
+pub use get_public_quizzes::*;
 pub use get_question::*;
 pub use get_quiz::*;
+pub use get_revisions::*;
+pub use get_shared_quiz::*;
+pub use get_tags::*;
+pub use get_trash::*;
 pub use health_check::*;
+pub use import_quiz::*;
+pub use invite_member::*;
+pub use join_group::*;
 pub use like_question::*;
 pub use login_user::*;
+pub use next_question::*;
+pub use restore_trash::*;
+pub use reveal_hint::*;
+pub use reveal_study_hint::*;
+pub use review_question::*;
+pub use rollback_revision::*;
+pub use search_quizzes::*;
+pub use self_mark_attempt::*;
+pub use set_user_role::*;
+pub use start_attempt::*;
+pub use submit_attempt::*;
+pub use tag_target::*;
+pub use upload_csv_questions::*;
 pub use user_logout::*;
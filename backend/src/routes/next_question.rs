@@ -0,0 +1,158 @@
+//! backend/src/routes/next_question.rs
+//! To serve the questions of an attempt timed per question, one at a time.
+use super::submit_attempt::pinned_questions;
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, QuestionRepo, RevisionRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use models::attempts::{
+    AnswerKey, Attempt, ExamQuestion, NextQuestionJsonPkg, QuestionTiming, ServedQuestion,
+    SurrealAttempt,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum NextQuestionError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for NextQuestionError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for NextQuestionError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            NextQuestionError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            NextQuestionError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            NextQuestionError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NextQuestionQueryString {
+    attempt: String,
+}
+
+// --- EndPoint ---
+/// Route handler for answering the open question of an attempt timed per question and serving the next.
+/// The server records when each question is served and answered, and throws away answers that arrive
+/// after the question's time and grace period. Answered questions cannot be gone back to.
+#[tracing::instrument(name = "Request to Serve Next Question", skip(db))]
+pub async fn next_question<R: AttemptRepo + QuestionRepo + RevisionRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    attempt_qp: web::Query<NextQuestionQueryString>,
+    next_pt: web::Json<NextQuestionJsonPkg>,
+) -> Result<HttpResponse, NextQuestionError> {
+    let next_pkg: NextQuestionJsonPkg = next_pt.into_inner();
+
+    // Decode Query String
+    let attempt_query_str: String = attempt_qp.into_inner().attempt;
+    let decoded_query_str: String = urlencoding::decode(&attempt_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let attempt_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(NextQuestionError::ValidationError)?;
+
+    let surreal_attempt: Option<SurrealAttempt> = db
+        .get_attempt(&attempt_id)
+        .await
+        .map_err(NextQuestionError::UnexpectedError)?;
+
+    // Sanity checks
+    let mut attempt: Attempt = match surreal_attempt {
+        None => {
+            return Err(NextQuestionError::ValidationError(anyhow::anyhow!(
+                "Attempt does not exist"
+            )));
+        }
+        Some(att) => {
+            if att.user_id != user.user_id {
+                return Err(NextQuestionError::AccessError(GuardError::PermissionError(
+                    String::from("User does not own attempt"),
+                )));
+            }
+            if att.submitted_at.is_some() {
+                return Err(NextQuestionError::ValidationError(anyhow::anyhow!(
+                    "Attempt has already been submitted"
+                )));
+            }
+            att.into()
+        }
+    };
+    if !attempt.is_paced() {
+        return Err(NextQuestionError::ValidationError(anyhow::anyhow!(
+            "Every question of this attempt was handed out when it started"
+        )));
+    }
+
+    let now: DateTime<Utc> = Utc::now();
+    let before: Vec<QuestionTiming> = attempt.timings.clone();
+    // The question being answered now, if one is open
+    let answering: Option<usize> = attempt.open_question().map(|_| before.len() - 1);
+    let next: Option<Thing> = attempt.next_question(next_pkg.answer, now);
+    let late: bool = answering.is_some_and(|idx| attempt.timings[idx].late);
+
+    // The question as it was when the attempt started, without its hint
+    let question: Option<ExamQuestion> = match &next {
+        None => None,
+        Some(question_id) => {
+            let keyed: Vec<(ExamQuestion, AnswerKey)> =
+                pinned_questions(db.get_ref(), &attempt, std::slice::from_ref(question_id))
+                    .await
+                    .map_err(NextQuestionError::UnexpectedError)?;
+            let question: ExamQuestion = keyed
+                .into_iter()
+                .map(|(eq, _): (ExamQuestion, AnswerKey)| eq.withhold_hint())
+                .next()
+                .context("Served question no longer exists")?;
+            Some(question)
+        }
+    };
+
+    // Only saved if nothing was served or submitted since, so a question cannot be served twice
+    let served: Option<SurrealAttempt> = db
+        .serve_question(&attempt_id, &before, &attempt.timings)
+        .await
+        .map_err(|err| NextQuestionError::UnexpectedError(anyhow::anyhow!(err)))?;
+    if served.is_none() {
+        return Err(NextQuestionError::ValidationError(anyhow::anyhow!(
+            "Attempt has moved on or been submitted"
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(ServedQuestion {
+        question,
+        time_allowed_secs: attempt.question_due().map(|due| (due - now).num_seconds()),
+        late,
+    }))
+}
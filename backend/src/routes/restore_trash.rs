@@ -0,0 +1,110 @@
+//! backend/src/routes/restore_trash.rs
+//! To take a quiz or question back out of the trash.
+use crate::authentication::{CurrentUser, GuardError};
+use crate::error_chain_helper;
+use crate::repository::TrashRepo;
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::web;
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::trash::TrashItem;
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum RestoreTrashError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for RestoreTrashError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for RestoreTrashError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            RestoreTrashError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            RestoreTrashError::AccessError(err) => err.error_response(),
+            RestoreTrashError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RestoreQueryString {
+    item: String,
+}
+
+// --- EndPoint ---
+/// Route handler for restoring a trashed quiz or question.
+/// A question whose quiz is also trashed waits for the quiz to be restored first.
+#[tracing::instrument(name = "Request to Restore Trash by User", skip(db))]
+pub async fn restore_my_trash<R: TrashRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    item_qp: web::Query<RestoreQueryString>,
+) -> Result<HttpResponse, RestoreTrashError> {
+    // Decode Query String
+    let item_query_str: String = item_qp.into_inner().item;
+    let decoded_query_str: String = urlencoding::decode(&item_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let item_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(RestoreTrashError::ValidationError)?;
+
+    let trashed: Option<TrashItem> = db
+        .get_trash_item(&item_id)
+        .await
+        .map_err(RestoreTrashError::ValidationError)?;
+
+    // Sanity checks
+    let trashed: TrashItem = match trashed {
+        None => {
+            return Err(RestoreTrashError::ValidationError(anyhow::anyhow!(
+                "Nothing in the trash with that ID"
+            )));
+        }
+        Some(item) => item,
+    };
+    user.require_manage(&trashed.author_id)?;
+    if let Some(quiz_id) = &trashed.parent_quiz {
+        let quiz_trashed: bool = db
+            .get_trash_item(quiz_id)
+            .await
+            .context("Failed to check quiz")?
+            .is_some();
+        if quiz_trashed {
+            return Err(RestoreTrashError::ValidationError(anyhow::anyhow!(
+                "Restore the quiz this question belongs to first"
+            )));
+        }
+    }
+
+    let restored: Option<TrashItem> = db
+        .restore(&item_id)
+        .await
+        .context("Failed to restore from trash")?;
+
+    Ok(HttpResponse::Ok().json(restored))
+}
@@ -0,0 +1,157 @@
+//! backend/src/routes/reveal_hint.rs
+//! To reveal the hint to a question of an attempt, which costs the quiz's hint penalty when graded.
+use super::submit_attempt::pinned_questions;
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, QuestionRepo, RevisionRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::attempts::{
+    AnswerKey, Attempt, ExamQuestion, HintJsonPkg, RevealedHint, SurrealAttempt,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum RevealHintError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for RevealHintError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for RevealHintError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            RevealHintError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            RevealHintError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            RevealHintError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RevealHintQueryString {
+    attempt: String,
+}
+
+// --- EndPoint ---
+/// Route handler for revealing a hint while taking a quiz, from the revision the attempt was pinned to.
+/// The attempt records the question, so the penalty is applied when it is graded.
+/// Revealing the same hint again costs nothing more.
+#[tracing::instrument(name = "Request to Reveal Hint", skip(db))]
+pub async fn reveal_hint<R: AttemptRepo + QuestionRepo + RevisionRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    attempt_qp: web::Query<RevealHintQueryString>,
+    hint_pt: web::Json<HintJsonPkg>,
+) -> Result<HttpResponse, RevealHintError> {
+    let hint_pkg: HintJsonPkg = hint_pt.into_inner();
+
+    // Decode Query String
+    let attempt_query_str: String = attempt_qp.into_inner().attempt;
+    let decoded_query_str: String = urlencoding::decode(&attempt_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let attempt_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(RevealHintError::ValidationError)?;
+
+    let surreal_attempt: Option<SurrealAttempt> = db
+        .get_attempt(&attempt_id)
+        .await
+        .map_err(RevealHintError::UnexpectedError)?;
+
+    // Sanity checks
+    let mut attempt: Attempt = match surreal_attempt {
+        None => {
+            return Err(RevealHintError::ValidationError(anyhow::anyhow!(
+                "Attempt does not exist"
+            )));
+        }
+        Some(att) => {
+            if att.user_id != user.user_id {
+                return Err(RevealHintError::AccessError(GuardError::PermissionError(
+                    String::from("User does not own attempt"),
+                )));
+            }
+            if att.submitted_at.is_some() {
+                return Err(RevealHintError::ValidationError(anyhow::anyhow!(
+                    "Attempt has already been submitted"
+                )));
+            }
+            att.into()
+        }
+    };
+
+    // Questions timed one by one only give hints to the question being answered
+    if attempt.is_paced()
+        && attempt
+            .open_question()
+            .is_none_or(|open| open.question_id != hint_pkg.question_id)
+    {
+        return Err(RevealHintError::ValidationError(anyhow::anyhow!(
+            "Hints are only revealed for the question being answered"
+        )));
+    }
+
+    // Checked before fetching, so hints to other questions are never handed out
+    if !attempt.reveal_hint(&hint_pkg.question_id) {
+        return Err(RevealHintError::ValidationError(anyhow::anyhow!(
+            "Question is not part of this attempt"
+        )));
+    }
+
+    // The hint as it was when the attempt started, like the rest of the question
+    let keyed: Vec<(ExamQuestion, AnswerKey)> = pinned_questions(
+        db.get_ref(),
+        &attempt,
+        std::slice::from_ref(&hint_pkg.question_id),
+    )
+    .await
+    .map_err(RevealHintError::UnexpectedError)?;
+    let hint: String = keyed
+        .into_iter()
+        .find_map(|(eq, _): (ExamQuestion, AnswerKey)| eq.hint)
+        .ok_or_else(|| RevealHintError::ValidationError(anyhow::anyhow!("Question has no hint")))?;
+
+    // Only the one question is added, and only while unsubmitted, so a racing submit keeps its grade
+    let revealed: Option<SurrealAttempt> = db
+        .reveal_hint(&attempt_id, &hint_pkg.question_id)
+        .await
+        .map_err(|err| RevealHintError::UnexpectedError(anyhow::anyhow!(err)))?;
+    if revealed.is_none() {
+        return Err(RevealHintError::ValidationError(anyhow::anyhow!(
+            "Attempt has already been submitted"
+        )));
+    }
+
+    Ok(HttpResponse::Ok().json(RevealedHint {
+        question_id: hint_pkg.question_id,
+        hint,
+    }))
+}
@@ -0,0 +1,137 @@
+//! backend/src/routes/reveal_study_hint.rs
+//! To reveal the hint to a question while studying, which costs nothing.
+use super::get_due_questions::taking_quiz;
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, QuestionRepo, QuizRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    attempts::{AnswerKey, ExamQuestion, HintJsonPkg, RevealedHint},
+    questions::AllQuestions,
+    quiz::SurrealQuiz,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum StudyHintError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for StudyHintError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for StudyHintError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            StudyHintError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            StudyHintError::ValidationError(anywho) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            StudyHintError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StudyHintQueryString {
+    quiz: String,
+}
+
+// --- EndPoint ---
+/// Route handler for revealing a hint while studying a quiz.
+/// Hints are free here, so they are refused while an attempt at the quiz is in progress.
+#[tracing::instrument(name = "Request to Reveal Study Hint", skip(db))]
+pub async fn reveal_study_hint<R: AttemptRepo + QuestionRepo + QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    quiz_qp: web::Query<StudyHintQueryString>,
+    hint_pt: web::Json<HintJsonPkg>,
+) -> Result<HttpResponse, StudyHintError> {
+    let hint_pkg: HintJsonPkg = hint_pt.into_inner();
+
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String
+    let quiz_query_str: String = quiz_qp.into_inner().quiz;
+    let decoded_query_str: String = urlencoding::decode(&quiz_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let quiz_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(StudyHintError::ValidationError)?;
+
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&quiz_id)
+        .await
+        .map_err(StudyHintError::ValidationError)?;
+
+    match &surreal_quiz {
+        None => {
+            return Err(StudyHintError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) if !qz.viewable_by(&user_id) => {
+            return Err(StudyHintError::AccessError(GuardError::PermissionError(
+                String::from("Quiz is private"),
+            )));
+        }
+        Some(_) => {}
+    }
+
+    if taking_quiz(db.get_ref(), &user_id, &quiz_id)
+        .await
+        .map_err(StudyHintError::UnexpectedError)?
+    {
+        return Err(StudyHintError::ValidationError(anyhow::anyhow!(
+            "Submit your attempt at this quiz before studying it"
+        )));
+    }
+
+    let all_questions: AllQuestions = db
+        .get_quiz_questions(&quiz_id)
+        .await
+        .map_err(|err| StudyHintError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    let hint: String = match all_questions
+        .into_exam()
+        .into_iter()
+        .find(|(eq, _): &(ExamQuestion, AnswerKey)| eq.id == hint_pkg.question_id)
+    {
+        Some((eq, _)) => eq.hint.ok_or_else(|| {
+            StudyHintError::ValidationError(anyhow::anyhow!("Question has no hint"))
+        })?,
+        None => {
+            return Err(StudyHintError::ValidationError(anyhow::anyhow!(
+                "Question does not belong to quiz"
+            )));
+        }
+    };
+
+    Ok(HttpResponse::Ok().json(RevealedHint {
+        question_id: hint_pkg.question_id,
+        hint,
+    }))
+}
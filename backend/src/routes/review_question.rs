@@ -0,0 +1,178 @@
+//! backend/src/routes/review_question.rs
+//! To record how a user did reviewing a question, and schedule its next review.
+use super::get_due_questions::taking_quiz;
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, QuestionRepo, QuizRepo, StudyRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use models::{
+    attempts::{AnswerKey, ExamQuestion},
+    questions::AllQuestions,
+    quiz::SurrealQuiz,
+    study::{ReviewCard, ReviewJsonPkg, ReviewOutcome, ReviewRating, SurrealReviewCard},
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum ReviewQuestionError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for ReviewQuestionError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for ReviewQuestionError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            ReviewQuestionError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            ReviewQuestionError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            ReviewQuestionError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReviewQueryString {
+    quiz: String,
+}
+
+// --- EndPoint ---
+/// Route handler for recording a review of a single question.
+/// The answer is marked on the server, and a wrong answer counts as a lapse whatever the rating.
+/// The answer key is handed back, so reviews wait until any attempt at the quiz is submitted.
+#[tracing::instrument(name = "Request to Review Question", skip(db))]
+pub async fn review_question<R: AttemptRepo + QuestionRepo + QuizRepo + StudyRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    quiz_qp: web::Query<ReviewQueryString>,
+    review_pt: web::Json<ReviewJsonPkg>,
+) -> Result<HttpResponse, ReviewQuestionError> {
+    let review: ReviewJsonPkg = review_pt.into_inner();
+
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String
+    let quiz_query_str: String = quiz_qp.into_inner().quiz;
+    let decoded_query_str: String = urlencoding::decode(&quiz_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let quiz_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(ReviewQuestionError::ValidationError)?;
+
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&quiz_id)
+        .await
+        .map_err(ReviewQuestionError::ValidationError)?;
+
+    match &surreal_quiz {
+        None => {
+            return Err(ReviewQuestionError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) if !qz.viewable_by(&user_id) => {
+            return Err(ReviewQuestionError::AccessError(
+                GuardError::PermissionError(String::from("Quiz is private")),
+            ));
+        }
+        Some(_) => {}
+    }
+
+    if taking_quiz(db.get_ref(), &user_id, &quiz_id)
+        .await
+        .map_err(ReviewQuestionError::UnexpectedError)?
+    {
+        return Err(ReviewQuestionError::ValidationError(anyhow::anyhow!(
+            "Submit your attempt at this quiz before studying it"
+        )));
+    }
+
+    let all_questions: AllQuestions = db
+        .get_quiz_questions(&quiz_id)
+        .await
+        .map_err(|err| ReviewQuestionError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    let key: AnswerKey = match all_questions
+        .into_exam()
+        .into_iter()
+        .find(|(eq, _): &(ExamQuestion, AnswerKey)| eq.id == review.question_id)
+    {
+        Some((_, key)) => key,
+        None => {
+            return Err(ReviewQuestionError::ValidationError(anyhow::anyhow!(
+                "Question does not belong to quiz"
+            )));
+        }
+    };
+
+    let earned: f64 = key.grade(&review.answer);
+    let rating: ReviewRating = review.rating.capped_by(&key, earned);
+
+    let existing: Option<SurrealReviewCard> = db
+        .get_review_card(&user_id, &review.question_id)
+        .await
+        .map_err(ReviewQuestionError::UnexpectedError)?;
+
+    let now: DateTime<Utc> = Utc::now();
+    let saved: Option<SurrealReviewCard> = match existing {
+        Some(surreal_card) => {
+            let card_id: Thing = surreal_card.id.clone();
+            let mut card: ReviewCard = surreal_card.into();
+            card.review(rating, now);
+            db.update_review_card(&card_id, &card)
+                .await
+                .map_err(ReviewQuestionError::UnexpectedError)?
+        }
+        None => {
+            let mut card: ReviewCard = ReviewCard::new(user_id, quiz_id, review.question_id);
+            card.review(rating, now);
+            let created: SurrealReviewCard = db
+                .create_review_card(&card)
+                .await
+                .map_err(ReviewQuestionError::UnexpectedError)?;
+            Some(created)
+        }
+    };
+
+    match saved {
+        Some(card) => Ok(HttpResponse::Ok().json(ReviewOutcome {
+            question_id: card.question_id,
+            earned,
+            key,
+            rating,
+            interval_days: card.interval_days,
+            due: card.due,
+        })),
+        None => Err(ReviewQuestionError::UnexpectedError(anyhow::anyhow!(
+            "Error saving review"
+        ))),
+    }
+}
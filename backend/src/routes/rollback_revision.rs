@@ -0,0 +1,154 @@
+//! backend/src/routes/rollback_revision.rs
+//! To put a quiz or question back the way it was at an earlier revision.
+use crate::authentication::{CurrentUser, GuardError};
+use crate::error_chain_helper;
+use crate::repository::{QuestionRepo, QuizRepo, RevisionRepo};
+use crate::routes::target_author;
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::web;
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    pools::validate_pools,
+    quiz::SurrealQuiz,
+    revisions::{Edit, Snapshot, SurrealRevision},
+    tags::SurrealTag,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// Errors
+#[derive(thiserror::Error)]
+pub enum RollbackRevisionError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for RollbackRevisionError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for RollbackRevisionError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            RollbackRevisionError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            RollbackRevisionError::AccessError(err) => err.error_response(),
+            RollbackRevisionError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RollbackQueryString {
+    target: String,
+    to: u32,
+}
+
+// --- EndPoint ---
+/// Route handler for rolling a quiz or question back to an earlier revision.
+/// Nothing is thrown away, the rollback is saved as a new revision on top of the others.
+/// Responds with that new revision.
+#[tracing::instrument(name = "Request to Roll Back Revision", skip(db))]
+pub async fn rollback_revision<R: QuizRepo + QuestionRepo + RevisionRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    rollback_qp: web::Query<RollbackQueryString>,
+) -> Result<HttpResponse, RollbackRevisionError> {
+    let rollback_query: RollbackQueryString = rollback_qp.into_inner();
+
+    // Decode Query String
+    let decoded_query_str: String = urlencoding::decode(&rollback_query.target)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let target_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(RollbackRevisionError::ValidationError)?;
+
+    let author_id: String = match target_author(db.get_ref(), &target_id)
+        .await
+        .map_err(RollbackRevisionError::ValidationError)?
+    {
+        None => {
+            return Err(RollbackRevisionError::ValidationError(anyhow::anyhow!(
+                "Quiz or question does not exist"
+            )));
+        }
+        Some(author_id) => author_id,
+    };
+    user.require_manage(&author_id)?;
+
+    let revision: SurrealRevision = db
+        .get_revision(&target_id, rollback_query.to)
+        .await
+        .context("Failed to fetch revision")?
+        .ok_or_else(|| {
+            RollbackRevisionError::ValidationError(anyhow::anyhow!("Revision does not exist"))
+        })?;
+
+    match revision.snapshot {
+        Snapshot::Quiz(quiz_data) => {
+            // Pools draw from the author's tags, which may have changed since
+            if let Some(pools) = &quiz_data.pools {
+                let tags: Vec<SurrealTag> = db
+                    .get_user_tags(&author_id)
+                    .await
+                    .context("Failed to fetch tags")?;
+                validate_pools(pools, &tags)
+                    .context("Cannot roll back, the quiz's pools no longer fit its tags")
+                    .map_err(RollbackRevisionError::ValidationError)?;
+            }
+            let current: SurrealQuiz = db
+                .get_quiz(&target_id)
+                .await
+                .context("Failed to fetch quiz")?
+                .context("Quiz went missing")?;
+            let revision: Edit = Edit {
+                author_id: user.user_id.clone(),
+                snapshot: Snapshot::of_edited_quiz(&current, &quiz_data),
+            };
+            db.update_quiz(&target_id, &quiz_data, Some(revision))
+                .await
+                .context("Failed to update quiz")?
+                .context("Unsure what happened in Database")?;
+        }
+        Snapshot::Question(question) => {
+            let revision: Edit = Edit {
+                author_id: user.user_id.clone(),
+                snapshot: Snapshot::Question(question.clone()),
+            };
+            db.update_question(&target_id, question, Some(revision))
+                .await
+                .context("Failed to update question")?
+                .context("Error updating question")?;
+        }
+    }
+
+    // Saved with the rollback, newest first
+    let saved: SurrealRevision = db
+        .get_revisions(&target_id)
+        .await
+        .context("Failed to fetch revision")?
+        .into_iter()
+        .next()
+        .context("Unsure what happened in Database")?;
+
+    Ok(HttpResponse::Ok().json(saved))
+}
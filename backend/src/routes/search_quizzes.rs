@@ -0,0 +1,78 @@
+//! backend/src/routes/search_quizzes.rs
+//! To search quizzes and questions by their text, using full-text indexes where the backend has them.
+//! Only the user's own quizzes and public quizzes are searched.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::QuizRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::search::{validate_query, SearchHit, SearchResults, MAX_SEARCH_RESULTS};
+use serde::Deserialize;
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum SearchError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for SearchError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for SearchError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            SearchError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            SearchError::ValidationError(anywho) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            SearchError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SearchQueryString {
+    q: String,
+}
+
+// --- EndPoint ---
+/// Route handler for full-text search, `?q=` is matched against quiz names and descriptions,
+/// and question text and choices. Results are ranked by relevance across both.
+#[tracing::instrument(name = "Request to Search Quizzes", skip(db))]
+pub async fn search_quizzes<R: QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    search_qp: web::Query<SearchQueryString>,
+) -> Result<HttpResponse, SearchError> {
+    let user_id: String = user.user_id.clone();
+
+    let decoded_query_str: String = urlencoding::decode(&search_qp.into_inner().q)
+        .expect("UTF-8")
+        .into_owned();
+    let query: String = validate_query(&decoded_query_str)
+        .map_err(|err| SearchError::ValidationError(anyhow::anyhow!(err)))?;
+
+    // Fetch Data, nothing in the trash is found
+    let hits: Vec<SearchHit> = db
+        .search(&query, &user_id)
+        .await
+        .map_err(SearchError::UnexpectedError)?;
+
+    Ok(HttpResponse::Ok().json(SearchResults::ranked(query, hits, MAX_SEARCH_RESULTS)))
+}
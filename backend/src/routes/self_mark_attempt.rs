@@ -0,0 +1,122 @@
+//! backend/src/routes/self_mark_attempt.rs
+//! To self-mark long answer questions of a submitted attempt against their rubric.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::AttemptRepo,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::attempts::{Attempt, SelfMarkJsonPkg, SurrealAttempt};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum SelfMarkError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for SelfMarkError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for SelfMarkError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            SelfMarkError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            SelfMarkError::ValidationError(anywho) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            SelfMarkError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SelfMarkQueryString {
+    attempt: String,
+}
+
+// --- EndPoint ---
+/// Route handler for self-marking a long answer question once an attempt is submitted.
+/// Marking the same question again replaces the previous mark.
+#[tracing::instrument(name = "Request to Self-Mark Quiz Attempt", skip(db))]
+pub async fn self_mark_attempt<R: AttemptRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    attempt_qp: web::Query<SelfMarkQueryString>,
+    mark_pt: web::Json<SelfMarkJsonPkg>,
+) -> Result<HttpResponse, SelfMarkError> {
+    let mark: SelfMarkJsonPkg = mark_pt.into_inner();
+
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String
+    let attempt_query_str: String = attempt_qp.into_inner().attempt;
+    let decoded_query_str: String = urlencoding::decode(&attempt_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let attempt_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(SelfMarkError::ValidationError)?;
+
+    let surreal_attempt: Option<SurrealAttempt> = db
+        .get_attempt(&attempt_id)
+        .await
+        .map_err(SelfMarkError::UnexpectedError)?;
+
+    // Sanity checks
+    let mut attempt: Attempt = match surreal_attempt {
+        None => {
+            return Err(SelfMarkError::ValidationError(anyhow::anyhow!(
+                "Attempt does not exist"
+            )));
+        }
+        Some(att) => {
+            if att.user_id != user_id {
+                return Err(SelfMarkError::AccessError(GuardError::PermissionError(
+                    String::from("User does not own attempt"),
+                )));
+            }
+            if att.submitted_at.is_none() {
+                return Err(SelfMarkError::ValidationError(anyhow::anyhow!(
+                    "Attempt must be submitted before self-marking"
+                )));
+            }
+            att.into()
+        }
+    };
+
+    attempt
+        .self_mark(&mark.question_id, mark.criteria_met)
+        .map_err(|err| SelfMarkError::ValidationError(anyhow::anyhow!(err)))?;
+
+    let updated: Option<SurrealAttempt> = db
+        .update_attempt(&attempt_id, &attempt)
+        .await
+        .map_err(|err| SelfMarkError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    match updated {
+        Some(att) => Ok(HttpResponse::Ok().json(att)),
+        None => Err(SelfMarkError::UnexpectedError(anyhow::anyhow!(
+            "Error updating attempt"
+        ))),
+    }
+}
@@ -0,0 +1,88 @@
+//! backend/src/routes/set_user_role.rs
+//! For admins to promote or demote users, as sign ups are always students.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::LookUpUser,
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    roles::{Permission, RoleJsonPkg},
+    GeneralUser, PartialUser,
+};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum SetRoleError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for SetRoleError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for SetRoleError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            SetRoleError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            SetRoleError::ValidationError(anywho) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            SetRoleError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for an admin to change a user's role, the same as `quizctl users set-role`.
+/// Admins cannot change their own role, so the last admin is never demoted by mistake.
+#[tracing::instrument(name = "Request to Set User Role", skip(db))]
+pub async fn set_user_role<R: LookUpUser>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    role_pt: web::Json<RoleJsonPkg>,
+) -> Result<HttpResponse, SetRoleError> {
+    let role_pkg: RoleJsonPkg = role_pt.into_inner();
+
+    user.require(Permission::ManageUsers)?;
+
+    let found: GeneralUser = db
+        .get_user_by_username(role_pkg.username)
+        .await
+        .context("Failed to look up user")?
+        .ok_or_else(|| SetRoleError::ValidationError(anyhow::anyhow!("User does not exist")))?;
+    if found.uuid == user.user_id {
+        return Err(SetRoleError::ValidationError(anyhow::anyhow!(
+            "Admins cannot change their own role"
+        )));
+    }
+
+    let updated: GeneralUser = db
+        .set_user_role(&found.uuid, role_pkg.role)
+        .await
+        .context("Failed to set role")?
+        .ok_or_else(|| SetRoleError::ValidationError(anyhow::anyhow!("User does not exist")))?;
+
+    // Leave the password hash behind
+    Ok(HttpResponse::Ok().json(PartialUser {
+        uuid: updated.uuid,
+        name: updated.name,
+        username: updated.username,
+        role: updated.role,
+    }))
+}
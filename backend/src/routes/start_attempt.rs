@@ -0,0 +1,219 @@
+//! backend/src/routes/start_attempt.rs
+//! To start an attempt at a quiz, handing out questions without their answers.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, GroupRepo, QuestionRepo, QuizRepo, RevisionRepo, TagRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use chrono::{Duration, Utc};
+use models::{
+    attempts::{AnswerKey, Attempt, AttemptStart, ExamQuestion, SurrealAttempt},
+    groups::{check_windows, SurrealAssignment},
+    pools::draw_questions,
+    questions::AllQuestions,
+    quiz::SurrealQuiz,
+    revisions::{Pin, Snapshot, SurrealRevision, WrittenQuestion},
+};
+use rand::{thread_rng, Rng};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum StartAttemptError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for StartAttemptError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for StartAttemptError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            StartAttemptError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            StartAttemptError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            StartAttemptError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StartAttemptQueryString {
+    quiz: String,
+}
+
+// --- EndPoint ---
+/// Route handler for starting an attempt at a quiz.
+/// The attempt remembers which questions were handed out, so they can be graded later.
+/// Members of a group a private quiz is assigned to may take it while the assignment is open.
+/// Timed quizzes give the attempt a deadline, which is enforced when it is submitted.
+/// Quizzes timing each question hand out none here, they are served one at a time through `/exam-room/next`.
+/// Questions are drawn and ordered with a seed kept on the attempt, so the paper can be regenerated.
+/// The attempt also keeps the revisions of the quiz and questions it was taken against.
+#[tracing::instrument(name = "Request to Start Quiz Attempt", skip(db))]
+pub async fn start_attempt<
+    R: AttemptRepo + GroupRepo + QuestionRepo + QuizRepo + RevisionRepo + TagRepo,
+>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    quiz_qp: web::Query<StartAttemptQueryString>,
+) -> Result<HttpResponse, StartAttemptError> {
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String
+    let quiz_query_str: String = quiz_qp.into_inner().quiz;
+    let decoded_query_str: String = urlencoding::decode(&quiz_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let quiz_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(StartAttemptError::ValidationError)?;
+
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&quiz_id)
+        .await
+        .map_err(StartAttemptError::ValidationError)?;
+
+    // Attempts let in by an assignment are held to its window again when submitted
+    let mut assigned: bool = false;
+    let quiz: &SurrealQuiz = match &surreal_quiz {
+        None => {
+            return Err(StartAttemptError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) if !qz.viewable_by(&user_id) => {
+            // Private quizzes can still be taken by groups they are assigned to, while open
+            let assignments: Vec<SurrealAssignment> = db
+                .get_member_assignments(&user_id, &quiz_id)
+                .await
+                .map_err(|err| StartAttemptError::UnexpectedError(anyhow::anyhow!(err)))?;
+            if assignments.is_empty() {
+                return Err(StartAttemptError::AccessError(GuardError::PermissionError(
+                    String::from("Quiz is private"),
+                )));
+            }
+            check_windows(&assignments, Utc::now()).map_err(GuardError::PermissionError)?;
+            assigned = true;
+            qz
+        }
+        Some(qz) => qz,
+    };
+
+    // Quizzes with pools draw from the author's tagged questions, others hand out every question
+    let mut pools: Vec<(u32, Vec<Thing>)> = Vec::new();
+    for pool in &quiz.pools {
+        let candidates: Vec<Thing> = db
+            .get_pool_question_ids(&quiz.author_id, &pool.tag)
+            .await
+            .map_err(|err| StartAttemptError::UnexpectedError(anyhow::anyhow!(err)))?;
+        pools.push((pool.count, candidates));
+    }
+    let all_questions: AllQuestions = if pools.is_empty() {
+        db.get_quiz_questions(&quiz_id).await
+    } else {
+        let drawn: Vec<Thing> = pools.iter().flat_map(|(_, ids)| ids.clone()).collect();
+        db.get_questions_by_ids(&drawn).await
+    }
+    .map_err(|err| StartAttemptError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    let written: Vec<WrittenQuestion> = all_questions.written();
+
+    // Answer keys are dropped here, they never leave the server.
+    // Hints are held back too, revealing one is recorded on the attempt.
+    let mut questions: Vec<ExamQuestion> = all_questions
+        .into_exam()
+        .into_iter()
+        .map(|(eq, _): (ExamQuestion, AnswerKey)| eq.withhold_hint())
+        .collect();
+    if pools.is_empty() {
+        let count: u32 = questions.len() as u32;
+        pools.push((count, questions.iter().map(|eq| eq.id.clone()).collect()));
+    }
+
+    // Seeds stay below i64::MAX so they fit a SurrealDB int
+    let seed: u64 = thread_rng().gen_range(0..=i64::MAX as u64);
+    let question_ids: Vec<Thing> = draw_questions(&pools, seed);
+    questions.retain(|eq| question_ids.contains(&eq.id));
+    questions.sort_by_key(|eq| question_ids.iter().position(|id| id == &eq.id));
+    let time_allowed: Option<Duration> = quiz.time_allowed();
+    let time_per_question: Option<u32> = quiz.time_per_question();
+
+    // The attempt is pinned to the quiz and questions as they are now, later edits make new revisions
+    let mut pins: Vec<Pin> = vec![Pin {
+        target: quiz_id.clone(),
+        author_id: quiz.author_id.clone(),
+        current: Snapshot::of_quiz(quiz),
+    }];
+    pins.extend(question_ids.iter().filter_map(|question_id| {
+        written
+            .iter()
+            .find(|wq| &wq.id == question_id)
+            .map(|asked| Pin {
+                target: asked.id.clone(),
+                author_id: asked.author_id.clone(),
+                current: Snapshot::Question(asked.question.clone()),
+            })
+    }));
+    let revisions: Vec<Thing> = db
+        .pin_revisions(pins)
+        .await
+        .context("Failed to pin revisions")?
+        .into_iter()
+        .map(|pinned: SurrealRevision| pinned.id)
+        .collect();
+
+    let mut attempt: Attempt = Attempt::start(
+        quiz_id,
+        user_id,
+        question_ids,
+        time_allowed,
+        seed,
+        quiz.hint_penalty,
+    )
+    .pinned_to(revisions);
+    if assigned {
+        attempt = attempt.through_assignment();
+    }
+    // Questions timed one by one are not seen before they are served, so none are handed out yet
+    if let Some(secs) = time_per_question {
+        attempt = attempt.timed_per_question(secs);
+        questions.clear();
+    }
+
+    let attempt: SurrealAttempt = db
+        .create_attempt(&attempt)
+        .await
+        .map_err(StartAttemptError::UnexpectedError)?;
+
+    Ok(HttpResponse::Created().json(AttemptStart {
+        attempt_id: attempt.id,
+        questions,
+        time_allowed_secs: time_allowed.map(|allowed| allowed.num_seconds()),
+        time_per_question_secs: time_per_question,
+        hint_penalty: quiz.hint_penalty,
+    }))
+}
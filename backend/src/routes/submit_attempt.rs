@@ -0,0 +1,176 @@
+//! backend/src/routes/submit_attempt.rs
+//! To submit and grade an attempt at a quiz.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{AttemptRepo, GroupRepo, QuestionRepo, RevisionRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use models::{
+    attempts::{
+        AnswerJsonPkg, AnswerKey, Attempt, AttemptSubmission, ExamQuestion, SurrealAttempt,
+    },
+    groups::{check_windows, SurrealAssignment},
+    questions::AllQuestions,
+    revisions::{pinned_exam, SurrealRevision},
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum SubmitAttemptError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for SubmitAttemptError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for SubmitAttemptError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            SubmitAttemptError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            SubmitAttemptError::ValidationError(anywho) => {
+                HttpResponse::build(StatusCode::BAD_REQUEST)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({ "msg": anywho.to_string() }))
+            }
+            SubmitAttemptError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AttemptQueryString {
+    attempt: String,
+}
+
+/// The attempt's `question_ids` as they were at the revisions it is pinned to, split for grading.
+/// Attempts from before revisions have none pinned, so they get the questions as they are now.
+pub(crate) async fn pinned_questions<R: QuestionRepo + RevisionRepo>(
+    db: &R,
+    attempt: &Attempt,
+    question_ids: &[Thing],
+) -> Result<Vec<(ExamQuestion, AnswerKey)>, anyhow::Error> {
+    if attempt.revisions.is_empty() {
+        // Drawn questions may come from any of the author's quizzes
+        let all_questions: AllQuestions = db.get_questions_by_ids(question_ids).await?;
+        return Ok(all_questions.into_exam());
+    }
+    let mut revisions: Vec<SurrealRevision> = db.get_revisions_by_ids(&attempt.revisions).await?;
+    revisions.retain(|revision| question_ids.contains(&revision.target));
+    Ok(pinned_exam(revisions))
+}
+
+// --- EndPoint ---
+/// Route handler for submitting an attempt, which is graded against the revisions it was pinned to.
+/// An attempt can only be submitted once, and one started through an assignment only while an assignment is open.
+/// Timed attempts received after their deadline and grace period are submitted with no answers.
+/// Attempts timed per question are graded on the answers given in time as each question was served,
+/// plus the submitted answer to the question still open.
+#[tracing::instrument(name = "Request to Submit Quiz Attempt", skip(db))]
+pub async fn submit_attempt<R: AttemptRepo + GroupRepo + QuestionRepo + RevisionRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    attempt_qp: web::Query<AttemptQueryString>,
+    submission_pt: web::Json<AttemptSubmission>,
+) -> Result<HttpResponse, SubmitAttemptError> {
+    let submission: AttemptSubmission = submission_pt.into_inner();
+
+    let user_id: String = user.user_id.clone();
+
+    // Decode Query String
+    let attempt_query_str: String = attempt_qp.into_inner().attempt;
+    let decoded_query_str: String = urlencoding::decode(&attempt_query_str)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let attempt_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(SubmitAttemptError::ValidationError)?;
+
+    let surreal_attempt: Option<SurrealAttempt> = db
+        .get_attempt(&attempt_id)
+        .await
+        .map_err(SubmitAttemptError::UnexpectedError)?;
+
+    // Sanity checks
+    let mut attempt: Attempt = match surreal_attempt {
+        None => {
+            return Err(SubmitAttemptError::ValidationError(anyhow::anyhow!(
+                "Attempt does not exist"
+            )));
+        }
+        Some(att) => {
+            if att.user_id != user_id {
+                return Err(SubmitAttemptError::AccessError(
+                    GuardError::PermissionError(String::from("User does not own attempt")),
+                ));
+            }
+            if att.submitted_at.is_some() {
+                return Err(SubmitAttemptError::ValidationError(anyhow::anyhow!(
+                    "Attempt has already been submitted"
+                )));
+            }
+            att.into()
+        }
+    };
+
+    // Attempts started through an assignment are only taken while one of the user's assignments is open,
+    // the same check that let them start
+    if attempt.assigned {
+        let assignments: Vec<SurrealAssignment> = db
+            .get_member_assignments(&user_id, &attempt.quiz_id)
+            .await
+            .map_err(|err| SubmitAttemptError::UnexpectedError(anyhow::anyhow!(err)))?;
+        check_windows(&assignments, Utc::now()).map_err(GuardError::PermissionError)?;
+    }
+
+    // Edits made since the attempt started do not change what it is graded against
+    let keyed: Vec<(ExamQuestion, AnswerKey)> =
+        pinned_questions(db.get_ref(), &attempt, &attempt.question_ids)
+            .await
+            .map_err(SubmitAttemptError::UnexpectedError)?;
+
+    // Answers arriving after the deadline are thrown away, whatever the client's timer said
+    let now: DateTime<Utc> = Utc::now();
+    if attempt.is_overdue(now) {
+        attempt.time_out(keyed);
+    } else if attempt.is_paced() {
+        let responses: Vec<AnswerJsonPkg> = attempt.paced_responses(&submission.answers, now);
+        attempt.grade(keyed, &responses);
+    } else {
+        attempt.grade(keyed, &submission.answers);
+    }
+
+    // Only saved if still unsubmitted, so of two submissions racing each other one is turned away
+    let updated: Option<SurrealAttempt> = db
+        .submit_attempt(&attempt_id, &attempt)
+        .await
+        .map_err(|err| SubmitAttemptError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    match updated {
+        Some(att) => Ok(HttpResponse::Ok().json(att)),
+        None => Err(SubmitAttemptError::ValidationError(anyhow::anyhow!(
+            "Attempt has already been submitted"
+        ))),
+    }
+}
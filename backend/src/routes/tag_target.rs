@@ -0,0 +1,101 @@
+//! backend/src/routes/tag_target.rs
+//! To add tags to, or remove them from, a quiz or question.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    error_chain_helper,
+    repository::{QuizRepo, TagRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use models::tags::{taggable_table, SurrealTag, TagAssignPkg, TagIndex};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum TagTargetError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for TagTargetError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for TagTargetError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            TagTargetError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            TagTargetError::ValidationError(anywho) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            TagTargetError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+// --- EndPoint ---
+/// Route handler for tagging, or untagging, a quiz or question.
+/// The request says whether the target should have the tag, so repeating it changes nothing.
+/// Responds with every tag the target now has, moderators may tag anyone's work.
+#[tracing::instrument(name = "Request to Tag Target", skip(db))]
+pub async fn tag_target<R: QuizRepo + TagRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    assign_pt: web::Json<TagAssignPkg>,
+) -> Result<HttpResponse, TagTargetError> {
+    let assign: TagAssignPkg = assign_pt.into_inner();
+
+    if !taggable_table(&assign.target.tb) || assign.tag.tb != "tags" {
+        return Err(TagTargetError::ValidationError(anyhow::anyhow!(
+            "Only quizzes and questions can be tagged"
+        )));
+    }
+
+    let surreal_tag: Option<SurrealTag> = db
+        .get_tag(&assign.tag)
+        .await
+        .map_err(TagTargetError::UnexpectedError)?;
+    let target_author: Option<String> = db
+        .get_author_of(&assign.target)
+        .await
+        .map_err(TagTargetError::UnexpectedError)?;
+
+    match (surreal_tag, target_author) {
+        (Some(tg), Some(author_id)) => {
+            user.require_manage(&author_id)?;
+            // Tags belong to an author, so they only go on that author's work
+            if tg.author_id != author_id {
+                return Err(TagTargetError::AccessError(GuardError::PermissionError(
+                    String::from("Tags only go on quizzes and questions by the tag's author"),
+                )));
+            }
+        }
+        _ => {
+            return Err(TagTargetError::ValidationError(anyhow::anyhow!(
+                "Tag or target does not exist"
+            )));
+        }
+    }
+
+    db.set_tagged(&assign.target, &assign.tag, assign.tagged)
+        .await
+        .map_err(TagTargetError::UnexpectedError)?;
+
+    let index: TagIndex = db
+        .get_tags(std::slice::from_ref(&assign.target))
+        .await
+        .map_err(|err| TagTargetError::UnexpectedError(anyhow::anyhow!(err)))?;
+
+    Ok(HttpResponse::Ok().json(index.tags_for(&assign.target)))
+}
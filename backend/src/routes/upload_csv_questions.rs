@@ -0,0 +1,119 @@
+//! backend/src/routes/upload_csv_questions.rs
+//! To add multiple choice questions to a quiz from a CSV spreadsheet.
+use crate::{
+    authentication::{CurrentUser, GuardError},
+    csv_questions::read_csv_questions,
+    error_chain_helper,
+    repository::{QuestionRepo, QuizRepo},
+};
+use actix_web::http::{header::ContentType, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use anyhow::Context;
+use models::{
+    csv_upload::{CsvRejection, CsvRowError},
+    questions::{JsonQuestion, SurrealQuestionMC},
+    quiz::SurrealQuiz,
+};
+use serde::Deserialize;
+use surrealdb::sql::{thing, Thing};
+
+// -- Errors --
+#[derive(thiserror::Error)]
+pub enum UploadCsvError {
+    #[error("{0}")]
+    ValidationError(#[source] anyhow::Error),
+    #[error("Upload has invalid rows, nothing was saved")]
+    InvalidRows(Vec<CsvRowError>),
+    #[error(transparent)]
+    AccessError(#[from] GuardError),
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+impl std::fmt::Debug for UploadCsvError {
+    /// Custom implementation to display root cause of errors
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_chain_helper(self, f)
+    }
+}
+
+impl ResponseError for UploadCsvError {
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        match self {
+            UploadCsvError::UnexpectedError(_) => {
+                HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR)
+                    .insert_header(ContentType::json())
+                    .json(serde_json::json!({"msg": "Unknown Error"}))
+            }
+            UploadCsvError::ValidationError(anywho) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(serde_json::json!({ "msg": anywho.to_string() })),
+            // Every row to fix, so the spreadsheet can be corrected in one go
+            UploadCsvError::InvalidRows(rows) => HttpResponse::build(StatusCode::BAD_REQUEST)
+                .insert_header(ContentType::json())
+                .json(CsvRejection {
+                    msg: self.to_string(),
+                    rows: rows.clone(),
+                }),
+            UploadCsvError::AccessError(err) => err.error_response(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UploadCsvQueryString {
+    quiz: String,
+}
+
+// --- EndPoint ---
+/// Route handler for adding a spreadsheet of multiple choice questions to one of the user's quizzes.
+/// Rows are saved all or nothing, so a single bad row saves nothing.
+#[tracing::instrument(name = "Request to Upload CSV Questions", skip(db, body))]
+pub async fn upload_csv_questions<R: QuestionRepo + QuizRepo>(
+    req: HttpRequest,
+    user: CurrentUser,
+    db: web::Data<R>,
+    upload_qp: web::Query<UploadCsvQueryString>,
+    body: String,
+) -> Result<HttpResponse, UploadCsvError> {
+    // Decode Query String
+    let decoded_query_str: String = urlencoding::decode(&upload_qp.quiz)
+        .expect("UTF-8")
+        .into_owned();
+
+    // If cannot be parsed, it cannot be in database
+    let quiz_id: Thing = thing(&decoded_query_str)
+        .context("Unable to parse query")
+        .map_err(UploadCsvError::ValidationError)?;
+
+    let surreal_quiz: Option<SurrealQuiz> = db
+        .get_quiz(&quiz_id)
+        .await
+        .map_err(UploadCsvError::ValidationError)?;
+    // Questions belong to the quiz author, even when a moderator uploads them
+    let author_id: String = match surreal_quiz {
+        None => {
+            return Err(UploadCsvError::ValidationError(anyhow::anyhow!(
+                "Quiz does not exist"
+            )));
+        }
+        Some(qz) => {
+            user.require_manage(&qz.author_id)?;
+            qz.author_id
+        }
+    };
+
+    let questions: Vec<JsonQuestion> =
+        read_csv_questions(&body).map_err(UploadCsvError::InvalidRows)?;
+
+    let created: Vec<SurrealQuestionMC> = db
+        .create_questions(questions, author_id, quiz_id)
+        .await
+        .map_err(UploadCsvError::UnexpectedError)?
+        .into_iter()
+        .map(serde_json::from_value)
+        .collect::<Result<_, _>>()
+        .context("Failed to read created questions")?;
+
+    Ok(HttpResponse::Created().json(created))
+}
@@ -1,7 +1,13 @@
 //! backend/src/startup.rs
 //! Holds application level information and functions.
 use crate::{
-    authentication::AuthCookie, configuration::AllSettings, routes::*, surrealdb_repo::Database,
+    authentication::AuthCookie,
+    configuration::{AllSettings, DatabaseEngine, DatabaseSettings, TrashSettings},
+    memory_repo::MemoryDatabase,
+    migrations::run_migrations,
+    repository::{Repository, RoleLookUp, TrashRepo},
+    routes::*,
+    surrealdb_repo::Database,
 };
 use actix_cors::Cors;
 use actix_session::{config::PersistentSession, SessionMiddleware};
@@ -12,6 +18,7 @@ use actix_web::{
 };
 use secrecy::{ExposeSecret, Secret};
 use std::net::TcpListener;
+use std::sync::Arc;
 use tracing_actix_web::TracingLogger;
 
 /// Builds an Actix-Web Server, from `HttpServer::new()` provided a TcpListener.
@@ -21,10 +28,31 @@ pub async fn run(
     listener: TcpListener,
     database: Database,
     hmac_secret: Secret<String>,
+) -> Result<Server, anyhow::Error> {
+    serve(listener, database, hmac_secret)
+}
+
+/// Same server as `run`, but kept in memory, so nothing is saved once it stops.
+pub async fn run_in_memory(
+    listener: TcpListener,
+    database: MemoryDatabase,
+    hmac_secret: Secret<String>,
+) -> Result<Server, anyhow::Error> {
+    serve(listener, database, hmac_secret)
+}
+
+/// Builds the server over any storage backend, every route is served by each of them.
+fn serve<R: Repository>(
+    listener: TcpListener,
+    repo: R,
+    hmac_secret: Secret<String>,
 ) -> Result<Server, anyhow::Error> {
     // Wrap connection in Smart Pointer
     // ideally we want separate database for cookies, but should be OK for small project
-    let db_connect: web::Data<Database> = web::Data::new(database);
+    let db_connect: web::Data<R> = web::Data::new(repo);
+    // `CurrentUser` looks up roles through this, whatever the backend
+    let role_look_up: web::Data<dyn RoleLookUp> =
+        web::Data::from(db_connect.clone().into_inner() as Arc<dyn RoleLookUp>);
     // Key for cookies
     let secret_key = Key::from(hmac_secret.expose_secret().as_bytes());
 
@@ -77,21 +105,70 @@ pub async fn run(
                 // Allows for API Versioning
                 web::scope("/api/v01")
                     .route("/health-check", web::get().to(health_check))
-                    .route("/create-user", web::post().to(create_user))
-                    .route("/user-login", web::post().to(user_login))
+                    .route("/create-user", web::post().to(create_user::<R>))
+                    .route("/user-login", web::post().to(user_login::<R>))
                     .service(
                         web::scope("")
                             .wrap(AuthCookie)
-                            .route("/check-login", web::get().to(check_login))
+                            .route("/check-login", web::get().to(check_login::<R>))
                             .route("/user-logout", web::get().to(user_logout))
-                            .route("/quiz-nexus", web::get().to(get_my_quizzes))
-                            .route("/quiz-nexus", web::post().to(create_new_quiz))
-                            .route("/quiz-nexus", web::put().to(edit_quiz))
-                            .route("/quiz-nexus", web::delete().to(destroy_my_quiz))
-                            .route("/question-forge", web::get().to(get_questions))
-                            .route("/question-forge", web::post().to(create_new_questions))
-                            .route("/question-forge", web::put().to(edit_question))
-                            .route("/question-forge", web::delete().to(destroy_my_quest)),
+                            .route("/quiz-nexus", web::get().to(get_my_quizzes::<R>))
+                            .route("/quiz-nexus", web::post().to(create_new_quiz::<R>))
+                            .route("/quiz-nexus", web::put().to(edit_quiz::<R>))
+                            .route("/quiz-nexus", web::delete().to(destroy_my_quiz::<R>))
+                            .route("/question-forge", web::get().to(get_questions::<R>))
+                            .route("/question-forge", web::post().to(create_new_questions::<R>))
+                            .route("/question-forge", web::put().to(edit_question::<R>))
+                            .route("/question-forge", web::delete().to(destroy_my_quest::<R>))
+                            .route("/trash", web::get().to(get_my_trash::<R>))
+                            .route("/trash", web::put().to(restore_my_trash::<R>))
+                            .route("/revisions", web::get().to(get_target_revisions::<R>))
+                            .route("/revisions/diff", web::get().to(diff_revisions::<R>))
+                            .route("/revisions/rollback", web::put().to(rollback_revision::<R>))
+                            .route("/quiz-nexus/public", web::get().to(get_public_quizzes::<R>))
+                            .route("/quiz-nexus/shared", web::get().to(get_shared_quiz::<R>))
+                            .route("/quiz-nexus/export", web::get().to(export_quiz::<R>))
+                            .route("/quiz-nexus/import", web::post().to(import_quiz::<R>))
+                            .route(
+                                "/question-forge/csv",
+                                web::post().to(upload_csv_questions::<R>),
+                            )
+                            .route("/exam-room", web::post().to(start_attempt::<R>))
+                            .route("/exam-room", web::put().to(submit_attempt::<R>))
+                            .route(
+                                "/exam-room/self-mark",
+                                web::put().to(self_mark_attempt::<R>),
+                            )
+                            .route("/exam-room/hint", web::put().to(reveal_hint::<R>))
+                            .route("/exam-room/next", web::put().to(next_question::<R>))
+                            .route(
+                                "/exam-room/history",
+                                web::get().to(get_attempt_history::<R>),
+                            )
+                            .route("/study-hall", web::get().to(get_due_questions::<R>))
+                            .route("/study-hall", web::post().to(review_question::<R>))
+                            .route("/study-hall/hint", web::put().to(reveal_study_hint::<R>))
+                            .route("/likes", web::put().to(toggle_like::<R>))
+                            .route("/search", web::get().to(search_quizzes::<R>))
+                            .route("/tags", web::get().to(get_my_tags::<R>))
+                            .route("/tags", web::post().to(create_tag::<R>))
+                            .route("/tags", web::put().to(edit_tag::<R>))
+                            .route("/tags", web::delete().to(destroy_tag::<R>))
+                            .route("/tags/assign", web::put().to(tag_target::<R>))
+                            .route("/groups", web::get().to(get_my_groups::<R>))
+                            .route("/groups", web::post().to(create_group::<R>))
+                            .route("/groups/members", web::put().to(invite_member::<R>))
+                            .route("/groups/join", web::post().to(join_group::<R>))
+                            .route(
+                                "/groups/assignments",
+                                web::get().to(get_my_assignments::<R>),
+                            )
+                            .route("/groups/assignments", web::post().to(assign_quiz::<R>))
+                            .route(
+                                "/groups/progress",
+                                web::get().to(get_assignment_progress::<R>),
+                            )
+                            .route("/users/role", web::put().to(set_user_role::<R>)),
                     ),
             )
             // Additional settings - everything returned as JSON
@@ -99,6 +176,7 @@ pub async fn run(
                 web::JsonConfig::default().content_type(|_| "application/json".parse().unwrap()),
             )
             .app_data(db_connect.clone())
+            .app_data(role_look_up.clone())
     })
     .listen(listener)?
     .run();
@@ -115,46 +193,36 @@ impl Application {
     /// Initialization for `Application` struct to set up application
     /// based on configuration setting from files or environment variables.
     pub async fn from_config(config: AllSettings) -> Result<Self, anyhow::Error> {
-        println!(
-            "Database on {:?}:{:?}",
-            &config.database.host, &config.database.port
-        );
-
-        // Below is a check for database connection when application starts.
-        let mut cnt = 1;
-        let database: Database = loop {
-            match Database::from_config(config.database.clone()).await {
-                Ok(db) => break db,
-                Err(e) => {
-                    println!("Error connecting to database");
-                    println!("{:?}", e);
-                    if cnt == 10 {
-                        return Err(anyhow::anyhow!(e));
-                    } else {
-                        cnt += 1;
-                        println!("Sleep for 5 seconds");
-                        tokio::time::sleep(tokio::time::Duration::new(5, 0)).await;
-                    }
-                }
-            }
-        };
-
-        // Update port based on settings
-        let address: String = format! {
-            "{}:{}",
-            // host address, 0.0.0.0 or 127.0.0.1
-            config.application.host,
-            // port
-            config.application.port,
-        };
-        println!("Running on {:?}", &address);
-
-        // Need listener to obtain randomly selected port
-        let listener: TcpListener = TcpListener::bind(address)?;
-        let port: u16 = listener.local_addr().unwrap().port();
+        // Connect before listening, so requests never arrive without a database
+        if config.database.in_memory {
+            println!("Database kept in memory, nothing is saved");
+            Self::over_memory(config, MemoryDatabase::default()).await
+        } else {
+            let database: Database = connect_database(config.database.clone()).await?;
+            Self::over_database(config, database).await
+        }
+    }
 
+    /// Serves a SurrealDB connection made elsewhere, which the caller can keep using alongside the server.
+    pub async fn over_database(
+        config: AllSettings,
+        database: Database,
+    ) -> Result<Self, anyhow::Error> {
+        let (listener, port) = listen(&config)?;
+        spawn_trash_purge(database.clone(), config.trash);
         let server: Server = run(listener, database, config.application.hmac_secret).await?;
+        Ok(Self { port, server })
+    }
 
+    /// Serves a memory database made elsewhere, which the caller can keep using alongside the server.
+    pub async fn over_memory(
+        config: AllSettings,
+        database: MemoryDatabase,
+    ) -> Result<Self, anyhow::Error> {
+        let (listener, port) = listen(&config)?;
+        spawn_trash_purge(database.clone(), config.trash);
+        let server: Server =
+            run_in_memory(listener, database, config.application.hmac_secret).await?;
         Ok(Self { port, server })
     }
 
@@ -169,3 +237,70 @@ impl Application {
         self.server.await
     }
 }
+
+/// Binds the configured address, the port is picked by the system when configured as 0.
+fn listen(config: &AllSettings) -> Result<(TcpListener, u16), anyhow::Error> {
+    let address: String = format! {
+        "{}:{}",
+        // host address, 0.0.0.0 or 127.0.0.1
+        config.application.host,
+        // port
+        config.application.port,
+    };
+    println!("Running on {:?}", &address);
+
+    // Need listener to obtain randomly selected port
+    let listener: TcpListener = TcpListener::bind(address)?;
+    let port: u16 = listener.local_addr()?.port();
+    Ok((listener, port))
+}
+
+/// Connects to SurrealDB, retrying while a remote server starts up, and brings the schema up to date.
+pub async fn connect_database(config: DatabaseSettings) -> Result<Database, anyhow::Error> {
+    println!("Database engine {:?}", &config.engine);
+
+    // Below is a check for database connection when application starts.
+    let mut cnt = 1;
+    let database: Database = loop {
+        match Database::from_config(config.clone()).await {
+            Ok(db) => break db,
+            Err(e) => {
+                println!("Error connecting to database");
+                println!("{:?}", e);
+                // An embedded database that fails to open will not fix itself
+                if cnt == 10 || config.engine != DatabaseEngine::Remote {
+                    return Err(e);
+                } else {
+                    cnt += 1;
+                    println!("Sleep for 5 seconds");
+                    tokio::time::sleep(tokio::time::Duration::new(5, 0)).await;
+                }
+            }
+        }
+    };
+
+    // Schema has to be in place before any requests come in
+    let schema_version: u32 = run_migrations(&database).await?;
+    println!("Database schema at version {}", schema_version);
+    Ok(database)
+}
+
+/// Empties the trash of anything older than the retention window, every `purge_interval_mins`.
+fn spawn_trash_purge<R: TrashRepo + Send + Sync + 'static>(repo: R, settings: TrashSettings) {
+    let retention: chrono::Duration = chrono::Duration::days(settings.retention_days.into());
+    let every = tokio::time::Duration::from_secs(settings.purge_interval_mins.max(1) * 60);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+            match repo.purge_trash(chrono::Utc::now() - retention).await {
+                Ok(purged) => tracing::info!(
+                    "Purged {} quizzes and {} questions from the trash",
+                    purged.quizzes,
+                    purged.questions
+                ),
+                Err(err) => tracing::error!("Failed to purge the trash: {:?}", err),
+            }
+        }
+    });
+}
@@ -1,23 +1,54 @@
 // backend/src/surreal_repo.rs
 // To hold SurrealDB logic connecting to and using the database.
 // TODO: If project grows, Add SessionStorage to different SurrealDB Instance
-use crate::configuration::DatabaseSettings;
+use crate::{
+    configuration::{DatabaseEngine, DatabaseSettings},
+    repository::{
+        AttemptRepo, GroupRepo, LookUpUser, QuestionRepo, QuizRepo, RevisionRepo, SessionRepo,
+        StudyRepo, TagRepo, TrashRepo,
+    },
+};
 use actix_session::storage::{LoadError, SaveError, SessionKey, SessionStore, UpdateError};
 use actix_web::cookie::time::Duration;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use models::GeneralUser;
+use models::{
+    attempts::{Attempt, QuestionTiming, SurrealAttempt},
+    groups::{
+        AssignedQuiz, Assignment, Group, Membership, SurrealAssignment, SurrealGroup,
+        SurrealMembership,
+    },
+    likes::{LikeCount, LikeTally},
+    questions::{
+        AllQuestions, JsonQuestion, QuestionJsonPkg, QuestionRecord, SurrealGenericQuestionData,
+        SurrealQuestionLA, SurrealQuestionMC, SurrealQuestionMS, SurrealQuestionSA,
+        SurrealQuestionTF, QUESTION_TABLES,
+    },
+    quiz::{Quiz, QuizDeletion, QuizJsonPkg, SurrealQuiz},
+    revisions::{Edit, Pin, Revision, Snapshot, SurrealRevision},
+    roles::Role,
+    search::{
+        snippet, SearchHit, SearchKind, HIGHLIGHT_END, HIGHLIGHT_START, MAX_SEARCH_RESULTS,
+        SNIPPET_CONTEXT,
+    },
+    study::{ReviewCard, SurrealReviewCard},
+    tags::{SurrealTag, Tag, TagIndex, TagJsonPkg, TagLink, TagSummary},
+    trash::{TrashItem, TrashPurge},
+    GeneralUser, PartialUser,
+};
 use rand::distributions::{Alphanumeric, DistString};
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
-use surrealdb::engine::remote::ws::{Client, Ws};
+use surrealdb::engine::any::{self, Any};
 use surrealdb::opt::auth::Root;
-use surrealdb::sql::{Id, Thing};
-use surrealdb::{Error, Surreal};
+use surrealdb::sql::{Datetime, Id, Thing};
+use surrealdb::Surreal;
 
 #[derive(Clone, Debug)]
 pub struct Database {
-    pub client: Surreal<Client>,
+    // the engine is picked at runtime, from the endpoint's scheme
+    pub client: Surreal<Any>,
     pub name_space: String,
     pub db_name: String,
 }
@@ -29,17 +60,21 @@ struct GeneralUserCount {
 
 impl Database {
     /// Creating Database connection from configuration in YAML files.
-    pub async fn from_config(config: DatabaseSettings) -> Result<Self, Error> {
-        let address: String = format!("{}:{}", config.host, config.port);
+    /// Embedded engines open their folder, and need the backend built with their `embedded-*` feature.
+    pub async fn from_config(config: DatabaseSettings) -> Result<Self> {
+        let address: String = config.endpoint()?;
         println!("{:?}", &address);
-        let client = Surreal::new::<Ws>(&address).await?;
-        println!("Signing in");
-        client
-            .signin(Root {
-                username: &config.username,
-                password: &config.password,
-            })
-            .await?;
+        let client: Surreal<Any> = any::connect(&address).await?;
+        // An embedded database has no users to sign in as
+        if config.engine == DatabaseEngine::Remote {
+            println!("Signing in");
+            client
+                .signin(Root {
+                    username: &config.username,
+                    password: &config.password,
+                })
+                .await?;
+        }
         println!("Getting namespace and database");
         // Name Space is like a level above a database
         client
@@ -48,12 +83,14 @@ impl Database {
             .await
             .expect("Unable to connect to database");
 
-        println!("Returning the goods");
-        Ok(Database {
+        let database: Database = Database {
             client,
             name_space: config.namespace,
             db_name: config.name,
-        })
+        };
+
+        println!("Returning the goods");
+        Ok(database)
     }
 
     /// helper function to fetch all users
@@ -65,42 +102,56 @@ impl Database {
         }
     }
 
-    /// helper function to create a new user.
-    pub async fn add_general_user(&self, new_general_user: GeneralUser) -> Option<GeneralUser> {
-        let created_gen_user: Result<Option<GeneralUser>, Error> = self
-            .client
-            .create(("general_user", new_general_user.uuid.clone()))
-            .content(new_general_user)
-            .await;
+    /// helper function to count the records of a table.
+    pub async fn count_records(&self, table: &str) -> surrealdb::Result<i64> {
+        let qry = "SELECT count() FROM type::table($table) GROUP ALL";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("table", table)).await?;
+        let count: Option<GeneralUserCount> = response.take(0)?;
+        Ok(count.map(|found| found.count).unwrap_or_default())
+    }
 
-        match created_gen_user {
-            Ok(this) => this,
-            Err(_) => None,
+    /// helper function to delete a user along with everything they made:
+    /// quizzes, questions, attempts, study cards, tags, likes, groups and sessions.
+    pub async fn delete_user(&self, user_id: &str) -> surrealdb::Result<Option<GeneralUser>> {
+        let mut surreal_ql: String = String::from(
+            "BEGIN TRANSACTION;
+            DELETE likes WHERE in = $user OR out.author_id = $user_id;
+            DELETE tagged WHERE in.author_id = $user_id OR out.author_id = $user_id;
+            DELETE tags WHERE author_id = $user_id;
+            DELETE attempts WHERE user_id = $user_id;
+            DELETE review_cards WHERE user_id = $user_id;
+            DELETE memberships WHERE user_id = $user_id OR group_id.owner_id = $user_id;
+            DELETE assignments WHERE group_id.owner_id = $user_id OR quiz_id.author_id = $user_id;
+            DELETE groups WHERE owner_id = $user_id;
+            DELETE revisions WHERE author_id = $user_id OR target.author_id = $user_id;
+            DELETE quizzes WHERE author_id = $user_id;\n",
+        );
+        for table in QUESTION_TABLES {
+            surreal_ql.push_str(&format!("DELETE {table} WHERE author_id = $user_id;\n"));
         }
-    }
+        // Session state is stored as JSON text holding the user ID
+        surreal_ql.push_str(
+            "DELETE sessions WHERE string::contains(token, $user_id);
+            DELETE $user RETURN BEFORE;
+            COMMIT TRANSACTION;",
+        );
 
-    /// helper function count users with username.
-    pub async fn count_users(&self, username: &str) -> surrealdb::Result<i64> {
-        let qry = r#"SELECT count() FROM type::table($table)
-        WHERE username = $username"#;
         let mut response: surrealdb::Response = self
             .client
-            .query(qry)
-            .bind(("table", "general_user"))
-            .bind(("username", username))
-            .await?;
-
-        let count: Option<GeneralUserCount> = response.take(0)?;
-        dbg!(&count);
-        match count {
-            Some(count) => Ok(count.count),
-            None => Ok(0),
-        }
+            .query(surreal_ql)
+            .bind(("user", Thing::from(("general_user", user_id))))
+            .bind(("user_id", user_id))
+            .await?
+            .check()?;
+        // The user is the last statement
+        let last: usize = response.num_statements() - 1;
+        response.take(last)
     }
 }
 
 // -- Below is for Session Store --
-type SessionState = HashMap<String, String>;
+pub(crate) type SessionState = HashMap<String, String>;
 
 /// Session token structure to be stored in SurrealDB database.
 #[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Clone)]
@@ -113,19 +164,22 @@ pub struct SessionToken {
 /// A structure used when sending session token updates to database.
 #[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Clone)]
 pub struct UpdatedSessionToken {
+    // left out when `None`, merging a missing value would remove the field
+    #[serde(skip_serializing_if = "Option::is_none")]
     token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     expiry: Option<surrealdb::sql::Datetime>,
 }
 
 /// Generates Random SessionKey for creating session tokens
-fn generate_session_key() -> anyhow::Result<SessionKey> {
+pub(crate) fn generate_session_key() -> anyhow::Result<SessionKey> {
     let key: String = Alphanumeric.sample_string(&mut rand::thread_rng(), 64);
     let session_key: SessionKey = key.try_into().context("Invalid Session Key")?;
     Ok(session_key)
 }
 
 /// Adding time to current time for setting expiry of cookie
-fn generate_time_stamp(dur: &Duration) -> anyhow::Result<DateTime<Utc>> {
+pub(crate) fn generate_time_stamp(dur: &Duration) -> anyhow::Result<DateTime<Utc>> {
     let duration_mili: i64 = dur
         .whole_milliseconds()
         .try_into()
@@ -315,15 +369,7 @@ impl SessionStore for Database {
     }
 }
 
-// -- Traits for DB
-// Compiler suggest not making public async trait...
-pub trait LookUpUser {
-    fn get_user_by_username(
-        &self,
-        username: String,
-    ) -> impl std::future::Future<Output = Result<Option<GeneralUser>, anyhow::Error>> + Send;
-}
-
+// -- Repository traits for SurrealDB --
 impl LookUpUser for Database {
     /// Custome Surreal query to get a user by their username.
     async fn get_user_by_username(
@@ -348,4 +394,1429 @@ impl LookUpUser for Database {
         let user: Option<GeneralUser> = response.take(0)?;
         Ok(user)
     }
+
+    async fn get_user(&self, user_id: &str) -> Result<Option<GeneralUser>, anyhow::Error> {
+        let user: Option<GeneralUser> = self.client.select(("general_user", user_id)).await?;
+        Ok(user)
+    }
+
+    async fn count_users(&self, username: &str) -> Result<i64, anyhow::Error> {
+        let qry = r#"SELECT count() FROM type::table($table)
+        WHERE username = $username"#;
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("table", "general_user"))
+            .bind(("username", username))
+            .await?;
+
+        let count: Option<GeneralUserCount> = response.take(0)?;
+        match count {
+            Some(count) => Ok(count.count),
+            None => Ok(0),
+        }
+    }
+
+    async fn add_general_user(
+        &self,
+        new_general_user: GeneralUser,
+    ) -> Result<Option<GeneralUser>, anyhow::Error> {
+        let created_gen_user: Option<GeneralUser> = self
+            .client
+            .create(("general_user", new_general_user.uuid.clone()))
+            .content(new_general_user)
+            .await?;
+        Ok(created_gen_user)
+    }
+
+    /// Matched by field rather than record ID, so a missing user is not created.
+    async fn set_user_role(
+        &self,
+        user_id: &str,
+        role: Role,
+    ) -> Result<Option<GeneralUser>, anyhow::Error> {
+        let qry = "UPDATE general_user SET role = $role WHERE uuid = $user_id RETURN AFTER";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("role", role))
+            .bind(("user_id", user_id))
+            .await?;
+        let updated: Vec<GeneralUser> = response.take(0)?;
+        Ok(updated.into_iter().next())
+    }
+}
+
+/// Highlighting keeps the shape of the field, so choices come back as a list.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Highlighted {
+    Text(String),
+    List(Vec<String>),
+}
+
+impl Highlighted {
+    fn joined(self) -> String {
+        match self {
+            Highlighted::Text(text) => text,
+            Highlighted::List(list) => list.join(" · "),
+        }
+    }
+}
+
+/// A row from any of the search queries.
+#[derive(Deserialize, Debug)]
+struct TextMatch {
+    id: Thing,
+    quiz_id: Thing,
+    // questions can outlive their quiz
+    quiz_name: Option<String>,
+    title: Highlighted,
+    snippet: Option<Highlighted>,
+    score: f64,
+}
+
+impl TextMatch {
+    fn into_hit(self, kind: SearchKind) -> SearchHit {
+        SearchHit {
+            id: self.id,
+            kind,
+            quiz_id: self.quiz_id,
+            quiz_name: self
+                .quiz_name
+                .unwrap_or_else(|| String::from("Deleted quiz")),
+            title: snippet(&self.title.joined(), SNIPPET_CONTEXT),
+            snippet: self
+                .snippet
+                .map(|hl| snippet(&hl.joined(), SNIPPET_CONTEXT))
+                .unwrap_or_default(),
+            score: self.score,
+        }
+    }
+}
+
+/// What deleting a quiz removes besides its questions, each named for the `QuizDeletion` field
+/// that counts it. Links and revisions of the questions go before the questions,
+/// they are found through `parent_quiz`.
+const QUIZ_LINKS: [(&str, &str); 6] = [
+    (
+        "likes",
+        "DELETE likes WHERE out = $quiz_id OR out.parent_quiz = $quiz_id",
+    ),
+    (
+        "tag_links",
+        "DELETE tagged WHERE in = $quiz_id OR in.parent_quiz = $quiz_id",
+    ),
+    ("attempts", "DELETE attempts WHERE quiz_id = $quiz_id"),
+    (
+        "review_cards",
+        "DELETE review_cards WHERE quiz_id = $quiz_id",
+    ),
+    ("assignments", "DELETE assignments WHERE quiz_id = $quiz_id"),
+    (
+        "revisions",
+        "DELETE revisions WHERE target = $quiz_id OR target.parent_quiz = $quiz_id",
+    ),
+];
+
+impl QuizRepo for Database {
+    async fn get_quiz(&self, quiz_id: &Thing) -> Result<Option<SurrealQuiz>, anyhow::Error> {
+        let qry = "SELECT * FROM $quiz_id WHERE deleted_at IS NONE";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("quiz_id", quiz_id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_author_quizzes(
+        &self,
+        author_id: &str,
+        tag: Option<&Thing>,
+    ) -> Result<Vec<SurrealQuiz>, anyhow::Error> {
+        let qry = match tag {
+            None => "SELECT * FROM quizzes WHERE author_id = $user_id AND deleted_at IS NONE",
+            Some(_) => {
+                "SELECT * FROM quizzes WHERE author_id = $user_id AND deleted_at IS NONE
+                AND ->tagged->tags CONTAINS $tag"
+            }
+        };
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("user_id", author_id))
+            .bind(("tag", tag))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn create_quiz(&self, quiz: &Quiz) -> Result<SurrealQuiz, anyhow::Error> {
+        let created: Vec<SurrealQuiz> = self.client.create("quizzes").content(quiz).await?;
+        created
+            .into_iter()
+            .next()
+            .context("Unsure what happened in Database")
+    }
+
+    /// Runs as one transaction, the quiz is selected once everything is committed.
+    async fn create_quiz_with_questions(
+        &self,
+        quiz_id: &Thing,
+        quiz: &Quiz,
+        packages: Vec<QuestionJsonPkg>,
+    ) -> Result<SurrealQuiz, anyhow::Error> {
+        let mut surreal_ql: String =
+            String::from("BEGIN TRANSACTION;\nCREATE $quiz_id CONTENT $quiz;\n");
+        for index in 0..packages.len() {
+            surreal_ql.push_str(&format!(
+                "CREATE type::table($table_{index}) CONTENT $question_{index};\n"
+            ));
+        }
+        surreal_ql.push_str("COMMIT TRANSACTION;");
+
+        let mut query = self
+            .client
+            .query(surreal_ql)
+            .bind(("quiz_id", quiz_id))
+            .bind(("quiz", quiz));
+        for (index, pkg) in packages.into_iter().enumerate() {
+            query = query
+                .bind((format!("table_{index}"), pkg.question.table_name()))
+                .bind((
+                    format!("question_{index}"),
+                    pkg.question
+                        .into_record(quiz.author_id.clone(), pkg.quiz_id),
+                ));
+        }
+        query.await?.check()?;
+
+        let created: Option<SurrealQuiz> = self.client.select(quiz_id).await?;
+        created.context("Imported quiz not found")
+    }
+
+    async fn get_public_quizzes(
+        &self,
+        search: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<SurrealQuiz>, u64), anyhow::Error> {
+        let qry = r#"SELECT * FROM quizzes WHERE visibility = "Public" AND deleted_at IS NONE
+            AND (string::lowercase(name) CONTAINS $search OR string::lowercase(description) CONTAINS $search)
+            ORDER BY name ASC LIMIT $limit START $start;
+        SELECT count() FROM quizzes WHERE visibility = "Public" AND deleted_at IS NONE
+            AND (string::lowercase(name) CONTAINS $search OR string::lowercase(description) CONTAINS $search)
+            GROUP ALL;"#;
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("search", search.to_lowercase()))
+            .bind(("limit", per_page))
+            .bind(("start", (page.max(1) - 1) * per_page))
+            .await?;
+
+        let quizzes: Vec<SurrealQuiz> = response.take(0)?;
+        let total: Option<GeneralUserCount> = response.take(1)?;
+        // no matches means no rows to count
+        Ok((quizzes, total.map_or(0, |tl| tl.count as u64)))
+    }
+
+    /// Uses the full-text indexes, quizzes first, then each question table.
+    /// Only multiple choice and multi-select questions have choices.
+    async fn search(&self, query: &str, user_id: &str) -> Result<Vec<SearchHit>, anyhow::Error> {
+        let qry = r#"SELECT id, id AS quiz_id, name AS quiz_name,
+                search::highlight($hl_start, $hl_end, 0) AS title,
+                search::highlight($hl_start, $hl_end, 1) AS snippet,
+                search::score(0) + search::score(1) AS score
+            FROM quizzes
+            WHERE (name @0@ $query OR description @1@ $query)
+                AND (author_id = $user_id OR visibility = "Public")
+                AND deleted_at IS NONE
+            ORDER BY score DESC LIMIT $limit;
+        SELECT id, parent_quiz AS quiz_id, parent_quiz.name AS quiz_name,
+                search::highlight($hl_start, $hl_end, 0) AS title,
+                search::highlight($hl_start, $hl_end, 1) AS snippet,
+                search::score(0) + search::score(1) AS score
+            FROM questions_mc
+            WHERE (question @0@ $query OR choices @1@ $query)
+                AND (author_id = $user_id OR parent_quiz.visibility = "Public")
+                AND deleted_at IS NONE AND parent_quiz.deleted_at IS NONE
+            ORDER BY score DESC LIMIT $limit;
+        SELECT id, parent_quiz AS quiz_id, parent_quiz.name AS quiz_name,
+                search::highlight($hl_start, $hl_end, 0) AS title,
+                search::score(0) AS score
+            FROM questions_sa
+            WHERE question @0@ $query
+                AND (author_id = $user_id OR parent_quiz.visibility = "Public")
+                AND deleted_at IS NONE AND parent_quiz.deleted_at IS NONE
+            ORDER BY score DESC LIMIT $limit;
+        SELECT id, parent_quiz AS quiz_id, parent_quiz.name AS quiz_name,
+                search::highlight($hl_start, $hl_end, 0) AS title,
+                search::score(0) AS score
+            FROM questions_la
+            WHERE question @0@ $query
+                AND (author_id = $user_id OR parent_quiz.visibility = "Public")
+                AND deleted_at IS NONE AND parent_quiz.deleted_at IS NONE
+            ORDER BY score DESC LIMIT $limit;
+        SELECT id, parent_quiz AS quiz_id, parent_quiz.name AS quiz_name,
+                search::highlight($hl_start, $hl_end, 0) AS title,
+                search::score(0) AS score
+            FROM questions_tf
+            WHERE question @0@ $query
+                AND (author_id = $user_id OR parent_quiz.visibility = "Public")
+                AND deleted_at IS NONE AND parent_quiz.deleted_at IS NONE
+            ORDER BY score DESC LIMIT $limit;
+        SELECT id, parent_quiz AS quiz_id, parent_quiz.name AS quiz_name,
+                search::highlight($hl_start, $hl_end, 0) AS title,
+                search::highlight($hl_start, $hl_end, 1) AS snippet,
+                search::score(0) + search::score(1) AS score
+            FROM questions_ms
+            WHERE (question @0@ $query OR choices @1@ $query)
+                AND (author_id = $user_id OR parent_quiz.visibility = "Public")
+                AND deleted_at IS NONE AND parent_quiz.deleted_at IS NONE
+            ORDER BY score DESC LIMIT $limit;"#;
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("query", query))
+            .bind(("user_id", user_id))
+            .bind(("hl_start", HIGHLIGHT_START))
+            .bind(("hl_end", HIGHLIGHT_END))
+            .bind(("limit", MAX_SEARCH_RESULTS))
+            .await?;
+
+        let mut hits: Vec<SearchHit> = Vec::new();
+        for idx in 0..response.num_statements() {
+            let kind: SearchKind = if idx == 0 {
+                SearchKind::Quiz
+            } else {
+                SearchKind::Question
+            };
+            let matches: Vec<TextMatch> = response.take(idx)?;
+            hits.extend(matches.into_iter().map(|tm| tm.into_hit(kind)));
+        }
+        Ok(hits)
+    }
+
+    async fn update_quiz(
+        &self,
+        quiz_id: &Thing,
+        changes: &QuizJsonPkg,
+        revision: Option<Edit>,
+    ) -> Result<Option<SurrealQuiz>, anyhow::Error> {
+        self.update_revised(quiz_id, changes, revision).await
+    }
+
+    /// Runs as one transaction, so a failure part way leaves the quiz and everything else in place.
+    /// Each removal is kept in a variable named for its `QuizDeletion` field, and returned by name.
+    async fn delete_quiz(&self, quiz_id: &Thing) -> Result<Option<QuizDeletion>, anyhow::Error> {
+        let mut surreal_ql: String = String::from("BEGIN TRANSACTION;\n");
+        let mut counts: Vec<String> = Vec::with_capacity(QUIZ_LINKS.len() + 1);
+        for (field, statement) in QUIZ_LINKS {
+            surreal_ql.push_str(&format!("LET ${field} = ({statement} RETURN BEFORE);\n"));
+            counts.push(format!("{field}: array::len(${field})"));
+        }
+        let mut questions: Vec<String> = Vec::with_capacity(QUESTION_TABLES.len());
+        for table in QUESTION_TABLES {
+            surreal_ql.push_str(&format!(
+                "LET ${table} = (DELETE {table} WHERE parent_quiz = $quiz_id RETURN BEFORE);\n"
+            ));
+            questions.push(format!("array::len(${table})"));
+        }
+        counts.push(format!("questions: {}", questions.join(" + ")));
+        surreal_ql.push_str(&format!(
+            "LET $quiz = (DELETE $quiz_id RETURN BEFORE);
+            RETURN IF $quiz[0] IS NOT NONE THEN {{ quiz: $quiz[0], {} }} ELSE NONE END;
+            COMMIT TRANSACTION;",
+            counts.join(", ")
+        ));
+
+        let mut response: surrealdb::Response = self
+            .client
+            .query(surreal_ql)
+            .bind(("quiz_id", quiz_id))
+            .await?
+            .check()?;
+        // Only the RETURN has a result worth taking, it comes last
+        let last: usize = response.num_statements() - 1;
+        Ok(response.take(last)?)
+    }
+
+    async fn get_user_tags(&self, user_id: &str) -> Result<Vec<SurrealTag>, anyhow::Error> {
+        let qry = "SELECT * FROM tags WHERE author_id = $user_id";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("user_id", user_id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_tags(&self, targets: &[Thing]) -> Result<TagIndex, anyhow::Error> {
+        let qry = "SELECT in AS target, out.* AS tag FROM tagged WHERE in IN $targets";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("targets", targets)).await?;
+
+        let links: Vec<TagLink> = response.take(0)?;
+        Ok(TagIndex { links })
+    }
+
+    /// Counts likes on a set of quizzes or questions, and finds which of them the given user likes.
+    async fn get_likes(
+        &self,
+        targets: &[Thing],
+        user_id: &str,
+    ) -> Result<LikeTally, anyhow::Error> {
+        let qry = r#"SELECT out, count() AS likes FROM likes WHERE out IN $targets GROUP BY out;
+        SELECT VALUE out FROM likes WHERE in = $user AND out IN $targets;"#;
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("targets", targets))
+            .bind(("user", Thing::from(("general_user", user_id))))
+            .await?;
+
+        let counts: Vec<LikeCount> = response.take(0)?;
+        let mine: Vec<Thing> = response.take(1)?;
+        Ok(LikeTally { counts, mine })
+    }
+
+    /// Only relates when no like exists yet, in the same transaction as the check.
+    async fn set_like(
+        &self,
+        user_id: &str,
+        target: &Thing,
+        liked: bool,
+    ) -> Result<(), anyhow::Error> {
+        let qry = r#"BEGIN TRANSACTION;
+        IF $liked AND array::len(SELECT id FROM likes WHERE in = $user AND out = $target) = 0 {
+            RELATE $user->likes->$target SET liked_at = time::now();
+        };
+        IF !$liked {
+            DELETE likes WHERE in = $user AND out = $target;
+        };
+        COMMIT TRANSACTION;"#;
+        self.client
+            .query(qry)
+            .bind(("liked", liked))
+            .bind(("user", Thing::from(("general_user", user_id))))
+            .bind(("target", target))
+            .await?
+            .check()?;
+        Ok(())
+    }
+
+    async fn get_author_of(&self, target: &Thing) -> Result<Option<String>, anyhow::Error> {
+        let qry = "SELECT VALUE author_id FROM $target WHERE deleted_at IS NONE";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("target", target)).await?;
+        Ok(response.take(0)?)
+    }
+}
+
+impl Database {
+    /// helper function to save a question record, returned in the shape of its own type.
+    async fn create_question_as<T>(&self, table: &str, record: QuestionRecord) -> Result<Value>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let created: Vec<T> = self.client.create(table).content(record).await?;
+        let first: &T = created.first().context("No values returned")?;
+        serde_json::to_value(first).context("Failed to serialize question")
+    }
+
+    /// helper function to merge changes into a quiz or question, saving `revision` in the same transaction.
+    /// The revision is numbered inside the transaction, like `save_revision`, and only saved if the record exists.
+    async fn update_revised<T, C>(
+        &self,
+        target: &Thing,
+        changes: C,
+        revision: Option<Edit>,
+    ) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+        C: Serialize,
+    {
+        let mut surreal_ql: String = String::from(
+            "BEGIN TRANSACTION;
+            LET $updated = (UPDATE $target MERGE $changes RETURN AFTER);\n",
+        );
+        if revision.is_some() {
+            surreal_ql.push_str(
+                "IF $updated[0] IS NOT NONE {
+                LET $latest = (SELECT VALUE number FROM revisions
+                    WHERE target = $target ORDER BY number DESC LIMIT 1)[0] ?? 0;
+                CREATE revisions CONTENT {
+                    target: $target,
+                    number: $latest + 1,
+                    author_id: $author_id,
+                    created_at: time::now(),
+                    snapshot: $snapshot
+                };
+            };\n",
+            );
+        }
+        surreal_ql.push_str(
+            "RETURN $updated[0];
+            COMMIT TRANSACTION;",
+        );
+
+        let mut query = self
+            .client
+            .query(surreal_ql)
+            .bind(("target", target))
+            .bind(("changes", changes));
+        if let Some(edit) = revision {
+            query = query
+                .bind(("author_id", edit.author_id))
+                .bind(("snapshot", edit.snapshot));
+        }
+        let mut response: surrealdb::Response = query.await?.check()?;
+        // Only the RETURN has a result worth taking, it comes last
+        let last: usize = response.num_statements() - 1;
+        Ok(response.take(last)?)
+    }
+
+    /// helper function to merge changes into a question, returned in the shape of its own type.
+    async fn update_question_as<T, C>(
+        &self,
+        question_id: &Thing,
+        changes: C,
+        revision: Option<Edit>,
+    ) -> Result<Option<Value>>
+    where
+        T: DeserializeOwned + Serialize,
+        C: Serialize,
+    {
+        let updated: Option<T> = self.update_revised(question_id, changes, revision).await?;
+        updated
+            .map(|question| serde_json::to_value(question).context("Failed to serialize question"))
+            .transpose()
+    }
+
+    /// helper function to delete a question, returned in the shape of its own type.
+    async fn delete_question_as<T>(&self, question_id: &Thing) -> Result<Option<Value>>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let deleted: Option<T> = self.client.delete(question_id).await?;
+        deleted
+            .map(|question| serde_json::to_value(question).context("Failed to serialize question"))
+            .transpose()
+    }
+}
+
+impl QuestionRepo for Database {
+    /// Fetches every question of a quiz, one list per question type.
+    async fn get_quiz_questions(&self, quiz_id: &Thing) -> Result<AllQuestions, anyhow::Error> {
+        let qry = r#"SELECT * FROM questions_mc WHERE parent_quiz = $quiz_id AND deleted_at IS NONE;
+        SELECT * FROM questions_sa WHERE parent_quiz = $quiz_id AND deleted_at IS NONE;
+        SELECT * FROM questions_la WHERE parent_quiz = $quiz_id AND deleted_at IS NONE;
+        SELECT * FROM questions_tf WHERE parent_quiz = $quiz_id AND deleted_at IS NONE;
+        SELECT * FROM questions_ms WHERE parent_quiz = $quiz_id AND deleted_at IS NONE;"#;
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("quiz_id", quiz_id)).await?;
+
+        Ok(AllQuestions {
+            mc: response.take(0)?,
+            sa: response.take(1)?,
+            la: response.take(2)?,
+            tf: response.take(3)?,
+            ms: response.take(4)?,
+        })
+    }
+
+    /// Fetches questions by their ids, wherever they live.
+    async fn get_questions_by_ids(&self, ids: &[Thing]) -> Result<AllQuestions, anyhow::Error> {
+        let qry = r#"SELECT * FROM questions_mc WHERE id IN $ids;
+        SELECT * FROM questions_sa WHERE id IN $ids;
+        SELECT * FROM questions_la WHERE id IN $ids;
+        SELECT * FROM questions_tf WHERE id IN $ids;
+        SELECT * FROM questions_ms WHERE id IN $ids;"#;
+        let mut response: surrealdb::Response = self.client.query(qry).bind(("ids", ids)).await?;
+
+        Ok(AllQuestions {
+            mc: response.take(0)?,
+            sa: response.take(1)?,
+            la: response.take(2)?,
+            tf: response.take(3)?,
+            ms: response.take(4)?,
+        })
+    }
+
+    async fn get_question(
+        &self,
+        question_id: &Thing,
+    ) -> Result<Option<SurrealGenericQuestionData>, anyhow::Error> {
+        let qry = "SELECT * FROM $question_id WHERE deleted_at IS NONE";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("question_id", question_id))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn create_question(
+        &self,
+        question: JsonQuestion,
+        author_id: String,
+        quiz_id: Thing,
+    ) -> Result<Value, anyhow::Error> {
+        let table: &str = question.table_name();
+        let record: QuestionRecord = question.into_record(author_id, quiz_id);
+        match record {
+            QuestionRecord::MultipleChoice(_) => {
+                self.create_question_as::<SurrealQuestionMC>(table, record)
+                    .await
+            }
+            QuestionRecord::ShortAnswer(_) => {
+                self.create_question_as::<SurrealQuestionSA>(table, record)
+                    .await
+            }
+            QuestionRecord::LongAnswer(_) => {
+                self.create_question_as::<SurrealQuestionLA>(table, record)
+                    .await
+            }
+            QuestionRecord::TrueFalse(_) => {
+                self.create_question_as::<SurrealQuestionTF>(table, record)
+                    .await
+            }
+            QuestionRecord::MultiSelect(_) => {
+                self.create_question_as::<SurrealQuestionMS>(table, record)
+                    .await
+            }
+        }
+    }
+
+    /// IDs are made up front so the new questions can be fetched, and put back in order, once committed.
+    async fn create_questions(
+        &self,
+        questions: Vec<JsonQuestion>,
+        author_id: String,
+        quiz_id: Thing,
+    ) -> Result<Vec<Value>, anyhow::Error> {
+        let question_ids: Vec<Thing> = questions
+            .iter()
+            .map(|question| Thing::from((question.table_name(), Id::rand())))
+            .collect();
+        let mut surreal_ql: String = String::from("BEGIN TRANSACTION;\n");
+        for index in 0..questions.len() {
+            surreal_ql.push_str(&format!("CREATE $id_{index} CONTENT $question_{index};\n"));
+        }
+        surreal_ql.push_str("COMMIT TRANSACTION;");
+
+        let mut query = self.client.query(surreal_ql);
+        for (index, (question, question_id)) in questions.into_iter().zip(&question_ids).enumerate()
+        {
+            query = query.bind((format!("id_{index}"), question_id)).bind((
+                format!("question_{index}"),
+                question.into_record(author_id.clone(), quiz_id.clone()),
+            ));
+        }
+        query.await?.check()?;
+
+        let created: AllQuestions = self.get_questions_by_ids(&question_ids).await?;
+        let mut found: Vec<(Thing, Value)> = Vec::with_capacity(question_ids.len());
+        for qmc in created.mc.iter() {
+            found.push((qmc.id.clone(), serde_json::to_value(qmc)?));
+        }
+        for qsa in created.sa.iter() {
+            found.push((qsa.id.clone(), serde_json::to_value(qsa)?));
+        }
+        for qla in created.la.iter() {
+            found.push((qla.id.clone(), serde_json::to_value(qla)?));
+        }
+        for qtf in created.tf.iter() {
+            found.push((qtf.id.clone(), serde_json::to_value(qtf)?));
+        }
+        for qms in created.ms.iter() {
+            found.push((qms.id.clone(), serde_json::to_value(qms)?));
+        }
+        found.sort_by_key(|(id, _)| question_ids.iter().position(|qid| qid == id));
+        Ok(found.into_iter().map(|(_, question)| question).collect())
+    }
+
+    async fn update_question(
+        &self,
+        question_id: &Thing,
+        question: JsonQuestion,
+        revision: Option<Edit>,
+    ) -> Result<Option<Value>, anyhow::Error> {
+        match question {
+            JsonQuestion::MultipleChoice(what) => {
+                self.update_question_as::<SurrealQuestionMC, _>(question_id, what, revision)
+                    .await
+            }
+            JsonQuestion::ShortAnswer(what) => {
+                self.update_question_as::<SurrealQuestionSA, _>(question_id, what, revision)
+                    .await
+            }
+            JsonQuestion::LongAnswer(what) => {
+                self.update_question_as::<SurrealQuestionLA, _>(question_id, what, revision)
+                    .await
+            }
+            JsonQuestion::TrueFalse(what) => {
+                self.update_question_as::<SurrealQuestionTF, _>(question_id, what, revision)
+                    .await
+            }
+            JsonQuestion::MultiSelect(what) => {
+                self.update_question_as::<SurrealQuestionMS, _>(question_id, what, revision)
+                    .await
+            }
+        }
+    }
+
+    async fn delete_question(&self, question_id: &Thing) -> Result<Option<Value>, anyhow::Error> {
+        match question_id.tb.as_str() {
+            "questions_sa" => {
+                self.delete_question_as::<SurrealQuestionSA>(question_id)
+                    .await
+            }
+            "questions_la" => {
+                self.delete_question_as::<SurrealQuestionLA>(question_id)
+                    .await
+            }
+            "questions_tf" => {
+                self.delete_question_as::<SurrealQuestionTF>(question_id)
+                    .await
+            }
+            "questions_ms" => {
+                self.delete_question_as::<SurrealQuestionMS>(question_id)
+                    .await
+            }
+            _ => {
+                self.delete_question_as::<SurrealQuestionMC>(question_id)
+                    .await
+            }
+        }
+    }
+}
+
+/// Merged into a quiz or question to trash it, `None` removes the field again.
+#[derive(Debug, Serialize)]
+struct TrashMark {
+    deleted_at: Option<Datetime>,
+}
+
+impl TrashRepo for Database {
+    async fn trash_quiz(&self, quiz_id: &Thing) -> Result<Option<SurrealQuiz>, anyhow::Error> {
+        let qry = "UPDATE $quiz_id SET deleted_at = time::now() WHERE deleted_at IS NONE";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("quiz_id", quiz_id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn trash_question(&self, question_id: &Thing) -> Result<Option<Value>, anyhow::Error> {
+        let mark: TrashMark = TrashMark {
+            deleted_at: Some(Datetime::default()),
+        };
+        match question_id.tb.as_str() {
+            "questions_sa" => {
+                self.update_question_as::<SurrealQuestionSA, _>(question_id, mark, None)
+                    .await
+            }
+            "questions_la" => {
+                self.update_question_as::<SurrealQuestionLA, _>(question_id, mark, None)
+                    .await
+            }
+            "questions_tf" => {
+                self.update_question_as::<SurrealQuestionTF, _>(question_id, mark, None)
+                    .await
+            }
+            "questions_ms" => {
+                self.update_question_as::<SurrealQuestionMS, _>(question_id, mark, None)
+                    .await
+            }
+            _ => {
+                self.update_question_as::<SurrealQuestionMC, _>(question_id, mark, None)
+                    .await
+            }
+        }
+    }
+
+    async fn get_trash(&self, author_id: &str) -> Result<Vec<TrashItem>, anyhow::Error> {
+        let mut surreal_ql: String = String::from(
+            "SELECT id, name AS title, author_id, deleted_at FROM quizzes
+            WHERE author_id = $user_id AND deleted_at IS NOT NONE;\n",
+        );
+        for table in QUESTION_TABLES {
+            surreal_ql.push_str(&format!(
+                "SELECT id, question AS title, author_id, parent_quiz, deleted_at FROM {table}
+                WHERE author_id = $user_id AND deleted_at IS NOT NONE;\n"
+            ));
+        }
+        let mut response: surrealdb::Response = self
+            .client
+            .query(surreal_ql)
+            .bind(("user_id", author_id))
+            .await?;
+
+        let mut trash: Vec<TrashItem> = Vec::new();
+        for index in 0..response.num_statements() {
+            let items: Vec<TrashItem> = response.take(index)?;
+            trash.extend(items);
+        }
+        trash.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+        Ok(trash)
+    }
+
+    async fn get_trash_item(&self, id: &Thing) -> Result<Option<TrashItem>, anyhow::Error> {
+        // Quizzes have a name, questions have the question
+        let qry = "SELECT id, name ?? question AS title, author_id, parent_quiz, deleted_at
+            FROM $id WHERE deleted_at IS NOT NONE";
+        let mut response: surrealdb::Response = self.client.query(qry).bind(("id", id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn restore(&self, id: &Thing) -> Result<Option<TrashItem>, anyhow::Error> {
+        let Some(item) = self.get_trash_item(id).await? else {
+            return Ok(None);
+        };
+        self.client
+            .query("UPDATE $id SET deleted_at = NONE WHERE deleted_at IS NOT NONE")
+            .bind(("id", id))
+            .await?
+            .check()?;
+        Ok(Some(item))
+    }
+
+    /// Each quiz goes in its own transaction through `delete_quiz`,
+    /// then the questions trashed on their own go together with their likes, tags, review cards and revisions.
+    async fn purge_trash(&self, cutoff: DateTime<Utc>) -> Result<TrashPurge, anyhow::Error> {
+        let cutoff: Datetime = Datetime::from(cutoff);
+        let qry =
+            "SELECT VALUE id FROM quizzes WHERE deleted_at IS NOT NONE AND deleted_at < $cutoff";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("cutoff", &cutoff)).await?;
+        let quiz_ids: Vec<Thing> = response.take(0)?;
+
+        let mut purged: TrashPurge = TrashPurge::default();
+        for quiz_id in quiz_ids.iter() {
+            if self.delete_quiz(quiz_id).await?.is_some() {
+                purged.quizzes += 1;
+            }
+        }
+
+        // Links to the questions and their revisions go before the questions
+        let mut surreal_ql: String = String::from(
+            "BEGIN TRANSACTION;
+            DELETE likes WHERE out.deleted_at IS NOT NONE AND out.deleted_at < $cutoff;
+            DELETE tagged WHERE in.deleted_at IS NOT NONE AND in.deleted_at < $cutoff;
+            DELETE review_cards
+                WHERE question_id.deleted_at IS NOT NONE AND question_id.deleted_at < $cutoff;
+            DELETE revisions WHERE target.deleted_at IS NOT NONE AND target.deleted_at < $cutoff;\n",
+        );
+        for table in QUESTION_TABLES {
+            surreal_ql.push_str(&format!(
+                "DELETE {table} WHERE deleted_at IS NOT NONE AND deleted_at < $cutoff RETURN BEFORE;\n"
+            ));
+        }
+        surreal_ql.push_str("COMMIT TRANSACTION;");
+
+        let mut response: surrealdb::Response = self
+            .client
+            .query(surreal_ql)
+            .bind(("cutoff", &cutoff))
+            .await?
+            .check()?;
+        // The question tables are the last statements
+        let first: usize = response.num_statements() - QUESTION_TABLES.len();
+        for index in first..response.num_statements() {
+            let rows: Vec<Value> = response.take(index)?;
+            purged.questions += rows.len();
+        }
+        Ok(purged)
+    }
+}
+
+impl Database {
+    /// helper function to fetch only the latest revision of each target, targets without one are skipped.
+    async fn latest_revisions(&self, targets: &[&Thing]) -> Result<Vec<SurrealRevision>> {
+        let qry = "LET $latest = (SELECT target, math::max(number) AS number FROM revisions
+                WHERE target IN $targets GROUP BY target);
+            SELECT * FROM revisions
+                WHERE target IN $targets AND { target: target, number: number } INSIDE $latest;";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("targets", targets)).await?;
+        Ok(response.take(1)?)
+    }
+}
+
+impl RevisionRepo for Database {
+    async fn get_revisions(&self, target: &Thing) -> Result<Vec<SurrealRevision>, anyhow::Error> {
+        let qry = "SELECT * FROM revisions WHERE target = $target ORDER BY number DESC";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("target", target)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_revisions_by_ids(
+        &self,
+        ids: &[Thing],
+    ) -> Result<Vec<SurrealRevision>, anyhow::Error> {
+        let qry = "SELECT * FROM revisions WHERE id IN $ids";
+        let mut response: surrealdb::Response = self.client.query(qry).bind(("ids", ids)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_revision(
+        &self,
+        target: &Thing,
+        number: u32,
+    ) -> Result<Option<SurrealRevision>, anyhow::Error> {
+        let qry = "SELECT * FROM revisions WHERE target = $target AND number = $number";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("target", target))
+            .bind(("number", number))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    /// Numbered inside the transaction, the unique index turns away a clash with a concurrent save.
+    async fn save_revision(
+        &self,
+        target: &Thing,
+        author_id: &str,
+        snapshot: Snapshot,
+    ) -> Result<SurrealRevision, anyhow::Error> {
+        let qry = "BEGIN TRANSACTION;
+            LET $latest = (SELECT VALUE number FROM revisions
+                WHERE target = $target ORDER BY number DESC LIMIT 1)[0] ?? 0;
+            CREATE revisions CONTENT {
+                target: $target,
+                number: $latest + 1,
+                author_id: $author_id,
+                created_at: time::now(),
+                snapshot: $snapshot
+            };
+            COMMIT TRANSACTION;";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("target", target))
+            .bind(("author_id", author_id))
+            .bind(("snapshot", snapshot))
+            .await?
+            .check()?;
+
+        let last: usize = response.num_statements() - 1;
+        let created: Option<SurrealRevision> = response.take(last)?;
+        created.context("Unsure what happened in Database")
+    }
+
+    async fn pin_revision(
+        &self,
+        target: &Thing,
+        author_id: &str,
+        current: Snapshot,
+    ) -> Result<SurrealRevision, anyhow::Error> {
+        let qry = "SELECT * FROM revisions WHERE target = $target ORDER BY number DESC LIMIT 1";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("target", target)).await?;
+        let latest: Option<SurrealRevision> = response.take(0)?;
+        match latest {
+            Some(revision) => Ok(revision),
+            None => self.save_revision(target, author_id, current).await,
+        }
+    }
+
+    /// One query finds the latest revisions, and one more saves the first revision of any target without one.
+    /// A concurrent pin may save the same first revisions in between. Should the unique index turn the insert
+    /// away, what it saved is read back and only the rest are saved again, once.
+    async fn pin_revisions(&self, pins: Vec<Pin>) -> Result<Vec<SurrealRevision>, anyhow::Error> {
+        let targets: Vec<&Thing> = pins.iter().map(|pin| &pin.target).collect();
+        let mut revisions: Vec<SurrealRevision> = self.latest_revisions(&targets).await?;
+
+        let mut retried: bool = false;
+        loop {
+            let firsts: Vec<Revision> = pins
+                .iter()
+                .filter(|pin| {
+                    !revisions
+                        .iter()
+                        .any(|revision| revision.target == pin.target)
+                })
+                .map(|pin| Revision {
+                    target: pin.target.clone(),
+                    number: 1,
+                    author_id: pin.author_id.clone(),
+                    created_at: Datetime::default(),
+                    snapshot: pin.current.clone(),
+                })
+                .collect();
+            if firsts.is_empty() {
+                break;
+            }
+            let qry = "INSERT INTO revisions $firsts";
+            let inserted: surrealdb::Result<surrealdb::Response> = self
+                .client
+                .query(qry)
+                .bind(("firsts", firsts))
+                .await
+                .and_then(surrealdb::Response::check);
+            match inserted {
+                Ok(mut response) => {
+                    let saved: Vec<SurrealRevision> = response.take(0)?;
+                    revisions.extend(saved);
+                    break;
+                }
+                Err(_) if !retried => {
+                    retried = true;
+                    revisions = self.latest_revisions(&targets).await?;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        pins.iter()
+            .map(|pin| {
+                revisions
+                    .iter()
+                    .find(|revision| revision.target == pin.target)
+                    .cloned()
+                    .context("Unsure what happened in Database")
+            })
+            .collect()
+    }
+}
+
+impl AttemptRepo for Database {
+    async fn get_attempt(
+        &self,
+        attempt_id: &Thing,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        if attempt_id.tb != "attempts" {
+            return Ok(None);
+        }
+        let attempt: Option<SurrealAttempt> = self.client.select(attempt_id).await?;
+        Ok(attempt)
+    }
+
+    async fn create_attempt(&self, attempt: &Attempt) -> Result<SurrealAttempt, anyhow::Error> {
+        let created: Vec<SurrealAttempt> = self.client.create("attempts").content(attempt).await?;
+        created.into_iter().next().context("No values returned")
+    }
+
+    async fn submit_attempt(
+        &self,
+        attempt_id: &Thing,
+        attempt: &Attempt,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        let qry = "UPDATE $attempt CONTENT $graded WHERE submitted_at IS NONE RETURN AFTER";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("attempt", attempt_id))
+            .bind(("graded", attempt))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn reveal_hint(
+        &self,
+        attempt_id: &Thing,
+        question_id: &Thing,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        let qry = "UPDATE $attempt SET hints_used = array::union(hints_used, [$question])
+            WHERE submitted_at IS NONE RETURN AFTER";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("attempt", attempt_id))
+            .bind(("question", question_id))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn serve_question(
+        &self,
+        attempt_id: &Thing,
+        before: &[QuestionTiming],
+        timings: &[QuestionTiming],
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        // Each step serves or answers a question, so the counts tell whether another step got in first
+        let qry = "UPDATE $attempt SET timings = $timings
+            WHERE submitted_at IS NONE
+                AND array::len(timings) = $served
+                AND array::len(timings[WHERE answered_at IS NOT NONE]) = $answered
+            RETURN AFTER";
+        let answered: usize = before.iter().filter(|qt| qt.answered_at.is_some()).count();
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("attempt", attempt_id))
+            .bind(("timings", timings))
+            .bind(("served", before.len()))
+            .bind(("answered", answered))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn update_attempt(
+        &self,
+        attempt_id: &Thing,
+        attempt: &Attempt,
+    ) -> Result<Option<SurrealAttempt>, anyhow::Error> {
+        let updated: Option<SurrealAttempt> =
+            self.client.update(attempt_id).content(attempt).await?;
+        Ok(updated)
+    }
+
+    async fn get_submitted_attempts(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<SurrealAttempt>, anyhow::Error> {
+        let qry = "SELECT * FROM attempts WHERE user_id = $user_id AND submitted_at != NONE
+            ORDER BY submitted_at ASC";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("user_id", user_id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_quiz_attempts(
+        &self,
+        quiz_id: &Thing,
+        user_ids: &[String],
+    ) -> Result<Vec<SurrealAttempt>, anyhow::Error> {
+        let qry = "SELECT * FROM attempts WHERE quiz_id = $quiz_id AND user_id IN $user_ids";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("quiz_id", quiz_id))
+            .bind(("user_ids", user_ids))
+            .await?;
+        Ok(response.take(0)?)
+    }
+}
+
+impl StudyRepo for Database {
+    async fn get_review_cards(
+        &self,
+        user_id: &str,
+        quiz_id: &Thing,
+    ) -> Result<Vec<SurrealReviewCard>, anyhow::Error> {
+        let qry = "SELECT * FROM review_cards WHERE user_id = $user_id AND quiz_id = $quiz_id";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("user_id", user_id))
+            .bind(("quiz_id", quiz_id))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_review_card(
+        &self,
+        user_id: &str,
+        question_id: &Thing,
+    ) -> Result<Option<SurrealReviewCard>, anyhow::Error> {
+        let qry =
+            "SELECT * FROM review_cards WHERE user_id = $user_id AND question_id = $question_id";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("user_id", user_id))
+            .bind(("question_id", question_id))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn create_review_card(
+        &self,
+        card: &ReviewCard,
+    ) -> Result<SurrealReviewCard, anyhow::Error> {
+        let created: Vec<SurrealReviewCard> =
+            self.client.create("review_cards").content(card).await?;
+        created.into_iter().next().context("No values returned")
+    }
+
+    async fn update_review_card(
+        &self,
+        card_id: &Thing,
+        card: &ReviewCard,
+    ) -> Result<Option<SurrealReviewCard>, anyhow::Error> {
+        let updated: Option<SurrealReviewCard> = self.client.update(card_id).content(card).await?;
+        Ok(updated)
+    }
+}
+
+/// A tag with its use counts, as selected from the database.
+#[derive(Deserialize, Debug)]
+struct TagRow {
+    id: Thing,
+    name: String,
+    author_id: String,
+    quizzes: u64,
+    tagged: u64,
+}
+
+impl TagRepo for Database {
+    async fn get_tag(&self, tag_id: &Thing) -> Result<Option<SurrealTag>, anyhow::Error> {
+        if tag_id.tb != "tags" {
+            return Ok(None);
+        }
+        let tag: Option<SurrealTag> = self.client.select(tag_id).await?;
+        Ok(tag)
+    }
+
+    /// Anything tagged that is not a quiz is a question.
+    async fn get_tag_summaries(&self, user_id: &str) -> Result<Vec<TagSummary>, anyhow::Error> {
+        let qry = r#"SELECT *,
+                array::len(<-tagged<-quizzes) AS quizzes,
+                array::len(<-tagged) AS tagged
+            FROM tags WHERE author_id = $user_id ORDER BY name ASC"#;
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("user_id", user_id)).await?;
+        let rows: Vec<TagRow> = response.take(0)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TagSummary {
+                tag: SurrealTag {
+                    id: row.id,
+                    name: row.name,
+                    author_id: row.author_id,
+                },
+                quizzes: row.quizzes,
+                questions: row.tagged.saturating_sub(row.quizzes),
+            })
+            .collect())
+    }
+
+    async fn create_tag(&self, tag: &Tag) -> Result<SurrealTag, anyhow::Error> {
+        let created: Vec<SurrealTag> = self.client.create("tags").content(tag).await?;
+        created
+            .into_iter()
+            .next()
+            .context("Unsure what happened in Database")
+    }
+
+    async fn rename_tag(
+        &self,
+        tag_id: &Thing,
+        name: &str,
+    ) -> Result<Option<SurrealTag>, anyhow::Error> {
+        let updated: Option<SurrealTag> = self
+            .client
+            .update(tag_id)
+            .merge(TagJsonPkg {
+                name: name.to_string(),
+            })
+            .await?;
+        Ok(updated)
+    }
+
+    async fn delete_tag(&self, tag_id: &Thing) -> Result<(), anyhow::Error> {
+        let qry = r#"BEGIN TRANSACTION;
+        DELETE tagged WHERE out = $tag;
+        DELETE $tag;
+        COMMIT TRANSACTION;"#;
+        self.client
+            .query(qry)
+            .bind(("tag", tag_id))
+            .await?
+            .check()?;
+        Ok(())
+    }
+
+    /// Only relates when not tagged yet, in the same transaction as the check.
+    async fn set_tagged(
+        &self,
+        target: &Thing,
+        tag_id: &Thing,
+        tagged: bool,
+    ) -> Result<(), anyhow::Error> {
+        let qry = r#"BEGIN TRANSACTION;
+        IF $tagged AND array::len(SELECT id FROM tagged WHERE in = $target AND out = $tag) = 0 {
+            RELATE $target->tagged->$tag SET tagged_at = time::now();
+        };
+        IF !$tagged {
+            DELETE tagged WHERE in = $target AND out = $tag;
+        };
+        COMMIT TRANSACTION;"#;
+        self.client
+            .query(qry)
+            .bind(("tagged", tagged))
+            .bind(("target", target))
+            .bind(("tag", tag_id))
+            .await?
+            .check()?;
+        Ok(())
+    }
+
+    async fn get_pool_question_ids(
+        &self,
+        author_id: &str,
+        tag_id: &Thing,
+    ) -> Result<Vec<Thing>, anyhow::Error> {
+        let qry = "SELECT VALUE in FROM tagged WHERE out = $tag AND in.author_id = $author_id
+            AND in.deleted_at IS NONE AND in.parent_quiz.deleted_at IS NONE";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("tag", tag_id))
+            .bind(("author_id", author_id))
+            .await?;
+        let mut ids: Vec<Thing> = response.take(0)?;
+        // quizzes share tags with questions
+        ids.retain(|id| QUESTION_TABLES.contains(&id.tb.as_str()));
+        Ok(ids)
+    }
+}
+
+impl GroupRepo for Database {
+    async fn get_group(&self, group_id: &Thing) -> Result<Option<SurrealGroup>, anyhow::Error> {
+        if group_id.tb != "groups" {
+            return Ok(None);
+        }
+        let group: Option<SurrealGroup> = self.client.select(group_id).await?;
+        Ok(group)
+    }
+
+    async fn get_group_by_code(
+        &self,
+        join_code: &str,
+    ) -> Result<Option<SurrealGroup>, anyhow::Error> {
+        let qry = "SELECT * FROM groups WHERE join_code = $join_code";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("join_code", join_code))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_user_groups(&self, user_id: &str) -> Result<Vec<SurrealGroup>, anyhow::Error> {
+        let qry = r#"SELECT * FROM groups WHERE owner_id = $user_id
+        OR id IN (SELECT VALUE group_id FROM memberships WHERE user_id = $user_id)
+        ORDER BY name"#;
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("user_id", user_id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_group_members(&self, group_id: &Thing) -> Result<Vec<PartialUser>, anyhow::Error> {
+        let qry = r#"SELECT uuid, name, username, role FROM general_user
+        WHERE uuid IN (SELECT VALUE user_id FROM memberships WHERE group_id = $group_id)
+        ORDER BY username"#;
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("group_id", group_id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn is_member(&self, group_id: &Thing, user_id: &str) -> Result<bool, anyhow::Error> {
+        let qry =
+            "SELECT VALUE id FROM memberships WHERE group_id = $group_id AND user_id = $user_id";
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("group_id", group_id))
+            .bind(("user_id", user_id))
+            .await?;
+        let found: Vec<Thing> = response.take(0)?;
+        Ok(!found.is_empty())
+    }
+
+    async fn create_group(&self, group: &Group) -> Result<SurrealGroup, anyhow::Error> {
+        let created: Vec<SurrealGroup> = self.client.create("groups").content(group).await?;
+        created
+            .into_iter()
+            .next()
+            .context("Unsure what happened in Database")
+    }
+
+    async fn add_member(
+        &self,
+        membership: &Membership,
+    ) -> Result<SurrealMembership, anyhow::Error> {
+        let created: Vec<SurrealMembership> = self
+            .client
+            .create("memberships")
+            .content(membership)
+            .await?;
+        created
+            .into_iter()
+            .next()
+            .context("Unsure what happened in Database")
+    }
+
+    async fn get_assignment(
+        &self,
+        assignment_id: &Thing,
+    ) -> Result<Option<SurrealAssignment>, anyhow::Error> {
+        if assignment_id.tb != "assignments" {
+            return Ok(None);
+        }
+        let assignment: Option<SurrealAssignment> = self.client.select(assignment_id).await?;
+        Ok(assignment)
+    }
+
+    async fn create_assignment(
+        &self,
+        assignment: &Assignment,
+    ) -> Result<SurrealAssignment, anyhow::Error> {
+        let created: Vec<SurrealAssignment> = self
+            .client
+            .create("assignments")
+            .content(assignment)
+            .await?;
+        created
+            .into_iter()
+            .next()
+            .context("Unsure what happened in Database")
+    }
+
+    async fn get_group_assignments(
+        &self,
+        group_id: &Thing,
+    ) -> Result<Vec<SurrealAssignment>, anyhow::Error> {
+        let qry = "SELECT * FROM assignments WHERE group_id = $group_id ORDER BY closes_at";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("group_id", group_id)).await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_member_assignments(
+        &self,
+        user_id: &str,
+        quiz_id: &Thing,
+    ) -> Result<Vec<SurrealAssignment>, anyhow::Error> {
+        let qry = r#"SELECT * FROM assignments WHERE quiz_id = $quiz_id
+        AND group_id IN (SELECT VALUE group_id FROM memberships WHERE user_id = $user_id)"#;
+        let mut response: surrealdb::Response = self
+            .client
+            .query(qry)
+            .bind(("quiz_id", quiz_id))
+            .bind(("user_id", user_id))
+            .await?;
+        Ok(response.take(0)?)
+    }
+
+    async fn get_assigned_quizzes(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<AssignedQuiz>, anyhow::Error> {
+        let qry = r#"SELECT *, quiz_id.* AS quiz, group_id.name AS group_name
+        FROM assignments
+        WHERE group_id IN (SELECT VALUE group_id FROM memberships WHERE user_id = $user_id)
+        ORDER BY closes_at"#;
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("user_id", user_id)).await?;
+        Ok(response.take(0)?)
+    }
+}
+
+impl SessionRepo for Database {
+    async fn delete_user_sessions(&self, user_id: &str) -> Result<usize, anyhow::Error> {
+        // Session state is stored as JSON text holding the user ID
+        let qry = "DELETE sessions WHERE string::contains(token, $user_id) RETURN BEFORE";
+        let mut response: surrealdb::Response =
+            self.client.query(qry).bind(("user_id", user_id)).await?;
+        let deleted: Vec<SessionToken> = response.take(0)?;
+        Ok(deleted.len())
+    }
+
+    async fn purge_expired_sessions(&self) -> Result<usize, anyhow::Error> {
+        let qry = "DELETE sessions WHERE expiry < time::now() RETURN BEFORE";
+        let mut response: surrealdb::Response = self.client.query(qry).await?;
+        let purged: Vec<SessionToken> = response.take(0)?;
+        Ok(purged.len())
+    }
 }
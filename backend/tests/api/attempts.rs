@@ -0,0 +1,475 @@
+//! backend/tests/api/attempts.rs
+use crate::utils::{
+    spawn_app, CreateQuestions, CreateQuiz, EditQuiz, TagThings, TakeQuiz, TestApp,
+};
+use models::{
+    attempts::{
+        AnswerJsonPkg, AttemptAnswer, AttemptStart, AttemptSubmission, ExamQuestionKind,
+        QuizProgress, RevealedHint, SurrealAttempt,
+    },
+    questions::{JsonQuestion, JsonQuestionMC, JsonQuestionTF, QuestionJsonPkg},
+    quiz::SurrealQuiz,
+    tags::SurrealTag,
+};
+use reqwest::Response;
+
+/// Creates a quiz with one multiple choice and one true or false question.
+pub async fn create_test_quiz(test_app: &TestApp) -> SurrealQuiz {
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    let quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let questions: Vec<JsonQuestion> = vec![
+        JsonQuestion::MultipleChoice(JsonQuestionMC {
+            question: String::from("Which sort is O(n log(n)) in the worst case?"),
+            hint: None,
+            answer: String::from("Merge Sort"),
+            choices: vec![String::from("Bubble Sort"), String::from("Quick Sort")],
+        }),
+        JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Binary search needs a sorted list."),
+            hint: None,
+            answer: true,
+        }),
+    ];
+    for question in questions {
+        let package: QuestionJsonPkg = QuestionJsonPkg {
+            quiz_id: quiz.id.clone(),
+            question,
+        };
+        let response: Response = test_app.post_create_questions(&package).await;
+        assert!(response.status() == 201, "Failed to create Question");
+    }
+    quiz
+}
+
+#[tokio::test]
+async fn test_start_attempt_hides_answers_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+
+    // Act
+    let response: Response = test_app.start_attempt(quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 201);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(!body.to_string().contains("\"answer\""));
+    let started: AttemptStart = serde_json::from_value(body).unwrap();
+    assert_eq!(started.questions.len(), 2);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_submit_attempt_graded_on_server_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let response: Response = test_app.start_attempt(quiz.id.to_raw()).await;
+    let started: AttemptStart = response.json().await.unwrap();
+
+    // Right on the true or false, wrong on the multiple choice
+    let answers: Vec<AnswerJsonPkg> = started
+        .questions
+        .iter()
+        .map(|eq| AnswerJsonPkg {
+            question_id: eq.id.clone(),
+            answer: match &eq.kind {
+                ExamQuestionKind::TrueFalse => AttemptAnswer::TrueFalse(true),
+                _ => AttemptAnswer::Choice(String::from("Bubble Sort")),
+            },
+        })
+        .collect();
+    let submission: AttemptSubmission = AttemptSubmission { answers };
+
+    // Act
+    let response: Response = test_app
+        .submit_attempt(started.attempt_id.to_raw(), &submission)
+        .await;
+    let resubmit: Response = test_app
+        .submit_attempt(started.attempt_id.to_raw(), &submission)
+        .await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let graded: SurrealAttempt = response.json().await.unwrap();
+    assert_eq!(graded.score, 1.0);
+    assert_eq!(graded.max_score, 2.0);
+    assert!(graded.submitted_at.is_some());
+    assert!(resubmit.status() == 400, "Attempt was submitted twice");
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_concurrent_submits_grade_once_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let response: Response = test_app.start_attempt(quiz.id.to_raw()).await;
+    let started: AttemptStart = response.json().await.unwrap();
+    let submission: AttemptSubmission = AttemptSubmission {
+        answers: Vec::new(),
+    };
+
+    // Act
+    let (first, second) = tokio::join!(
+        test_app.submit_attempt(started.attempt_id.to_raw(), &submission),
+        test_app.submit_attempt(started.attempt_id.to_raw(), &submission),
+    );
+
+    // Assert
+    let mut statuses: Vec<u16> = vec![first.status().as_u16(), second.status().as_u16()];
+    statuses.sort();
+    assert_eq!(statuses, vec![200, 400], "Attempt was submitted twice");
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_attempt_history_summarises_per_quiz_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+
+    // Submit once with everything wrong, then once with everything right
+    for correct in [false, true] {
+        let response: Response = test_app.start_attempt(quiz.id.to_raw()).await;
+        let started: AttemptStart = response.json().await.unwrap();
+        let answers: Vec<AnswerJsonPkg> = started
+            .questions
+            .iter()
+            .map(|eq| AnswerJsonPkg {
+                question_id: eq.id.clone(),
+                answer: match (&eq.kind, correct) {
+                    (ExamQuestionKind::TrueFalse, _) => AttemptAnswer::TrueFalse(correct),
+                    (_, true) => AttemptAnswer::Choice(String::from("Merge Sort")),
+                    (_, false) => AttemptAnswer::Choice(String::from("Bubble Sort")),
+                },
+            })
+            .collect();
+        let response: Response = test_app
+            .submit_attempt(started.attempt_id.to_raw(), &AttemptSubmission { answers })
+            .await;
+        assert!(response.status() == 200, "Failed to submit Attempt");
+    }
+    // An unsubmitted attempt is left out of the history
+    let _ = test_app.start_attempt(quiz.id.to_raw()).await;
+
+    // Act
+    let response: Response = test_app.get_attempt_history().await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let progress: Vec<QuizProgress> = response.json().await.unwrap();
+    assert_eq!(progress.len(), 1);
+    assert_eq!(progress[0].quiz_name, "Algorithms");
+    assert_eq!(progress[0].attempts.len(), 2);
+    assert_eq!(progress[0].best, 100.0);
+    assert_eq!(progress[0].latest, 100.0);
+    assert_eq!(progress[0].average, 50.0);
+    assert!(progress[0]
+        .question_stats
+        .iter()
+        .all(|qs| qs.success_rate == 0.5));
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_late_submission_times_out_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    test_app.create_new_test_user().await;
+    test_app.log_in_test_user().await;
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let limits: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz",
+        "time_limit_secs": 120
+    });
+    let response: Response = test_app.edit_quiz(quiz.id.to_raw(), &limits).await;
+    assert!(response.status() == 200, "Failed to set time limits");
+    let started: AttemptStart = test_app
+        .start_attempt(quiz.id.to_raw())
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(started.time_allowed_secs, Some(120));
+    // Move the deadline past the grace period rather than waiting for it
+    test_app
+        .database
+        .client
+        .query("UPDATE $attempt SET deadline = time::now() - 1m")
+        .bind(("attempt", &started.attempt_id))
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+    let answers: Vec<AnswerJsonPkg> = started
+        .questions
+        .iter()
+        .map(|eq| AnswerJsonPkg {
+            question_id: eq.id.clone(),
+            answer: match &eq.kind {
+                ExamQuestionKind::TrueFalse => AttemptAnswer::TrueFalse(true),
+                _ => AttemptAnswer::Choice(String::from("Merge Sort")),
+            },
+        })
+        .collect();
+
+    // Act
+    let response: Response = test_app
+        .submit_attempt(started.attempt_id.to_raw(), &AttemptSubmission { answers })
+        .await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let graded: SurrealAttempt = response.json().await.unwrap();
+    assert!(graded.timed_out);
+    assert_eq!(graded.score, 0.0);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_pool_draws_tagged_questions_from_other_quizzes_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    test_app.create_new_test_user().await;
+    test_app.log_in_test_user().await;
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let started: AttemptStart = test_app
+        .start_attempt(quiz.id.to_raw())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let searching = started
+        .questions
+        .iter()
+        .find(|eq| eq.kind == ExamQuestionKind::TrueFalse)
+        .unwrap();
+    let tag: SurrealTag = test_app
+        .post_tag(&serde_json::json!({ "name": "Searching" }))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let response: Response = test_app
+        .put_tag_assign(&serde_json::json!({
+            "tag": tag.id,
+            "target": searching.id,
+            "tagged": true
+        }))
+        .await;
+    assert!(response.status() == 200, "Failed to tag question");
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Searching Drill",
+        "description": "Drawn from the searching pool",
+        "pools": [{ "tag": tag.id, "count": 3 }]
+    });
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(
+        response.status().is_success(),
+        "Failed to create pooled Quiz"
+    );
+    let pooled: SurrealQuiz = response.json().await.unwrap();
+
+    // Act
+    let response: Response = test_app.start_attempt(pooled.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 201);
+    let drawn: AttemptStart = response.json().await.unwrap();
+    // only one question has the tag, even though the pool asks for three
+    assert_eq!(drawn.questions.len(), 1);
+    assert_eq!(drawn.questions[0].id, searching.id);
+    let submission: AttemptSubmission = AttemptSubmission {
+        answers: vec![AnswerJsonPkg {
+            question_id: searching.id.clone(),
+            answer: AttemptAnswer::TrueFalse(true),
+        }],
+    };
+    let graded: SurrealAttempt = test_app
+        .submit_attempt(drawn.attempt_id.to_raw(), &submission)
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(graded.score, 1.0);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_revealed_hint_costs_penalty_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    test_app.create_new_test_user().await;
+    test_app.log_in_test_user().await;
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Searching",
+        "description": "Hints cost half a mark",
+        "hint_penalty": 0.5
+    });
+    let quiz: SurrealQuiz = test_app.post_create_quiz(&info).await.json().await.unwrap();
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Binary search needs a sorted list."),
+            hint: Some(String::from("Think halves")),
+            answer: true,
+        }),
+    };
+    let response: Response = test_app.post_create_questions(&package).await;
+    assert!(response.status() == 201, "Failed to create Question");
+    let started: AttemptStart = test_app
+        .start_attempt(quiz.id.to_raw())
+        .await
+        .json()
+        .await
+        .unwrap();
+    // hints are only handed out when revealed
+    assert!(started.questions[0].has_hint);
+    assert!(started.questions[0].hint.is_none());
+    let question_id = started.questions[0].id.clone();
+
+    // Act
+    let response: Response = test_app
+        .put_reveal_hint(
+            started.attempt_id.to_raw(),
+            &serde_json::json!({ "question_id": question_id }),
+        )
+        .await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let revealed: RevealedHint = response.json().await.unwrap();
+    assert_eq!(revealed.hint, "Think halves");
+    let submission: AttemptSubmission = AttemptSubmission {
+        answers: vec![AnswerJsonPkg {
+            question_id,
+            answer: AttemptAnswer::TrueFalse(true),
+        }],
+    };
+    let graded: SurrealAttempt = test_app
+        .submit_attempt(started.attempt_id.to_raw(), &submission)
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert!(graded.answers[0].hint_used);
+    assert_eq!(graded.score, 0.5);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_hint_after_submit_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    test_app.create_new_test_user().await;
+    test_app.log_in_test_user().await;
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Searching",
+        "description": "Hints cost half a mark",
+        "hint_penalty": 0.5
+    });
+    let quiz: SurrealQuiz = test_app.post_create_quiz(&info).await.json().await.unwrap();
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Binary search needs a sorted list."),
+            hint: Some(String::from("Think halves")),
+            answer: true,
+        }),
+    };
+    test_app.post_create_questions(&package).await;
+    let started: AttemptStart = test_app
+        .start_attempt(quiz.id.to_raw())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let hint: serde_json::Value = serde_json::json!({ "question_id": started.questions[0].id });
+    let submission: AttemptSubmission = AttemptSubmission {
+        answers: Vec::new(),
+    };
+
+    // Act
+    let first: Response = test_app
+        .put_reveal_hint(started.attempt_id.to_raw(), &hint)
+        .await;
+    let again: Response = test_app
+        .put_reveal_hint(started.attempt_id.to_raw(), &hint)
+        .await;
+    let graded: SurrealAttempt = test_app
+        .submit_attempt(started.attempt_id.to_raw(), &submission)
+        .await
+        .json()
+        .await
+        .unwrap();
+    let late: Response = test_app
+        .put_reveal_hint(started.attempt_id.to_raw(), &hint)
+        .await;
+
+    // Assert
+    assert!(first.status() == 200);
+    assert!(again.status() == 200);
+    assert_eq!(graded.hints_used.len(), 1);
+    assert!(late.status() == 400, "Hint revealed after submitting");
+    test_app.cleanup_db().await;
+}
@@ -0,0 +1,95 @@
+//! backend/tests/api/browse_quizzes.rs
+use crate::utils::{spawn_app, BrowseQuizzes, CreateQuiz, TakeQuiz, TestApp};
+use models::quiz::{PublicQuizPage, SurrealQuiz};
+use reqwest::Response;
+
+/// Creates a public and a private quiz as the test user, then logs in as someone else.
+pub async fn share_quizzes(test_app: &TestApp) -> (SurrealQuiz, SurrealQuiz) {
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+
+    let public: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz",
+        "visibility": "Public"
+    });
+    let response: Response = test_app.post_create_quiz(&public).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    let public_quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let private: serde_json::Value = serde_json::json!({
+        "name": "Answers to Algorithms",
+        "description": "Notes to self"
+    });
+    let response: Response = test_app.post_create_quiz(&private).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    let private_quiz: SurrealQuiz = response.json().await.unwrap();
+
+    test_app_response = test_app.log_in_other_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log other user in"
+    );
+    (public_quiz, private_quiz)
+}
+
+#[tokio::test]
+async fn test_browse_lists_only_public_quizzes_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, _) = share_quizzes(&test_app).await;
+
+    // Act
+    let response: Response = test_app.get_public_quizzes("search=ALGO&page=1").await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let page: PublicQuizPage = response.json().await.unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(page.quizzes.len(), 1);
+    assert_eq!(page.quizzes[0].record.id, public_quiz.id);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_browse_page_zero_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let _ = share_quizzes(&test_app).await;
+
+    // Act
+    let response: Response = test_app.get_public_quizzes("page=0").await;
+
+    // Assert
+    assert!(response.status() == 400);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_private_quiz_hidden_from_others_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, private_quiz) = share_quizzes(&test_app).await;
+
+    // Act
+    let shared_public: Response = test_app.get_shared_quiz(public_quiz.id.to_raw()).await;
+    let shared_private: Response = test_app.get_shared_quiz(private_quiz.id.to_raw()).await;
+    let take_private: Response = test_app.start_attempt(private_quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(shared_public.status() == 200);
+    assert!(shared_private.status() == 403);
+    assert!(take_private.status() == 403);
+    test_app.cleanup_db().await;
+}
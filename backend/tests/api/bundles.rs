@@ -0,0 +1,149 @@
+//! backend/tests/api/bundles.rs
+use crate::attempts::create_test_quiz;
+use crate::browse_quizzes::share_quizzes;
+use crate::utils::{spawn_app, BundleQuizzes, GetQuiz, TestApp};
+use models::bundle::{ImportReport, QuizBundle};
+use models::quiz::SurrealQuiz;
+use reqwest::Response;
+
+/// Creates and logs in the test user.
+async fn log_in(test_app: &TestApp) {
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+}
+
+/// A bundle whose second question has no accepted answers.
+fn invalid_bundle() -> String {
+    serde_json::json!({
+        "version": 1,
+        "quiz": { "name": "Graphs", "description": "Paths and trees" },
+        "questions": [
+            { "TrueFalse": { "question": "Trees have no cycles", "hint": null, "answer": true } },
+            { "ShortAnswer": { "question": "Name a shortest path algorithm", "hint": null, "answers": [] } }
+        ]
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_export_then_import_yaml_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    log_in(&test_app).await;
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+
+    // Act
+    let exported: Response = test_app.get_export(quiz.id.to_raw(), "yaml").await;
+    assert!(exported.status() == 200);
+    let yaml: String = exported.text().await.unwrap();
+    let imported: Response = test_app.post_import(yaml, "yaml", false).await;
+
+    // Assert
+    assert!(imported.status() == 201);
+    let report: ImportReport = imported.json().await.unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.items.len(), 2);
+    let new_quiz: SurrealQuiz = report.quiz.expect("Imported quiz missing");
+    assert_ne!(new_quiz.id, quiz.id);
+    assert_eq!(new_quiz.name, quiz.name);
+
+    let response: Response = test_app.get_export(new_quiz.id.to_raw(), "json").await;
+    let bundle: QuizBundle = response.json().await.unwrap();
+    assert_eq!(bundle.questions.len(), 2);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_dry_run_reports_items_without_saving_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    log_in(&test_app).await;
+
+    // Act
+    let response: Response = test_app.post_import(invalid_bundle(), "json", true).await;
+    let quizzes: Response = test_app.get_quizzes().await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let report: ImportReport = response.json().await.unwrap();
+    assert!(report.dry_run);
+    assert!(report.quiz.is_none());
+    assert!(report.items[0].error.is_none());
+    assert!(report.items[1].error.is_some());
+
+    let quizzes: Vec<serde_json::Value> = quizzes.json().await.unwrap();
+    assert!(quizzes.is_empty());
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_import_with_invalid_items_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    log_in(&test_app).await;
+
+    // Act
+    let response: Response = test_app.post_import(invalid_bundle(), "json", false).await;
+    let quizzes: Response = test_app.get_quizzes().await;
+
+    // Assert
+    assert!(response.status() == 400);
+    let report: ImportReport = response.json().await.unwrap();
+    assert_eq!(
+        report.items.iter().filter(|it| it.error.is_some()).count(),
+        1
+    );
+
+    let quizzes: Vec<serde_json::Value> = quizzes.json().await.unwrap();
+    assert!(quizzes.is_empty());
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_export_other_users_quiz_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, _) = share_quizzes(&test_app).await;
+
+    // Act
+    let response: Response = test_app.get_export(public_quiz.id.to_raw(), "json").await;
+
+    // Assert
+    assert!(response.status() == 403);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_import_gift_reports_unmapped_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    log_in(&test_app).await;
+    let gift: String = String::from(
+        "$CATEGORY: Sorting\n\nMerge sort is stable {T}\n\nPair them {=a -> b =c -> d}\n",
+    );
+
+    // Act
+    let response: Response = test_app.post_import(gift, "gift", true).await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let report: ImportReport = response.json().await.unwrap();
+    assert_eq!(report.items.len(), 1);
+    assert!(report.items[0].error.is_none());
+    assert_eq!(report.unmapped.len(), 1);
+    assert!(report.unmapped[0].skipped);
+    test_app.cleanup_db().await;
+}
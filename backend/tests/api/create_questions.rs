@@ -1,7 +1,10 @@
 //! backend/tests/api/create_questions.rs
 use crate::utils::{spawn_app, CreateQuestions, CreateQuiz, TestApp};
 use models::{
-    questions::{JsonQuestion, JsonQuestionMC, QuestionJsonPkg},
+    questions::{
+        JsonQuestion, JsonQuestionLA, JsonQuestionMC, JsonQuestionMS, JsonQuestionSA,
+        QuestionJsonPkg, RubricCriterion, SurrealQuestionLA, SurrealQuestionMS, SurrealQuestionSA,
+    },
     quiz::SurrealQuiz,
 };
 use reqwest::Response;
@@ -116,3 +119,166 @@ async fn test_create_questions_400() {
     // Clean up
     test_app.cleanup_db().await;
 }
+
+#[tokio::test]
+async fn test_create_short_answer_question_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    // Create User for testing
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+
+    // Quiz Structure
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    let quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let q1 = JsonQuestion::ShortAnswer(JsonQuestionSA {
+        question: String::from("Which sorting algorithm always splits the list in half?"),
+        hint: None,
+        answers: vec![String::from("Merge Sort"), String::from("Mergesort")],
+    });
+
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: q1,
+    };
+
+    //Act
+    let question_response: Response = test_app.post_create_questions(&package).await;
+
+    // Assert
+    assert!(question_response.status() == 201);
+    let created: SurrealQuestionSA = question_response.json().await.unwrap();
+    assert_eq!(created.id.tb, "questions_sa");
+    assert!(created.is_correct("  merge   SORT"));
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_create_long_answer_question_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    // Create User for testing
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+
+    // Quiz Structure
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    let quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let q1 = JsonQuestion::LongAnswer(JsonQuestionLA {
+        question: String::from("Explain why merge sort is O(n log(n))."),
+        hint: None,
+        model_answer: String::from(
+            "The list is halved log(n) times and each level does O(n) work merging.",
+        ),
+        rubric: vec![
+            RubricCriterion {
+                criterion: String::from("Mentions halving the list"),
+                weight: 1,
+            },
+            RubricCriterion {
+                criterion: String::from("Mentions linear work per level"),
+                weight: 2,
+            },
+        ],
+    });
+
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: q1,
+    };
+
+    //Act
+    let question_response: Response = test_app.post_create_questions(&package).await;
+
+    // Assert
+    assert!(question_response.status() == 201);
+    let created: SurrealQuestionLA = question_response.json().await.unwrap();
+    assert_eq!(created.id.tb, "questions_la");
+    assert_eq!(created.rubric.len(), 2);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_create_multi_select_question_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    // Create User for testing
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+
+    // Quiz Structure
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Languages",
+        "description": "A programming languages quiz"
+    });
+
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    let quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let q1 = JsonQuestion::MultiSelect(JsonQuestionMS {
+        question: String::from("Which languages are compiled ahead of time?"),
+        hint: None,
+        answers: vec![String::from("Rust"), String::from("C")],
+        choices: vec![String::from("Python")],
+    });
+
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: q1,
+    };
+
+    //Act
+    let question_response: Response = test_app.post_create_questions(&package).await;
+
+    // Assert
+    assert!(question_response.status() == 201);
+    let created: SurrealQuestionMS = question_response.json().await.unwrap();
+    assert_eq!(created.id.tb, "questions_ms");
+    assert_eq!(created.answers.len(), 2);
+    test_app.cleanup_db().await;
+}
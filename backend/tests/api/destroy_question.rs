@@ -1,7 +1,7 @@
 //! backend/tests/api/destroy_quiz.rs
-use crate::utils::{spawn_app, CreateQuestions, CreateQuiz, DestroyQuestion, TestApp};
+use crate::utils::{spawn_app, CreateQuestions, CreateQuiz, DestroyQuestion, GetQuestion, TestApp};
 use models::{
-    questions::{JsonQuestion, JsonQuestionMC, QuestionJsonPkg, SurrealQuestionMC},
+    questions::{AllQuestions, JsonQuestion, JsonQuestionMC, QuestionJsonPkg, SurrealQuestionMC},
     quiz::SurrealQuiz,
 };
 use reqwest::Response;
@@ -67,13 +67,22 @@ async fn test_user_delete_quest_200() {
     // Make sure the quiz is OK
     let actual: Vec<SurrealQuiz> = test_app.database.client.select("quizzes").await.unwrap();
     assert!(0 < actual.len());
+    // Trashed, so kept but no longer listed
     let actual_quest: Vec<SurrealQuestionMC> = test_app
         .database
         .client
         .select("questions_mc")
         .await
         .unwrap();
-    assert!(1 > actual_quest.len());
+    assert!(1 == actual_quest.len());
+    let quiz_param: String = urlencoding::encode(&quiz.id.to_raw()).to_string();
+    let listed: AllQuestions = test_app
+        .get_questions(quiz_param)
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert!(listed.mc.is_empty());
 
     // clean up database
     test_app.cleanup_db().await;
@@ -1,8 +1,16 @@
 //! backend/tests/api/destroy_quiz.rs
-use crate::utils::{spawn_app, CreateQuestions, CreateQuiz, DestroyQuiz, TestApp};
+use crate::attempts::create_test_quiz;
+use crate::utils::{
+    spawn_app, CreateQuestions, CreateQuiz, DestroyQuiz, GetQuestion, GetQuiz, LikeThings,
+    TagThings, TakeQuiz, TestApp, UseTrash,
+};
+use backend::repository::{QuizRepo, TrashRepo};
 use models::{
-    questions::{JsonQuestion, JsonQuestionMC, QuestionJsonPkg, SurrealQuestionMC},
-    quiz::SurrealQuiz,
+    likes::LikeJsonPkg,
+    questions::{AllQuestions, JsonQuestion, JsonQuestionMC, QuestionJsonPkg, SurrealQuestionMC},
+    quiz::{QuizDeletion, SurrealQuiz},
+    tags::SurrealTag,
+    trash::{TrashItem, TrashPurge},
 };
 use reqwest::Response;
 use serde_json::Value;
@@ -62,6 +70,21 @@ async fn test_user_delete_quiz_200() {
     assert!(test_res.status().as_u16() == 200);
 
     // Assert
+    let trashed: SurrealQuiz = test_res.json().await.unwrap();
+    assert_eq!(trashed.id, quiz.id);
+    let listed: Vec<Value> = test_app.get_quizzes().await.json().await.unwrap();
+    assert!(listed.is_empty());
+    let trash: Vec<TrashItem> = test_app.get_trash().await.json().await.unwrap();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].id, quiz.id);
+
+    // Purged for good, along with its questions
+    let purged: TrashPurge = test_app
+        .database
+        .purge_trash(chrono::Utc::now() + chrono::Duration::seconds(1))
+        .await
+        .unwrap();
+    assert_eq!(purged.quizzes, 1);
     let actual: Vec<SurrealQuiz> = test_app.database.client.select("quizzes").await.unwrap();
     assert!(1 > actual.len());
     let actual_quest: Vec<SurrealQuestionMC> = test_app
@@ -76,6 +99,68 @@ async fn test_user_delete_quiz_200() {
     test_app.cleanup_db().await;
 }
 
+/// Each kind of record pointing at a deleted quiz is counted under its own name.
+#[tokio::test]
+async fn test_delete_quiz_counts_each_link_200() {
+    // -- Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let test_app_response = test_app.create_new_test_user().await;
+    assert!(test_app_response.status().is_success());
+    let test_app_response = test_app.log_in_test_user().await;
+    assert!(test_app_response.status().is_success());
+
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let questions: AllQuestions = test_app
+        .get_questions(quiz.id.to_raw())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let tag: SurrealTag = test_app
+        .post_tag(&serde_json::json!({ "name": "Exam" }))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let response: Response = test_app
+        .put_tag_assign(&serde_json::json!({
+            "tag": tag.id,
+            "target": questions.tf[0].id,
+            "tagged": true
+        }))
+        .await;
+    assert!(response.status() == 200, "Failed to tag question");
+    for target in [&quiz.id, &questions.mc[0].id, &questions.tf[0].id] {
+        let like: LikeJsonPkg = LikeJsonPkg {
+            target: target.clone(),
+            liked: true,
+        };
+        let response: Response = test_app.put_like(&like).await;
+        assert!(response.status() == 200, "Failed to like");
+    }
+    let response: Response = test_app.start_attempt(quiz.id.to_raw()).await;
+    assert!(response.status() == 201, "Failed to start attempt");
+
+    // Act
+    let deleted: Option<QuizDeletion> = test_app.database.delete_quiz(&quiz.id).await.unwrap();
+    let missing: Option<QuizDeletion> = test_app.database.delete_quiz(&quiz.id).await.unwrap();
+
+    // Assert
+    let deleted: QuizDeletion = deleted.expect("Quiz was not deleted");
+    assert_eq!(deleted.quiz.id, quiz.id);
+    assert_eq!(deleted.questions, 2);
+    assert_eq!(deleted.likes, 3);
+    assert_eq!(deleted.tag_links, 1);
+    assert_eq!(deleted.attempts, 1);
+    assert_eq!(deleted.review_cards, 0);
+    assert_eq!(deleted.assignments, 0);
+    // pinned when the attempt started, one for the quiz and each question
+    assert_eq!(deleted.revisions, 3);
+    assert!(missing.is_none());
+    test_app.cleanup_db().await;
+}
+
 /// Test an anonymous user cannot delete quizzes.
 #[tokio::test]
 async fn test_anon_user_delete_quiz_401() {
@@ -0,0 +1,69 @@
+//! backend/tests/api/embedded.rs
+//! Runs against SurrealDB embedded in a temporary folder, so these need an `embedded-*` feature
+//! but no SurrealDB server.
+use crate::utils::{spawn_embedded_app, MemoryTestApp};
+use backend::{configuration::DatabaseEngine, surrealdb_repo::Database};
+use models::{quiz::SurrealQuiz, search::SearchResults};
+use reqwest::Response;
+use std::path::PathBuf;
+
+/// Creates a quiz in a fresh database of `engine`, then finds it again by listing and by searching.
+async fn quiz_is_saved_and_found(engine: DatabaseEngine) {
+    // Arrange
+    let path: PathBuf = std::env::temp_dir().join(format!("quiz_app_{}", uuid::Uuid::new_v4()));
+    let test_app: MemoryTestApp<Database> = spawn_embedded_app(engine, &path).await;
+    let response: Response = test_app.log_in_test_user().await;
+    assert!(response.status().is_success(), "Failed to log user in");
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+
+    // Act
+    let created: Response = test_app
+        .api_client
+        .post(format!("{}/v01/quiz-nexus", &test_app.address))
+        .json(&info)
+        .send()
+        .await
+        .expect("Failed to execute POST Request");
+    let listed: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/quiz-nexus", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let results: SearchResults = test_app
+        .api_client
+        .get(format!("{}/v01/search?q=algorithms", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(created.status().as_u16(), 200);
+    let quiz: SurrealQuiz = created.json().await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0]["name"], "Algorithms");
+    assert!(results.hits.iter().any(|hit| hit.id == quiz.id));
+    assert!(path.is_dir(), "Nothing was stored in the folder");
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[cfg(feature = "embedded-surrealkv")]
+#[tokio::test]
+async fn test_embedded_surrealkv_saves_quizzes_200() {
+    quiz_is_saved_and_found(DatabaseEngine::SurrealKv).await;
+}
+
+#[cfg(feature = "embedded-rocksdb")]
+#[tokio::test]
+async fn test_embedded_rocksdb_saves_quizzes_200() {
+    quiz_is_saved_and_found(DatabaseEngine::RocksDb).await;
+}
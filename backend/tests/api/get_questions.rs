@@ -1,7 +1,7 @@
 //! backend/tests/api/create_questions.rs
 use crate::utils::{spawn_app, CreateQuestions, CreateQuiz, GetQuestion, TestApp};
 use models::{
-    questions::{AllQuestions, JsonQuestion, JsonQuestionMC, QuestionJsonPkg},
+    questions::{AllQuestions, JsonQuestion, JsonQuestionMC, JsonQuestionSA, QuestionJsonPkg},
     quiz::SurrealQuiz,
 };
 use reqwest::Response;
@@ -98,6 +98,19 @@ async fn test_get_questions_200() {
     };
     let question_response3: Response = test_app.post_create_questions(&package3).await;
     assert!(question_response3.status() == 201);
+
+    let q4 = JsonQuestion::ShortAnswer(JsonQuestionSA {
+        question: String::from("What is the time complexity of binary search?"),
+        hint: None,
+        answers: vec![String::from("O(log(n))"), String::from("O(log n)")],
+    });
+
+    let package4: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: q4,
+    };
+    let question_response4: Response = test_app.post_create_questions(&package4).await;
+    assert!(question_response4.status() == 201);
     let query_param: String = urlencoding::encode(&quiz.id.to_raw()).to_string();
 
     //Act
@@ -108,6 +121,7 @@ async fn test_get_questions_200() {
     assert!(res.status() == 200);
     let everything: AllQuestions = res.json().await.unwrap();
     assert!(everything.mc.len() == 3);
+    assert!(everything.sa.len() == 1);
 
     // Clean UP
     test_app.cleanup_db().await;
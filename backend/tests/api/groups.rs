@@ -0,0 +1,193 @@
+//! backend/tests/api/groups.rs
+use crate::browse_quizzes::share_quizzes;
+use crate::utils::{spawn_app, ManageGroups, TakeQuiz, TestApp};
+use chrono::{Duration, Utc};
+use models::{
+    attempts::{AttemptStart, SurrealAttempt},
+    groups::{AssignmentProgress, GroupSummary, SurrealAssignment, SurrealGroup},
+    quiz::SurrealQuiz,
+};
+use reqwest::Response;
+use serde_json::Value;
+
+/// Has testuser123 make a group, add otheruser123 and assign them a quiz,
+/// open from `opens_in` to `closes_in` hours from now. Leaves otheruser123 logged in.
+async fn assign_to_other_user(
+    test_app: &TestApp,
+    quiz: &SurrealQuiz,
+    opens_in: i64,
+    closes_in: i64,
+) -> SurrealAssignment {
+    test_app.log_in_test_user().await;
+    let response: Response = test_app.post_create_group("Period 1").await;
+    assert!(response.status() == 201, "Failed to create group");
+    let group: SurrealGroup = response.json().await.unwrap();
+
+    let response: Response = test_app
+        .put_invite_member(group.id.to_raw(), "otheruser123")
+        .await;
+    assert!(response.status() == 201, "Failed to invite member");
+
+    let now = Utc::now();
+    let assignment: Value = serde_json::json!({
+        "quiz_id": quiz.id,
+        "opens_at": now + Duration::hours(opens_in),
+        "closes_at": now + Duration::hours(closes_in)
+    });
+    let response: Response = test_app
+        .post_assign_quiz(group.id.to_raw(), &assignment)
+        .await;
+    assert!(response.status() == 201, "Failed to assign quiz");
+    let assignment: SurrealAssignment = response.json().await.unwrap();
+
+    test_app.log_in_other_user().await;
+    assignment
+}
+
+#[tokio::test]
+async fn test_join_group_by_code_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    test_app.create_new_test_user().await;
+    test_app.log_in_test_user().await;
+    let response: Response = test_app.post_create_group("Period 2").await;
+    let group: SurrealGroup = response.json().await.unwrap();
+    test_app.log_in_other_user().await;
+
+    // Act
+    let response: Response = test_app.post_join_group(&group.join_code).await;
+
+    // Assert
+    assert!(response.status() == 201);
+    let groups: Vec<GroupSummary> = test_app.get_my_groups().await.json().await.unwrap();
+    assert_eq!(groups.len(), 1);
+    assert!(!groups[0].owned);
+    // Members do not get the code to pass around
+    assert!(groups[0].group.join_code.is_empty());
+    let response: Response = test_app.post_join_group(&group.join_code).await;
+    assert!(response.status() == 400);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_assigned_private_quiz_completed_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (_, private_quiz) = share_quizzes(&test_app).await;
+    let assignment: SurrealAssignment = assign_to_other_user(&test_app, &private_quiz, -1, 1).await;
+
+    // Act
+    let response: Response = test_app.start_attempt(private_quiz.id.to_raw()).await;
+    assert!(response.status() == 201, "Assigned member could not start");
+    let started: AttemptStart = response.json().await.unwrap();
+    let response: Response = test_app
+        .submit_attempt(
+            started.attempt_id.to_raw(),
+            &serde_json::json!({ "answers": [] }),
+        )
+        .await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let _: SurrealAttempt = response.json().await.unwrap();
+    let response: Response = test_app
+        .get_assignment_progress(assignment.id.to_raw())
+        .await;
+    // Only the group owner sees progress
+    assert!(response.status() == 403);
+    test_app.log_in_test_user().await;
+    let progress: AssignmentProgress = test_app
+        .get_assignment_progress(assignment.id.to_raw())
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(progress.members.len(), 1);
+    assert!(progress.members[0].completed);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_submit_after_window_closes_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (_, private_quiz) = share_quizzes(&test_app).await;
+    let assignment: SurrealAssignment = assign_to_other_user(&test_app, &private_quiz, -2, 1).await;
+    let started: AttemptStart = test_app
+        .start_attempt(private_quiz.id.to_raw())
+        .await
+        .json()
+        .await
+        .unwrap();
+    // The window closes while the attempt is being taken
+    test_app
+        .database
+        .client
+        .query("UPDATE $assignment SET closes_at = time::now() - 1h")
+        .bind(("assignment", &assignment.id))
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    // Act
+    let response: Response = test_app
+        .submit_attempt(
+            started.attempt_id.to_raw(),
+            &serde_json::json!({ "answers": [] }),
+        )
+        .await;
+
+    // Assert
+    assert!(response.status() == 403);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_public_quiz_submits_after_window_closes_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, _) = share_quizzes(&test_app).await;
+    assign_to_other_user(&test_app, &public_quiz, -2, -1).await;
+
+    // Act
+    // Anyone may take a public quiz, so a closed assignment turns nobody away
+    let response: Response = test_app.start_attempt(public_quiz.id.to_raw()).await;
+    assert!(response.status() == 201, "Public quiz could not start");
+    let started: AttemptStart = response.json().await.unwrap();
+    let response: Response = test_app
+        .submit_attempt(
+            started.attempt_id.to_raw(),
+            &serde_json::json!({ "answers": [] }),
+        )
+        .await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let attempt: SurrealAttempt = response.json().await.unwrap();
+    assert!(!attempt.assigned);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_private_quiz_before_window_opens_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (_, private_quiz) = share_quizzes(&test_app).await;
+    assign_to_other_user(&test_app, &private_quiz, 1, 2).await;
+
+    // Act
+    let response: Response = test_app.start_attempt(private_quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 403);
+    let assigned: Vec<Value> = test_app.get_my_assignments().await.json().await.unwrap();
+    assert_eq!(assigned.len(), 1);
+    assert_eq!(assigned[0]["quiz"]["name"], "Answers to Algorithms");
+    test_app.cleanup_db().await;
+}
@@ -0,0 +1,949 @@
+//! backend/tests/api/in_memory.rs
+//! Runs against the in-memory backend, so these pass without a SurrealDB instance.
+use crate::utils::{set_role, spawn_memory_app, MemoryTestApp};
+use chrono::{Duration, Utc};
+use models::{
+    attempts::{
+        AnswerJsonPkg, AttemptAnswer, AttemptStart, AttemptSubmission, ExamQuestion,
+        ExamQuestionKind, NextQuestionJsonPkg, ServedQuestion, SurrealAttempt,
+    },
+    bundle::ImportReport,
+    groups::SurrealGroup,
+    likes::{LikeJsonPkg, LikeStatus},
+    questions::{
+        AllQuestions, EditQuestionJsonPkg, JsonQuestion, JsonQuestionMC, JsonQuestionTF,
+        QuestionJsonPkg, SurrealQuestionMC, SurrealQuestionTF,
+    },
+    quiz::SurrealQuiz,
+    revisions::{RevisionDiff, SurrealRevision},
+    roles::Role,
+    search::{SearchKind, SearchResults},
+    study::{ReviewJsonPkg, ReviewOutcome, ReviewRating},
+    tags::{SurrealTag, TagSummary, Tagged},
+    trash::TrashItem,
+    PartialUser,
+};
+use reqwest::{Client, Response};
+
+/// Logs the test user in and creates a quiz with one multiple choice and one true or false question.
+async fn create_memory_quiz(test_app: &MemoryTestApp) -> SurrealQuiz {
+    let response: Response = test_app.log_in_test_user().await;
+    assert!(response.status().is_success(), "Failed to log user in");
+
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let quiz: SurrealQuiz = test_app
+        .api_client
+        .post(format!("{}/v01/quiz-nexus", &test_app.address))
+        .json(&info)
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+
+    let questions: Vec<JsonQuestion> = vec![
+        JsonQuestion::MultipleChoice(JsonQuestionMC {
+            question: String::from("Which sort is O(n log(n)) in the worst case?"),
+            hint: None,
+            answer: String::from("Merge Sort"),
+            choices: vec![String::from("Bubble Sort"), String::from("Quick Sort")],
+        }),
+        JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Binary search needs a sorted list."),
+            hint: None,
+            answer: true,
+        }),
+    ];
+    for question in questions {
+        let package: QuestionJsonPkg = QuestionJsonPkg {
+            quiz_id: quiz.id.clone(),
+            question,
+        };
+        let response: Response = test_app
+            .api_client
+            .post(format!("{}/v01/question-forge", &test_app.address))
+            .json(&package)
+            .send()
+            .await
+            .expect("Failed to execute POST Request");
+        assert!(response.status() == 201, "Failed to create Question");
+    }
+    quiz
+}
+
+#[tokio::test]
+async fn test_memory_backend_quiz_and_questions_200() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let response: Response = test_app.log_in_test_user().await;
+    assert!(response.status().is_success(), "Failed to log user in");
+
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let quiz: SurrealQuiz = test_app
+        .api_client
+        .post(format!("{}/v01/quiz-nexus", &test_app.address))
+        .json(&info)
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Merge sort is stable"),
+            hint: None,
+            answer: true,
+        }),
+    };
+    let response: Response = test_app
+        .api_client
+        .post(format!("{}/v01/question-forge", &test_app.address))
+        .json(&package)
+        .send()
+        .await
+        .expect("Failed to execute POST Request");
+    assert_eq!(response.status().as_u16(), 201);
+    let question: SurrealQuestionTF = response.json().await.unwrap();
+
+    // Act
+    let edit: EditQuestionJsonPkg = EditQuestionJsonPkg {
+        question: JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Quick sort is stable"),
+            hint: None,
+            answer: false,
+        }),
+    };
+    let edited: Response = test_app
+        .api_client
+        .put(format!(
+            "{}/v01/question-forge?quest={}",
+            &test_app.address, question.id
+        ))
+        .json(&edit)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    let fetched: AllQuestions = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/question-forge?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let destroyed: Response = test_app
+        .api_client
+        .delete(format!(
+            "{}/v01/quiz-nexus?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute DELETE Request");
+    let remaining: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/quiz-nexus", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(edited.status().as_u16(), 200);
+    assert_eq!(fetched.tf.len(), 1);
+    assert_eq!(fetched.tf[0].question, "Quick sort is stable");
+    assert!(!fetched.tf[0].answer);
+    assert_eq!(destroyed.status().as_u16(), 200);
+    assert!(remaining.is_empty());
+}
+
+#[tokio::test]
+async fn test_memory_backend_sessions_401() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let response: Response = test_app.log_in_test_user().await;
+    assert!(response.status().is_success(), "Failed to log user in");
+
+    // Act
+    let logged_in: PartialUser = test_app
+        .api_client
+        .get(format!("{}/v01/check-login", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    test_app
+        .api_client
+        .get(format!("{}/v01/user-logout", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request");
+    let logged_out: Response = test_app
+        .api_client
+        .get(format!("{}/v01/check-login", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request");
+
+    // Assert
+    assert_eq!(logged_in.username, "testuser123");
+    assert_eq!(logged_out.status().as_u16(), 401);
+}
+
+#[tokio::test]
+async fn test_memory_backend_sign_up_student_admin_promotes_200() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let response: Response = test_app.log_in_test_user().await;
+    assert!(response.status().is_success(), "Failed to log user in");
+
+    let student_client: Client = Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .cookie_store(true)
+        .build()
+        .unwrap();
+    let student_data: serde_json::Value = serde_json::json!({
+        "name": "Other User",
+        "username": "otheruser123",
+        "password": "Password@1234"
+    });
+    for route in ["create-user", "user-login"] {
+        student_client
+            .post(format!("{}/v01/{}", &test_app.address, route))
+            .json(&student_data)
+            .send()
+            .await
+            .expect("Failed to execute POST Request");
+    }
+    let signed_up: PartialUser = student_client
+        .get(format!("{}/v01/check-login", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let promotion: serde_json::Value = serde_json::json!({
+        "username": "otheruser123",
+        "role": "Instructor"
+    });
+
+    // Act
+    let as_instructor: Response = test_app
+        .api_client
+        .put(format!("{}/v01/users/role", &test_app.address))
+        .json(&promotion)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    set_role(&test_app.database, "testuser123", Role::Admin).await;
+    let as_admin: Response = test_app
+        .api_client
+        .put(format!("{}/v01/users/role", &test_app.address))
+        .json(&promotion)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    let own_role: Response = test_app
+        .api_client
+        .put(format!("{}/v01/users/role", &test_app.address))
+        .json(&serde_json::json!({ "username": "testuser123", "role": "Student" }))
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+
+    // Assert
+    assert_eq!(signed_up.role, Role::Student);
+    assert_eq!(as_instructor.status().as_u16(), 403);
+    assert_eq!(as_admin.status().as_u16(), 200);
+    let promoted: PartialUser = as_admin.json().await.unwrap();
+    assert_eq!(promoted.role, Role::Instructor);
+    assert_eq!(own_role.status().as_u16(), 400);
+}
+
+#[tokio::test]
+async fn test_memory_backend_trash_and_restore_200() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let response: Response = test_app.log_in_test_user().await;
+    assert!(response.status().is_success(), "Failed to log user in");
+
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let quiz: SurrealQuiz = test_app
+        .api_client
+        .post(format!("{}/v01/quiz-nexus", &test_app.address))
+        .json(&info)
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Act
+    let destroyed: Response = test_app
+        .api_client
+        .delete(format!(
+            "{}/v01/quiz-nexus?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute DELETE Request");
+    let trash: Vec<TrashItem> = test_app
+        .api_client
+        .get(format!("{}/v01/trash", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let hidden: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/quiz-nexus", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let restored: Response = test_app
+        .api_client
+        .put(format!("{}/v01/trash?item={}", &test_app.address, quiz.id))
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    let listed: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/quiz-nexus", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(destroyed.status().as_u16(), 200);
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].id, quiz.id);
+    assert!(hidden.is_empty());
+    assert_eq!(restored.status().as_u16(), 200);
+    assert_eq!(listed.len(), 1);
+}
+
+#[tokio::test]
+async fn test_memory_backend_revisions_and_rollback_200() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let response: Response = test_app.log_in_test_user().await;
+    assert!(response.status().is_success(), "Failed to log user in");
+
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let quiz: SurrealQuiz = test_app
+        .api_client
+        .post(format!("{}/v01/quiz-nexus", &test_app.address))
+        .json(&info)
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+    let edit: serde_json::Value = serde_json::json!({
+        "name": "Sorting",
+        "description": "An algorithms quiz"
+    });
+    let response: Response = test_app
+        .api_client
+        .put(format!(
+            "{}/v01/quiz-nexus?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .json(&edit)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    assert_eq!(response.status().as_u16(), 200);
+
+    // Act
+    let revisions: Vec<SurrealRevision> = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/revisions?target={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let diff: RevisionDiff = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/revisions/diff?target={}&from=1&to=2",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let rolled_back: Response = test_app
+        .api_client
+        .put(format!(
+            "{}/v01/revisions/rollback?target={}&to=1",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    let listed: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/quiz-nexus", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(revisions.len(), 2);
+    // newest first
+    assert_eq!(revisions[0].number, 2);
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].field, "name");
+    assert_eq!(rolled_back.status().as_u16(), 200);
+    let saved: SurrealRevision = rolled_back.json().await.unwrap();
+    assert_eq!(saved.number, 3);
+    assert_eq!(listed[0]["name"], "Algorithms");
+}
+
+#[tokio::test]
+async fn test_memory_backend_attempts_and_study_200() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let quiz: SurrealQuiz = create_memory_quiz(&test_app).await;
+    let started: AttemptStart = test_app
+        .api_client
+        .post(format!(
+            "{}/v01/exam-room?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Right on the true or false, wrong on the multiple choice
+    let answers: Vec<AnswerJsonPkg> = started
+        .questions
+        .iter()
+        .map(|eq| AnswerJsonPkg {
+            question_id: eq.id.clone(),
+            answer: match &eq.kind {
+                ExamQuestionKind::TrueFalse => AttemptAnswer::TrueFalse(true),
+                _ => AttemptAnswer::Choice(String::from("Bubble Sort")),
+            },
+        })
+        .collect();
+    let submission: AttemptSubmission = AttemptSubmission { answers };
+    let tf_question: &ExamQuestion = started
+        .questions
+        .iter()
+        .find(|eq| eq.kind == ExamQuestionKind::TrueFalse)
+        .unwrap();
+
+    // Act
+    let studied_during: Response = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/study-hall?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request");
+    let submitted: Response = test_app
+        .api_client
+        .put(format!(
+            "{}/v01/exam-room?attempt={}",
+            &test_app.address, started.attempt_id
+        ))
+        .json(&submission)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    let resubmitted: Response = test_app
+        .api_client
+        .put(format!(
+            "{}/v01/exam-room?attempt={}",
+            &test_app.address, started.attempt_id
+        ))
+        .json(&submission)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    let history: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/exam-room/history", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let review: ReviewJsonPkg = ReviewJsonPkg {
+        question_id: tf_question.id.clone(),
+        answer: AttemptAnswer::TrueFalse(true),
+        rating: ReviewRating::Easy,
+    };
+    let reviewed: Response = test_app
+        .api_client
+        .post(format!(
+            "{}/v01/study-hall?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .json(&review)
+        .send()
+        .await
+        .expect("Failed to execute POST Request");
+    let still_due: Vec<ExamQuestion> = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/study-hall?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(studied_during.status().as_u16(), 400);
+    assert_eq!(submitted.status().as_u16(), 200);
+    let graded: SurrealAttempt = submitted.json().await.unwrap();
+    assert_eq!(graded.score, 1.0);
+    assert_eq!(graded.max_score, 2.0);
+    assert_eq!(resubmitted.status().as_u16(), 400);
+    assert_eq!(history.len(), 1);
+    assert_eq!(reviewed.status().as_u16(), 200);
+    let outcome: ReviewOutcome = reviewed.json().await.unwrap();
+    assert_eq!(outcome.earned, 1.0);
+    assert_eq!(still_due.len(), 1);
+    assert!(still_due.iter().all(|eq| eq.id != tf_question.id));
+    assert!(still_due.iter().all(|eq| eq.hint.is_none()));
+}
+
+#[tokio::test]
+async fn test_memory_backend_questions_timed_one_by_one_200() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let quiz: SurrealQuiz = create_memory_quiz(&test_app).await;
+    let limits: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz",
+        "time_per_question_secs": 60
+    });
+    let response: Response = test_app
+        .api_client
+        .put(format!(
+            "{}/v01/quiz-nexus?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .json(&limits)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+    assert!(response.status() == 200, "Failed to set time per question");
+    let started: AttemptStart = test_app
+        .api_client
+        .post(format!(
+            "{}/v01/exam-room?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+    let right = |eq: &ExamQuestion| match &eq.kind {
+        ExamQuestionKind::TrueFalse => AttemptAnswer::TrueFalse(true),
+        _ => AttemptAnswer::Choice(String::from("Merge Sort")),
+    };
+    let next_url: String = format!(
+        "{}/v01/exam-room/next?attempt={}",
+        &test_app.address, started.attempt_id
+    );
+
+    // Act
+    let first: ServedQuestion = test_app
+        .api_client
+        .put(&next_url)
+        .json(&NextQuestionJsonPkg { answer: None })
+        .send()
+        .await
+        .expect("Failed to execute PUT Request")
+        .json()
+        .await
+        .unwrap();
+    let first_question: ExamQuestion = first.question.clone().unwrap();
+    let second: ServedQuestion = test_app
+        .api_client
+        .put(&next_url)
+        .json(&NextQuestionJsonPkg {
+            answer: Some(right(&first_question)),
+        })
+        .send()
+        .await
+        .expect("Failed to execute PUT Request")
+        .json()
+        .await
+        .unwrap();
+    let second_question: ExamQuestion = second.question.clone().unwrap();
+    // Only the open question's answer is taken, the first was answered already
+    let answers: Vec<AnswerJsonPkg> = vec![
+        AnswerJsonPkg {
+            question_id: first_question.id.clone(),
+            answer: AttemptAnswer::Choice(String::from("Bubble Sort")),
+        },
+        AnswerJsonPkg {
+            question_id: second_question.id.clone(),
+            answer: right(&second_question),
+        },
+    ];
+    let submitted: Response = test_app
+        .api_client
+        .put(format!(
+            "{}/v01/exam-room?attempt={}",
+            &test_app.address, started.attempt_id
+        ))
+        .json(&AttemptSubmission { answers })
+        .send()
+        .await
+        .expect("Failed to execute PUT Request");
+
+    // Assert
+    assert!(started.questions.is_empty());
+    assert_eq!(started.time_per_question_secs, Some(60));
+    assert_eq!(started.time_allowed_secs, None);
+    assert!(first.time_allowed_secs.is_some_and(|secs| secs <= 60));
+    assert!(!second.late);
+    assert_ne!(first_question.id, second_question.id);
+    assert_eq!(submitted.status().as_u16(), 200);
+    let graded: SurrealAttempt = submitted.json().await.unwrap();
+    assert_eq!(graded.score, 2.0);
+    assert_eq!(graded.timings.len(), 2);
+    assert!(graded.timings.iter().all(|qt| qt.answered_at.is_some()));
+}
+
+#[tokio::test]
+async fn test_memory_backend_likes_and_tags_200() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let quiz: SurrealQuiz = create_memory_quiz(&test_app).await;
+    let like: LikeJsonPkg = LikeJsonPkg {
+        target: quiz.id.clone(),
+        liked: true,
+    };
+    let tag: SurrealTag = test_app
+        .api_client
+        .post(format!("{}/v01/tags", &test_app.address))
+        .json(&serde_json::json!({ "name": "Exam" }))
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+    let assign: serde_json::Value = serde_json::json!({
+        "tag": tag.id,
+        "target": quiz.id,
+        "tagged": true
+    });
+
+    // Act
+    for _ in 0..2 {
+        test_app
+            .api_client
+            .put(format!("{}/v01/likes", &test_app.address))
+            .json(&like)
+            .send()
+            .await
+            .expect("Failed to execute PUT Request");
+        test_app
+            .api_client
+            .put(format!("{}/v01/tags/assign", &test_app.address))
+            .json(&assign)
+            .send()
+            .await
+            .expect("Failed to execute PUT Request");
+    }
+    let status: LikeStatus = test_app
+        .api_client
+        .put(format!("{}/v01/likes", &test_app.address))
+        .json(&like)
+        .send()
+        .await
+        .expect("Failed to execute PUT Request")
+        .json()
+        .await
+        .unwrap();
+    let filtered: Vec<Tagged<SurrealQuiz>> = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/quiz-nexus?tag={}",
+            &test_app.address, tag.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let summaries: Vec<TagSummary> = test_app
+        .api_client
+        .get(format!("{}/v01/tags", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let destroyed: Response = test_app
+        .api_client
+        .delete(format!("{}/v01/tags?tag={}", &test_app.address, tag.id))
+        .send()
+        .await
+        .expect("Failed to execute DELETE Request");
+    let listed: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/quiz-nexus", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(status.likes, 1);
+    assert!(status.liked);
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].record.id, quiz.id);
+    assert_eq!(filtered[0].tags[0].name, "Exam");
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].quizzes, 1);
+    assert_eq!(summaries[0].questions, 0);
+    assert_eq!(destroyed.status().as_u16(), 200);
+    assert_eq!(listed[0]["likes"], 1);
+    assert_eq!(listed[0]["tags"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_memory_backend_groups_and_assignments_201() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let quiz: SurrealQuiz = create_memory_quiz(&test_app).await;
+    let group: SurrealGroup = test_app
+        .api_client
+        .post(format!("{}/v01/groups", &test_app.address))
+        .json(&serde_json::json!({ "name": "Period 1" }))
+        .send()
+        .await
+        .expect("Failed to execute POST Request")
+        .json()
+        .await
+        .unwrap();
+
+    // A second client keeps its own session
+    let member_client: Client = Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .cookie_store(true)
+        .build()
+        .unwrap();
+    let member_data: serde_json::Value = serde_json::json!({
+        "name": "Other User",
+        "username": "otheruser123",
+        "password": "Password@1234"
+    });
+    member_client
+        .post(format!("{}/v01/create-user", &test_app.address))
+        .json(&member_data)
+        .send()
+        .await
+        .expect("Failed to create user");
+    member_client
+        .post(format!("{}/v01/user-login", &test_app.address))
+        .json(&member_data)
+        .send()
+        .await
+        .expect("Failed to send login data");
+
+    // Act
+    let joined: Response = member_client
+        .post(format!("{}/v01/groups/join", &test_app.address))
+        .json(&serde_json::json!({ "join_code": group.join_code }))
+        .send()
+        .await
+        .expect("Failed to execute POST Request");
+    let now = Utc::now();
+    let assigned: Response = test_app
+        .api_client
+        .post(format!(
+            "{}/v01/groups/assignments?group={}",
+            &test_app.address, group.id
+        ))
+        .json(&serde_json::json!({
+            "quiz_id": quiz.id,
+            "opens_at": now - Duration::hours(1),
+            "closes_at": now + Duration::hours(1)
+        }))
+        .send()
+        .await
+        .expect("Failed to execute POST Request");
+    let groups: Vec<serde_json::Value> = test_app
+        .api_client
+        .get(format!("{}/v01/groups", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let assignments: Vec<serde_json::Value> = member_client
+        .get(format!("{}/v01/groups/assignments", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(joined.status().as_u16(), 201);
+    assert_eq!(assigned.status().as_u16(), 201);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0]["members"][0]["username"], "otheruser123");
+    assert_eq!(assignments.len(), 1);
+    assert_eq!(assignments[0]["quiz"]["name"], "Algorithms");
+    assert_eq!(assignments[0]["group_name"], "Period 1");
+}
+
+#[tokio::test]
+async fn test_memory_backend_search_bundles_and_csv_201() {
+    // Arrange
+    let test_app: MemoryTestApp = spawn_memory_app().await;
+    let quiz: SurrealQuiz = create_memory_quiz(&test_app).await;
+    let csv: String = String::from(
+        "question,answer,choice1,choice2,hint
+Shortest path with negative edges?,Bellman-Ford,Dijkstra,,Think about relaxing edges
+",
+    );
+
+    // Act
+    let results: SearchResults = test_app
+        .api_client
+        .get(format!("{}/v01/search?q=binary", &test_app.address))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+    let yaml: String = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/quiz-nexus/export?quiz={}&format=yaml",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .text()
+        .await
+        .unwrap();
+    let imported: Response = test_app
+        .api_client
+        .post(format!(
+            "{}/v01/quiz-nexus/import?format=yaml&dry_run=false",
+            &test_app.address
+        ))
+        .body(yaml)
+        .send()
+        .await
+        .expect("Failed to execute POST Request");
+    let uploaded: Response = test_app
+        .api_client
+        .post(format!(
+            "{}/v01/question-forge/csv?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .header("Content-Type", "text/csv")
+        .body(csv)
+        .send()
+        .await
+        .expect("Failed to execute POST Request");
+    let questions: AllQuestions = test_app
+        .api_client
+        .get(format!(
+            "{}/v01/question-forge?quiz={}",
+            &test_app.address, quiz.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute GET Request")
+        .json()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(results.hits.len(), 1);
+    assert_eq!(results.hits[0].kind, SearchKind::Question);
+    assert_eq!(results.hits[0].quiz_id, quiz.id);
+    assert!(results.hits[0].title.iter().any(|part| part.hit));
+
+    assert_eq!(imported.status().as_u16(), 201);
+    let report: ImportReport = imported.json().await.unwrap();
+    assert_eq!(report.items.len(), 2);
+    let new_quiz: SurrealQuiz = report.quiz.expect("Imported quiz missing");
+    assert_ne!(new_quiz.id, quiz.id);
+
+    assert_eq!(uploaded.status().as_u16(), 201);
+    let created: Vec<SurrealQuestionMC> = uploaded.json().await.unwrap();
+    assert_eq!(created.len(), 1);
+    assert_eq!(questions.mc.len(), 2);
+}
@@ -0,0 +1,151 @@
+//! backend/tests/api/likes.rs
+use crate::attempts::create_test_quiz;
+use crate::browse_quizzes::share_quizzes;
+use crate::utils::{spawn_app, GetQuestion, GetQuiz, LikeThings, TestApp};
+use models::{
+    likes::{LikeJsonPkg, LikeStatus},
+    quiz::SurrealQuiz,
+};
+use reqwest::Response;
+
+#[tokio::test]
+async fn test_like_is_idempotent_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let like: LikeJsonPkg = LikeJsonPkg {
+        target: quiz.id.clone(),
+        liked: true,
+    };
+
+    // Act
+    let first: Response = test_app.put_like(&like).await;
+    let second: Response = test_app.put_like(&like).await;
+
+    // Assert
+    assert!(first.status() == 200);
+    assert!(second.status() == 200);
+    let status: LikeStatus = second.json().await.unwrap();
+    assert_eq!(status.likes, 1);
+    assert!(status.liked);
+
+    let response: Response = test_app.get_quizzes().await;
+    let quizzes: Vec<serde_json::Value> = response.json().await.unwrap();
+    assert_eq!(quizzes[0]["likes"], 1);
+    assert_eq!(quizzes[0]["liked"], true);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_unlike_question_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let response: Response = test_app.get_questions(quiz.id.to_raw()).await;
+    let questions: serde_json::Value = response.json().await.unwrap();
+    let question_id: serde_json::Value = questions["tf"][0]["id"].clone();
+    let like: LikeJsonPkg = LikeJsonPkg {
+        target: serde_json::from_value(question_id).unwrap(),
+        liked: true,
+    };
+    let liked: Response = test_app.put_like(&like).await;
+    assert!(liked.status() == 200, "Failed to like Question");
+
+    // Act
+    let unlike: LikeJsonPkg = LikeJsonPkg {
+        liked: false,
+        ..like
+    };
+    let response: Response = test_app.put_like(&unlike).await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let status: LikeStatus = response.json().await.unwrap();
+    assert_eq!(status.likes, 0);
+    assert!(!status.liked);
+
+    let response: Response = test_app.get_questions(quiz.id.to_raw()).await;
+    let questions: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(questions["tf"][0]["likes"], 0);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_like_unknown_table_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let like: LikeJsonPkg = LikeJsonPkg {
+        target: surrealdb::sql::Thing::from(("general_user", "someone")),
+        liked: true,
+    };
+
+    // Act
+    let response: Response = test_app.put_like(&like).await;
+
+    // Assert
+    assert!(response.status() == 400);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_like_private_quiz_of_other_user_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, private_quiz) = share_quizzes(&test_app).await;
+
+    // Act
+    let private: Response = test_app
+        .put_like(&LikeJsonPkg {
+            target: private_quiz.id.clone(),
+            liked: true,
+        })
+        .await;
+    let public: Response = test_app
+        .put_like(&LikeJsonPkg {
+            target: public_quiz.id.clone(),
+            liked: true,
+        })
+        .await;
+
+    // Assert
+    assert!(private.status() == 403);
+    assert!(public.status() == 200);
+    test_app.cleanup_db().await;
+}
@@ -1,6 +1,9 @@
 //! backend/tests/api/main.rs
 //! This structure will scope tests into a single test executable.
 //! This makes it easier to share code and setup / execute tests
+mod attempts;
+mod browse_quizzes;
+mod bundles;
 mod create_questions;
 mod create_quiz;
 mod create_user;
@@ -8,9 +11,22 @@ mod destroy_question;
 mod destroy_quiz;
 mod edit_question;
 mod edit_quiz;
+#[cfg(any(feature = "embedded-surrealkv", feature = "embedded-rocksdb"))]
+mod embedded;
 mod get_questions;
 mod get_quiz;
+mod groups;
 mod health_check;
+mod in_memory;
+mod likes;
 mod log_out_users;
 mod loggin_user;
+mod migrations;
+mod revisions;
+mod roles;
+mod search;
+mod study;
+mod tags;
+mod trash;
+mod upload_csv;
 mod utils;
@@ -0,0 +1,60 @@
+//! backend/tests/api/migrations.rs
+use crate::utils::{spawn_app, TestApp};
+use backend::migrations::{applied_version, MIGRATIONS};
+use models::{roles::Role, GeneralUser};
+use reqwest::Response;
+
+#[tokio::test]
+async fn test_migrations_recorded() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+
+    // Act
+    let version: u32 = applied_version(&test_app.database).await.unwrap();
+
+    // Assert
+    assert_eq!(version as usize, MIGRATIONS.len());
+}
+
+#[tokio::test]
+async fn test_duplicate_username_rejected() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    let response: Response = test_app.create_new_test_user().await;
+    assert!(response.status().is_success());
+
+    // Act
+    // Straight to the database, skipping the check in the create-user route
+    let duplicate: surrealdb::Result<Option<GeneralUser>> = test_app
+        .database
+        .client
+        .create(("general_user", "duplicate-uuid"))
+        .content(GeneralUser {
+            uuid: String::from("duplicate-uuid"),
+            name: String::from("Someone Else"),
+            username: String::from("testuser123"),
+            password_hash: String::from("not-a-hash"),
+            role: Role::Student,
+        })
+        .await;
+
+    // Assert
+    assert!(duplicate.is_err());
+}
+
+#[tokio::test]
+async fn test_field_types_enforced() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+
+    // Act
+    let created: surrealdb::Result<surrealdb::Response> = test_app
+        .database
+        .client
+        .query("CREATE questions_tf CONTENT { question: 'Is water wet?', answer: 'yes', author_id: 'x', parent_quiz: quizzes:none }")
+        .await
+        .and_then(surrealdb::Response::check);
+
+    // Assert
+    assert!(created.is_err());
+}
@@ -0,0 +1,219 @@
+//! backend/tests/api/revisions.rs
+use crate::attempts::create_test_quiz;
+use crate::utils::{
+    spawn_app, CreateQuestions, CreateQuiz, EditQuestion, EditQuiz, GetQuestion, TakeQuiz, TestApp,
+    UseRevisions,
+};
+use models::{
+    attempts::{
+        AnswerJsonPkg, AnswerKey, AttemptAnswer, AttemptStart, AttemptSubmission, HintJsonPkg,
+        SurrealAttempt,
+    },
+    questions::{
+        AllQuestions, EditQuestionJsonPkg, JsonQuestion, JsonQuestionTF, QuestionJsonPkg,
+        SurrealQuestionTF,
+    },
+    quiz::SurrealQuiz,
+    revisions::{RevisionDiff, SurrealRevision},
+};
+use reqwest::Response;
+use serde_json::Value;
+
+fn true_false(question: &str, answer: bool) -> JsonQuestion {
+    JsonQuestion::TrueFalse(JsonQuestionTF {
+        question: String::from(question),
+        hint: None,
+        answer,
+    })
+}
+
+/// Creates a quiz with a single true/false question as the logged in test user.
+async fn quiz_with_question(test_app: &TestApp) -> (SurrealQuiz, SurrealQuestionTF) {
+    let info: Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success());
+    let quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: true_false("Merge sort is stable", true),
+    };
+    let response: Response = test_app.post_create_questions(&package).await;
+    assert!(response.status() == 201);
+    let question: SurrealQuestionTF = response.json().await.unwrap();
+    (quiz, question)
+}
+
+#[tokio::test]
+async fn test_edit_and_rollback_question_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    assert!(test_app.create_new_test_user().await.status().is_success());
+    assert!(test_app.log_in_test_user().await.status().is_success());
+    let (quiz, question) = quiz_with_question(&test_app).await;
+    let quest_param: String = urlencoding::encode(&question.id.to_raw()).to_string();
+    let edit: EditQuestionJsonPkg = EditQuestionJsonPkg {
+        question: true_false("Quick sort is stable", false),
+    };
+    let response: Response = test_app.edit_question(quest_param.clone(), &edit).await;
+    assert!(response.status() == 200);
+
+    // Act
+    let revisions: Vec<SurrealRevision> = test_app
+        .get_revisions(quest_param.clone())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let diff: RevisionDiff = test_app
+        .diff_revisions(quest_param.clone(), 1, 2)
+        .await
+        .json()
+        .await
+        .unwrap();
+    let response: Response = test_app.rollback_revision(quest_param.clone(), 1).await;
+
+    // Assert
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].number, 2);
+    let fields: Vec<&str> = diff.changes.iter().map(|ch| ch.field.as_str()).collect();
+    assert_eq!(fields, vec!["answer", "question"]);
+    assert_eq!(response.status().as_u16(), 200);
+    let saved: SurrealRevision = response.json().await.unwrap();
+    assert_eq!(saved.number, 3);
+    let all: AllQuestions = test_app
+        .get_questions(urlencoding::encode(&quiz.id.to_raw()).to_string())
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(all.tf[0].question, "Merge sort is stable");
+    assert!(all.tf[0].answer);
+
+    // clean up database
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_attempt_keeps_pinned_revisions_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    assert!(test_app.create_new_test_user().await.status().is_success());
+    assert!(test_app.log_in_test_user().await.status().is_success());
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+
+    // Act
+    let response: Response = test_app.start_attempt(quiz.id.to_raw()).await;
+    assert!(response.status() == 201);
+    let started: AttemptStart = response.json().await.unwrap();
+    let rename: Value = serde_json::json!({
+        "name": "Sorting",
+        "description": "An algorithms quiz"
+    });
+    let response: Response = test_app.edit_quiz(quiz.id.to_raw(), &rename).await;
+    assert!(response.status() == 200);
+
+    // Assert
+    let attempt: Option<SurrealAttempt> = test_app
+        .database
+        .client
+        .select(&started.attempt_id)
+        .await
+        .unwrap();
+    let attempt: SurrealAttempt = attempt.unwrap();
+    // the quiz and both of its questions
+    assert_eq!(attempt.revisions.len(), 3);
+    let revisions: Vec<SurrealRevision> = test_app
+        .get_revisions(urlencoding::encode(&quiz.id.to_raw()).to_string())
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(revisions.len(), 2);
+    // the attempt still points at the quiz as it was taken
+    assert_eq!(attempt.revisions[0], revisions[1].id);
+
+    // clean up database
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_attempt_graded_against_pinned_revision_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    assert!(test_app.create_new_test_user().await.status().is_success());
+    assert!(test_app.log_in_test_user().await.status().is_success());
+    let (quiz, question) = quiz_with_question(&test_app).await;
+    let response: Response = test_app.start_attempt(quiz.id.to_raw()).await;
+    assert!(response.status() == 201);
+    let started: AttemptStart = response.json().await.unwrap();
+
+    // The answer flips and a hint appears after the attempt started
+    let edit: EditQuestionJsonPkg = EditQuestionJsonPkg {
+        question: JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Merge sort is stable"),
+            hint: Some(String::from("Think of ties")),
+            answer: false,
+        }),
+    };
+    let quest_param: String = urlencoding::encode(&question.id.to_raw()).to_string();
+    let response: Response = test_app.edit_question(quest_param, &edit).await;
+    assert!(response.status() == 200);
+
+    // Act
+    let hint: Response = test_app
+        .put_reveal_hint(
+            started.attempt_id.to_raw(),
+            &HintJsonPkg {
+                question_id: question.id.clone(),
+            },
+        )
+        .await;
+    let submission: AttemptSubmission = AttemptSubmission {
+        answers: vec![AnswerJsonPkg {
+            question_id: question.id.clone(),
+            answer: AttemptAnswer::TrueFalse(true),
+        }],
+    };
+    let response: Response = test_app
+        .submit_attempt(started.attempt_id.to_raw(), &submission)
+        .await;
+
+    // Assert
+    // the question had no hint when the attempt started
+    assert_eq!(hint.status().as_u16(), 400);
+    assert_eq!(response.status().as_u16(), 200);
+    let graded: SurrealAttempt = response.json().await.unwrap();
+    assert_eq!(graded.score, 1.0);
+    assert_eq!(graded.answers[0].key, AnswerKey::TrueFalse(true));
+
+    // clean up database
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_diff_missing_revision_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    assert!(test_app.create_new_test_user().await.status().is_success());
+    assert!(test_app.log_in_test_user().await.status().is_success());
+    let (quiz, _) = quiz_with_question(&test_app).await;
+    let quiz_param: String = urlencoding::encode(&quiz.id.to_raw()).to_string();
+
+    // Act
+    let response: Response = test_app.diff_revisions(quiz_param, 1, 2).await;
+
+    // Assert
+    // the quiz was never edited, so it has no revisions yet
+    assert_eq!(response.status().as_u16(), 400);
+
+    // clean up database
+    test_app.cleanup_db().await;
+}
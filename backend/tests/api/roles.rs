@@ -0,0 +1,148 @@
+//! backend/tests/api/roles.rs
+use crate::browse_quizzes::share_quizzes;
+use crate::utils::{
+    set_role, spawn_app, CreateQuiz, DestroyQuiz, EditQuiz, TagThings, TakeQuiz, TestApp,
+};
+use models::{roles::Role, tags::SurrealTag};
+use reqwest::Response;
+use serde_json::Value;
+
+#[tokio::test]
+async fn test_sign_up_as_instructor_or_admin_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    for role in ["Instructor", "Admin"] {
+        let user_data: Value = serde_json::json!({
+            "name": "Sneaky",
+            "username": "sneaky123",
+            "password": "Password@1234",
+            "role": role
+        });
+
+        // Act
+        let response: Response = test_app
+            .api_client
+            .post(format!("{}/v01/create-user", &test_app.address))
+            .json(&user_data)
+            .send()
+            .await
+            .expect("Failed to create user");
+
+        // Assert
+        assert!(response.status() == 400);
+    }
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_student_cannot_author_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let user_data: Value = serde_json::json!({
+        "name": "Student",
+        "username": "student123",
+        "password": "Password@1234",
+        "role": "Student"
+    });
+    for route in ["create-user", "user-login"] {
+        let response: Response = test_app
+            .api_client
+            .post(format!("{}/v01/{}", &test_app.address, route))
+            .json(&user_data)
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert!(response.status().is_success());
+    }
+
+    // Act
+    let info: Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let response: Response = test_app.post_create_quiz(&info).await;
+
+    // Assert
+    assert!(response.status() == 403);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_student_takes_shared_quiz_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, _) = share_quizzes(&test_app).await;
+    set_role(&test_app.database, "otheruser123", Role::Student).await;
+
+    // Act
+    let response: Response = test_app.start_attempt(public_quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 201);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_admin_moderates_other_users_quiz_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, private_quiz) = share_quizzes(&test_app).await;
+    let query_param: String = urlencoding::encode(&public_quiz.id.to_raw()).to_string();
+    let edit: Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "Edited by a moderator"
+    });
+
+    // Act
+    let before: Response = test_app.edit_quiz(query_param.clone(), &edit).await;
+    set_role(&test_app.database, "otheruser123", Role::Admin).await;
+    let edited: Response = test_app.edit_quiz(query_param, &edit).await;
+    let deleted: Response = test_app
+        .destroy_quiz(urlencoding::encode(&private_quiz.id.to_raw()).to_string())
+        .await;
+
+    // Assert
+    assert!(before.status() == 403);
+    assert!(edited.status() == 200);
+    assert!(deleted.status() == 200);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_admin_moderates_other_users_tags_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    test_app.create_new_test_user().await;
+    test_app.log_in_test_user().await;
+    let response: Response = test_app
+        .post_tag(&serde_json::json!({ "name": "sorting" }))
+        .await;
+    assert!(response.status() == 201, "Failed to create Tag");
+    let tag: SurrealTag = response.json().await.unwrap();
+    let query_param: String = urlencoding::encode(&tag.id.to_raw()).to_string();
+    let rename: Value = serde_json::json!({ "name": "Sorting" });
+    test_app.log_in_other_user().await;
+
+    // Act
+    let before: Response = test_app.edit_tag(query_param.clone(), &rename).await;
+    set_role(&test_app.database, "otheruser123", Role::Student).await;
+    let student_tag: Response = test_app
+        .post_tag(&serde_json::json!({ "name": "graphs" }))
+        .await;
+    set_role(&test_app.database, "otheruser123", Role::Admin).await;
+    let renamed: Response = test_app.edit_tag(query_param.clone(), &rename).await;
+    let deleted: Response = test_app.destroy_tag(query_param).await;
+
+    // Assert
+    assert!(before.status() == 403);
+    assert!(student_tag.status() == 403);
+    assert!(renamed.status() == 200);
+    assert!(deleted.status() == 200);
+    test_app.cleanup_db().await;
+}
@@ -0,0 +1,80 @@
+//! backend/tests/api/search.rs
+use crate::attempts::create_test_quiz;
+use crate::browse_quizzes::share_quizzes;
+use crate::utils::{spawn_app, SearchQuizzes, TestApp};
+use models::quiz::SurrealQuiz;
+use models::search::{SearchKind, SearchResults};
+use reqwest::Response;
+
+/// Creates and logs in the test user.
+async fn log_in(test_app: &TestApp) {
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+}
+
+#[tokio::test]
+async fn test_search_skips_other_users_private_quizzes_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, private_quiz) = share_quizzes(&test_app).await;
+
+    // Act
+    let response: Response = test_app.get_search("algorithms").await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let results: SearchResults = response.json().await.unwrap();
+    assert!(results.hits.iter().any(|hit| hit.id == public_quiz.id));
+    assert!(results.hits.iter().all(|hit| hit.id != private_quiz.id));
+    assert!(results.hits[0].title.iter().any(|part| part.hit));
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_search_matches_question_choices_but_not_answers_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    log_in(&test_app).await;
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+
+    // Act
+    let choice_response: Response = test_app.get_search("bubble").await;
+    let answer_response: Response = test_app.get_search("merge").await;
+
+    // Assert
+    assert!(choice_response.status() == 200);
+    let results: SearchResults = choice_response.json().await.unwrap();
+    assert_eq!(results.hits.len(), 1);
+    assert_eq!(results.hits[0].kind, SearchKind::Question);
+    assert_eq!(results.hits[0].quiz_id, quiz.id);
+    assert!(results.hits[0].snippet.iter().any(|part| part.hit));
+
+    let results: SearchResults = answer_response.json().await.unwrap();
+    assert!(results.hits.is_empty());
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_blank_search_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    log_in(&test_app).await;
+
+    // Act
+    let response: Response = test_app.get_search("   ").await;
+
+    // Assert
+    assert!(response.status() == 400);
+    test_app.cleanup_db().await;
+}
@@ -0,0 +1,123 @@
+//! backend/tests/api/study.rs
+use crate::attempts::create_test_quiz;
+use crate::utils::{spawn_app, StudyQuiz, TestApp};
+use models::{
+    attempts::{AttemptAnswer, ExamQuestion, ExamQuestionKind},
+    quiz::SurrealQuiz,
+    study::{ReviewJsonPkg, ReviewOutcome, ReviewRating},
+};
+use reqwest::Response;
+
+#[tokio::test]
+async fn test_new_questions_are_due_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+
+    // Act
+    let response: Response = test_app.get_due_questions(quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(!body.to_string().contains("\"answer\""));
+    let due: Vec<ExamQuestion> = serde_json::from_value(body).unwrap();
+    assert_eq!(due.len(), 2);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_reviewed_question_is_scheduled_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let response: Response = test_app.get_due_questions(quiz.id.to_raw()).await;
+    let due: Vec<ExamQuestion> = response.json().await.unwrap();
+    let tf_question: &ExamQuestion = due
+        .iter()
+        .find(|eq| eq.kind == ExamQuestionKind::TrueFalse)
+        .unwrap();
+
+    // Act
+    let review: ReviewJsonPkg = ReviewJsonPkg {
+        question_id: tf_question.id.clone(),
+        answer: AttemptAnswer::TrueFalse(true),
+        rating: ReviewRating::Easy,
+    };
+    let response: Response = test_app.review_question(quiz.id.to_raw(), &review).await;
+    let still_due: Response = test_app.get_due_questions(quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let outcome: ReviewOutcome = response.json().await.unwrap();
+    assert_eq!(outcome.earned, 1.0);
+    assert_eq!(outcome.interval_days, 1);
+    let still_due: Vec<ExamQuestion> = still_due.json().await.unwrap();
+    assert_eq!(still_due.len(), 1);
+    assert!(still_due.iter().all(|eq| eq.id != tf_question.id));
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_wrong_answer_is_a_lapse_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+    let quiz: SurrealQuiz = create_test_quiz(&test_app).await;
+    let response: Response = test_app.get_due_questions(quiz.id.to_raw()).await;
+    let due: Vec<ExamQuestion> = response.json().await.unwrap();
+    let tf_question: &ExamQuestion = due
+        .iter()
+        .find(|eq| eq.kind == ExamQuestionKind::TrueFalse)
+        .unwrap();
+
+    // Act
+    let review: ReviewJsonPkg = ReviewJsonPkg {
+        question_id: tf_question.id.clone(),
+        answer: AttemptAnswer::TrueFalse(false),
+        rating: ReviewRating::Easy,
+    };
+    let response: Response = test_app.review_question(quiz.id.to_raw(), &review).await;
+
+    // Assert
+    assert!(response.status() == 200);
+    let outcome: ReviewOutcome = response.json().await.unwrap();
+    assert_eq!(outcome.earned, 0.0);
+    assert_eq!(outcome.rating, ReviewRating::Again);
+    test_app.cleanup_db().await;
+}
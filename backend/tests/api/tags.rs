@@ -0,0 +1,150 @@
+//! backend/tests/api/tags.rs
+use crate::attempts::create_test_quiz;
+use crate::browse_quizzes::share_quizzes;
+use crate::utils::{spawn_app, CreateQuiz, GetQuiz, TagThings, TestApp};
+use models::quiz::SurrealQuiz;
+use models::tags::{SurrealTag, TagSummary, Tagged};
+use reqwest::Response;
+
+/// Creates and logs in the test user, with a tag and two quizzes.
+async fn tag_setup(test_app: &TestApp) -> (SurrealTag, SurrealQuiz, SurrealQuiz) {
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+
+    let tagged_quiz: SurrealQuiz = create_test_quiz(test_app).await;
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Data Structures",
+        "description": "A data structures quiz"
+    });
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    let other_quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let response: Response = test_app
+        .post_tag(&serde_json::json!({ "name": "Exam" }))
+        .await;
+    assert!(response.status() == 201, "Failed to create Tag");
+    let tag: SurrealTag = response.json().await.unwrap();
+
+    (tag, tagged_quiz, other_quiz)
+}
+
+#[tokio::test]
+async fn test_filter_quizzes_by_tag_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (tag, tagged_quiz, _) = tag_setup(&test_app).await;
+    let assign: serde_json::Value = serde_json::json!({
+        "tag": tag.id,
+        "target": tagged_quiz.id,
+        "tagged": true
+    });
+
+    // Act
+    let first: Response = test_app.put_tag_assign(&assign).await;
+    let second: Response = test_app.put_tag_assign(&assign).await;
+    let filtered: Response = test_app.get_quizzes_tagged(tag.id.to_raw()).await;
+    let summaries: Response = test_app.get_tags().await;
+
+    // Assert
+    assert!(first.status() == 200);
+    let tags: Vec<SurrealTag> = second.json().await.unwrap();
+    assert_eq!(tags.len(), 1);
+
+    assert!(filtered.status() == 200);
+    let quizzes: Vec<Tagged<SurrealQuiz>> = filtered.json().await.unwrap();
+    assert_eq!(quizzes.len(), 1);
+    assert_eq!(quizzes[0].record.id, tagged_quiz.id);
+    assert_eq!(quizzes[0].tags[0].name, "Exam");
+
+    let summaries: Vec<TagSummary> = summaries.json().await.unwrap();
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].quizzes, 1);
+    assert_eq!(summaries[0].questions, 0);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_duplicate_tag_name_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let _ = tag_setup(&test_app).await;
+
+    // Act
+    let response: Response = test_app
+        .post_tag(&serde_json::json!({ "name": " exam " }))
+        .await;
+
+    // Assert
+    assert!(response.status() == 400);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_rename_and_destroy_tag_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (tag, tagged_quiz, _) = tag_setup(&test_app).await;
+    let assign: serde_json::Value = serde_json::json!({
+        "tag": tag.id,
+        "target": tagged_quiz.id,
+        "tagged": true
+    });
+    let response: Response = test_app.put_tag_assign(&assign).await;
+    assert!(response.status() == 200, "Failed to tag Quiz");
+
+    // Act
+    let renamed: Response = test_app
+        .edit_tag(tag.id.to_raw(), &serde_json::json!({ "name": "Finals" }))
+        .await;
+    let destroyed: Response = test_app.destroy_tag(tag.id.to_raw()).await;
+    let quizzes: Response = test_app.get_quizzes().await;
+
+    // Assert
+    assert!(renamed.status() == 200);
+    let renamed: SurrealTag = renamed.json().await.unwrap();
+    assert_eq!(renamed.name, "Finals");
+
+    assert!(destroyed.status() == 200);
+    let quizzes: Vec<Tagged<SurrealQuiz>> = quizzes.json().await.unwrap();
+    assert_eq!(quizzes.len(), 2);
+    assert!(quizzes.iter().all(|qz| qz.tags.is_empty()));
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_tag_other_users_quiz_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, _) = share_quizzes(&test_app).await;
+    let response: Response = test_app
+        .post_tag(&serde_json::json!({ "name": "Borrowed" }))
+        .await;
+    assert!(response.status() == 201, "Failed to create Tag");
+    let tag: SurrealTag = response.json().await.unwrap();
+
+    // Act
+    let response: Response = test_app
+        .put_tag_assign(&serde_json::json!({
+            "tag": tag.id,
+            "target": public_quiz.id,
+            "tagged": true
+        }))
+        .await;
+
+    // Assert
+    assert!(response.status() == 403);
+    test_app.cleanup_db().await;
+}
@@ -0,0 +1,118 @@
+//! backend/tests/api/trash.rs
+use crate::utils::{
+    spawn_app, CreateQuestions, CreateQuiz, DestroyQuestion, DestroyQuiz, GetQuiz, TestApp,
+    UseTrash,
+};
+use models::{
+    questions::{JsonQuestion, JsonQuestionTF, QuestionJsonPkg, SurrealQuestionTF},
+    quiz::SurrealQuiz,
+    trash::TrashItem,
+};
+use reqwest::Response;
+use serde_json::Value;
+
+/// Creates a quiz with a single true/false question as the logged in test user.
+async fn quiz_with_question(test_app: &TestApp) -> (SurrealQuiz, SurrealQuestionTF) {
+    let info: Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success());
+    let quiz: SurrealQuiz = response.json().await.unwrap();
+
+    let package: QuestionJsonPkg = QuestionJsonPkg {
+        quiz_id: quiz.id.clone(),
+        question: JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from("Merge sort is stable"),
+            hint: None,
+            answer: true,
+        }),
+    };
+    let response: Response = test_app.post_create_questions(&package).await;
+    assert!(response.status() == 201);
+    let question: SurrealQuestionTF = response.json().await.unwrap();
+    (quiz, question)
+}
+
+#[tokio::test]
+async fn test_restore_quiz_200() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    assert!(test_app.create_new_test_user().await.status().is_success());
+    assert!(test_app.log_in_test_user().await.status().is_success());
+    let (quiz, _) = quiz_with_question(&test_app).await;
+    let quiz_param: String = urlencoding::encode(&quiz.id.to_raw()).to_string();
+    assert!(test_app.destroy_quiz(quiz_param.clone()).await.status() == 200);
+
+    // Act
+    let response: Response = test_app.restore_trash(quiz_param).await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let restored: TrashItem = response.json().await.unwrap();
+    assert_eq!(restored.id, quiz.id);
+    assert_eq!(restored.title, "Algorithms");
+    let listed: Vec<Value> = test_app.get_quizzes().await.json().await.unwrap();
+    assert_eq!(listed.len(), 1);
+    let trash: Vec<TrashItem> = test_app.get_trash().await.json().await.unwrap();
+    assert!(trash.is_empty());
+
+    // clean up database
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_restore_question_of_trashed_quiz_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    assert!(test_app.create_new_test_user().await.status().is_success());
+    assert!(test_app.log_in_test_user().await.status().is_success());
+    let (quiz, question) = quiz_with_question(&test_app).await;
+    let quest_param: String = urlencoding::encode(&question.id.to_raw()).to_string();
+    let quiz_param: String = urlencoding::encode(&quiz.id.to_raw()).to_string();
+    assert!(
+        test_app
+            .destroy_question(quest_param.clone())
+            .await
+            .status()
+            == 200
+    );
+    assert!(test_app.destroy_quiz(quiz_param).await.status() == 200);
+
+    // Act
+    let response: Response = test_app.restore_trash(quest_param).await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    let trash: Vec<TrashItem> = test_app.get_trash().await.json().await.unwrap();
+    assert_eq!(trash.len(), 2);
+    // most recently trashed first
+    assert_eq!(trash[0].id, quiz.id);
+    assert_eq!(trash[1].parent_quiz, Some(quiz.id));
+
+    // clean up database
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_restore_not_trashed_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    assert!(test_app.create_new_test_user().await.status().is_success());
+    assert!(test_app.log_in_test_user().await.status().is_success());
+    let (quiz, _) = quiz_with_question(&test_app).await;
+    let quiz_param: String = urlencoding::encode(&quiz.id.to_raw()).to_string();
+
+    // Act
+    let response: Response = test_app.restore_trash(quiz_param).await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+
+    // clean up database
+    test_app.cleanup_db().await;
+}
@@ -0,0 +1,106 @@
+//! backend/tests/api/upload_csv.rs
+use crate::browse_quizzes::share_quizzes;
+use crate::utils::{spawn_app, CreateQuiz, GetQuestion, TestApp, UploadCsv};
+use models::{
+    csv_upload::CsvRejection,
+    questions::{AllQuestions, SurrealQuestionMC},
+    quiz::SurrealQuiz,
+};
+use reqwest::Response;
+
+/// Logs in the test user and creates an empty quiz.
+async fn empty_quiz(test_app: &TestApp) -> SurrealQuiz {
+    let mut test_app_response = test_app.create_new_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to create new Test User"
+    );
+    test_app_response = test_app.log_in_test_user().await;
+    assert!(
+        test_app_response.status().is_success(),
+        "Failed to log user in"
+    );
+
+    let info: serde_json::Value = serde_json::json!({
+        "name": "Algorithms",
+        "description": "An algorithms quiz"
+    });
+    let response: Response = test_app.post_create_quiz(&info).await;
+    assert!(response.status().is_success(), "Failed to create Quiz");
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_upload_csv_creates_every_row_201() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let quiz: SurrealQuiz = empty_quiz(&test_app).await;
+    let csv: String = String::from(
+        "question,answer,choice1,choice2,hint
+Which sort is O(n log(n)) in the worst case?,Merge Sort,Bubble Sort,Quick Sort,
+Shortest path with negative edges?,Bellman-Ford,Dijkstra,,Think about relaxing edges
+",
+    );
+
+    // Act
+    let response: Response = test_app.post_csv_questions(quiz.id.to_raw(), csv).await;
+    let questions: Response = test_app.get_questions(quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 201);
+    let created: Vec<SurrealQuestionMC> = response.json().await.unwrap();
+    assert_eq!(created.len(), 2);
+    assert!(created.iter().all(|qmc| qmc.parent_quiz == quiz.id));
+
+    let questions: AllQuestions = questions.json().await.unwrap();
+    assert_eq!(questions.mc.len(), 2);
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_upload_csv_with_bad_rows_saves_nothing_400() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let quiz: SurrealQuiz = empty_quiz(&test_app).await;
+    let csv: String = String::from(
+        "question,answer,choice1
+What is 6 x 7?,42,41
+Largest planet?,,Mars
+,Merge Sort,Heap Sort
+",
+    );
+
+    // Act
+    let response: Response = test_app.post_csv_questions(quiz.id.to_raw(), csv).await;
+    let questions: Response = test_app.get_questions(quiz.id.to_raw()).await;
+
+    // Assert
+    assert!(response.status() == 400);
+    let rejection: CsvRejection = response.json().await.unwrap();
+    let rows: Vec<u64> = rejection.rows.iter().map(|err| err.row).collect();
+    assert_eq!(rows, vec![3, 4]);
+
+    let questions: AllQuestions = questions.json().await.unwrap();
+    assert!(questions.mc.is_empty());
+    test_app.cleanup_db().await;
+}
+
+#[tokio::test]
+async fn test_upload_csv_to_other_users_quiz_403() {
+    // Arrange
+    let test_app: TestApp = spawn_app().await;
+    test_app.cleanup_db().await;
+    let (public_quiz, _) = share_quizzes(&test_app).await;
+    let csv: String = String::from("question,answer,choice1\nWhat is 6 x 7?,42,41\n");
+
+    // Act
+    let response: Response = test_app
+        .post_csv_questions(public_quiz.id.to_raw(), csv)
+        .await;
+
+    // Assert
+    assert!(response.status() == 403);
+    test_app.cleanup_db().await;
+}
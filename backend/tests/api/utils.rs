@@ -1,12 +1,18 @@
 //! backend/tests/api/utils.rs
 //! To house utility functions for testing.
+#[cfg(any(feature = "embedded-surrealkv", feature = "embedded-rocksdb"))]
+use backend::configuration::DatabaseEngine;
+#[cfg(any(feature = "embedded-surrealkv", feature = "embedded-rocksdb"))]
+use backend::startup::connect_database;
 use backend::{
     configuration::{get_configuration, AllSettings},
+    memory_repo::MemoryDatabase,
+    repository::LookUpUser,
     startup::Application,
     surrealdb_repo::Database,
     telemetry::{get_subscriber, init_subscriber},
 };
-use models::SurrealRecord;
+use models::{questions::QUESTION_TABLES, roles::Role, GeneralUser, SurrealRecord};
 use reqwest::{Client, Response};
 use serde_json::Value;
 use std::future::Future;
@@ -178,6 +184,440 @@ impl DestroyQuestion for TestApp {
     }
 }
 
+pub trait UseTrash {
+    fn get_trash(&self) -> impl Future<Output = Response>;
+    fn restore_trash(&self, item_id: String) -> impl Future<Output = Response>;
+}
+
+impl UseTrash for TestApp {
+    async fn get_trash(&self) -> Response {
+        self.api_client
+            .get(format!("{}/v01/trash", &self.address))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn restore_trash(&self, item_id: String) -> Response {
+        self.api_client
+            .put(format!("{}/v01/trash?item={}", &self.address, item_id))
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+}
+
+pub trait UseRevisions {
+    fn get_revisions(&self, target_id: String) -> impl Future<Output = Response>;
+    fn diff_revisions(
+        &self,
+        target_id: String,
+        from: u32,
+        to: u32,
+    ) -> impl Future<Output = Response>;
+    fn rollback_revision(&self, target_id: String, to: u32) -> impl Future<Output = Response>;
+}
+
+impl UseRevisions for TestApp {
+    async fn get_revisions(&self, target_id: String) -> Response {
+        self.api_client
+            .get(format!(
+                "{}/v01/revisions?target={}",
+                &self.address, target_id
+            ))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn diff_revisions(&self, target_id: String, from: u32, to: u32) -> Response {
+        self.api_client
+            .get(format!(
+                "{}/v01/revisions/diff?target={}&from={}&to={}",
+                &self.address, target_id, from, to
+            ))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn rollback_revision(&self, target_id: String, to: u32) -> Response {
+        self.api_client
+            .put(format!(
+                "{}/v01/revisions/rollback?target={}&to={}",
+                &self.address, target_id, to
+            ))
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+}
+
+pub trait TakeQuiz {
+    fn start_attempt(&self, quiz_id: String) -> impl Future<Output = Response>;
+    fn submit_attempt<Body: serde::Serialize>(
+        &self,
+        attempt_id: String,
+        json: &Body,
+    ) -> impl Future<Output = Response>;
+    fn get_attempt_history(&self) -> impl Future<Output = Response>;
+    fn put_reveal_hint<Body: serde::Serialize>(
+        &self,
+        attempt_id: String,
+        json: &Body,
+    ) -> impl Future<Output = Response>;
+}
+
+impl TakeQuiz for TestApp {
+    async fn start_attempt(&self, quiz_id: String) -> Response {
+        self.api_client
+            .post(format!("{}/v01/exam-room?quiz={}", &self.address, quiz_id))
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+
+    async fn submit_attempt<Body: serde::Serialize>(
+        &self,
+        attempt_id: String,
+        json: &Body,
+    ) -> Response {
+        self.api_client
+            .put(format!(
+                "{}/v01/exam-room?attempt={}",
+                &self.address, attempt_id
+            ))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+
+    async fn get_attempt_history(&self) -> Response {
+        self.api_client
+            .get(format!("{}/v01/exam-room/history", &self.address))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn put_reveal_hint<Body: serde::Serialize>(
+        &self,
+        attempt_id: String,
+        json: &Body,
+    ) -> Response {
+        self.api_client
+            .put(format!(
+                "{}/v01/exam-room/hint?attempt={}",
+                &self.address, attempt_id
+            ))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+}
+
+pub trait ManageGroups {
+    fn post_create_group(&self, name: &str) -> impl Future<Output = Response>;
+    fn get_my_groups(&self) -> impl Future<Output = Response>;
+    fn put_invite_member(&self, group_id: String, username: &str)
+        -> impl Future<Output = Response>;
+    fn post_join_group(&self, join_code: &str) -> impl Future<Output = Response>;
+    fn post_assign_quiz(&self, group_id: String, json: &Value) -> impl Future<Output = Response>;
+    fn get_my_assignments(&self) -> impl Future<Output = Response>;
+    fn get_assignment_progress(&self, assignment_id: String) -> impl Future<Output = Response>;
+}
+
+impl ManageGroups for TestApp {
+    async fn post_create_group(&self, name: &str) -> Response {
+        self.api_client
+            .post(format!("{}/v01/groups", &self.address))
+            .json(&serde_json::json!({ "name": name }))
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+
+    async fn get_my_groups(&self) -> Response {
+        self.api_client
+            .get(format!("{}/v01/groups", &self.address))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn put_invite_member(&self, group_id: String, username: &str) -> Response {
+        self.api_client
+            .put(format!(
+                "{}/v01/groups/members?group={}",
+                &self.address, group_id
+            ))
+            .json(&serde_json::json!({ "username": username }))
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+
+    async fn post_join_group(&self, join_code: &str) -> Response {
+        self.api_client
+            .post(format!("{}/v01/groups/join", &self.address))
+            .json(&serde_json::json!({ "join_code": join_code }))
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+
+    async fn post_assign_quiz(&self, group_id: String, json: &Value) -> Response {
+        self.api_client
+            .post(format!(
+                "{}/v01/groups/assignments?group={}",
+                &self.address, group_id
+            ))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+
+    async fn get_my_assignments(&self) -> Response {
+        self.api_client
+            .get(format!("{}/v01/groups/assignments", &self.address))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn get_assignment_progress(&self, assignment_id: String) -> Response {
+        self.api_client
+            .get(format!(
+                "{}/v01/groups/progress?assignment={}",
+                &self.address, assignment_id
+            ))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+}
+
+pub trait BrowseQuizzes {
+    fn get_public_quizzes(&self, query: &str) -> impl Future<Output = Response>;
+    fn get_shared_quiz(&self, quiz_id: String) -> impl Future<Output = Response>;
+}
+
+impl BrowseQuizzes for TestApp {
+    async fn get_public_quizzes(&self, query: &str) -> Response {
+        self.api_client
+            .get(format!("{}/v01/quiz-nexus/public?{}", &self.address, query))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn get_shared_quiz(&self, quiz_id: String) -> Response {
+        self.api_client
+            .get(format!(
+                "{}/v01/quiz-nexus/shared?quiz={}",
+                &self.address, quiz_id
+            ))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+}
+
+pub trait SearchQuizzes {
+    fn get_search(&self, query: &str) -> impl Future<Output = Response>;
+}
+
+impl SearchQuizzes for TestApp {
+    async fn get_search(&self, query: &str) -> Response {
+        self.api_client
+            .get(format!(
+                "{}/v01/search?q={}",
+                &self.address,
+                urlencoding::encode(query)
+            ))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+}
+
+pub trait LikeThings<Body>
+where
+    Body: serde::Serialize,
+{
+    fn put_like(&self, json: &Body) -> impl Future<Output = Response>;
+}
+
+impl<Body> LikeThings<Body> for TestApp
+where
+    Body: serde::Serialize,
+{
+    async fn put_like(&self, json: &Body) -> Response {
+        self.api_client
+            .put(format!("{}/v01/likes", &self.address))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+}
+
+pub trait StudyQuiz {
+    fn get_due_questions(&self, quiz_id: String) -> impl Future<Output = Response>;
+    fn review_question<Body: serde::Serialize>(
+        &self,
+        quiz_id: String,
+        json: &Body,
+    ) -> impl Future<Output = Response>;
+}
+
+impl StudyQuiz for TestApp {
+    async fn get_due_questions(&self, quiz_id: String) -> Response {
+        self.api_client
+            .get(format!("{}/v01/study-hall?quiz={}", &self.address, quiz_id))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn review_question<Body: serde::Serialize>(
+        &self,
+        quiz_id: String,
+        json: &Body,
+    ) -> Response {
+        self.api_client
+            .post(format!("{}/v01/study-hall?quiz={}", &self.address, quiz_id))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+}
+
+pub trait TagThings {
+    fn get_tags(&self) -> impl Future<Output = Response>;
+    fn post_tag<Body: serde::Serialize>(&self, json: &Body) -> impl Future<Output = Response>;
+    fn edit_tag<Body: serde::Serialize>(
+        &self,
+        tag_id: String,
+        json: &Body,
+    ) -> impl Future<Output = Response>;
+    fn destroy_tag(&self, tag_id: String) -> impl Future<Output = Response>;
+    fn put_tag_assign<Body: serde::Serialize>(&self, json: &Body)
+        -> impl Future<Output = Response>;
+    fn get_quizzes_tagged(&self, tag_id: String) -> impl Future<Output = Response>;
+}
+
+impl TagThings for TestApp {
+    async fn get_tags(&self) -> Response {
+        self.api_client
+            .get(format!("{}/v01/tags", &self.address))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn post_tag<Body: serde::Serialize>(&self, json: &Body) -> Response {
+        self.api_client
+            .post(format!("{}/v01/tags", &self.address))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+
+    async fn edit_tag<Body: serde::Serialize>(&self, tag_id: String, json: &Body) -> Response {
+        self.api_client
+            .put(format!("{}/v01/tags?tag={}", &self.address, tag_id))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+
+    async fn destroy_tag(&self, tag_id: String) -> Response {
+        self.api_client
+            .delete(format!("{}/v01/tags?tag={}", &self.address, tag_id))
+            .send()
+            .await
+            .expect("Failed to execute DELETE Request")
+    }
+
+    async fn put_tag_assign<Body: serde::Serialize>(&self, json: &Body) -> Response {
+        self.api_client
+            .put(format!("{}/v01/tags/assign", &self.address))
+            .json(json)
+            .send()
+            .await
+            .expect("Failed to execute PUT Request")
+    }
+
+    async fn get_quizzes_tagged(&self, tag_id: String) -> Response {
+        self.api_client
+            .get(format!("{}/v01/quiz-nexus?tag={}", &self.address, tag_id))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+}
+
+pub trait BundleQuizzes {
+    fn get_export(&self, quiz_id: String, format: &str) -> impl Future<Output = Response>;
+    fn post_import(
+        &self,
+        body: String,
+        format: &str,
+        dry_run: bool,
+    ) -> impl Future<Output = Response>;
+}
+
+impl BundleQuizzes for TestApp {
+    async fn get_export(&self, quiz_id: String, format: &str) -> Response {
+        self.api_client
+            .get(format!(
+                "{}/v01/quiz-nexus/export?quiz={}&format={}",
+                &self.address, quiz_id, format
+            ))
+            .send()
+            .await
+            .expect("Failed to execute GET Request")
+    }
+
+    async fn post_import(&self, body: String, format: &str, dry_run: bool) -> Response {
+        self.api_client
+            .post(format!(
+                "{}/v01/quiz-nexus/import?format={}&dry_run={}",
+                &self.address, format, dry_run
+            ))
+            .body(body)
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+}
+
+pub trait UploadCsv {
+    fn post_csv_questions(&self, quiz_id: String, body: String) -> impl Future<Output = Response>;
+}
+
+impl UploadCsv for TestApp {
+    async fn post_csv_questions(&self, quiz_id: String, body: String) -> Response {
+        self.api_client
+            .post(format!(
+                "{}/v01/question-forge/csv?quiz={}",
+                &self.address, quiz_id
+            ))
+            .header("Content-Type", "text/csv")
+            .body(body)
+            .send()
+            .await
+            .expect("Failed to execute POST Request")
+    }
+}
+
 /// Some helper function for the `TestApp`
 /// Be sure to initialize an instance with `spawn_app()` before using these methods.
 impl TestApp {
@@ -201,12 +641,16 @@ impl TestApp {
 
         dbg!("Trying to create test user");
         // Creating User via API
-        self.api_client
+        let response: Response = self
+            .api_client
             .post(&format!("{}/v01/create-user", &self.address))
             .json(&user_data)
             .send()
             .await
-            .expect("Failed to create user")
+            .expect("Failed to create user");
+        // Sign ups are students, the test user writes quizzes
+        set_role(&self.database, "testuser123", Role::Instructor).await;
+        response
     }
 
     /// Assuming user is created in Database, Helper method attempts to log user into application
@@ -224,11 +668,46 @@ impl TestApp {
             .expect("Failed to send login data")
     }
 
+    /// Creates a second user alongside the test user, and logs them in instead.
+    /// Useful when checking what other users can see.
+    pub async fn log_in_other_user(&self) -> Response {
+        let user_data: Value = serde_json::json!({
+            "name": "Other User",
+            "username": "otheruser123",
+            "password": "Password@1234"
+        });
+        self.api_client
+            .post(format!("{}/v01/create-user", &self.address))
+            .json(&user_data)
+            .send()
+            .await
+            .expect("Failed to create user");
+        set_role(&self.database, "otheruser123", Role::Instructor).await;
+
+        self.api_client
+            .post(format!("{}/v01/user-login", &self.address))
+            .json(&user_data)
+            .send()
+            .await
+            .expect("Failed to send login data")
+    }
+
     /// To clean out database automatically
     pub async fn cleanup_db(&self) {
         // clean up database
         let _: Vec<SurrealRecord> = self.database.client.delete("quizzes").await.unwrap();
-        let _: Vec<SurrealRecord> = self.database.client.delete("questions_mc").await.unwrap();
+        for table in QUESTION_TABLES {
+            let _: Vec<SurrealRecord> = self.database.client.delete(table).await.unwrap();
+        }
+        let _: Vec<SurrealRecord> = self.database.client.delete("attempts").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("review_cards").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("likes").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("tagged").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("tags").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("assignments").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("memberships").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("groups").await.unwrap();
+        let _: Vec<SurrealRecord> = self.database.client.delete("revisions").await.unwrap();
         // Clear out users
         let _: Vec<SurrealRecord> = self.database.client.delete("general_user").await.unwrap();
         // Clear out session tokens
@@ -238,9 +717,8 @@ impl TestApp {
 
 /// Setup function for the Test Application
 /// Env var $TEST_LOG=true can send logs to standard out.
-pub async fn spawn_app() -> TestApp {
-    dbg!("Starting Spawn App");
-    // Initiate the global Logger and Subscriber
+/// Initiate the global Logger and Subscriber, once for every test.
+fn init_tracing() {
     TRACING.get_or_init(|| {
         if std::env::var("TEST_LOG").is_ok() {
             let subscriber = get_subscriber("test".into(), "info".into(), std::io::stdout);
@@ -251,6 +729,11 @@ pub async fn spawn_app() -> TestApp {
             init_subscriber(subscriber);
         }
     });
+}
+
+pub async fn spawn_app() -> TestApp {
+    dbg!("Starting Spawn App");
+    init_tracing();
 
     // Get App Configurations
     let mut configuration: AllSettings =
@@ -291,3 +774,111 @@ pub async fn spawn_app() -> TestApp {
         database,
     }
 }
+
+/// Changes a user's role straight in the database, the way `quizctl users set-role` does.
+pub async fn set_role<R: LookUpUser>(database: &R, username: &str, role: Role) {
+    let user: GeneralUser = database
+        .get_user_by_username(username.to_string())
+        .await
+        .unwrap()
+        .expect("User does not exist");
+    database.set_user_role(&user.uuid, role).await.unwrap();
+}
+
+/// A server kept in memory, each one starts empty, so these tests need no SurrealDB
+/// and can run side by side. The database is shared with the server, for setting up what no route does.
+pub struct MemoryTestApp<R = MemoryDatabase> {
+    pub address: String,
+    pub api_client: Client,
+    pub database: R,
+}
+
+pub async fn spawn_memory_app() -> MemoryTestApp {
+    init_tracing();
+
+    let mut configuration: AllSettings =
+        get_configuration().expect("Failed to Read Configuration File(s)");
+    configuration.application.port = 0;
+    configuration.database.in_memory = true;
+
+    let database: MemoryDatabase = MemoryDatabase::default();
+    let application: Application = Application::over_memory(configuration, database.clone())
+        .await
+        .expect("Failed to Build Application from Configuration");
+    let application_port: u16 = application.get_port();
+    tokio::spawn(application.run_until_stopped());
+
+    let client: Client = Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    MemoryTestApp {
+        address: format!("http://127.0.0.1:{}/api", application_port),
+        api_client: client,
+        database,
+    }
+}
+
+/// A server over SurrealDB embedded in `path`, which should be an empty folder.
+/// Only one connection can hold the folder, so the tests share the server's.
+#[cfg(any(feature = "embedded-surrealkv", feature = "embedded-rocksdb"))]
+pub async fn spawn_embedded_app(
+    engine: DatabaseEngine,
+    path: &std::path::Path,
+) -> MemoryTestApp<Database> {
+    init_tracing();
+
+    let mut configuration: AllSettings =
+        get_configuration().expect("Failed to Read Configuration File(s)");
+    configuration.application.port = 0;
+    configuration.database.engine = engine;
+    configuration.database.path = Some(path.to_path_buf());
+
+    let database: Database = connect_database(configuration.database.clone())
+        .await
+        .expect("Failed to Open Embedded Database");
+    let application: Application = Application::over_database(configuration, database.clone())
+        .await
+        .expect("Failed to Build Application from Configuration");
+    let application_port: u16 = application.get_port();
+    tokio::spawn(application.run_until_stopped());
+
+    let client: Client = Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    MemoryTestApp {
+        address: format!("http://127.0.0.1:{}/api", application_port),
+        api_client: client,
+        database,
+    }
+}
+
+impl<R: LookUpUser> MemoryTestApp<R> {
+    /// Creates the test user as an instructor and logs them in.
+    pub async fn log_in_test_user(&self) -> Response {
+        let user_data: Value = serde_json::json!({
+            "name": "Test User",
+            "username": "testuser123",
+            "password": "Password@1234"
+        });
+        self.api_client
+            .post(format!("{}/v01/create-user", &self.address))
+            .json(&user_data)
+            .send()
+            .await
+            .expect("Failed to create user");
+        set_role(&self.database, "testuser123", Role::Instructor).await;
+
+        self.api_client
+            .post(format!("{}/v01/user-login", &self.address))
+            .json(&user_data)
+            .send()
+            .await
+            .expect("Failed to send login data")
+    }
+}
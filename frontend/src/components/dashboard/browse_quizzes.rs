@@ -0,0 +1,144 @@
+//! frontend/src/components/dashboard/browse_quizzes.rs
+//! This component lists public quizzes by other users, a page at a time.
+use leptos::*;
+use web_sys::{Headers, RequestMode, Response};
+
+use crate::{
+    components::{Card, LikeButton},
+    models::{mimic_surreal::SurrealQuiz, quizzes::PublicQuizPage},
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+
+/// How many public quizzes are shown per page.
+const PER_PAGE: u32 = 10;
+
+/// The Public Show Case lets users search public quizzes and take them.
+#[component]
+pub fn PublicShowCase(quiz_selector: Callback<SurrealQuiz>) -> impl IntoView {
+    // -- Create Signals --
+    let search: RwSignal<String> = create_rw_signal(String::new());
+    let page: RwSignal<u32> = create_rw_signal(1);
+    let quiz_page: RwSignal<Option<PublicQuizPage>> = create_rw_signal(None);
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Resource --
+    // Fetches again whenever the search or page changes
+    let browse_resource = create_resource(
+        move || (search.get(), page.get()),
+        move |(search_str, page_num): (String, u32)| {
+            let headers: Headers = Headers::new().unwrap();
+            headers
+                .set("Content-Type", "application/json;charset=UTF-8")
+                .unwrap();
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(app_settings.backend_url.clone() + "quiz-nexus/public")
+                .add_query_param("search", &search_str)
+                .add_query_param("page", &page_num.to_string())
+                .add_query_param("per_page", &PER_PAGE.to_string())
+                .set_method("GET")
+                .set_headers(headers)
+                .set_mode(RequestMode::Cors)
+                .build();
+            async move {
+                let response: Response = fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: PublicQuizPage = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(None);
+                    quiz_page.set(Some(data));
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
+                }
+            }
+        },
+    );
+
+    // -- Create Effect --
+    create_effect(move |_| {
+        browse_resource.get();
+    });
+
+    // -- Closures --
+    let search_change = move |evnt: ev::Event| {
+        page.set(1);
+        search.set(event_target_value(&evnt));
+    };
+    let last_page = move || {
+        quiz_page.with(|qp| {
+            qp.as_ref()
+                .map_or(1, |qp| qp.total.div_ceil(u64::from(qp.per_page)).max(1))
+        })
+    };
+    let on_first_page = move || page.get() == 1;
+    let on_last_page = move || u64::from(page.get()) >= last_page();
+
+    // -- Render View --
+    view! {
+        <div
+            class:quiz-showcase-container=true
+        >
+            <h2>"Public Quizzes"</h2>
+            <input
+                type="search"
+                placeholder="Search by name or description"
+                on:change=search_change
+            />
+            <h4>{move || err_msg.get()}</h4>
+            <For
+                each=move || quiz_page.get().map(|qp| qp.quizzes).unwrap_or_default()
+                key=|qz| qz.id.to_raw()
+                children=move |this| view! {
+                    <PublicQuizExhibit surreal_quiz=this quiz_selector=quiz_selector />
+                }
+            />
+            <div class:horizontal-even=true>
+                <button
+                    on:click=move |_| page.update(|pg| *pg -= 1)
+                    disabled=on_first_page
+                >"Previous"</button>
+                <p>"Page "{move || page.get()}" of "{last_page}</p>
+                <button
+                    on:click=move |_| page.update(|pg| *pg += 1)
+                    disabled=on_last_page
+                >"Next"</button>
+            </div>
+        </div>
+    }
+}
+
+/// A single public quiz, with a link that can be shared.
+#[component]
+pub fn PublicQuizExhibit(
+    surreal_quiz: SurrealQuiz,
+    quiz_selector: Callback<SurrealQuiz>,
+) -> impl IntoView {
+    let share_link: String = format!("/quiz/{}", surreal_quiz.id.to_raw());
+    let quiz: StoredValue<SurrealQuiz> = store_value(surreal_quiz);
+
+    // -- Render View --
+    view! {
+        <Card on_click=None>
+            <h3>"Name: "{quiz.with_value(|qz| qz.name.clone())}</h3>
+            <p>{quiz.with_value(|qz| qz.description.clone())}</p>
+            <div
+                class:horizontal-even=true
+            >
+                <button
+                    data-note="take_public_quiz_button"
+                    on:click=move |_| quiz_selector.call(quiz.get_value())
+                >"Take Quiz"</button>
+                <LikeButton
+                    target=quiz.with_value(|qz| qz.id.clone())
+                    likes=quiz.with_value(|qz| qz.likes)
+                    liked=quiz.with_value(|qz| qz.liked)
+                />
+                <a href=share_link>"Share Link"</a>
+            </div>
+        </Card>
+    }
+}
@@ -1,10 +1,13 @@
 //! frontend/src/components/dashboard/create_questions.rs
 //! This component will handle the initial question making procecss
 use crate::{
-    components::dashboard::{QuestionMold, QuestionShowcase},
+    components::dashboard::{CsvUpload, QuestionMold, QuestionShowcase},
     models::{
         mimic_surreal::SurrealQuiz,
-        questions::{AllQuestions, JsonQuestion, QLInternals, QuestType},
+        questions::{
+            AllQuestions, JsonQuestion, JsonQuestionLA, JsonQuestionMS, JsonQuestionSA,
+            JsonQuestionTF, QLInternals, QuestType,
+        },
     },
     store::AppSettings,
     utils::DashDisplay,
@@ -42,6 +45,50 @@ pub fn QuestionForge(
             *val = *val + 1;
         });
     };
+    let add_short_answer = move |_event: ev::MouseEvent| {
+        new_question_signal.update(|this| {
+            this.push(QLInternals {
+                id: bin_count.get(),
+                data: JsonQuestion::ShortAnswer(JsonQuestionSA::default()),
+            })
+        });
+        bin_count.update(|val| {
+            *val += 1;
+        });
+    };
+    let add_long_answer = move |_event: ev::MouseEvent| {
+        new_question_signal.update(|this| {
+            this.push(QLInternals {
+                id: bin_count.get(),
+                data: JsonQuestion::LongAnswer(JsonQuestionLA::default()),
+            })
+        });
+        bin_count.update(|val| {
+            *val += 1;
+        });
+    };
+    let add_true_false = move |_event: ev::MouseEvent| {
+        new_question_signal.update(|this| {
+            this.push(QLInternals {
+                id: bin_count.get(),
+                data: JsonQuestion::TrueFalse(JsonQuestionTF::default()),
+            })
+        });
+        bin_count.update(|val| {
+            *val += 1;
+        });
+    };
+    let add_multi_select = move |_event: ev::MouseEvent| {
+        new_question_signal.update(|this| {
+            this.push(QLInternals {
+                id: bin_count.get(),
+                data: JsonQuestion::MultiSelect(JsonQuestionMS::default()),
+            })
+        });
+        bin_count.update(|val| {
+            *val += 1;
+        });
+    };
     let add_quest = Callback::new(move |q: QuestType| {
         quest_signal.update(|this| {
             this.push(q);
@@ -89,6 +136,22 @@ pub fn QuestionForge(
                     for surreal_quest_mc in data.mc {
                         quest_signal.update(|this| this.push(QuestType::MC(surreal_quest_mc)));
                     }
+                    data.sa.sort_by_key(|a| a.id.to_raw());
+                    for surreal_quest_sa in data.sa {
+                        quest_signal.update(|this| this.push(QuestType::SA(surreal_quest_sa)));
+                    }
+                    data.la.sort_by_key(|a| a.id.to_raw());
+                    for surreal_quest_la in data.la {
+                        quest_signal.update(|this| this.push(QuestType::LA(surreal_quest_la)));
+                    }
+                    data.tf.sort_by_key(|a| a.id.to_raw());
+                    for surreal_quest_tf in data.tf {
+                        quest_signal.update(|this| this.push(QuestType::TF(surreal_quest_tf)));
+                    }
+                    data.ms.sort_by_key(|a| a.id.to_raw());
+                    for surreal_quest_ms in data.ms {
+                        quest_signal.update(|this| this.push(QuestType::MS(surreal_quest_ms)));
+                    }
                 } else {
                     // Todo: display error message somewhere for failed fetch?
                     let _deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
@@ -137,6 +200,11 @@ pub fn QuestionForge(
                 }
             />
             <button on:click=add_question>"+ add question"</button>
+            <button on:click=add_short_answer>"+ add short answer"</button>
+            <button on:click=add_long_answer>"+ add long answer"</button>
+            <button on:click=add_true_false>"+ add true or false"</button>
+            <button on:click=add_multi_select>"+ add multi-select"</button>
+            <CsvUpload quiz_data=quiz_data add_quest=add_quest/>
         </>
     }
 }
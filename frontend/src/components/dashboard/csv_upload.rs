@@ -0,0 +1,120 @@
+//! frontend/src/components/dashboard/csv_upload.rs
+//! This component uploads a spreadsheet of multiple choice questions to a quiz.
+//! Columns are question, answer, choice1, choice2, ... and an optional hint.
+use leptos::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{File, Headers, RequestMode, Response};
+
+use crate::{
+    models::{
+        csv_upload::{CsvRejection, CsvRowError},
+        mimic_surreal::{SurrealQuestionMC, SurrealQuiz},
+        questions::QuestType,
+    },
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+
+/// To render the CSV upload widget of the `QuestionForge`.
+#[component]
+pub fn CsvUpload(
+    quiz_data: ReadSignal<Option<SurrealQuiz>>,
+    add_quest: Callback<QuestType>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let row_errors: RwSignal<Vec<CsvRowError>> = create_rw_signal(Vec::new());
+    let msg: RwSignal<Option<String>> = create_rw_signal(None);
+    // -- Create References --
+    let csv_file: NodeRef<html::Input> = create_node_ref();
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Actions --
+    let upload_action = create_action(move |file: &File| {
+        let text_promise = file.text();
+        let quiz_id: String = quiz_data
+            .get_untracked()
+            .map(|quiz| quiz.id.to_raw())
+            .unwrap_or_default();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "text/csv;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.clone() + "question-forge/csv")
+            .add_query_param("quiz", &quiz_id)
+            .set_method("POST")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let Some(body) = JsFuture::from(text_promise)
+                .await
+                .ok()
+                .and_then(|text| text.as_string())
+            else {
+                msg.set(Some(String::from("Unable to read the file")));
+                return;
+            };
+            let response: Response = fetcher.fetch(Some(body)).await;
+            if response.status() == 201 {
+                let created: Vec<SurrealQuestionMC> = Fetcher::response_to_struct(&response).await;
+                msg.set(Some(format!("Added {} questions", created.len())));
+                row_errors.set(Vec::new());
+                for question in created {
+                    add_quest.call(QuestType::MC(question));
+                }
+                return;
+            }
+            // Bad rows come back with the row numbers, other problems as a message
+            let data: serde_json::Value = Fetcher::response_to_struct(&response).await;
+            match serde_json::from_value::<CsvRejection>(data.clone()) {
+                Ok(rejection) => {
+                    msg.set(Some(rejection.msg));
+                    row_errors.set(rejection.rows);
+                }
+                Err(_) => {
+                    let json_msg: JsonMsg = serde_json::from_value(data).unwrap_or_default();
+                    msg.set(json_msg.msg);
+                    row_errors.set(Vec::new());
+                }
+            }
+        }
+    });
+
+    let send_file = move |_event: ev::MouseEvent| {
+        let file: Option<File> = csv_file
+            .get()
+            .expect("<input> should be mounted")
+            .files()
+            .and_then(|files| files.get(0));
+        match file {
+            Some(file) => upload_action.dispatch(file),
+            None => msg.set(Some(String::from("Choose a CSV file first"))),
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <div class:quiz-make-container=true>
+            <h3>"Upload Multiple Choice Questions"</h3>
+            <p>
+                "CSV columns: question, answer, choice1, choice2, ... and an optional hint. "
+                "Nothing is added unless every row is valid."
+            </p>
+            <input type="file" accept=".csv,text/csv" node_ref=csv_file/>
+            <button on:click=send_file>"Upload CSV"</button>
+            <h5>{move || msg.get()}</h5>
+            <ul>
+                <For
+                    each=move || row_errors.get()
+                    key=|err| err.row
+                    children=move |err| {
+                        view! { <li>"Row "{err.row}": "{err.error}</li> }
+                    }
+                />
+            </ul>
+        </div>
+    }
+}
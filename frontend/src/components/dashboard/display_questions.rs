@@ -1,18 +1,22 @@
 //! frontend/src/components/dashboard/display_questions.rs
 //! This component will handle the question rendering procecss for viewing and editing Questions
 use crate::{
-    components::dashboard::QuestionCalibrateMC, models::mimic_surreal::SurrealQuestionMC,
-    models::questions::QuestType, store::AppSettings, utils::Fetcher,
+    components::dashboard::{
+        QuestionCalibrateLA, QuestionCalibrateMC, QuestionCalibrateMS, QuestionCalibrateSA,
+        QuestionCalibrateTF,
+    },
+    components::LikeButton,
+    models::mimic_surreal::{
+        SurrealQuestionLA, SurrealQuestionMC, SurrealQuestionMS, SurrealQuestionSA,
+        SurrealQuestionTF,
+    },
+    models::questions::QuestType,
+    store::AppSettings,
+    utils::Fetcher,
 };
 use leptos::*;
 use web_sys::{Headers, RequestMode, Response};
 
-/// A Dummy Component that should never be rendered
-#[component]
-fn Unimplemented() -> impl IntoView {
-    view! {<div>"Not Implemented"</div>}
-}
-
 /// Component to display questions, for review or to be edited.
 /// This component should not perform the editing.
 #[component]
@@ -47,7 +51,46 @@ pub fn QuestionShowcase(
                             />
                         }
                     }
-                    _ => view! {<Unimplemented />},
+                    QuestType::SA(data) => {
+                        view! {
+                            <QuestionCalibrateSA
+                                quest_sa=data.to_owned()
+                                add_quest=add_quest
+                                pop_quest=pop_quest
+                                cancel_edit=unchoose_edit
+                            />
+                        }
+                    }
+                    QuestType::LA(data) => {
+                        view! {
+                            <QuestionCalibrateLA
+                                quest_la=data.to_owned()
+                                add_quest=add_quest
+                                pop_quest=pop_quest
+                                cancel_edit=unchoose_edit
+                            />
+                        }
+                    }
+                    QuestType::TF(data) => {
+                        view! {
+                            <QuestionCalibrateTF
+                                quest_tf=data.to_owned()
+                                add_quest=add_quest
+                                pop_quest=pop_quest
+                                cancel_edit=unchoose_edit
+                            />
+                        }
+                    }
+                    QuestType::MS(data) => {
+                        view! {
+                            <QuestionCalibrateMS
+                                quest_ms=data.to_owned()
+                                add_quest=add_quest
+                                pop_quest=pop_quest
+                                cancel_edit=unchoose_edit
+                            />
+                        }
+                    }
                 }
             } else {
                 match &quest_type {
@@ -60,7 +103,42 @@ pub fn QuestionShowcase(
                             />
                         }
                     }
-                    _ => view! {<Unimplemented />},
+                    QuestType::SA(data) => {
+                        view! {
+                            <QuestionExhibitSA
+                                data=data.to_owned()
+                                click_edit=choose_edit
+                                pop_quest=pop_quest
+                            />
+                        }
+                    }
+                    QuestType::LA(data) => {
+                        view! {
+                            <QuestionExhibitLA
+                                data=data.to_owned()
+                                click_edit=choose_edit
+                                pop_quest=pop_quest
+                            />
+                        }
+                    }
+                    QuestType::TF(data) => {
+                        view! {
+                            <QuestionExhibitTF
+                                data=data.to_owned()
+                                click_edit=choose_edit
+                                pop_quest=pop_quest
+                            />
+                        }
+                    }
+                    QuestType::MS(data) => {
+                        view! {
+                            <QuestionExhibitMS
+                                data=data.to_owned()
+                                click_edit=choose_edit
+                                pop_quest=pop_quest
+                            />
+                        }
+                    }
                 }
             }
         }}
@@ -120,6 +198,287 @@ pub fn QuestionExhibitMC(
             <div
                 class="horizontal-even"
             >
+                <LikeButton
+                    target=quest_signal.get_untracked().id
+                    likes=quest_signal.get_untracked().likes
+                    liked=quest_signal.get_untracked().liked
+                />
+                <button
+                    data-note="edit_quest_button"
+                    on:click=move |_| click_edit.call(())
+                >"Edit"</button>
+                <button
+                    data-note="delete_quest_button"
+                    on:click=move |_| destroy_quest_action.dispatch(())
+                >"Delete"</button>
+            </div>
+        </div>
+    }
+}
+
+/// Component to display Short Answer questions
+/// and allow for them to be editted or deleted.
+#[component]
+pub fn QuestionExhibitSA(
+    data: SurrealQuestionSA,
+    click_edit: Callback<()>,
+    pop_quest: Callback<QuestType>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let quest_signal: RwSignal<SurrealQuestionSA> = create_rw_signal(data);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Actions --
+    let destroy_quest_action = create_action(move |_| {
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_signal.get().id.to_raw())
+            .set_method("DELETE")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(None).await;
+            if response.status() == 200 {
+                let del_quest: SurrealQuestionSA = Fetcher::response_to_struct(&response).await;
+                pop_quest.call(QuestType::SA(del_quest));
+            }
+        }
+    });
+    view! {
+        <div
+            class="quest-case"
+        >
+            <p>"Q: "{move || quest_signal.get().question}</p>
+            <p>"Hint: "{move || quest_signal.get().hint}</p>
+            <p>"Accepted: "{move || quest_signal.get().answers.join("; ")}</p>
+            <div
+                class="horizontal-even"
+            >
+                <LikeButton
+                    target=quest_signal.get_untracked().id
+                    likes=quest_signal.get_untracked().likes
+                    liked=quest_signal.get_untracked().liked
+                />
+                <button
+                    data-note="edit_quest_button"
+                    on:click=move |_| click_edit.call(())
+                >"Edit"</button>
+                <button
+                    data-note="delete_quest_button"
+                    on:click=move |_| destroy_quest_action.dispatch(())
+                >"Delete"</button>
+            </div>
+        </div>
+    }
+}
+
+/// Component to display Long Answer questions with their rubric
+/// and allow for them to be editted or deleted.
+#[component]
+pub fn QuestionExhibitLA(
+    data: SurrealQuestionLA,
+    click_edit: Callback<()>,
+    pop_quest: Callback<QuestType>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let quest_signal: RwSignal<SurrealQuestionLA> = create_rw_signal(data);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Actions --
+    let destroy_quest_action = create_action(move |_| {
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_signal.get().id.to_raw())
+            .set_method("DELETE")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(None).await;
+            if response.status() == 200 {
+                let del_quest: SurrealQuestionLA = Fetcher::response_to_struct(&response).await;
+                pop_quest.call(QuestType::LA(del_quest));
+            }
+        }
+    });
+    view! {
+        <div
+            class="quest-case"
+        >
+            <p>"Q: "{move || quest_signal.get().question}</p>
+            <p>"Hint: "{move || quest_signal.get().hint}</p>
+            <p>"Model Answer: "{move || quest_signal.get().model_answer}</p>
+            <For
+                each=move || quest_signal.get().rubric.into_iter().enumerate()
+                key=|(idx, _)| *idx
+                children=move |(_, rc)| view! {
+                    <p>"Rubric ("{rc.weight}"): "{rc.criterion}</p>
+                }
+            />
+            <div
+                class="horizontal-even"
+            >
+                <LikeButton
+                    target=quest_signal.get_untracked().id
+                    likes=quest_signal.get_untracked().likes
+                    liked=quest_signal.get_untracked().liked
+                />
+                <button
+                    data-note="edit_quest_button"
+                    on:click=move |_| click_edit.call(())
+                >"Edit"</button>
+                <button
+                    data-note="delete_quest_button"
+                    on:click=move |_| destroy_quest_action.dispatch(())
+                >"Delete"</button>
+            </div>
+        </div>
+    }
+}
+
+/// Component to display True or False questions
+/// and allow for them to be editted or deleted.
+#[component]
+pub fn QuestionExhibitTF(
+    data: SurrealQuestionTF,
+    click_edit: Callback<()>,
+    pop_quest: Callback<QuestType>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let quest_signal: RwSignal<SurrealQuestionTF> = create_rw_signal(data);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Actions --
+    let destroy_quest_action = create_action(move |_| {
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_signal.get().id.to_raw())
+            .set_method("DELETE")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(None).await;
+            if response.status() == 200 {
+                let del_quest: SurrealQuestionTF = Fetcher::response_to_struct(&response).await;
+                pop_quest.call(QuestType::TF(del_quest));
+            }
+        }
+    });
+    view! {
+        <div
+            class="quest-case"
+        >
+            <p>"Q: "{move || quest_signal.get().question}</p>
+            <p>"Hint: "{move || quest_signal.get().hint}</p>
+            <p>"A: "{move || if quest_signal.get().answer { "True" } else { "False" }}</p>
+            <div
+                class="horizontal-even"
+            >
+                <LikeButton
+                    target=quest_signal.get_untracked().id
+                    likes=quest_signal.get_untracked().likes
+                    liked=quest_signal.get_untracked().liked
+                />
+                <button
+                    data-note="edit_quest_button"
+                    on:click=move |_| click_edit.call(())
+                >"Edit"</button>
+                <button
+                    data-note="delete_quest_button"
+                    on:click=move |_| destroy_quest_action.dispatch(())
+                >"Delete"</button>
+            </div>
+        </div>
+    }
+}
+
+/// Component to display Multi-Select questions
+/// and allow for them to be editted or deleted.
+#[component]
+pub fn QuestionExhibitMS(
+    data: SurrealQuestionMS,
+    click_edit: Callback<()>,
+    pop_quest: Callback<QuestType>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let quest_signal: RwSignal<SurrealQuestionMS> = create_rw_signal(data);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Actions --
+    let destroy_quest_action = create_action(move |_| {
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_signal.get().id.to_raw())
+            .set_method("DELETE")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(None).await;
+            if response.status() == 200 {
+                let del_quest: SurrealQuestionMS = Fetcher::response_to_struct(&response).await;
+                pop_quest.call(QuestType::MS(del_quest));
+            }
+        }
+    });
+    view! {
+        <div
+            class="quest-case"
+        >
+            <p>"Q: "{move || quest_signal.get().question}</p>
+            <p>"Hint: "{move || quest_signal.get().hint}</p>
+            <For
+                each=move || quest_signal.get().answers.clone()
+                key=|this| this.bytes().fold(0u32, |sum, byte| sum.wrapping_add(byte as u32))
+                children=move |it| view! {
+                    <p>"Correct: "{it}</p>
+                }
+            />
+            <For
+                each=move || quest_signal.get().choices.clone()
+                key=|this| this.bytes().fold(0u32, |sum, byte| sum.wrapping_add(byte as u32))
+                children=move |it| view! {
+                    <p>"Wrong: "{it}</p>
+                }
+            />
+            <div
+                class="horizontal-even"
+            >
+                <LikeButton
+                    target=quest_signal.get_untracked().id
+                    likes=quest_signal.get_untracked().likes
+                    liked=quest_signal.get_untracked().liked
+                />
                 <button
                     data-note="edit_quest_button"
                     on:click=move |_| click_edit.call(())
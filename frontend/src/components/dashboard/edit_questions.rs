@@ -1,8 +1,15 @@
 //! frontend/src/components/dashboard/edit_questions.rs
 //! This component will handle the initial question making procecss
 use crate::{
-    models::mimic_surreal::SurrealQuestionMC,
-    models::questions::{EditQuestionJsonPkg, JsonQuestion, JsonQuestionMC, QuestType},
+    components::dashboard::{parse_rubric, rubric_to_text, split_accepted_answers},
+    models::mimic_surreal::{
+        SurrealQuestionLA, SurrealQuestionMC, SurrealQuestionMS, SurrealQuestionSA,
+        SurrealQuestionTF,
+    },
+    models::questions::{
+        EditQuestionJsonPkg, JsonQuestion, JsonQuestionLA, JsonQuestionMC, JsonQuestionMS,
+        JsonQuestionSA, JsonQuestionTF, QuestType,
+    },
     store::AppSettings,
     utils::{Fetcher, JsonMsg},
 };
@@ -169,3 +176,525 @@ pub fn QuestionCalibrateMC(
         </form>
     }
 }
+
+/// Calibrate a Short Answer question.
+#[component]
+pub fn QuestionCalibrateSA(
+    quest_sa: SurrealQuestionSA,
+    add_quest: Callback<QuestType>,
+    pop_quest: Callback<QuestType>,
+    cancel_edit: Callback<()>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+    let quest_sig: RwSignal<SurrealQuestionSA> = create_rw_signal(quest_sa);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let answers_ref: NodeRef<html::Input> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let update_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_sig.get().id.to_raw())
+            .set_method("PUT")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionSA = Fetcher::response_to_struct(&response).await;
+                // Pop and Add the Updated Quest
+                pop_quest.call(QuestType::SA(data.clone()));
+                add_quest.call(QuestType::SA(data));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let answers_val: String = answers_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        let pre_pkg: EditQuestionJsonPkg = EditQuestionJsonPkg {
+            question: JsonQuestion::ShortAnswer(JsonQuestionSA {
+                question: String::from(question_val.trim()),
+                hint: real_hint,
+                answers: split_accepted_answers(&answers_val),
+            }),
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            update_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"Question Calibration"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input
+                type="text"
+                placeholder="question"
+                node_ref=question_ref required
+                value=move || quest_sig.get().question
+            />
+            <input
+                type="text"
+                placeholder="hint"
+                node_ref=hint_ref
+                value=move || quest_sig.get().hint
+            />
+            <input
+                type="text"
+                placeholder="accepted answers; separated; by semicolons"
+                node_ref=answers_ref required
+                value=move || quest_sig.get().answers.join("; ")
+            />
+            <div
+                class="button-case"
+            >
+                <input type="submit" value="Save" />
+                <button on:click=move |_| cancel_edit.call(())>"Cancel"</button>
+            </div>
+        </form>
+    }
+}
+
+/// Calibrate a Long Answer question and its rubric.
+#[component]
+pub fn QuestionCalibrateLA(
+    quest_la: SurrealQuestionLA,
+    add_quest: Callback<QuestType>,
+    pop_quest: Callback<QuestType>,
+    cancel_edit: Callback<()>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+    let quest_sig: RwSignal<SurrealQuestionLA> = create_rw_signal(quest_la);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let model_answer_ref: NodeRef<html::Textarea> = create_node_ref();
+    let rubric_ref: NodeRef<html::Textarea> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let update_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_sig.get().id.to_raw())
+            .set_method("PUT")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionLA = Fetcher::response_to_struct(&response).await;
+                // Pop and Add the Updated Quest
+                pop_quest.call(QuestType::LA(data.clone()));
+                add_quest.call(QuestType::LA(data));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let model_answer_val: String = model_answer_ref
+            .get()
+            .expect("<textarea> should be mounted")
+            .value();
+        let rubric_val: String = rubric_ref
+            .get()
+            .expect("<textarea> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        let pre_pkg: EditQuestionJsonPkg = EditQuestionJsonPkg {
+            question: JsonQuestion::LongAnswer(JsonQuestionLA {
+                question: String::from(question_val.trim()),
+                hint: real_hint,
+                model_answer: String::from(model_answer_val.trim()),
+                rubric: parse_rubric(&rubric_val),
+            }),
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            update_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"Question Calibration"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input
+                type="text"
+                placeholder="question"
+                node_ref=question_ref required
+                value=move || quest_sig.get().question
+            />
+            <input
+                type="text"
+                placeholder="hint"
+                node_ref=hint_ref
+                value=move || quest_sig.get().hint
+            />
+            <textarea
+                placeholder="model answer"
+                node_ref=model_answer_ref
+                required
+                prop:value=move || quest_sig.get().model_answer
+            />
+            <textarea
+                placeholder="rubric, one criterion per line, e.g. 2: Gives an example"
+                node_ref=rubric_ref
+                required
+                prop:value=move || rubric_to_text(&quest_sig.get().rubric)
+            />
+            <div
+                class="button-case"
+            >
+                <input type="submit" value="Save" />
+                <button on:click=move |_| cancel_edit.call(())>"Cancel"</button>
+            </div>
+        </form>
+    }
+}
+
+/// Calibrate a True or False question.
+#[component]
+pub fn QuestionCalibrateTF(
+    quest_tf: SurrealQuestionTF,
+    add_quest: Callback<QuestType>,
+    pop_quest: Callback<QuestType>,
+    cancel_edit: Callback<()>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+    let quest_sig: RwSignal<SurrealQuestionTF> = create_rw_signal(quest_tf);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let answer_ref: NodeRef<html::Select> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let update_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_sig.get().id.to_raw())
+            .set_method("PUT")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionTF = Fetcher::response_to_struct(&response).await;
+                // Pop and Add the Updated Quest
+                pop_quest.call(QuestType::TF(data.clone()));
+                add_quest.call(QuestType::TF(data));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let answer_val: String = answer_ref
+            .get()
+            .expect("<select> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        let pre_pkg: EditQuestionJsonPkg = EditQuestionJsonPkg {
+            question: JsonQuestion::TrueFalse(JsonQuestionTF {
+                question: String::from(question_val.trim()),
+                hint: real_hint,
+                answer: answer_val == "true",
+            }),
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            update_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"Question Calibration"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input
+                type="text"
+                placeholder="question"
+                node_ref=question_ref required
+                value=move || quest_sig.get().question
+            />
+            <input
+                type="text"
+                placeholder="hint"
+                node_ref=hint_ref
+                value=move || quest_sig.get().hint
+            />
+            <select node_ref=answer_ref>
+                <option value="true" selected=move || quest_sig.get().answer>"True"</option>
+                <option value="false" selected=move || !quest_sig.get().answer>"False"</option>
+            </select>
+            <div
+                class="button-case"
+            >
+                <input type="submit" value="Save" />
+                <button on:click=move |_| cancel_edit.call(())>"Cancel"</button>
+            </div>
+        </form>
+    }
+}
+
+/// Calibrate a Multi-Select question.
+#[component]
+pub fn QuestionCalibrateMS(
+    quest_ms: SurrealQuestionMS,
+    add_quest: Callback<QuestType>,
+    pop_quest: Callback<QuestType>,
+    cancel_edit: Callback<()>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+    let quest_sig: RwSignal<SurrealQuestionMS> = create_rw_signal(quest_ms);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let answers_ref: NodeRef<html::Input> = create_node_ref();
+    let choices_ref: NodeRef<html::Input> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let update_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .add_query_param("quest", &quest_sig.get().id.to_raw())
+            .set_method("PUT")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionMS = Fetcher::response_to_struct(&response).await;
+                // Pop and Add the Updated Quest
+                pop_quest.call(QuestType::MS(data.clone()));
+                add_quest.call(QuestType::MS(data));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let answers_val: String = answers_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let choices_val: String = choices_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        let pre_pkg: EditQuestionJsonPkg = EditQuestionJsonPkg {
+            question: JsonQuestion::MultiSelect(JsonQuestionMS {
+                question: String::from(question_val.trim()),
+                hint: real_hint,
+                answers: split_accepted_answers(&answers_val),
+                choices: split_accepted_answers(&choices_val),
+            }),
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            update_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"Question Calibration"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input
+                type="text"
+                placeholder="question"
+                node_ref=question_ref required
+                value=move || quest_sig.get().question
+            />
+            <input
+                type="text"
+                placeholder="hint"
+                node_ref=hint_ref
+                value=move || quest_sig.get().hint
+            />
+            <input
+                type="text"
+                placeholder="correct choices; separated; by semicolons"
+                node_ref=answers_ref required
+                value=move || quest_sig.get().answers.join("; ")
+            />
+            <input
+                type="text"
+                placeholder="wrong choices; separated; by semicolons"
+                node_ref=choices_ref
+                value=move || quest_sig.get().choices.join("; ")
+            />
+            <div
+                class="button-case"
+            >
+                <input type="submit" value="Save" />
+                <button on:click=move |_| cancel_edit.call(())>"Cancel"</button>
+            </div>
+        </form>
+    }
+}
@@ -5,7 +5,16 @@ use leptos::*;
 use web_sys::{Headers, RequestMode, Response};
 
 use crate::{
-    components::Card, models::mimic_surreal::SurrealQuiz, store::AppSettings, utils::Fetcher,
+    components::{
+        dashboard::{TagBar, TagEditor},
+        Card, LikeButton,
+    },
+    models::{
+        mimic_surreal::{SurrealQuiz, Thing},
+        tags::TagSummary,
+    },
+    store::AppSettings,
+    utils::Fetcher,
 };
 
 /// The Show Case is like a container to display all quizzes by a user.
@@ -14,14 +23,31 @@ use crate::{
 pub fn QuizShowCase(
     quiz_list: RwSignal<Vec<SurrealQuiz>>,
     quiz_selector: Callback<SurrealQuiz>,
+    quiz_studier: Callback<SurrealQuiz>,
     pop_quiz: Callback<SurrealQuiz>,
     quiz_updater: Callback<SurrealQuiz>,
     quest_calibrate: Callback<SurrealQuiz>,
 ) -> impl IntoView {
     // -- Create Signals --
+    let tag_list: RwSignal<Vec<TagSummary>> = create_rw_signal(Vec::new());
+    let tag_filter: RwSignal<Option<Thing>> = create_rw_signal(None);
     // -- Create References --
     // -- Use Context --
 
+    // Quizzes with the chosen tag, or every quiz
+    let shown_quizzes = move || {
+        let filter: Option<Thing> = tag_filter.get();
+        quiz_list
+            .get()
+            .into_iter()
+            .filter(|qz| {
+                filter
+                    .as_ref()
+                    .is_none_or(|tag_id| qz.tags.iter().any(|tg| &tg.id == tag_id))
+            })
+            .collect::<Vec<SurrealQuiz>>()
+    };
+
     // -- Render View --
     view! {
         <div
@@ -29,17 +55,21 @@ pub fn QuizShowCase(
             class:quiz-showcase-container=true
         >
             <h2>"My Quizzes!"</h2>
+            <TagBar quiz_list=quiz_list tag_list=tag_list tag_filter=tag_filter />
             <For
-                each=move || quiz_list.get()
+                each=shown_quizzes
                 key=|q| q.id.to_raw()
                 children=move |this| view! {
                     // Card has to be in the Exhibit to obtain information.
                     <QuizExhibit
                         surreal_quiz=this
                         quiz_selector=quiz_selector
+                        quiz_studier=quiz_studier
                         pop_quiz=pop_quiz
                         quiz_updater=quiz_updater
                         quest_calibrate=quest_calibrate
+                        quiz_list=quiz_list
+                        tag_list=tag_list
                     />
                 }
             />
@@ -53,9 +83,12 @@ pub fn QuizShowCase(
 pub fn QuizExhibit(
     surreal_quiz: SurrealQuiz,
     quiz_selector: Callback<SurrealQuiz>,
+    quiz_studier: Callback<SurrealQuiz>,
     pop_quiz: Callback<SurrealQuiz>,
     quiz_updater: Callback<SurrealQuiz>,
     quest_calibrate: Callback<SurrealQuiz>,
+    quiz_list: RwSignal<Vec<SurrealQuiz>>,
+    tag_list: RwSignal<Vec<TagSummary>>,
 ) -> impl IntoView {
     // -- Create Signals --
     let quiz_sig: RwSignal<SurrealQuiz> = create_rw_signal(surreal_quiz);
@@ -63,11 +96,20 @@ pub fn QuizExhibit(
     // -- Use Context --
     let app_settings: AppSettings =
         use_context::<AppSettings>().expect("AppSettings context not found");
+    // Downloads are plain links, the session cookie goes along with them
+    let export_url: String = format!(
+        "{}quiz-nexus/export?quiz={}",
+        app_settings.backend_url,
+        urlencoding::encode(&quiz_sig.get_untracked().id.to_raw())
+    );
 
     // -- Create Closures
     let take_quiz_closure = move |_| {
         quiz_selector.call(quiz_sig.get());
     };
+    let study_quiz_closure = move |_| {
+        quiz_studier.call(quiz_sig.get());
+    };
     let update_quiz_closure = move |_| {
         quiz_updater.call(quiz_sig.get());
     };
@@ -109,6 +151,15 @@ pub fn QuizExhibit(
                     data-note="take_quiz_button"
                     on:click=take_quiz_closure
                 >"Take Quiz"</button>
+                <button
+                    data-note="study_quiz_button"
+                    on:click=study_quiz_closure
+                >"Study"</button>
+                <LikeButton
+                    target=quiz_sig.get_untracked().id
+                    likes=quiz_sig.get_untracked().likes
+                    liked=quiz_sig.get_untracked().liked
+                />
                 <button
                     data-note="update_quiz_button"
                     on:click=update_quiz_closure
@@ -121,6 +172,19 @@ pub fn QuizExhibit(
                     on:click=move |_| destroy_quiz_action.dispatch(())
                 >"Delete Quiz"</button>
             </div>
+            <div class:horizontal-even=true>
+                <a href=format!("{}&format=json", export_url) target="_blank">"Export JSON"</a>
+                <a href=format!("{}&format=yaml", export_url) target="_blank">"Export YAML"</a>
+                <a href=format!("{}&format=gift", export_url) target="_blank">"Export GIFT"</a>
+                <a href=format!("{}&format=xml", export_url) target="_blank">
+                    "Export Moodle XML"
+                </a>
+            </div>
+            <TagEditor
+                quiz_id=quiz_sig.get_untracked().id
+                quiz_list=quiz_list
+                tag_list=tag_list
+            />
         </Card>
     }
 }
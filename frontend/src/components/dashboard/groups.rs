@@ -0,0 +1,450 @@
+//! frontend/src/components/dashboard/groups.rs
+//! Components for groups, where instructors assign quizzes and students take them.
+//! Owners manage members and assignments, members see what is assigned to them.
+use leptos::*;
+use web_sys::{js_sys::Date, wasm_bindgen::JsValue, RequestMode, Response};
+
+use crate::{
+    components::Card,
+    models::{
+        groups::{
+            AssignedQuiz, AssignmentJsonPkg, AssignmentProgress, GroupJsonPkg, GroupSummary,
+            InvitePkg, JoinPkg, SurrealAssignment,
+        },
+        mimic_surreal::{SurrealQuiz, Thing},
+    },
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg, PartialUser},
+};
+
+/// Turns a `datetime-local` input value, in the browser's time zone, into RFC 3339 in UTC.
+fn local_to_rfc3339(local: &str) -> Option<String> {
+    let date: Date = Date::new(&JsValue::from_str(local));
+    // Invalid dates have no time value
+    if date.get_time().is_nan() {
+        None
+    } else {
+        Some(String::from(date.to_iso_string()))
+    }
+}
+
+/// Shows a time from the backend in the browser's time zone.
+fn display_time(rfc3339: &str) -> String {
+    Date::new(&JsValue::from_str(rfc3339))
+        .to_locale_string("default", &JsValue::UNDEFINED)
+        .into()
+}
+
+/// Container for the user's assignments and groups.
+#[component]
+pub fn GroupHall(
+    quiz_list: RwSignal<Vec<SurrealQuiz>>,
+    quiz_selector: Callback<SurrealQuiz>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let group_list: RwSignal<Vec<GroupSummary>> = create_rw_signal(Vec::new());
+    let assigned_list: RwSignal<Vec<AssignedQuiz>> = create_rw_signal(Vec::new());
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+
+    // -- Use Context --
+    let user: PartialUser = use_context().expect("PartialUser Context not set");
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+    let groups_url: StoredValue<String> = store_value(app_settings.backend_url.clone() + "groups");
+
+    // -- Create Resource --
+    // Refetched after joining or creating a group
+    let refresh: RwSignal<u32> = create_rw_signal(0);
+    let groups_resource = create_resource(
+        move || refresh.get(),
+        move |_| {
+            let groups_fetcher: Fetcher = Fetcher::init()
+                .set_url(groups_url.get_value())
+                .set_method("GET")
+                .set_mode(RequestMode::Cors)
+                .build();
+            let assigned_fetcher: Fetcher = Fetcher::init()
+                .set_url(groups_url.get_value() + "/assignments")
+                .set_method("GET")
+                .set_mode(RequestMode::Cors)
+                .build();
+            async move {
+                let response: Response = groups_fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: Vec<GroupSummary> = Fetcher::response_to_struct(&response).await;
+                    group_list.set(data);
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
+                }
+                let response: Response = assigned_fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: Vec<AssignedQuiz> = Fetcher::response_to_struct(&response).await;
+                    assigned_list.set(data);
+                }
+            }
+        },
+    );
+
+    // -- Create Effect --
+    create_effect(move |_| {
+        groups_resource.get();
+    });
+
+    // -- Create Actions --
+    let create_group_action = create_action(move |name: &String| {
+        let pkg: GroupJsonPkg = GroupJsonPkg { name: name.clone() };
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(groups_url.get_value())
+            .set_method("POST")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let pkg: String = match serde_json::to_string(&pkg) {
+                Ok(pkg) => pkg,
+                Err(_) => return,
+            };
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 201 {
+                err_msg.set(None);
+                refresh.update(|rf| *rf += 1);
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    let join_group_action = create_action(move |join_code: &String| {
+        let pkg: JoinPkg = JoinPkg {
+            join_code: join_code.clone(),
+        };
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(groups_url.get_value() + "/join")
+            .set_method("POST")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let pkg: String = match serde_json::to_string(&pkg) {
+                Ok(pkg) => pkg,
+                Err(_) => return,
+            };
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 201 {
+                err_msg.set(None);
+                refresh.update(|rf| *rf += 1);
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // Both forms are a single text box, submitted on change
+    let submit_text = move |evnt: ev::Event, action: Action<String, ()>| {
+        let text: String = event_target_value(&evnt);
+        if !text.trim().is_empty() {
+            action.dispatch(text);
+            event_target::<web_sys::HtmlInputElement>(&evnt).set_value("");
+        }
+    };
+
+    let nothing_assigned = move || assigned_list.with(|al| al.is_empty());
+
+    // -- Render View --
+    view! {
+        <div
+            class:quiz-showcase-container=true
+        >
+            <h2>"Assigned to Me"</h2>
+            <Show when=nothing_assigned>
+                <p>"Nothing assigned yet."</p>
+            </Show>
+            <For
+                each=move || assigned_list.get()
+                key=|aq| aq.id.to_raw()
+                children=move |this| {
+                    let quiz: StoredValue<SurrealQuiz> = store_value(this.quiz.clone());
+                    view! {
+                        <Card on_click=None>
+                            <h3>{this.quiz.name.clone()}</h3>
+                            <p>"Group: "{this.group_name}</p>
+                            <p>
+                                "Open "{display_time(&this.opens_at)}
+                                " to "{display_time(&this.closes_at)}
+                            </p>
+                            <button on:click=move |_| quiz_selector.call(quiz.get_value())>
+                                "Take Quiz"
+                            </button>
+                        </Card>
+                    }
+                }
+            />
+            <h2>"My Groups"</h2>
+            <h4>{move || err_msg.get()}</h4>
+            <div class:horizontal-even=true>
+                <input
+                    type="text"
+                    placeholder="Join with a code"
+                    on:change=move |evnt| submit_text(evnt, join_group_action)
+                />
+                // Students join groups, they do not run them
+                {user.role.can_author().then(|| view! {
+                    <input
+                        type="text"
+                        placeholder="New group name"
+                        on:change=move |evnt| submit_text(evnt, create_group_action)
+                    />
+                })}
+            </div>
+            <For
+                each=move || group_list.get()
+                key=|gs| (gs.id.to_raw(), gs.members.len(), gs.assignments.len())
+                children=move |this| view! {
+                    <GroupExhibit group=this quiz_list=quiz_list refresh=refresh />
+                }
+            />
+        </div>
+    }
+}
+
+/// A single group, owners can add members and assign quizzes here.
+#[component]
+pub fn GroupExhibit(
+    group: GroupSummary,
+    quiz_list: RwSignal<Vec<SurrealQuiz>>,
+    refresh: RwSignal<u32>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+    let group_id: StoredValue<Thing> = store_value(group.id.clone());
+    // -- Create References --
+    let quiz_select: NodeRef<html::Select> = create_node_ref();
+    let opens_input: NodeRef<html::Input> = create_node_ref();
+    let closes_input: NodeRef<html::Input> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+    let groups_url: StoredValue<String> = store_value(app_settings.backend_url.clone() + "groups");
+
+    // -- Create Actions --
+    let invite_action = create_action(move |username: &String| {
+        let pkg: InvitePkg = InvitePkg {
+            username: username.clone(),
+        };
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(groups_url.get_value() + "/members")
+            .add_query_param("group", &group_id.get_value().to_raw())
+            .set_method("PUT")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let pkg: String = match serde_json::to_string(&pkg) {
+                Ok(pkg) => pkg,
+                Err(_) => return,
+            };
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 201 {
+                refresh.update(|rf| *rf += 1);
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    let assign_action = create_action(move |pkg: &AssignmentJsonPkg| {
+        let pkg: AssignmentJsonPkg = pkg.clone();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(groups_url.get_value() + "/assignments")
+            .add_query_param("group", &group_id.get_value().to_raw())
+            .set_method("POST")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let pkg: String = match serde_json::to_string(&pkg) {
+                Ok(pkg) => pkg,
+                Err(_) => return,
+            };
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 201 {
+                refresh.update(|rf| *rf += 1);
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    let on_invite = move |evnt: ev::Event| {
+        let username: String = event_target_value(&evnt);
+        if !username.trim().is_empty() {
+            invite_action.dispatch(username.trim().to_string());
+            event_target::<web_sys::HtmlInputElement>(&evnt).set_value("");
+        }
+    };
+
+    let on_assign = move |evnt: ev::SubmitEvent| {
+        evnt.prevent_default();
+        let quiz_raw: String = quiz_select
+            .get()
+            .expect("<select> should be mounted")
+            .value();
+        let quiz_id: Option<Thing> = quiz_list.with_untracked(|ql| {
+            ql.iter()
+                .find(|qz| qz.id.to_raw() == quiz_raw)
+                .map(|qz| qz.id.clone())
+        });
+        let opens_at = local_to_rfc3339(
+            &opens_input
+                .get()
+                .expect("<input> should be mounted")
+                .value(),
+        );
+        let closes_at = local_to_rfc3339(
+            &closes_input
+                .get()
+                .expect("<input> should be mounted")
+                .value(),
+        );
+        match (quiz_id, opens_at, closes_at) {
+            (Some(quiz_id), Some(opens_at), Some(closes_at)) => {
+                err_msg.set(None);
+                assign_action.dispatch(AssignmentJsonPkg {
+                    quiz_id,
+                    opens_at,
+                    closes_at,
+                });
+            }
+            _ => err_msg.set(Some(String::from("Pick a quiz, open and close time"))),
+        }
+    };
+
+    let quiz_name = move |quiz_id: &Thing| -> String {
+        quiz_list.with_untracked(|ql| {
+            ql.iter()
+                .find(|qz| &qz.id == quiz_id)
+                .map(|qz| qz.name.clone())
+                .unwrap_or_else(|| quiz_id.to_raw())
+        })
+    };
+
+    // -- Render View --
+    view! {
+        <Card on_click=None>
+            <h3>{group.name.clone()}</h3>
+            <p>{move || err_msg.get()}</p>
+            {group.owned.then(|| view! {
+                <p>"Join code: "<b>{group.join_code.clone()}</b></p>
+                <p>
+                    "Members: "
+                    {group
+                        .members
+                        .iter()
+                        .map(|mbr| mbr.username.clone())
+                        .collect::<Vec<String>>()
+                        .join(", ")}
+                </p>
+                <input type="text" placeholder="Add member by username" on:change=on_invite />
+                <form on:submit=on_assign>
+                    <select node_ref=quiz_select>
+                        {move || quiz_list
+                            .get()
+                            .into_iter()
+                            .map(|qz| view! { <option value=qz.id.to_raw()>{qz.name}</option> })
+                            .collect_view()}
+                    </select>
+                    <label>"Opens "<input type="datetime-local" node_ref=opens_input /></label>
+                    <label>"Closes "<input type="datetime-local" node_ref=closes_input /></label>
+                    <input type="submit" value="Assign" />
+                </form>
+                {group
+                    .assignments
+                    .iter()
+                    .map(|asg| view! {
+                        <AssignmentExhibit
+                            assignment=asg.clone()
+                            quiz_name=quiz_name(&asg.quiz_id)
+                        />
+                    })
+                    .collect_view()}
+            })}
+        </Card>
+    }
+}
+
+/// An assignment of an owned group, with each member's progress on request.
+#[component]
+pub fn AssignmentExhibit(assignment: SurrealAssignment, quiz_name: String) -> impl IntoView {
+    // -- Create Signals --
+    let progress: RwSignal<Option<AssignmentProgress>> = create_rw_signal(None);
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+    let assignment_id: StoredValue<Thing> = store_value(assignment.id.clone());
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+    let progress_url: StoredValue<String> =
+        store_value(app_settings.backend_url.clone() + "groups/progress");
+
+    // -- Create Actions --
+    let progress_action = create_action(move |_: &()| {
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(progress_url.get_value())
+            .add_query_param("assignment", &assignment_id.get_value().to_raw())
+            .set_method("GET")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(None).await;
+            if response.status() == 200 {
+                let data: AssignmentProgress = Fetcher::response_to_struct(&response).await;
+                progress.set(Some(data));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // -- Render View --
+    view! {
+        <div>
+            <h4>{quiz_name}</h4>
+            <p>
+                "Open "{display_time(&assignment.opens_at)}
+                " to "{display_time(&assignment.closes_at)}
+            </p>
+            <button on:click=move |_| progress_action.dispatch(())>"Show Progress"</button>
+            <p>{move || err_msg.get()}</p>
+            {move || progress.get().map(|data| view! {
+                <table class="progress-table">
+                    <tr>
+                        <th>"Member"</th>
+                        <th>"Attempts"</th>
+                        <th>"Completed"</th>
+                        <th>"Best"</th>
+                    </tr>
+                    {data
+                        .members
+                        .into_iter()
+                        .map(|mp| view! {
+                            <tr>
+                                <td>{mp.member.username}</td>
+                                <td>{mp.attempts}</td>
+                                <td>{if mp.completed { "Yes" } else { "No" }}</td>
+                                <td>
+                                    {mp
+                                        .best_score
+                                        .map(|best| format!("{:.0}%", best * 100.0))
+                                        .unwrap_or_else(|| String::from("-"))}
+                                </td>
+                            </tr>
+                        })
+                        .collect_view()}
+                </table>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,171 @@
+//! frontend/src/components/dashboard/import_quiz.rs
+//! This component imports a quiz bundle pasted in as JSON or YAML.
+//! Bundles can be checked first, nothing is saved until every question is valid.
+use leptos::*;
+use web_sys::{Headers, RequestMode, Response};
+
+use crate::{
+    models::{bundle::ImportReport, mimic_surreal::SurrealQuiz},
+    store::AppSettings,
+    utils::Fetcher,
+};
+
+/// Bundle text, format, and whether it is a dry run.
+type ImportPkg = (String, String, bool);
+
+/// To render the component for importing quizzes.
+#[component]
+pub fn ImportQuiz(push_quiz: Callback<SurrealQuiz>) -> impl IntoView {
+    // -- Create Signals --
+    let report: RwSignal<Option<ImportReport>> = create_rw_signal(None);
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+    // -- Create References --
+    let bundle_text: NodeRef<html::Textarea> = create_node_ref();
+    let bundle_format: NodeRef<html::Select> = create_node_ref();
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Actions --
+    let import_action = create_action(move |(body, format, dry_run): &ImportPkg| {
+        let body: String = body.clone();
+        let headers: Headers = Headers::new().unwrap();
+        let content_type: &str = if format == "yaml" {
+            "application/yaml;charset=UTF-8"
+        } else {
+            "application/json;charset=UTF-8"
+        };
+        headers.set("Content-Type", content_type).unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.clone() + "quiz-nexus/import")
+            .add_query_param("format", format)
+            .add_query_param("dry_run", &dry_run.to_string())
+            .set_method("POST")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(Some(body)).await;
+            // Invalid items come back as a report, other problems as a message
+            let data: serde_json::Value = Fetcher::response_to_struct(&response).await;
+            match serde_json::from_value::<ImportReport>(data.clone()) {
+                Ok(data) => {
+                    if let Some(quiz) = data.quiz.clone() {
+                        push_quiz.call(quiz);
+                    }
+                    err_msg.set(None);
+                    report.set(Some(data));
+                }
+                Err(_) => {
+                    report.set(None);
+                    err_msg.set(data["msg"].as_str().map(String::from));
+                }
+            }
+        }
+    });
+
+    let send_bundle = move |dry_run: bool| {
+        let body: String = bundle_text
+            .get()
+            .expect("<textarea> should be mounted")
+            .value();
+        let format: String = bundle_format
+            .get()
+            .expect("<select> should be mounted")
+            .value();
+        import_action.dispatch((body, format, dry_run));
+    };
+
+    let summary = move || {
+        report.with(|rp| {
+            rp.as_ref().map(|rp| {
+                let invalid: usize = rp.items.iter().filter(|it| it.error.is_some()).count();
+                match (&rp.quiz, rp.dry_run) {
+                    (Some(quiz), _) => {
+                        format!("Imported {} with {} questions", quiz.name, rp.items.len())
+                    }
+                    (None, true) if invalid == 0 && rp.quiz_error.is_none() => {
+                        format!("All {} questions are ready to import", rp.items.len())
+                    }
+                    _ => format!("{} of {} questions need fixing", invalid, rp.items.len()),
+                }
+            })
+        })
+    };
+    let quiz_error = move || report.with(|rp| rp.as_ref().and_then(|rp| rp.quiz_error.clone()));
+    let problems = move || {
+        report.with(|rp| {
+            rp.as_ref()
+                .map(|rp| {
+                    rp.items
+                        .iter()
+                        .filter(|it| it.error.is_some())
+                        .cloned()
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        })
+    };
+    let unmapped = move || {
+        report.with(|rp| {
+            rp.as_ref()
+                .map(|rp| rp.unmapped.clone())
+                .unwrap_or_default()
+        })
+    };
+
+    // -- Render View --
+    view! {
+        <div class:quiz-make-container=true>
+            <h2>"Import a Quiz"</h2>
+            <p>"Paste a bundle exported from a quiz, or GIFT or Moodle XML questions, it is added as a new quiz of your own."</p>
+            <h5>{move || err_msg.get()}</h5>
+            <select node_ref=bundle_format>
+                <option value="json" selected=true>"JSON"</option>
+                <option value="yaml">"YAML"</option>
+                <option value="gift">"GIFT"</option>
+                <option value="xml">"Moodle XML"</option>
+            </select>
+            <textarea
+                data-note="import_bundle_text"
+                placeholder="Bundle..."
+                node_ref=bundle_text
+            />
+            <div class:horizontal-even=true>
+                <button on:click=move |_| send_bundle(true)>"Check"</button>
+                <button on:click=move |_| send_bundle(false)>"Import"</button>
+            </div>
+            <p>{summary}</p>
+            <p>{quiz_error}</p>
+            <ul>
+                <For
+                    each=problems
+                    key=|it| it.index
+                    children=move |it| {
+                        view! {
+                            <li>
+                                "Question "{it.index + 1}" ("{it.question}"): "
+                                {it.error.unwrap_or_default()}
+                            </li>
+                        }
+                    }
+                />
+            </ul>
+            <ul>
+                <For
+                    each=move || unmapped().into_iter().enumerate()
+                    key=|(pos, _)| *pos
+                    children=move |(_, part)| {
+                        let action: &str = if part.skipped { "skipped" } else { "lost" };
+                        view! {
+                            <li>
+                                "Question "{part.item + 1}" "{part.name.unwrap_or_default()}
+                                " "{action}": "{part.construct}
+                            </li>
+                        }
+                    }
+                />
+            </ul>
+        </div>
+    }
+}
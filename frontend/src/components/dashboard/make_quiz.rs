@@ -2,7 +2,7 @@
 //! This component will handle quiz making logic and pass
 //! user to the making questions screen.
 use crate::{
-    models::mimic_surreal::SurrealQuiz,
+    models::{mimic_surreal::SurrealQuiz, quizzes::Visibility},
     store::AppSettings,
     utils::{DashDisplay, Fetcher, JsonMsg},
 };
@@ -16,6 +16,97 @@ pub struct QuizJsonPkg {
     pub description: String,
 }
 
+/// Drop down for who can see a quiz, shared by making and updating quizzes.
+#[component]
+pub fn VisibilityPicker(node_ref: NodeRef<html::Select>, current: Visibility) -> impl IntoView {
+    view! {
+        <select id="quiz-visibility" node_ref=node_ref>
+            {Visibility::ALL
+                .into_iter()
+                .map(|vis| view! {
+                    <option value=vis.as_str() selected=vis == current>{vis.describe()}</option>
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// Optional time limits for a quiz, shared by making and updating quizzes.
+/// Left blank or 0 the quiz is untimed.
+#[component]
+pub fn TimeLimitPicker(
+    total_ref: NodeRef<html::Input>,
+    question_ref: NodeRef<html::Input>,
+    current_total: Option<u32>,
+    current_question: Option<u32>,
+) -> impl IntoView {
+    // the whole quiz is set in minutes, each question in seconds
+    let total_minutes: Option<u32> = current_total.filter(|secs| *secs > 0).map(|secs| secs / 60);
+    let question_secs: Option<u32> = current_question.filter(|secs| *secs > 0);
+    view! {
+        <input
+            type="number"
+            id="quiz-time-limit"
+            min="0"
+            placeholder="Time limit (minutes)"
+            node_ref=total_ref
+            value=total_minutes
+        />
+        <input
+            type="number"
+            id="quiz-time-per-question"
+            min="0"
+            placeholder="Seconds per question"
+            node_ref=question_ref
+            value=question_secs
+        />
+    }
+}
+
+/// Reads the time limit inputs as seconds, blank or invalid values clear the limit.
+pub fn read_time_limits(
+    total_ref: NodeRef<html::Input>,
+    question_ref: NodeRef<html::Input>,
+) -> (u32, u32) {
+    let parse = |node: NodeRef<html::Input>| -> u32 {
+        node.get()
+            .expect("<input> should be mounted")
+            .value()
+            .trim()
+            .parse()
+            .unwrap_or(0)
+    };
+    (parse(total_ref).saturating_mul(60), parse(question_ref))
+}
+
+/// How much of a question's mark revealing its hint costs, shared by making and updating quizzes.
+#[component]
+pub fn HintPenaltyPicker(node_ref: NodeRef<html::Input>, current: f64) -> impl IntoView {
+    view! {
+        <label for="quiz-hint-penalty">"Hint penalty (0 to 1 of a mark)"</label>
+        <input
+            type="number"
+            id="quiz-hint-penalty"
+            min="0"
+            max="1"
+            step="0.05"
+            node_ref=node_ref
+            value=current
+        />
+    }
+}
+
+/// Reads the hint penalty input, blank or invalid values mean hints are free.
+pub fn read_hint_penalty(node_ref: NodeRef<html::Input>) -> f64 {
+    node_ref
+        .get()
+        .expect("<input> should be mounted")
+        .value()
+        .trim()
+        .parse()
+        .unwrap_or(0.0)
+}
+
 /// To render the component for creating new quizzes.
 #[component]
 pub fn MakeQuiz(
@@ -29,6 +120,10 @@ pub fn MakeQuiz(
     //  -- Create References --
     let quiz_title: NodeRef<html::Input> = create_node_ref();
     let quiz_description: NodeRef<html::Textarea> = create_node_ref();
+    let quiz_visibility: NodeRef<html::Select> = create_node_ref();
+    let quiz_time_limit: NodeRef<html::Input> = create_node_ref();
+    let time_per_question: NodeRef<html::Input> = create_node_ref();
+    let hint_penalty: NodeRef<html::Input> = create_node_ref();
     // -- Use Context --
     let app_settings: AppSettings =
         use_context::<AppSettings>().expect("AppSettings context not found");
@@ -69,11 +164,21 @@ pub fn MakeQuiz(
             .get()
             .expect("<textarea> should be mounted")
             .value();
+        let visibility_value: String = quiz_visibility
+            .get()
+            .expect("<select> should be mounted")
+            .value();
+        let (time_limit_secs, time_per_question_secs) =
+            read_time_limits(quiz_time_limit, time_per_question);
 
         // Package Data into JSON String
         let pkg: String = serde_json::json!({
             "name": title_value,
-            "description": description_value
+            "description": description_value,
+            "visibility": visibility_value,
+            "time_limit_secs": time_limit_secs,
+            "time_per_question_secs": time_per_question_secs,
+            "hint_penalty": read_hint_penalty(hint_penalty)
         })
         .to_string();
         create_quiz.dispatch(pkg);
@@ -90,6 +195,14 @@ pub fn MakeQuiz(
             <form on:submit=on_submit>
                 <input type="text" id="quiz-title" placeholder="Quiz Title" node_ref=quiz_title required/>
                 <textarea id="quiz-description" placeholder="Description..." node_ref=quiz_description required />
+                <VisibilityPicker node_ref=quiz_visibility current=Visibility::default() />
+                <TimeLimitPicker
+                    total_ref=quiz_time_limit
+                    question_ref=time_per_question
+                    current_total=None
+                    current_question=None
+                />
+                <HintPenaltyPicker node_ref=hint_penalty current=0.0 />
                 <input type="submit" value="Create Quiz!" />
             </form>
         </div>
@@ -1,20 +1,38 @@
 //! frontend/src/components/dashboard/mod.rs
 // -- Mods
+pub mod browse_quizzes;
 pub mod create_questions;
+pub mod csv_upload;
 pub mod display_questions;
 pub mod edit_questions;
 pub mod get_quiz;
+pub mod groups;
+pub mod import_quiz;
 pub mod make_quiz;
+pub mod pools;
+pub mod progress;
 pub mod question_types;
+pub mod search_quizzes;
+pub mod study_quiz;
+pub mod tags;
 pub mod take_quiz;
 pub mod update_quiz;
 
 // -- Uses
+pub use browse_quizzes::*;
 pub use create_questions::*;
+pub use csv_upload::*;
 pub use display_questions::*;
 pub use edit_questions::*;
 pub use get_quiz::*;
+pub use groups::*;
+pub use import_quiz::*;
 pub use make_quiz::*;
+pub use pools::*;
+pub use progress::*;
 pub use question_types::*;
+pub use search_quizzes::*;
+pub use study_quiz::*;
+pub use tags::*;
 pub use take_quiz::*;
 pub use update_quiz::*;
@@ -0,0 +1,137 @@
+//! frontend/src/components/dashboard/pools.rs
+//! Question pools let a quiz draw a random paper from the author's tagged questions.
+//! Each attempt gets its own draw from the backend.
+use leptos::*;
+use web_sys::{RequestMode, Response};
+
+use crate::{
+    models::{quizzes::PoolDraw, tags::TagSummary},
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+
+/// Rows of tags and how many questions to draw from each.
+/// With no pools every question of the quiz is handed out.
+#[component]
+pub fn PoolPicker(pools: RwSignal<Vec<PoolDraw>>) -> impl IntoView {
+    // -- Create Signals --
+    let tag_list: RwSignal<Vec<TagSummary>> = create_rw_signal(Vec::new());
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+    let tags_url: String = app_settings.backend_url.clone() + "tags";
+
+    // -- Create Resource --
+    let tags_resource = create_resource(
+        || (), // only render once
+        move |_| {
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(tags_url.clone())
+                .set_method("GET")
+                .set_mode(RequestMode::Cors)
+                .build();
+            async move {
+                let response: Response = fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: Vec<TagSummary> = Fetcher::response_to_struct(&response).await;
+                    tag_list.set(data);
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
+                }
+            }
+        },
+    );
+
+    // -- Create Effect --
+    // This resource is only set to run once, depends on ()
+    create_effect(move |_| {
+        tags_resource.get();
+    });
+
+    // -- Callbacks --
+    // Adds a pool for the first tagged tag not already drawn from
+    let add_pool = move |_ev: ev::MouseEvent| {
+        let unused: Option<TagSummary> = tag_list.with(|tl| {
+            pools.with(|pl| {
+                tl.iter()
+                    .find(|tg| tg.questions > 0 && pl.iter().all(|pool| pool.tag != tg.id))
+                    .cloned()
+            })
+        });
+        match unused {
+            Some(tag) => pools.update(|pl| {
+                pl.push(PoolDraw {
+                    tag: tag.id,
+                    count: 1,
+                })
+            }),
+            None => err_msg.set(Some(String::from("Tag some questions to draw from them"))),
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <div class="pool-picker">
+            <h4>"Question pools"</h4>
+            {move || {
+                pools
+                    .get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(at, pool)| {
+                        let set_tag = move |evnt: ev::Event| {
+                            let raw: String = event_target_value(&evnt);
+                            let picked: Option<TagSummary> = tag_list
+                                .with_untracked(|tl| tl.iter().find(|tg| tg.id.to_raw() == raw).cloned());
+                            if let Some(tag) = picked {
+                                pools.update(|pl| pl[at].tag = tag.id);
+                            }
+                        };
+                        let set_count = move |evnt: ev::Event| {
+                            let count: u32 = event_target_value(&evnt).parse().unwrap_or(1);
+                            pools.update(|pl| pl[at].count = count.max(1));
+                        };
+                        let current: String = pool.tag.to_raw();
+                        view! {
+                            <div class="pool-row">
+                                <input
+                                    type="number"
+                                    min="1"
+                                    value=pool.count
+                                    on:change=set_count
+                                />
+                                " questions tagged "
+                                <select on:change=set_tag>
+                                    {tag_list
+                                        .get()
+                                        .into_iter()
+                                        .map(|tg| {
+                                            let raw: String = tg.id.to_raw();
+                                            view! {
+                                                <option value=raw.clone() selected=raw == current>
+                                                    {tg.name}" ("{tg.questions}")"
+                                                </option>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </select>
+                                <button
+                                    type="button"
+                                    title="Remove pool"
+                                    on:click=move |_| pools.update(|pl| {
+                                        pl.remove(at);
+                                    })
+                                >"x"</button>
+                            </div>
+                        }
+                    })
+                    .collect_view()
+            }}
+            <button type="button" on:click=add_pool>"Add pool"</button>
+            <p>{move || err_msg.get()}</p>
+        </div>
+    }
+}
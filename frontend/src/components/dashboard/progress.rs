@@ -0,0 +1,155 @@
+//! frontend/src/components/dashboard/progress.rs
+//! This component shows how a user has done on the quizzes they have taken.
+//! Statistics are worked out by the backend, this only renders them.
+use crate::{
+    components::Card,
+    models::attempts::{QuestionStat, QuizProgress},
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+use leptos::*;
+use web_sys::{Headers, RequestMode, Response};
+
+/// Container listing progress for every quiz the user has submitted an attempt at.
+#[component]
+pub fn ProgressReport() -> impl IntoView {
+    // -- Create Signals --
+    let progress_list: RwSignal<Vec<QuizProgress>> = create_rw_signal(Vec::new());
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Resource --
+    let history_resource = create_resource(
+        || (), // only render once
+        move |_| {
+            let headers: Headers = Headers::new().unwrap();
+            headers
+                .set("Content-Type", "application/json;charset=UTF-8")
+                .unwrap();
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(app_settings.backend_url.clone() + "exam-room/history")
+                .set_method("GET")
+                .set_headers(headers)
+                .set_mode(RequestMode::Cors)
+                .build();
+            async move {
+                let response: Response = fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: Vec<QuizProgress> = Fetcher::response_to_struct(&response).await;
+                    progress_list.set(data);
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
+                }
+            }
+        },
+    );
+
+    // -- Create Effect --
+    // This resource is only set to run once, depends on ()
+    create_effect(move |_| {
+        history_resource.get();
+    });
+
+    let no_attempts = move || progress_list.with(|pl| pl.is_empty());
+
+    // -- Render View --
+    view! {
+        <div
+            class:quiz-showcase-container=true
+        >
+            <h2>"My Progress"</h2>
+            <h4>{move || err_msg.get()}</h4>
+            <Show when=no_attempts>
+                <p>"No quizzes submitted yet, take one to start tracking progress."</p>
+            </Show>
+            <For
+                each=move || progress_list.get()
+                key=|qp| qp.quiz_id.to_raw()
+                children=move |this| view! {
+                    <ProgressExhibit progress=this />
+                }
+            />
+        </div>
+    }
+}
+
+/// Progress on a single quiz, with a chart of scores over time.
+#[component]
+pub fn ProgressExhibit(progress: QuizProgress) -> impl IntoView {
+    let attempt_count: usize = progress.attempts.len();
+    let percents: Vec<f64> = progress.attempts.iter().map(|sm| sm.percent).collect();
+    let question_stats: Vec<QuestionStat> = progress.question_stats.clone();
+
+    // -- Render View --
+    view! {
+        <Card on_click=None>
+            <h3>"Name: "{progress.quiz_name}</h3>
+            <div
+                class:horizontal-even=true
+            >
+                <p>"Attempts: "{attempt_count}</p>
+                <p>"Best: "{format!("{:.0}%", progress.best)}</p>
+                <p>"Latest: "{format!("{:.0}%", progress.latest)}</p>
+                <p>"Average: "{format!("{:.0}%", progress.average)}</p>
+            </div>
+            <TrendChart percents=percents />
+            <table class="progress-table">
+                <tr>
+                    <th>"Question"</th>
+                    <th>"Answered"</th>
+                    <th>"Success"</th>
+                </tr>
+                {question_stats
+                    .into_iter()
+                    .map(|qs| view! {
+                        <tr>
+                            <td>{qs.question}</td>
+                            <td>{qs.times_answered}</td>
+                            <td>{format!("{:.0}%", qs.success_rate * 100.0)}</td>
+                        </tr>
+                    })
+                    .collect_view()}
+            </table>
+        </Card>
+    }
+}
+
+/// Line chart of attempt scores, oldest on the left, from 0% at the bottom to 100% at the top.
+#[component]
+pub fn TrendChart(percents: Vec<f64>) -> impl IntoView {
+    // A single attempt is drawn as a flat line
+    let step: f64 = if percents.len() > 1 {
+        100.0 / (percents.len() - 1) as f64
+    } else {
+        0.0
+    };
+    let mut points: Vec<(f64, f64)> = percents
+        .iter()
+        .enumerate()
+        .map(|(idx, percent)| (idx as f64 * step, 100.0 - percent.clamp(0.0, 100.0)))
+        .collect();
+    if points.len() == 1 {
+        points.push((100.0, points[0].1));
+    }
+    let polyline: String = points
+        .iter()
+        .map(|(x, y)| format!("{x:.2},{y:.2}"))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    // -- Render View --
+    view! {
+        <svg
+            class="trend-chart"
+            viewBox="-2 -2 104 104"
+            preserveAspectRatio="none"
+        >
+            <line x1="0" y1="50" x2="100" y2="50" class="trend-chart-midline" />
+            <polyline points=polyline class="trend-chart-line" />
+        </svg>
+    }
+}
@@ -1,8 +1,14 @@
 //! frontend/src/components/dashboard/create_questions.rs
 //! This component will handle the initial question making procecss
 use crate::{
-    models::mimic_surreal::{SurrealQuestionMC, SurrealQuiz},
-    models::questions::{JsonQuestion, JsonQuestionMC, QLInternals, QuestType, QuestionJsonPkg},
+    models::mimic_surreal::{
+        SurrealQuestionLA, SurrealQuestionMC, SurrealQuestionMS, SurrealQuestionSA,
+        SurrealQuestionTF, SurrealQuiz,
+    },
+    models::questions::{
+        JsonQuestion, JsonQuestionLA, JsonQuestionMC, JsonQuestionMS, JsonQuestionSA,
+        JsonQuestionTF, QLInternals, QuestType, QuestionJsonPkg, RubricCriterion,
+    },
     store::AppSettings,
     utils::{Fetcher, JsonMsg},
 };
@@ -27,7 +33,6 @@ pub fn QuestionMold(
         <div
             style="width: 100%"
         >
-            <p>"Only Multiple Choice at the moment"</p>
             {move || {
                 match question.data {
                     JsonQuestion::MultipleChoice(_) => view! {
@@ -37,7 +42,39 @@ pub fn QuestionMold(
                             rw=new_quest_rw
                             quest_callback=quest_callback
                         />
-                    }
+                    },
+                    JsonQuestion::ShortAnswer(_) => view! {
+                        <QuestionCastSA
+                            question=question.to_owned()
+                            quiz_data=quiz_data
+                            rw=new_quest_rw
+                            quest_callback=quest_callback
+                        />
+                    },
+                    JsonQuestion::LongAnswer(_) => view! {
+                        <QuestionCastLA
+                            question=question.to_owned()
+                            quiz_data=quiz_data
+                            rw=new_quest_rw
+                            quest_callback=quest_callback
+                        />
+                    },
+                    JsonQuestion::TrueFalse(_) => view! {
+                        <QuestionCastTF
+                            question=question.to_owned()
+                            quiz_data=quiz_data
+                            rw=new_quest_rw
+                            quest_callback=quest_callback
+                        />
+                    },
+                    JsonQuestion::MultiSelect(_) => view! {
+                        <QuestionCastMS
+                            question=question.to_owned()
+                            quiz_data=quiz_data
+                            rw=new_quest_rw
+                            quest_callback=quest_callback
+                        />
+                    },
             }
         }}
         </div>
@@ -175,3 +212,544 @@ pub fn QuestionCastMC(
         </form>
     }
 }
+
+/// Splits the accepted answers typed into a single input, separated by ";".
+pub fn split_accepted_answers(raw: &str) -> Vec<String> {
+    raw.split(';')
+        .map(|ans| ans.trim().to_string())
+        .filter(|ans| !ans.is_empty())
+        .collect()
+}
+
+/// Reads a rubric typed one criterion per line.
+/// A line may start with a weight, like "2: Gives an example", otherwise the weight is 1.
+pub fn parse_rubric(raw: &str) -> Vec<RubricCriterion> {
+    raw.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split_once(':') {
+            Some((weight, criterion)) if weight.trim().parse::<u32>().is_ok() => RubricCriterion {
+                criterion: criterion.trim().to_string(),
+                weight: weight.trim().parse::<u32>().unwrap_or(1),
+            },
+            _ => RubricCriterion {
+                criterion: line.to_string(),
+                weight: 1,
+            },
+        })
+        .collect()
+}
+
+/// Writes a rubric back out in the format `parse_rubric` reads.
+pub fn rubric_to_text(rubric: &[RubricCriterion]) -> String {
+    rubric
+        .iter()
+        .map(|rc| format!("{}: {}", rc.weight, rc.criterion))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Casting a Short Answer question (from a mold)
+#[component]
+pub fn QuestionCastSA(
+    question: QLInternals,
+    rw: RwSignal<Vec<QLInternals>>,
+    quest_callback: Callback<QuestType>,
+    quiz_data: ReadSignal<Option<SurrealQuiz>>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let answers_ref: NodeRef<html::Input> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let create_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .set_method("POST")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            // Saving question and moving component from Make to Display
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionSA = Fetcher::response_to_struct(&response).await;
+                quest_callback.call(QuestType::SA(data));
+
+                // Remove Component since it has been saved
+                rw.update(|this| {
+                    if let Some(index) = this.iter().position(|comp| comp.id == question.id) {
+                        this.remove(index);
+                    }
+                })
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    // extract values from form and pass to create question action for ingestion
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let answers_val: String = answers_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        // Package Data into JSON String
+        let pre_pre_pkg: JsonQuestion = JsonQuestion::ShortAnswer(JsonQuestionSA {
+            question: String::from(question_val.trim()),
+            hint: real_hint,
+            answers: split_accepted_answers(&answers_val),
+        });
+
+        let owned_quiz_data = if let Some(surreal_quiz) = quiz_data.get() {
+            surreal_quiz
+        } else {
+            set_err_msg.set(Some(String::from("Parent quiz data not provied")));
+            return;
+        };
+
+        let pre_pkg: QuestionJsonPkg = QuestionJsonPkg {
+            quiz_id: owned_quiz_data.id,
+            question: pre_pre_pkg,
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            create_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"New Short Answer Question"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input type="text" placeholder="question" node_ref=question_ref required/>
+            <input type="text" placeholder="hint" node_ref=hint_ref/>
+            <input type="text" placeholder="accepted answers; separated; by semicolons" node_ref=answers_ref required/>
+            <input type="submit" value="Save" />
+        </form>
+    }
+}
+
+/// Casting a Long Answer question (from a mold)
+#[component]
+pub fn QuestionCastLA(
+    question: QLInternals,
+    rw: RwSignal<Vec<QLInternals>>,
+    quest_callback: Callback<QuestType>,
+    quiz_data: ReadSignal<Option<SurrealQuiz>>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let model_answer_ref: NodeRef<html::Textarea> = create_node_ref();
+    let rubric_ref: NodeRef<html::Textarea> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let create_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .set_method("POST")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            // Saving question and moving component from Make to Display
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionLA = Fetcher::response_to_struct(&response).await;
+                quest_callback.call(QuestType::LA(data));
+
+                // Remove Component since it has been saved
+                rw.update(|this| {
+                    if let Some(index) = this.iter().position(|comp| comp.id == question.id) {
+                        this.remove(index);
+                    }
+                })
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    // extract values from form and pass to create question action for ingestion
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let model_answer_val: String = model_answer_ref
+            .get()
+            .expect("<textarea> should be mounted")
+            .value();
+        let rubric_val: String = rubric_ref
+            .get()
+            .expect("<textarea> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        // Package Data into JSON String
+        let pre_pre_pkg: JsonQuestion = JsonQuestion::LongAnswer(JsonQuestionLA {
+            question: String::from(question_val.trim()),
+            hint: real_hint,
+            model_answer: String::from(model_answer_val.trim()),
+            rubric: parse_rubric(&rubric_val),
+        });
+
+        let owned_quiz_data = if let Some(surreal_quiz) = quiz_data.get() {
+            surreal_quiz
+        } else {
+            set_err_msg.set(Some(String::from("Parent quiz data not provied")));
+            return;
+        };
+
+        let pre_pkg: QuestionJsonPkg = QuestionJsonPkg {
+            quiz_id: owned_quiz_data.id,
+            question: pre_pre_pkg,
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            create_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"New Long Answer Question"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input type="text" placeholder="question" node_ref=question_ref required/>
+            <input type="text" placeholder="hint" node_ref=hint_ref/>
+            <textarea placeholder="model answer" node_ref=model_answer_ref required />
+            <textarea
+                placeholder="rubric, one criterion per line, e.g. 2: Gives an example"
+                node_ref=rubric_ref
+                required
+            />
+            <input type="submit" value="Save" />
+        </form>
+    }
+}
+
+/// Casting a True or False question (from a mold)
+#[component]
+pub fn QuestionCastTF(
+    question: QLInternals,
+    rw: RwSignal<Vec<QLInternals>>,
+    quest_callback: Callback<QuestType>,
+    quiz_data: ReadSignal<Option<SurrealQuiz>>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let answer_ref: NodeRef<html::Select> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let create_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .set_method("POST")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            // Saving question and moving component from Make to Display
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionTF = Fetcher::response_to_struct(&response).await;
+                quest_callback.call(QuestType::TF(data));
+
+                // Remove Component since it has been saved
+                rw.update(|this| {
+                    if let Some(index) = this.iter().position(|comp| comp.id == question.id) {
+                        this.remove(index);
+                    }
+                })
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    // extract values from form and pass to create question action for ingestion
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let answer_val: String = answer_ref
+            .get()
+            .expect("<select> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        // Package Data into JSON String
+        let pre_pre_pkg: JsonQuestion = JsonQuestion::TrueFalse(JsonQuestionTF {
+            question: String::from(question_val.trim()),
+            hint: real_hint,
+            answer: answer_val == "true",
+        });
+
+        let owned_quiz_data = if let Some(surreal_quiz) = quiz_data.get() {
+            surreal_quiz
+        } else {
+            set_err_msg.set(Some(String::from("Parent quiz data not provied")));
+            return;
+        };
+
+        let pre_pkg: QuestionJsonPkg = QuestionJsonPkg {
+            quiz_id: owned_quiz_data.id,
+            question: pre_pre_pkg,
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            create_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"New True or False Question"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input type="text" placeholder="statement" node_ref=question_ref required/>
+            <input type="text" placeholder="hint" node_ref=hint_ref/>
+            <select node_ref=answer_ref>
+                <option value="true" selected>"True"</option>
+                <option value="false">"False"</option>
+            </select>
+            <input type="submit" value="Save" />
+        </form>
+    }
+}
+
+/// Casting a Multi-Select question (from a mold)
+#[component]
+pub fn QuestionCastMS(
+    question: QLInternals,
+    rw: RwSignal<Vec<QLInternals>>,
+    quest_callback: Callback<QuestType>,
+    quiz_data: ReadSignal<Option<SurrealQuiz>>,
+) -> impl IntoView {
+    //  -- Create Signals --
+    let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
+        create_signal(None);
+
+    //  -- Create References --
+    let question_ref: NodeRef<html::Input> = create_node_ref();
+    let hint_ref: NodeRef<html::Input> = create_node_ref();
+    let answers_ref: NodeRef<html::Input> = create_node_ref();
+    let choices_ref: NodeRef<html::Input> = create_node_ref();
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Question Action for Submitting --
+    let create_question = create_action(move |pkg: &String| {
+        let pkg_clone = pkg.clone();
+        let headers: Headers = Headers::new().unwrap();
+        headers
+            .set("Content-Type", "application/json;charset=UTF-8")
+            .unwrap();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "question-forge")
+            .set_method("POST")
+            .set_headers(headers)
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            // Saving question and moving component from Make to Display
+            let response: Response = fetcher.fetch(Some(pkg_clone)).await;
+            if response.status() >= 200 && response.status() < 300 {
+                let data: SurrealQuestionMS = Fetcher::response_to_struct(&response).await;
+                quest_callback.call(QuestType::MS(data));
+
+                // Remove Component since it has been saved
+                rw.update(|this| {
+                    if let Some(index) = this.iter().position(|comp| comp.id == question.id) {
+                        this.remove(index);
+                    }
+                })
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                set_err_msg.set(deserialized.msg.clone());
+            }
+        }
+    });
+
+    // -- On Submit --
+    // extract values from form and pass to create question action for ingestion
+    let on_submit = move |sub_ev: ev::SubmitEvent| {
+        sub_ev.prevent_default();
+
+        // extract values
+        let question_val: String = question_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let hint_val: String = hint_ref.get().expect("<input> should be mounted").value();
+        let answers_val: String = answers_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+        let choices_val: String = choices_ref
+            .get()
+            .expect("<input> should be mounted")
+            .value();
+
+        // Fixing Hint type
+        let real_hint: Option<String> = if hint_val.trim().is_empty() {
+            None
+        } else {
+            Some(String::from(hint_val.trim()))
+        };
+
+        // Package Data into JSON String
+        let pre_pre_pkg: JsonQuestion = JsonQuestion::MultiSelect(JsonQuestionMS {
+            question: String::from(question_val.trim()),
+            hint: real_hint,
+            answers: split_accepted_answers(&answers_val),
+            choices: split_accepted_answers(&choices_val),
+        });
+
+        let owned_quiz_data = if let Some(surreal_quiz) = quiz_data.get() {
+            surreal_quiz
+        } else {
+            set_err_msg.set(Some(String::from("Parent quiz data not provied")));
+            return;
+        };
+
+        let pre_pkg: QuestionJsonPkg = QuestionJsonPkg {
+            quiz_id: owned_quiz_data.id,
+            question: pre_pre_pkg,
+        };
+
+        let pkg_res: serde_json::Result<String> = serde_json::to_string(&pre_pkg);
+        // Gracefully handle Result
+        if let Ok(pkg) = pkg_res {
+            create_question.dispatch(pkg);
+        } else {
+            set_err_msg.set(Some(String::from("Failed to serialize data")));
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <form
+            class="forge-container"
+            on:submit=on_submit
+        >
+            <h4>"New Multi-Select Question"</h4>
+            <h4>{move || err_msg.get() }</h4>
+            <input type="text" placeholder="question" node_ref=question_ref required/>
+            <input type="text" placeholder="hint" node_ref=hint_ref/>
+            <input type="text" placeholder="correct choices; separated; by semicolons" node_ref=answers_ref required/>
+            <input type="text" placeholder="wrong choices; separated; by semicolons" node_ref=choices_ref/>
+            <input type="submit" value="Save" />
+        </form>
+    }
+}
@@ -0,0 +1,139 @@
+//! frontend/src/components/dashboard/search_quizzes.rs
+//! This component shows full-text search results for quizzes and questions.
+use leptos::*;
+use web_sys::{Headers, RequestMode, Response};
+
+use crate::{
+    components::Card,
+    models::search::{SearchHit, SearchKind, SearchResults, SnippetPart},
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+
+/// Search box for the dashboard sidebar, results are shown in the main screen.
+#[component]
+pub fn SearchBox(search_rw: RwSignal<String>, on_search: Callback<()>) -> impl IntoView {
+    let search_change = move |evnt: ev::Event| {
+        let query: String = event_target_value(&evnt);
+        if !query.trim().is_empty() {
+            search_rw.set(query);
+            on_search.call(());
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <Card on_click=None>
+            <input
+                type="search"
+                placeholder="Search Quizzes"
+                data-note="search_quizzes_input"
+                on:change=search_change
+            />
+        </Card>
+    }
+}
+
+/// Lists results for the current search, best match first.
+#[component]
+pub fn SearchResultList(search_rw: RwSignal<String>) -> impl IntoView {
+    // -- Create Signals --
+    let hits: RwSignal<Vec<SearchHit>> = create_rw_signal(Vec::new());
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Resource --
+    // Searches again whenever the search changes
+    let search_resource = create_resource(
+        move || search_rw.get(),
+        move |query: String| {
+            let headers: Headers = Headers::new().unwrap();
+            headers
+                .set("Content-Type", "application/json;charset=UTF-8")
+                .unwrap();
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(app_settings.backend_url.clone() + "search")
+                .add_query_param("q", &query)
+                .set_method("GET")
+                .set_headers(headers)
+                .set_mode(RequestMode::Cors)
+                .build();
+            async move {
+                let response: Response = fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: SearchResults = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(None);
+                    hits.set(data.hits);
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    hits.set(Vec::new());
+                    err_msg.set(deserialized.msg);
+                }
+            }
+        },
+    );
+
+    // -- Create Effect --
+    create_effect(move |_| {
+        search_resource.get();
+    });
+
+    let no_hits = move || hits.with(|hs| hs.is_empty()) && err_msg.with(|em| em.is_none());
+
+    // -- Render View --
+    view! {
+        <div
+            class:quiz-showcase-container=true
+        >
+            <h2>"Search: "{move || search_rw.get()}</h2>
+            <h4>{move || err_msg.get()}</h4>
+            <Show when=no_hits>
+                <p>"No quizzes or questions matched."</p>
+            </Show>
+            <For
+                each=move || hits.get()
+                key=|hit| hit.id.to_raw()
+                children=move |this| view! {
+                    <SearchHitExhibit hit=this />
+                }
+            />
+        </div>
+    }
+}
+
+/// A single search hit, linking to its quiz.
+#[component]
+pub fn SearchHitExhibit(hit: SearchHit) -> impl IntoView {
+    let quiz_link: String = format!("/quiz/{}", hit.quiz_id.to_raw());
+    let kind: &str = match hit.kind {
+        SearchKind::Quiz => "Quiz",
+        SearchKind::Question => "Question",
+    };
+
+    // -- Render View --
+    view! {
+        <Card on_click=None>
+            <p class="search-kind">{kind}" in "<a href=quiz_link>{hit.quiz_name}</a></p>
+            <h3><Snippet parts=hit.title /></h3>
+            <p><Snippet parts=hit.snippet /></p>
+        </Card>
+    }
+}
+
+/// Text with the matched words marked, rendered as text so user content is never treated as HTML.
+#[component]
+pub fn Snippet(parts: Vec<SnippetPart>) -> impl IntoView {
+    parts
+        .into_iter()
+        .map(|part| {
+            if part.hit {
+                view! { <mark>{part.text}</mark> }.into_view()
+            } else {
+                part.text.into_view()
+            }
+        })
+        .collect_view()
+}
@@ -0,0 +1,258 @@
+//! frontend/src/components/dashboard/study_quiz.rs
+//! This component will handle studying a quiz with spaced repetition.
+//! Only questions due today are handed out, one at a time, and each is rated after answering.
+use crate::{
+    components::dashboard::{
+        ExamHints, LAQuestion, MCQuestion, MSQuestion, SAQuestion, TFQuestion,
+    },
+    models::attempts::{
+        AnswerJsonPkg, ExamQuestion, ExamQuestionKind, GradedAnswer, HintJsonPkg, RevealedHint,
+        SelfMarkJsonPkg,
+    },
+    models::mimic_surreal::{SurrealQuiz, Thing},
+    models::study::{ReviewJsonPkg, ReviewOutcome, ReviewRating},
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+use leptos::*;
+use std::boxed::Box;
+use std::future::Future;
+use std::pin::Pin;
+use web_sys::{Headers, RequestMode, Response};
+
+/// Container for reviewing the questions of a quiz that are due today.
+#[component]
+pub fn StudyHall(some_quiz: Option<SurrealQuiz>) -> impl IntoView {
+    // -- Create Signals --
+    let due: RwSignal<Vec<ExamQuestion>> = create_rw_signal(Vec::new());
+    let current: RwSignal<usize> = create_rw_signal(0);
+    let answer: RwSignal<Option<AnswerJsonPkg>> = create_rw_signal(None);
+    // Only set once the backend has recorded the review
+    let outcome: RwSignal<Option<ReviewOutcome>> = create_rw_signal(None);
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+    let some_name: RwSignal<Option<String>> = create_rw_signal(None);
+    let revealed: RwSignal<Vec<RevealedHint>> = create_rw_signal(Vec::new());
+    let quiz_raw: StoredValue<String> = store_value(
+        some_quiz
+            .as_ref()
+            .map(|qz| qz.id.to_raw())
+            .unwrap_or_default(),
+    );
+
+    if let Some(qn) = &some_quiz {
+        some_name.set(Some(qn.name.clone()));
+    };
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+    let study_url: String = app_settings.backend_url.clone() + "study-hall";
+    let hint_url: String = app_settings.backend_url.clone() + "study-hall/hint";
+
+    // -- Create Resource --
+    let due_resource = create_resource(
+        || (), // only render once
+        move |_| {
+            if some_quiz.is_none() {
+                // this branch should not run
+                return Box::pin(async {}) as Pin<Box<dyn Future<Output = _>>>;
+            }
+            let headers: Headers = Headers::new().unwrap();
+            headers
+                .set("Content-Type", "application/json;charset=UTF-8")
+                .unwrap();
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(app_settings.backend_url.clone() + "study-hall")
+                .add_query_param("quiz", &quiz_raw.get_value())
+                .set_method("GET")
+                .set_headers(headers)
+                .set_mode(RequestMode::Cors)
+                .build();
+            Box::pin(async move {
+                let response: Response = fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: Vec<ExamQuestion> = Fetcher::response_to_struct(&response).await;
+                    due.set(data);
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
+                }
+            }) as Pin<Box<dyn Future<Output = _>>>
+        },
+    );
+
+    // -- Create Effect --
+    // This resource is only set to run once, depends on ()
+    create_effect(move |_| {
+        due_resource.get();
+    });
+
+    // -- Create Actions --
+    let review_action = create_action(move |review: &ReviewJsonPkg| {
+        let study_url: String = study_url.clone();
+        let review: ReviewJsonPkg = review.clone();
+        async move {
+            let pkg: String = match serde_json::to_string(&review) {
+                Ok(pkg) => pkg,
+                Err(_) => {
+                    err_msg.set(Some(String::from("Failed to serialize data")));
+                    return;
+                }
+            };
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(study_url)
+                .add_query_param("quiz", &quiz_raw.get_value())
+                .set_method("POST")
+                .set_mode(RequestMode::Cors)
+                .build();
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 200 {
+                let reviewed: ReviewOutcome = Fetcher::response_to_struct(&response).await;
+                outcome.set(Some(reviewed));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // Hints are free while studying, but still asked for one at a time
+    let reveal_action = create_action(move |question_id: &Thing| {
+        let hint_url: String = hint_url.clone();
+        let pkg: HintJsonPkg = HintJsonPkg {
+            question_id: question_id.clone(),
+        };
+        async move {
+            let pkg: String = match serde_json::to_string(&pkg) {
+                Ok(pkg) => pkg,
+                Err(_) => {
+                    err_msg.set(Some(String::from("Failed to serialize data")));
+                    return;
+                }
+            };
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(hint_url)
+                .add_query_param("quiz", &quiz_raw.get_value())
+                .set_method("PUT")
+                .set_mode(RequestMode::Cors)
+                .build();
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 200 {
+                let hint: RevealedHint = Fetcher::response_to_struct(&response).await;
+                revealed.update(|rh| rh.push(hint));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // -- Provide Context --
+    provide_context(ExamHints {
+        revealed,
+        penalty: create_rw_signal(0.0),
+        reveal: Callback::new(move |question_id: Thing| reveal_action.dispatch(question_id)),
+    });
+
+    // -- Callbacks --
+    let record_answer: Callback<AnswerJsonPkg> = Callback::new(move |pkg: AnswerJsonPkg| {
+        answer.set(Some(pkg));
+    });
+    // Rubrics are not marked while studying, the rating stands in for them
+    let self_mark: Callback<SelfMarkJsonPkg> = Callback::new(|_mark: SelfMarkJsonPkg| {});
+
+    let rate = move |rating: ReviewRating| match answer.get() {
+        Some(pkg) => {
+            err_msg.set(None);
+            review_action.dispatch(ReviewJsonPkg {
+                question_id: pkg.question_id,
+                answer: pkg.answer,
+                rating,
+            });
+        }
+        None => err_msg.set(Some(String::from("Answer the question before rating it"))),
+    };
+    let next_question = move |_ev: ev::MouseEvent| {
+        answer.set(None);
+        outcome.set(None);
+        current.update(|idx| *idx += 1);
+    };
+
+    let remaining = move || due.with(|qs| qs.len().saturating_sub(current.get()));
+
+    // The question on screen, rendered again whenever the index moves on
+    let question_view = move || {
+        let eq: ExamQuestion = due.with(|qs| qs.get(current.get()).cloned())?;
+        let question: String = eq.question.clone();
+        let graded: Signal<Option<GradedAnswer>> = Signal::derive(move || {
+            outcome.get().map(|oc| GradedAnswer {
+                question_id: oc.question_id,
+                question: question.clone(),
+                response: answer.get_untracked().map(|pkg| pkg.answer),
+                key: oc.key,
+                earned: oc.earned,
+                criteria_met: Vec::new(),
+                hint_used: false,
+                deducted: 0.0,
+            })
+        });
+        Some(match eq.kind.clone() {
+            ExamQuestionKind::MultipleChoice { choices } => view! {
+                <MCQuestion eq=eq choices=choices graded=graded record_answer=record_answer />
+            }
+            .into_view(),
+            ExamQuestionKind::ShortAnswer => view! {
+                <SAQuestion eq=eq graded=graded record_answer=record_answer />
+            }
+            .into_view(),
+            ExamQuestionKind::LongAnswer => view! {
+                <LAQuestion eq=eq graded=graded record_answer=record_answer self_mark=self_mark />
+            }
+            .into_view(),
+            ExamQuestionKind::TrueFalse => view! {
+                <TFQuestion eq=eq graded=graded record_answer=record_answer />
+            }
+            .into_view(),
+            ExamQuestionKind::MultiSelect { choices } => view! {
+                <MSQuestion eq=eq choices=choices graded=graded record_answer=record_answer />
+            }
+            .into_view(),
+        })
+    };
+
+    // -- View --
+    view! {
+        <h2>{move || some_name.get()}</h2>
+        <h3>"Studying: "{remaining}" due today"</h3>
+        <h4>{move || err_msg.get()}</h4>
+        {question_view}
+        {move || {
+            if remaining() == 0 {
+                return view! { <p>"Nothing left to review today, come back tomorrow!"</p> }
+                    .into_view();
+            }
+            match outcome.get() {
+                None => view! {
+                    <div class:horizontal-even=true>
+                        <button
+                            title="Easy"
+                            on:click=move |_| rate(ReviewRating::Easy)
+                            disabled=move || review_action.pending().get()
+                        >":)"</button>
+                        <button
+                            title="Hard"
+                            on:click=move |_| rate(ReviewRating::Hard)
+                            disabled=move || review_action.pending().get()
+                        >":("</button>
+                    </div>
+                }
+                .into_view(),
+                Some(oc) => view! {
+                    <p>"Next review in "{oc.interval_days}" day(s)"</p>
+                    <button on:click=next_question>"Next"</button>
+                }
+                .into_view(),
+            }
+        }}
+    }
+}
@@ -0,0 +1,274 @@
+//! frontend/src/components/dashboard/tags.rs
+//! Components for organising quizzes with tags.
+//! Tags on each quiz live in the quiz list, so filtering and counts stay in step with edits.
+use leptos::*;
+use web_sys::{Headers, RequestMode, Response};
+
+use crate::{
+    models::{
+        mimic_surreal::{SurrealQuiz, SurrealTag, Thing},
+        tags::{TagAssignPkg, TagJsonPkg, TagSummary},
+    },
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+
+/// Row of the user's tags above their quizzes, picking one shows only quizzes with that tag.
+#[component]
+pub fn TagBar(
+    quiz_list: RwSignal<Vec<SurrealQuiz>>,
+    tag_list: RwSignal<Vec<TagSummary>>,
+    tag_filter: RwSignal<Option<Thing>>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+    let tags_url: StoredValue<String> = store_value(app_settings.backend_url.clone() + "tags");
+
+    // -- Create Resource --
+    let tags_resource = create_resource(
+        || (), // only render once
+        move |_| {
+            let headers: Headers = Headers::new().unwrap();
+            headers
+                .set("Content-Type", "application/json;charset=UTF-8")
+                .unwrap();
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(tags_url.get_value())
+                .set_method("GET")
+                .set_headers(headers)
+                .set_mode(RequestMode::Cors)
+                .build();
+            async move {
+                let response: Response = fetcher.fetch(None).await;
+                if response.status() == 200 {
+                    let data: Vec<TagSummary> = Fetcher::response_to_struct(&response).await;
+                    tag_list.set(data);
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
+                }
+            }
+        },
+    );
+
+    // -- Create Effect --
+    // This resource is only set to run once, depends on ()
+    create_effect(move |_| {
+        tags_resource.get();
+    });
+
+    // -- Create Actions --
+    let destroy_tag_action = create_action(move |tag_id: &Thing| {
+        let tag_id: Thing = tag_id.clone();
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(tags_url.get_value())
+            .add_query_param("tag", &tag_id.to_raw())
+            .set_method("DELETE")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let response: Response = fetcher.fetch(None).await;
+            if response.status() == 200 {
+                tag_list.update(|tl| tl.retain(|tg| tg.id != tag_id));
+                quiz_list.update(|ql| {
+                    ql.iter_mut()
+                        .for_each(|qz| qz.tags.retain(|tg| tg.id != tag_id))
+                });
+                if tag_filter.get_untracked().as_ref() == Some(&tag_id) {
+                    tag_filter.set(None);
+                }
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    let no_filter = move || tag_filter.with(|tf| tf.is_none());
+
+    // -- Render View --
+    view! {
+        <div class="tag-bar">
+            <button
+                class="tag-chip"
+                class:tag-chip-selected=no_filter
+                on:click=move |_| tag_filter.set(None)
+            >"All"</button>
+            <For
+                each=move || tag_list.get()
+                key=|tg| (tg.id.to_raw(), tg.name.clone())
+                children=move |this| {
+                    let tag_id: StoredValue<Thing> = store_value(this.id.clone());
+                    let selected = move || {
+                        tag_filter.with(|tf| tf.as_ref() == Some(&tag_id.get_value()))
+                    };
+                    let count = move || {
+                        quiz_list.with(|ql| {
+                            ql.iter()
+                                .filter(|qz| qz.tags.iter().any(|tg| tg.id == tag_id.get_value()))
+                                .count()
+                        })
+                    };
+                    view! {
+                        <span class="tag-chip" class:tag-chip-selected=selected>
+                            <button on:click=move |_| tag_filter.set(Some(tag_id.get_value()))>
+                                {this.name}" ("{count}")"
+                            </button>
+                            <button
+                                title="Delete tag"
+                                on:click=move |_| destroy_tag_action.dispatch(tag_id.get_value())
+                            >"x"</button>
+                        </span>
+                    }
+                }
+            />
+            <p>{move || err_msg.get()}</p>
+        </div>
+    }
+}
+
+/// Tags on a single quiz, new tags can be typed in and are created when needed.
+#[component]
+pub fn TagEditor(
+    quiz_id: Thing,
+    quiz_list: RwSignal<Vec<SurrealQuiz>>,
+    tag_list: RwSignal<Vec<TagSummary>>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let quiz_id: StoredValue<Thing> = store_value(quiz_id);
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+    let tags_url: String = app_settings.backend_url.clone() + "tags";
+    let assign_url: String = app_settings.backend_url.clone() + "tags/assign";
+
+    // -- Create Closures --
+    let quiz_tags = move || {
+        quiz_list.with(|ql| {
+            ql.iter()
+                .find(|qz| qz.id == quiz_id.get_value())
+                .map(|qz| qz.tags.clone())
+                .unwrap_or_default()
+        })
+    };
+
+    // -- Create Actions --
+    // Sets whether the quiz has the tag, the backend responds with all of its tags
+    let assign_action = create_action(move |(tag_id, tagged): &(Thing, bool)| {
+        let assign: TagAssignPkg = TagAssignPkg {
+            tag: tag_id.clone(),
+            target: quiz_id.get_value(),
+            tagged: *tagged,
+        };
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(assign_url.clone())
+            .set_method("PUT")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let pkg: String = match serde_json::to_string(&assign) {
+                Ok(pkg) => pkg,
+                Err(_) => return,
+            };
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 200 {
+                let tags: Vec<SurrealTag> = Fetcher::response_to_struct(&response).await;
+                quiz_list.update(|ql| {
+                    if let Some(qz) = ql.iter_mut().find(|qz| qz.id == assign.target) {
+                        qz.tags = tags;
+                    }
+                });
+                err_msg.set(None);
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // Uses an existing tag with the same name, or creates one
+    let add_tag_action = create_action(move |name: &String| {
+        let name: String = name.trim().to_string();
+        let existing: Option<Thing> = tag_list.with_untracked(|tl| {
+            tl.iter()
+                .find(|tg| tg.name.to_lowercase() == name.to_lowercase())
+                .map(|tg| tg.id.clone())
+        });
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(tags_url.clone())
+            .set_method("POST")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            if let Some(tag_id) = existing {
+                assign_action.dispatch((tag_id, true));
+                return;
+            }
+            let pkg: String = match serde_json::to_string(&TagJsonPkg { name }) {
+                Ok(pkg) => pkg,
+                Err(_) => return,
+            };
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 201 {
+                let tag: SurrealTag = Fetcher::response_to_struct(&response).await;
+                tag_list.update(|tl| {
+                    tl.push(TagSummary {
+                        id: tag.id.clone(),
+                        name: tag.name,
+                        author_id: tag.author_id,
+                        quizzes: 0,
+                        questions: 0,
+                    });
+                    tl.sort_by_key(|tg| tg.name.to_lowercase());
+                });
+                assign_action.dispatch((tag.id, true));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    let add_tag = move |evnt: ev::Event| {
+        let name: String = event_target_value(&evnt);
+        if !name.trim().is_empty() {
+            add_tag_action.dispatch(name);
+            event_target::<web_sys::HtmlInputElement>(&evnt).set_value("");
+        }
+    };
+
+    // -- Render View --
+    view! {
+        <div class="tag-bar">
+            <For
+                each=quiz_tags
+                key=|tg| (tg.id.to_raw(), tg.name.clone())
+                children=move |this| {
+                    let tag_id: Thing = this.id.clone();
+                    view! {
+                        <span class="tag-chip">
+                            {this.name}
+                            <button
+                                title="Remove tag"
+                                on:click=move |_| assign_action.dispatch((tag_id.clone(), false))
+                            >"x"</button>
+                        </span>
+                    }
+                }
+            />
+            <input
+                type="text"
+                class="tag-input"
+                placeholder="Add tag"
+                on:change=add_tag
+            />
+            <p>{move || err_msg.get()}</p>
+        </div>
+    }
+}
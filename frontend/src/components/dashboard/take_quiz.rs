@@ -1,11 +1,15 @@
 //! frontend/src/components/dashboard/take_quiz.rs
-//! This component will handle quiz making logic and pass
-//! user to the making questions screen.
+//! This component will handle taking a quiz.
+//! Questions arrive without answers, responses are graded by the backend on submission.
 use crate::{
-    models::mimic_surreal::{SurrealQuestionMC, SurrealQuiz},
-    models::questions::AllQuestions,
+    models::attempts::{
+        AnswerJsonPkg, AnswerKey, AttemptAnswer, AttemptStart, AttemptSubmission, ExamQuestion,
+        ExamQuestionKind, GradedAnswer, HintJsonPkg, NextQuestionJsonPkg, RevealedHint,
+        SelfMarkJsonPkg, ServedQuestion, SurrealAttempt,
+    },
+    models::mimic_surreal::{SurrealQuiz, Thing},
     store::AppSettings,
-    utils::{generate_random_string, Fetcher, JsonMsg},
+    utils::{Fetcher, JsonMsg},
 };
 use leptos::*;
 use rand::{seq::SliceRandom, thread_rng};
@@ -14,17 +18,35 @@ use std::future::Future;
 use std::pin::Pin;
 use web_sys::{Headers, RequestMode, Response};
 
-// TODO: Update score results - perhaps render in separate componenet?
+/// Hints revealed during an attempt, shared with every question through context.
+#[derive(Clone, Copy)]
+pub struct ExamHints {
+    pub revealed: RwSignal<Vec<RevealedHint>>,
+    // taken off a question's mark for revealing its hint
+    pub penalty: RwSignal<f64>,
+    pub reveal: Callback<Thing>,
+}
+
 /// This is container for rendering a shuffled set of questions to a quiz.
-/// There is also a small part to display results when quiz is submitted.
+/// Starting the component starts an attempt, and grading the quiz submits it.
+/// Quizzes timing each question are shown one question at a time, as the backend serves them.
 #[component]
 pub fn ExamRoom(some_quiz: Option<SurrealQuiz>) -> impl IntoView {
     // -- Create Signals --
-    let mcquestions: RwSignal<Vec<SurrealQuestionMC>> = create_rw_signal(Vec::new());
-    let signal_to_grade: RwSignal<bool> = create_rw_signal(false);
-    let user_grade: RwSignal<usize> = create_rw_signal(0);
+    let attempt_id: RwSignal<Option<Thing>> = create_rw_signal(None);
+    let questions: RwSignal<Vec<ExamQuestion>> = create_rw_signal(Vec::new());
+    let responses: RwSignal<Vec<AnswerJsonPkg>> = create_rw_signal(Vec::new());
+    // Only set once the backend has graded the attempt
+    let result: RwSignal<Option<SurrealAttempt>> = create_rw_signal(None);
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
     let some_name: RwSignal<Option<String>> = create_rw_signal(None);
-    // Add more signals for additional question types
+    // Seconds left on a timed attempt, counted from what the backend allows
+    let remaining: RwSignal<Option<i64>> = create_rw_signal(None);
+    let revealed: RwSignal<Vec<RevealedHint>> = create_rw_signal(Vec::new());
+    let hint_penalty: RwSignal<f64> = create_rw_signal(0.0);
+    // Questions timed one by one, only the last one served can be answered
+    let paced: RwSignal<bool> = create_rw_signal(false);
+    let question_remaining: RwSignal<Option<i64>> = create_rw_signal(None);
 
     if let Some(qn) = &some_quiz {
         some_name.set(Some(qn.name.clone()));
@@ -33,9 +55,91 @@ pub fn ExamRoom(some_quiz: Option<SurrealQuiz>) -> impl IntoView {
     // -- Use Context --
     let app_settings: AppSettings =
         use_context::<AppSettings>().expect("AppSettings context not found");
+    let submit_url: String = app_settings.backend_url.clone() + "exam-room";
+    let self_mark_url: String = app_settings.backend_url.clone() + "exam-room/self-mark";
+    let hint_url: String = app_settings.backend_url.clone() + "exam-room/hint";
+    let next_url: String = app_settings.backend_url.clone() + "exam-room/next";
+
+    // -- Create Actions --
+    let submit_action = create_action(move |_: &()| {
+        let submit_url: String = submit_url.clone();
+        let submission: AttemptSubmission = AttemptSubmission {
+            answers: responses.get_untracked(),
+        };
+        let attempt_raw: String = attempt_id
+            .get_untracked()
+            .map(|id| id.to_raw())
+            .unwrap_or_default();
+        async move {
+            let pkg: String = match serde_json::to_string(&submission) {
+                Ok(pkg) => pkg,
+                Err(_) => {
+                    err_msg.set(Some(String::from("Failed to serialize data")));
+                    return;
+                }
+            };
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(submit_url)
+                .add_query_param("attempt", &attempt_raw)
+                .set_method("PUT")
+                .set_mode(RequestMode::Cors)
+                .build();
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 200 {
+                let graded: SurrealAttempt = Fetcher::response_to_struct(&response).await;
+                result.set(Some(graded));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // Answers the question on screen and asks for the next, the attempt is submitted once none are left
+    let next_action =
+        create_action(move |answer: &Option<AttemptAnswer>| {
+            let next_url: String = next_url.clone();
+            let pkg: NextQuestionJsonPkg = NextQuestionJsonPkg {
+                answer: answer.clone(),
+            };
+            let attempt_raw: String = attempt_id
+                .get_untracked()
+                .map(|id| id.to_raw())
+                .unwrap_or_default();
+            async move {
+                let pkg: String = match serde_json::to_string(&pkg) {
+                    Ok(pkg) => pkg,
+                    Err(_) => {
+                        err_msg.set(Some(String::from("Failed to serialize data")));
+                        return;
+                    }
+                };
+                let fetcher: Fetcher = Fetcher::init()
+                    .set_url(next_url)
+                    .add_query_param("attempt", &attempt_raw)
+                    .set_method("PUT")
+                    .set_mode(RequestMode::Cors)
+                    .build();
+                let response: Response = fetcher.fetch(Some(pkg)).await;
+                if response.status() == 200 {
+                    let served: ServedQuestion = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(served.late.then(|| {
+                        String::from("Time ran out on that question, it was not counted")
+                    }));
+                    question_remaining.set(served.time_allowed_secs);
+                    match served.question {
+                        Some(eq) => questions.update(|qs| qs.push(eq)),
+                        None => submit_action.dispatch(()),
+                    }
+                } else {
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
+                }
+            }
+        });
 
     // -- Create Resource --
-    let quizzes_resource = create_resource(
+    let attempt_resource = create_resource(
         || (), // only render once
         move |_| {
             // should be safe to unwrap
@@ -43,29 +147,36 @@ pub fn ExamRoom(some_quiz: Option<SurrealQuiz>) -> impl IntoView {
                 quiz.id.to_raw()
             } else {
                 // this branch should not run
-                return Box::pin(async { () }) as Pin<Box<dyn Future<Output = _>>>;
+                return Box::pin(async {}) as Pin<Box<dyn Future<Output = _>>>;
             };
             let headers: Headers = Headers::new().unwrap();
             headers
                 .set("Content-Type", "application/json;charset=UTF-8")
                 .unwrap();
             let fetcher: Fetcher = Fetcher::init()
-                .set_url(app_settings.backend_url.clone() + "question-forge")
+                .set_url(app_settings.backend_url.clone() + "exam-room")
                 .add_query_param("quiz", &quiz_id)
-                .set_method("GET")
+                .set_method("POST")
                 .set_headers(headers)
                 .set_mode(RequestMode::Cors)
                 .build();
             Box::pin(async move {
                 let response: Response = fetcher.fetch(None).await;
-                if response.status() == 200 {
-                    let data: AllQuestions = Fetcher::response_to_struct(&response).await;
-                    // -- Update question signals below
-                    mcquestions.set(data.mc);
+                if response.status() == 201 {
+                    // questions arrive in the order the backend drew them
+                    let data: AttemptStart = Fetcher::response_to_struct(&response).await;
+                    attempt_id.set(Some(data.attempt_id));
+                    questions.set(data.questions);
+                    remaining.set(data.time_allowed_secs);
+                    hint_penalty.set(data.hint_penalty);
+                    // Questions timed one by one are served as they are asked for
+                    if data.time_per_question_secs.is_some() {
+                        paced.set(true);
+                        next_action.dispatch(None);
+                    }
                 } else {
-                    // Todo: display error message somewhere for failed fetch?
-                    let _deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
-                    // set_err_msg.set(deserialized.msg.clone());
+                    let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                    err_msg.set(deserialized.msg);
                 }
             }) as Pin<Box<dyn Future<Output = _>>>
         },
@@ -75,67 +186,243 @@ pub fn ExamRoom(some_quiz: Option<SurrealQuiz>) -> impl IntoView {
     // Runs code when signal changes
     // This resource is only set to run once, depends on ()
     create_effect(move |_| {
-        quizzes_resource.get();
+        attempt_resource.get();
     });
 
-    // Update quiz grading status
-    let click_grade = move |_ev: ev::MouseEvent| {
-        // Only changes to true
-        if !signal_to_grade.get() {
-            signal_to_grade.set(true);
+    // -- Create Actions --
+    let self_mark_action = create_action(move |mark: &SelfMarkJsonPkg| {
+        let self_mark_url: String = self_mark_url.clone();
+        let mark: SelfMarkJsonPkg = mark.clone();
+        let attempt_raw: String = attempt_id
+            .get_untracked()
+            .map(|id| id.to_raw())
+            .unwrap_or_default();
+        async move {
+            let pkg: String = match serde_json::to_string(&mark) {
+                Ok(pkg) => pkg,
+                Err(_) => {
+                    err_msg.set(Some(String::from("Failed to serialize data")));
+                    return;
+                }
+            };
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(self_mark_url)
+                .add_query_param("attempt", &attempt_raw)
+                .set_method("PUT")
+                .set_mode(RequestMode::Cors)
+                .build();
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 200 {
+                let graded: SurrealAttempt = Fetcher::response_to_struct(&response).await;
+                result.set(Some(graded));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // The backend records the reveal, so the penalty is applied when graded
+    let reveal_action = create_action(move |question_id: &Thing| {
+        let hint_url: String = hint_url.clone();
+        let pkg: HintJsonPkg = HintJsonPkg {
+            question_id: question_id.clone(),
         };
+        let attempt_raw: String = attempt_id
+            .get_untracked()
+            .map(|id| id.to_raw())
+            .unwrap_or_default();
+        async move {
+            let pkg: String = match serde_json::to_string(&pkg) {
+                Ok(pkg) => pkg,
+                Err(_) => {
+                    err_msg.set(Some(String::from("Failed to serialize data")));
+                    return;
+                }
+            };
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(hint_url)
+                .add_query_param("attempt", &attempt_raw)
+                .set_method("PUT")
+                .set_mode(RequestMode::Cors)
+                .build();
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 200 {
+                let hint: RevealedHint = Fetcher::response_to_struct(&response).await;
+                revealed.update(|rh| rh.push(hint));
+            } else {
+                let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                err_msg.set(deserialized.msg);
+            }
+        }
+    });
+
+    // The response to the question on screen, when questions are timed one by one
+    let current_answer = move || -> Option<AttemptAnswer> {
+        let current: Thing = questions.with_untracked(|qs| qs.last().map(|eq| eq.id.clone()))?;
+        responses.with_untracked(|res| {
+            res.iter()
+                .find(|res| res.question_id == current)
+                .map(|res| res.answer.clone())
+        })
     };
 
-    // -- Callback
-    let score_callback: Callback<bool, ()> = Callback::new(move |correct: bool| {
-        if correct {
-            user_grade.update(|s| *s += 1)
-        } else {
-            user_grade.update(|s| *s -= 1)
-        }
+    // Counts down once a second, submitting whatever is answered when time runs out.
+    // The backend discards the answers if they arrive past the deadline anyway.
+    // Questions timed one by one move on to the next question when their own time runs out.
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            if result.get_untracked().is_some() || submit_action.pending().get_untracked() {
+                return;
+            }
+            if !next_action.pending().get_untracked() {
+                match question_remaining.get_untracked() {
+                    Some(secs) if secs <= 1 => {
+                        question_remaining.set(None);
+                        next_action.dispatch(current_answer());
+                    }
+                    Some(secs) => question_remaining.set(Some(secs - 1)),
+                    None => {}
+                }
+            }
+            match remaining.get_untracked() {
+                Some(secs) if secs <= 1 => {
+                    remaining.set(Some(0));
+                    submit_action.dispatch(());
+                }
+                Some(secs) => remaining.set(Some(secs - 1)),
+                None => {}
+            }
+        },
+        std::time::Duration::from_secs(1),
+    ) {
+        on_cleanup(move || handle.clear());
+    };
+
+    // -- Callbacks --
+    // Each question reports its latest response, replacing any earlier one
+    let record_answer: Callback<AnswerJsonPkg> = Callback::new(move |pkg: AnswerJsonPkg| {
+        responses.update(|this| {
+            this.retain(|res| res.question_id != pkg.question_id);
+            this.push(pkg);
+        });
+    });
+    let self_mark: Callback<SelfMarkJsonPkg> = Callback::new(move |mark: SelfMarkJsonPkg| {
+        self_mark_action.dispatch(mark);
+    });
+
+    // -- Provide Context --
+    provide_context(ExamHints {
+        revealed,
+        penalty: hint_penalty,
+        reveal: Callback::new(move |question_id: Thing| {
+            if result.get_untracked().is_none() {
+                reveal_action.dispatch(question_id);
+            }
+        }),
     });
 
-    // Shuffle Questions too
-    let shuffled_mc_questions = move || {
-        let mut randrng = thread_rng();
-        let mut mcqs: Vec<SurrealQuestionMC> = mcquestions.get();
-        mcqs.shuffle(&mut randrng);
-        return mcqs;
+    // Answer the question on screen and move on, it cannot be changed after
+    let click_next = move |_ev: ev::MouseEvent| {
+        if result.get().is_none() && !next_action.pending().get() {
+            next_action.dispatch(current_answer());
+        };
+    };
+
+    // Submit the attempt for grading
+    let click_grade = move |_ev: ev::MouseEvent| {
+        if result.get().is_none() && attempt_id.get().is_some() {
+            submit_action.dispatch(());
+        };
     };
 
     // -- View --
     view! {
         <h2>{move || some_name.get()}</h2>
         <h3>"Taking an exam"</h3>
+        <h4>{move || err_msg.get()}</h4>
+        {move || {
+            match (remaining.get(), result.get()) {
+                (Some(secs), None) => Some(view! {
+                    <p>"Time left: "{format!("{}:{:02}", secs / 60, secs % 60)}</p>
+                }),
+                _ => None,
+            }
+        }}
+        {move || {
+            match (question_remaining.get(), result.get()) {
+                (Some(secs), None) => Some(view! {
+                    <p>"Time left on this question: "{format!("{}:{:02}", secs / 60, secs % 60)}</p>
+                }),
+                _ => None,
+            }
+        }}
         <For
-            each=move || shuffled_mc_questions()
-            key=|q| q.id.to_raw()
-            children=move |this| view! {
-                <MCQuestion
-                    sq=this
-                    to_grade=signal_to_grade
-                    user_grade=score_callback
-                />
+            // Questions timed one by one are shown one at a time until graded
+            each=move || {
+                let all: Vec<ExamQuestion> = questions.get();
+                if paced.get() && result.get().is_none() {
+                    all.into_iter().last().into_iter().collect()
+                } else {
+                    all
+                }
+            }
+            key=|eq| eq.id.to_raw()
+            children=move |eq| {
+                let quest_id: Thing = eq.id.clone();
+                let graded: Signal<Option<GradedAnswer>> = Signal::derive(move || {
+                    result.with(|att| att.as_ref().and_then(|att| att.graded(&quest_id)))
+                });
+                match eq.kind.clone() {
+                    ExamQuestionKind::MultipleChoice { choices } => view! {
+                        <MCQuestion eq=eq choices=choices graded=graded record_answer=record_answer />
+                    }.into_view(),
+                    ExamQuestionKind::ShortAnswer => view! {
+                        <SAQuestion eq=eq graded=graded record_answer=record_answer />
+                    }.into_view(),
+                    ExamQuestionKind::LongAnswer => view! {
+                        <LAQuestion
+                            eq=eq
+                            graded=graded
+                            record_answer=record_answer
+                            self_mark=self_mark
+                        />
+                    }.into_view(),
+                    ExamQuestionKind::TrueFalse => view! {
+                        <TFQuestion eq=eq graded=graded record_answer=record_answer />
+                    }.into_view(),
+                    ExamQuestionKind::MultiSelect { choices } => view! {
+                        <MSQuestion eq=eq choices=choices graded=graded record_answer=record_answer />
+                    }.into_view(),
+                }
             }
         />
         {
             move || {
-                match signal_to_grade.get() {
-                    true => None,
-                    false => Some(view! { <button on:click=click_grade>"Grade Quiz"</button> } ),
+                match result.get() {
+                    Some(_) => None,
+                    None => Some(view! {
+                        {move || paced.get().then(|| view! {
+                            <button
+                                on:click=click_next
+                                disabled=move || next_action.pending().get()
+                            >"Next Question"</button>
+                        })}
+                        <button
+                            on:click=click_grade
+                            disabled=move || submit_action.pending().get()
+                        >"Grade Quiz"</button>
+                    }),
                 }
             }
         }
         {move || {
-            if signal_to_grade.get() {
-                Some(
-                    view! {
-                        <p>"Score: "{user_grade.get()}"/"{mcquestions.get().len()}</p>
-                    }
-                )
-            } else {
-                None
-            }
+            result.get().map(|att| view! {
+                <p>"Score: "{format!("{:.2}", att.score)}"/"{att.max_score}</p>
+                {att.timed_out.then(|| view! {
+                    <p>"Submitted after time ran out, answers were not counted"</p>
+                })}
+            })
         }}
     }
 }
@@ -144,124 +431,430 @@ pub fn ExamRoom(some_quiz: Option<SurrealQuiz>) -> impl IntoView {
 /// answered by a user.
 #[component]
 pub fn MCQuestion(
-    sq: SurrealQuestionMC,
-    to_grade: RwSignal<bool>,
-    user_grade: Callback<bool, ()>,
+    eq: ExamQuestion,
+    mut choices: Vec<String>,
+    graded: Signal<Option<GradedAnswer>>,
+    record_answer: Callback<AnswerJsonPkg>,
 ) -> impl IntoView {
     // -- Create Signals --
-    let choices: RwSignal<Vec<(String, String)>> = create_rw_signal(Vec::new());
-    let is_correct: RwSignal<bool> = create_rw_signal(false);
-    let correct_key: RwSignal<String> = create_rw_signal(generate_random_string(16));
-    let correct_answer: RwSignal<String> = create_rw_signal(sq.answer.clone());
-    let radio_val: RwSignal<String> = create_rw_signal("".to_string());
-
-    // Pairing each choicec with random value
-    choices.set(
-        sq.choices
-            .iter()
-            .map(|c| (c.clone(), generate_random_string(16)))
-            .collect(),
-    );
-    // If more than 3 choices, can randomly select 3 here
-    // --
-    // Pairing the correct answer with correct_key
-    choices.update(|this| this.push((sq.answer.clone(), correct_key.get().clone())));
+    let picked: RwSignal<Option<String>> = create_rw_signal(None);
+    let quest_key: String = eq.id.to_raw();
+    let quest_id: Thing = eq.id;
+    let hint_id: Thing = quest_id.clone();
 
     // Shuffle Choices
-    choices.update(|this| {
-        let mut randrng = thread_rng();
-        this.shuffle(&mut randrng);
-    });
+    choices.shuffle(&mut thread_rng());
 
-    // Lambda to constantly update score based on the user's answers
-    // Comes from failed attempts to grade at end - all at once.
-    let radio_change = move |evnt: ev::Event| {
-        let val: String = event_target_value(&evnt);
-        radio_val.set(val.clone());
-        if val == correct_key.get() {
-            is_correct.update(|this| {
-                if *this {
-                    ()
-                } else {
-                    // this was false and is now true
-                    user_grade.call(true);
-                    *this = true;
-                }
-            })
-        } else {
-            is_correct.update(|this| {
-                // wrong answer - if previously correct...
-                if *this {
-                    user_grade.call(false);
-                    *this = false;
-                }
-            })
-        }
-    };
+    let is_graded = move || graded.with(|ga| ga.is_some());
+    let is_correct =
+        move || graded.with(|ga| ga.as_ref().is_some_and(|ga| ga.earned + ga.deducted >= 1.0));
 
     // For displaying answer
-    let answer_display = move || {
-        if is_correct.get() {
-            None
-        } else {
-            let p_tag = leptos::html::p();
-            p_tag.set_inner_text(&format!("Answer: {}", correct_answer.get()));
-            Some(p_tag)
-        }
+    let answer_display = move || match graded.get() {
+        Some(GradedAnswer {
+            key: AnswerKey::MultipleChoice(answer),
+            earned,
+            deducted,
+            ..
+        }) if earned + deducted < 1.0 => Some(view! { <p>"Answer: "{answer}</p> }),
+        _ => None,
     };
 
     // -- Render View --
     view! {
         <div
             class:quest-case=true
-            class:correct=move || is_correct.get() && to_grade.get()
-            class:incorrect=move || !is_correct.get() && to_grade.get()
+            class:correct=move || is_correct() && is_graded()
+            class:incorrect=move || !is_correct() && is_graded()
         >
-            <p>{&sq.question}</p>
-            {move || {
-                if to_grade.get() {
-                    view! {
-                        <form>
-                            <For
-                                each=move || choices.get()
-                                key=|c| c.1.clone()
-                                children=move |this| {
-                                    let is_checked = &this.1 == &radio_val.get();
-                                    view! {
-                                        <input
-                                            type="radio"
-                                            id=&this.1
-                                            name="question"
-                                            value=&this.1
-                                            on:change=radio_change
-                                            disabled=true
-                                            checked=is_checked
-                                        />
-                                        <label for=&this.1>{&this.0}</label><br />
-                                    }
+            <p>{&eq.question}</p>
+            <HintReveal question_id=hint_id has_hint=eq.has_hint hint=eq.hint.clone() graded=graded />
+            <form>
+                {choices
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, choice)| {
+                        let radio_id: String = format!("{}-{}", quest_key, idx);
+                        let label: String = choice.clone();
+                        let checked_choice: String = choice.clone();
+                        let quest_id: Thing = quest_id.clone();
+                        view! {
+                            <input
+                                type="radio"
+                                id=radio_id.clone()
+                                name="question"
+                                disabled=is_graded
+                                prop:checked=move || picked.get().as_ref() == Some(&checked_choice)
+                                on:change=move |_| {
+                                    picked.set(Some(choice.clone()));
+                                    record_answer.call(AnswerJsonPkg {
+                                        question_id: quest_id.clone(),
+                                        answer: AttemptAnswer::Choice(choice.clone()),
+                                    });
                                 }
                             />
-                        </form>
-                        {answer_display}
-                    }.into_view()
-                } else {
-                    view! {
-                        <form>
-                            <For
-                                each=move || choices.get()
-                                key=|c| c.1.clone()
-                                children=move |this| view! {
-                                    <input type="radio" id=&this.1 name="question" value=&this.1 on:change=radio_change/>
-                                    <label for=&this.1>{&this.0}</label><br />
+                            <label for=radio_id>{label}</label><br />
+                        }
+                    })
+                    .collect_view()}
+            </form>
+            {answer_display}
+        </div>
+    }
+}
+
+/// To render Short Answer questions for a quiz so that they can be
+/// answered by a user. Answers are matched ignoring case and whitespace.
+#[component]
+pub fn SAQuestion(
+    eq: ExamQuestion,
+    graded: Signal<Option<GradedAnswer>>,
+    record_answer: Callback<AnswerJsonPkg>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let quest_id: Thing = eq.id;
+    let hint_id: Thing = quest_id.clone();
+
+    let is_graded = move || graded.with(|ga| ga.is_some());
+    let is_correct =
+        move || graded.with(|ga| ga.as_ref().is_some_and(|ga| ga.earned + ga.deducted >= 1.0));
+
+    let input_change = move |evnt: ev::Event| {
+        record_answer.call(AnswerJsonPkg {
+            question_id: quest_id.clone(),
+            answer: AttemptAnswer::Text(event_target_value(&evnt)),
+        });
+    };
+
+    // For displaying accepted answers
+    let answer_display = move || match graded.get() {
+        Some(GradedAnswer {
+            key: AnswerKey::ShortAnswer(answers),
+            earned,
+            deducted,
+            ..
+        }) if earned + deducted < 1.0 => Some(view! { <p>"Accepted: "{answers.join("; ")}</p> }),
+        _ => None,
+    };
+
+    // -- Render View --
+    view! {
+        <div
+            class:quest-case=true
+            class:correct=move || is_correct() && is_graded()
+            class:incorrect=move || !is_correct() && is_graded()
+        >
+            <p>{&eq.question}</p>
+            <HintReveal question_id=hint_id has_hint=eq.has_hint hint=eq.hint.clone() graded=graded />
+            <input
+                type="text"
+                placeholder="Your answer"
+                disabled=is_graded
+                on:input=input_change
+            />
+            {answer_display}
+        </div>
+    }
+}
+
+/// To render True or False questions for a quiz so that they can be
+/// answered by a user.
+#[component]
+pub fn TFQuestion(
+    eq: ExamQuestion,
+    graded: Signal<Option<GradedAnswer>>,
+    record_answer: Callback<AnswerJsonPkg>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let picked: RwSignal<Option<bool>> = create_rw_signal(None);
+    let quest_key: String = eq.id.to_raw();
+    let quest_id: Thing = eq.id;
+    let hint_id: Thing = quest_id.clone();
+
+    let is_graded = move || graded.with(|ga| ga.is_some());
+    let is_correct =
+        move || graded.with(|ga| ga.as_ref().is_some_and(|ga| ga.earned + ga.deducted >= 1.0));
+
+    // For displaying answer
+    let answer_display = move || match graded.get() {
+        Some(GradedAnswer {
+            key: AnswerKey::TrueFalse(answer),
+            earned,
+            deducted,
+            ..
+        }) if earned + deducted < 1.0 => Some(view! {
+            <p>"Answer: "{if answer { "True" } else { "False" }}</p>
+        }),
+        _ => None,
+    };
+
+    // -- Render View --
+    view! {
+        <div
+            class:quest-case=true
+            class:correct=move || is_correct() && is_graded()
+            class:incorrect=move || !is_correct() && is_graded()
+        >
+            <p>{&eq.question}</p>
+            <HintReveal question_id=hint_id has_hint=eq.has_hint hint=eq.hint.clone() graded=graded />
+            <form>
+                {[true, false]
+                    .into_iter()
+                    .map(|val| {
+                        let radio_id: String = format!("{}-{}", quest_key, val);
+                        let quest_id: Thing = quest_id.clone();
+                        view! {
+                            <input
+                                type="radio"
+                                id=radio_id.clone()
+                                name="question"
+                                disabled=is_graded
+                                prop:checked=move || picked.get() == Some(val)
+                                on:change=move |_| {
+                                    picked.set(Some(val));
+                                    record_answer.call(AnswerJsonPkg {
+                                        question_id: quest_id.clone(),
+                                        answer: AttemptAnswer::TrueFalse(val),
+                                    });
                                 }
                             />
-                        </form>
-                        <button>":)"</button>
-                        <button>":("</button>
-                    }.into_view()
-                }
+                            <label for=radio_id>{if val { "True" } else { "False" }}</label><br />
+                        }
+                    })
+                    .collect_view()}
+            </form>
+            {answer_display}
+        </div>
+    }
+}
+
+/// To render Multi-Select questions for a quiz with checkboxes.
+/// The backend gives partial credit for these.
+#[component]
+pub fn MSQuestion(
+    eq: ExamQuestion,
+    mut choices: Vec<String>,
+    graded: Signal<Option<GradedAnswer>>,
+    record_answer: Callback<AnswerJsonPkg>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let selected: RwSignal<Vec<String>> = create_rw_signal(Vec::new());
+    let quest_key: String = eq.id.to_raw();
+    let quest_id: StoredValue<Thing> = store_value(eq.id);
+    let hint_id: Thing = quest_id.get_value();
+
+    choices.shuffle(&mut thread_rng());
+
+    let is_graded = move || graded.with(|ga| ga.is_some());
+    let is_correct =
+        move || graded.with(|ga| ga.as_ref().is_some_and(|ga| ga.earned + ga.deducted >= 1.0));
+
+    let toggle_choice = move |choice: String, checked: bool| {
+        selected.update(|sel| {
+            sel.retain(|c| *c != choice);
+            if checked {
+                sel.push(choice);
             }
+        });
+        record_answer.call(AnswerJsonPkg {
+            question_id: quest_id.get_value(),
+            answer: AttemptAnswer::Choices(selected.get_untracked()),
+        });
+    };
+
+    // For displaying the correct choices and partial credit
+    let answer_display = move || match graded.get() {
+        Some(GradedAnswer {
+            key: AnswerKey::MultiSelect(answers),
+            earned,
+            ..
+        }) => Some(view! {
+            <p>"Correct: "{answers.join("; ")}</p>
+            <p>"Earned: "{format!("{:.2}", earned)}</p>
+        }),
+        _ => None,
+    };
+
+    // -- Render View --
+    view! {
+        <div
+            class:quest-case=true
+            class:correct=move || is_correct() && is_graded()
+            class:incorrect=move || !is_correct() && is_graded()
+        >
+            <p>{&eq.question}</p>
+            <HintReveal question_id=hint_id has_hint=eq.has_hint hint=eq.hint.clone() graded=graded />
+            <p>"Select all that apply"</p>
+            <form>
+                {choices
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, choice)| {
+                        let box_id: String = format!("{}-{}", quest_key, idx);
+                        let label: String = choice.clone();
+                        view! {
+                            <input
+                                type="checkbox"
+                                id=box_id.clone()
+                                disabled=is_graded
+                                on:change=move |evnt| toggle_choice(choice.clone(), event_target_checked(&evnt))
+                            />
+                            <label for=box_id>{label}</label><br />
+                        }
+                    })
+                    .collect_view()}
+            </form>
+            {answer_display}
+        </div>
+    }
+}
+
+/// To render Long Answer (essay) questions for a quiz.
+/// After the quiz is graded the model answer and rubric are shown so the user
+/// can self-mark each criterion, which the backend adds to their score.
+#[component]
+pub fn LAQuestion(
+    eq: ExamQuestion,
+    graded: Signal<Option<GradedAnswer>>,
+    record_answer: Callback<AnswerJsonPkg>,
+    self_mark: Callback<SelfMarkJsonPkg>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let response: RwSignal<String> = create_rw_signal(String::new());
+    let quest_key: String = eq.id.to_raw();
+    let quest_id: StoredValue<Thing> = store_value(eq.id);
+    let hint_id: Thing = quest_id.get_value();
+
+    let is_graded = move || graded.with(|ga| ga.is_some());
+
+    let input_change = move |evnt: ev::Event| {
+        let val: String = event_target_value(&evnt);
+        response.set(val.clone());
+        record_answer.call(AnswerJsonPkg {
+            question_id: quest_id.get_value(),
+            answer: AttemptAnswer::Text(val),
+        });
+    };
+
+    // Sends every criterion currently met, the backend recalculates the score
+    let toggle_criterion = move |idx: usize, checked: bool| {
+        let mut criteria_met: Vec<usize> = graded
+            .get_untracked()
+            .map(|ga| ga.criteria_met)
+            .unwrap_or_default();
+        criteria_met.retain(|m| *m != idx);
+        if checked {
+            criteria_met.push(idx);
         }
+        self_mark.call(SelfMarkJsonPkg {
+            question_id: quest_id.get_value(),
+            criteria_met,
+        });
+    };
+
+    // -- Render View --
+    view! {
+        <div
+            class:quest-case=true
+        >
+            <p>{&eq.question}</p>
+            <HintReveal question_id=hint_id has_hint=eq.has_hint hint=eq.hint.clone() graded=graded />
+            <textarea
+                placeholder="Your answer"
+                disabled=is_graded
+                prop:value=move || response.get()
+                on:input=input_change
+            />
+            {move || match graded.get() {
+                Some(GradedAnswer {
+                    key: AnswerKey::LongAnswer { model_answer, rubric },
+                    earned,
+                    criteria_met,
+                    ..
+                }) => Some(view! {
+                    <p>"Model Answer: "{model_answer}</p>
+                    <p>"Mark each criterion your answer meets:"</p>
+                    {rubric
+                        .into_iter()
+                        .enumerate()
+                        .map(|(idx, rc)| {
+                            let box_id: String = format!("{}-{}", quest_key, idx);
+                            view! {
+                                <input
+                                    type="checkbox"
+                                    id=box_id.clone()
+                                    checked=criteria_met.contains(&idx)
+                                    on:change=move |evnt| toggle_criterion(idx, event_target_checked(&evnt))
+                                />
+                                <label for=box_id>{rc.criterion}" ("{rc.weight}")"</label><br />
+                            }
+                        })
+                        .collect_view()}
+                    <p>"Earned: "{format!("{:.2}", earned)}</p>
+                }),
+                _ => None,
+            }}
         </div>
     }
 }
+
+/// Reveals a question's hint on request, and shows what it cost once graded.
+/// Exams and studying ask the backend for the hint, only exams count it against the attempt.
+/// Without either, a hint handed out with the question is shown.
+#[component]
+pub fn HintReveal(
+    question_id: Thing,
+    has_hint: bool,
+    hint: Option<String>,
+    graded: Signal<Option<GradedAnswer>>,
+) -> impl IntoView {
+    // -- Create Signals --
+    let shown: RwSignal<bool> = create_rw_signal(false);
+    let question_id: StoredValue<Thing> = store_value(question_id);
+    let given: StoredValue<Option<String>> = store_value(hint);
+
+    // -- Use Context --
+    let exam_hints: Option<ExamHints> = use_context::<ExamHints>();
+
+    let hint = move || match exam_hints {
+        Some(exam_hints) => exam_hints.revealed.with(|rh| {
+            rh.iter()
+                .find(|hint| hint.question_id == question_id.get_value())
+                .map(|hint| hint.hint.clone())
+        }),
+        None => given.get_value().filter(|_| shown.get()),
+    };
+    let can_reveal = move || match exam_hints {
+        Some(_) => has_hint && graded.with(Option::is_none),
+        None => given.with_value(Option::is_some),
+    };
+    let reveal = move |_ev: ev::MouseEvent| match exam_hints {
+        Some(exam_hints) => exam_hints.reveal.call(question_id.get_value()),
+        None => shown.set(true),
+    };
+    let button_label = move || match exam_hints.map(|eh| eh.penalty.get()) {
+        Some(penalty) if penalty > 0.0 => {
+            format!("Show hint (costs {:.2} of this mark)", penalty)
+        }
+        _ => String::from("Show hint"),
+    };
+    let cost = move || {
+        graded.with(|ga| {
+            ga.as_ref()
+                .filter(|ga| ga.hint_used && ga.deducted > 0.0)
+                .map(|ga| view! { <p>"Hint used: -"{format!("{:.2}", ga.deducted)}</p> })
+        })
+    };
+
+    // -- Render View --
+    view! {
+        {move || match hint() {
+            Some(hint) => Some(view! { <p>"Hint: "{hint}</p> }.into_view()),
+            None if can_reveal() => Some(
+                view! { <button type="button" on:click=reveal>{button_label}</button> }.into_view(),
+            ),
+            None => None,
+        }}
+        {cost}
+    }
+}
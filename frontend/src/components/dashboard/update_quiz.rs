@@ -2,7 +2,14 @@
 //! This component will handle quiz update logic and redirect
 //! users back to the home screen
 use crate::{
-    models::{mimic_surreal::SurrealQuiz, quizzes::UpdateQuizActionPkg},
+    components::dashboard::{
+        read_hint_penalty, read_time_limits, HintPenaltyPicker, PoolPicker, TimeLimitPicker,
+        VisibilityPicker,
+    },
+    models::{
+        mimic_surreal::SurrealQuiz,
+        quizzes::{PoolDraw, UpdateQuizActionPkg},
+    },
     store::AppSettings,
     utils::{DashDisplay, Fetcher, JsonMsg},
 };
@@ -20,9 +27,19 @@ pub fn UpdateQuiz(
     //  -- Create Signals --
     let (err_msg, set_err_msg): (ReadSignal<Option<String>>, WriteSignal<Option<String>>) =
         create_signal(None);
+    let pools: RwSignal<Vec<PoolDraw>> = create_rw_signal(
+        quiz_rw
+            .get_untracked()
+            .map(|qz| qz.pools)
+            .unwrap_or_default(),
+    );
     //  -- Create References --
     let quiz_title: NodeRef<html::Input> = create_node_ref();
     let quiz_description: NodeRef<html::Textarea> = create_node_ref();
+    let quiz_visibility: NodeRef<html::Select> = create_node_ref();
+    let quiz_time_limit: NodeRef<html::Input> = create_node_ref();
+    let time_per_question: NodeRef<html::Input> = create_node_ref();
+    let hint_penalty: NodeRef<html::Input> = create_node_ref();
     // -- Use Context --
     let app_settings: AppSettings =
         use_context::<AppSettings>().expect("AppSettings context not found");
@@ -44,7 +61,13 @@ pub fn UpdateQuiz(
         async move {
             let response: Response = fetcher.fetch(Some(pkg_clone)).await;
             if response.status() == 200 {
-                let data: SurrealQuiz = Fetcher::response_to_struct(&response).await;
+                let mut data: SurrealQuiz = Fetcher::response_to_struct(&response).await;
+                // editing only returns the quiz record, keep what the listing added
+                if let Some(current) = quiz_rw.get_untracked() {
+                    data.likes = current.likes;
+                    data.liked = current.liked;
+                    data.tags = current.tags;
+                }
                 pop_quiz.call(data.clone());
                 push_quiz.call(data);
                 // response_setter.set(Some(data));
@@ -90,11 +113,22 @@ pub fn UpdateQuiz(
             .get()
             .expect("<textarea> should be mounted")
             .value();
+        let visibility_value: String = quiz_visibility
+            .get()
+            .expect("<select> should be mounted")
+            .value();
+        let (time_limit_secs, time_per_question_secs) =
+            read_time_limits(quiz_time_limit, time_per_question);
 
         // Package Data into JSON String
         let pkg: String = serde_json::json!({
             "name": title_value,
-            "description": description_value
+            "description": description_value,
+            "visibility": visibility_value,
+            "time_limit_secs": time_limit_secs,
+            "time_per_question_secs": time_per_question_secs,
+            "hint_penalty": read_hint_penalty(hint_penalty),
+            "pools": pools.get_untracked()
         })
         .to_string();
 
@@ -127,6 +161,21 @@ pub fn UpdateQuiz(
                     placeholder="Description..."
                     node_ref=quiz_description required
                 >{get_quiz_description}</textarea>
+                <VisibilityPicker
+                    node_ref=quiz_visibility
+                    current=quiz_rw.get_untracked().map(|qz| qz.visibility).unwrap_or_default()
+                />
+                <TimeLimitPicker
+                    total_ref=quiz_time_limit
+                    question_ref=time_per_question
+                    current_total=quiz_rw.get_untracked().and_then(|qz| qz.time_limit_secs)
+                    current_question=quiz_rw.get_untracked().and_then(|qz| qz.time_per_question_secs)
+                />
+                <HintPenaltyPicker
+                    node_ref=hint_penalty
+                    current=quiz_rw.get_untracked().map(|qz| qz.hint_penalty).unwrap_or_default()
+                />
+                <PoolPicker pools=pools />
                 <input type="submit" value="Update Quiz!" />
             </form>
         </div>
@@ -0,0 +1,64 @@
+//! frontend/src/components/like_button.rs
+//! A button to like, or unlike, a quiz or question, showing how many users like it.
+use leptos::*;
+use web_sys::{RequestMode, Response};
+
+use crate::{
+    models::{
+        likes::{LikeJsonPkg, LikeStatus},
+        mimic_surreal::Thing,
+    },
+    store::AppSettings,
+    utils::Fetcher,
+};
+
+/// Clicking flips whether the user likes the target, the count comes back from the backend.
+#[component]
+pub fn LikeButton(target: Thing, likes: u64, liked: bool) -> impl IntoView {
+    // -- Create Signals --
+    let likes_rw: RwSignal<u64> = create_rw_signal(likes);
+    let liked_rw: RwSignal<bool> = create_rw_signal(liked);
+    let target: StoredValue<Thing> = store_value(target);
+
+    // -- Use Context --
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Actions --
+    let like_action = create_action(move |_: &()| {
+        let like: LikeJsonPkg = LikeJsonPkg {
+            target: target.get_value(),
+            liked: !liked_rw.get_untracked(),
+        };
+        let fetcher: Fetcher = Fetcher::init()
+            .set_url(app_settings.backend_url.to_string() + "likes")
+            .set_method("PUT")
+            .set_mode(RequestMode::Cors)
+            .build();
+        async move {
+            let pkg: String = match serde_json::to_string(&like) {
+                Ok(pkg) => pkg,
+                Err(_) => return,
+            };
+            let response: Response = fetcher.fetch(Some(pkg)).await;
+            if response.status() == 200 {
+                let status: LikeStatus = Fetcher::response_to_struct(&response).await;
+                likes_rw.set(status.likes);
+                liked_rw.set(status.liked);
+            }
+        }
+    });
+
+    // -- Render View --
+    view! {
+        <button
+            data-note="like_button"
+            class:liked=move || liked_rw.get()
+            on:click=move |_| like_action.dispatch(())
+            disabled=move || like_action.pending().get()
+        >
+            {move || if liked_rw.get() { "Unlike" } else { "Like" }}
+            " ("{move || likes_rw.get()}")"
+        </button>
+    }
+}
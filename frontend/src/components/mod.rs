@@ -2,6 +2,8 @@
 pub mod card;
 pub mod dashboard;
 pub mod footer;
+pub mod like_button;
 
 pub use card::*;
 pub use footer::*;
+pub use like_button::*;
@@ -0,0 +1,189 @@
+//! frontend/src/models/attempts.rs
+//! Models for taking a quiz, mirroring `models::attempts`.
+//! Attempts are graded by the backend, these only carry data back and forth.
+use crate::models::{mimic_surreal::Thing, questions::RubricCriterion};
+use serde::{Deserialize, Serialize};
+
+/// A question as handed out when an attempt starts, without the answer.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExamQuestion {
+    pub id: Thing,
+    pub question: String,
+    pub hint: Option<String>,
+    // hints are held back until revealed, which costs the quiz's hint penalty
+    #[serde(default)]
+    pub has_hint: bool,
+    pub kind: ExamQuestionKind,
+}
+
+/// What kind of response a question expects.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ExamQuestionKind {
+    MultipleChoice { choices: Vec<String> },
+    ShortAnswer,
+    LongAnswer,
+    TrueFalse,
+    MultiSelect { choices: Vec<String> },
+}
+
+/// The correct answer to a question, only seen once an attempt is submitted.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum AnswerKey {
+    MultipleChoice(String),
+    ShortAnswer(Vec<String>),
+    LongAnswer {
+        model_answer: String,
+        rubric: Vec<RubricCriterion>,
+    },
+    TrueFalse(bool),
+    MultiSelect(Vec<String>),
+}
+
+/// A response to a single question.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum AttemptAnswer {
+    Choice(String),
+    Text(String),
+    TrueFalse(bool),
+    Choices(Vec<String>),
+}
+
+/// One question of a submitted attempt, with its response and mark.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GradedAnswer {
+    pub question_id: Thing,
+    pub question: String,
+    pub response: Option<AttemptAnswer>,
+    pub key: AnswerKey,
+    pub earned: f64,
+    pub criteria_met: Vec<usize>,
+    #[serde(default)]
+    pub hint_used: bool,
+    // already taken off `earned`
+    #[serde(default)]
+    pub deducted: f64,
+}
+
+/// An attempt as stored by the backend, timestamps are kept as strings.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SurrealAttempt {
+    pub id: Thing,
+    pub quiz_id: Thing,
+    pub user_id: String,
+    pub question_ids: Vec<Thing>,
+    pub started_at: String,
+    pub submitted_at: Option<String>,
+    pub answers: Vec<GradedAnswer>,
+    pub score: f64,
+    pub max_score: f64,
+    #[serde(default)]
+    pub deadline: Option<String>,
+    // set when submitted after the deadline, answers are discarded
+    #[serde(default)]
+    pub timed_out: bool,
+}
+
+impl SurrealAttempt {
+    /// The graded answer for a question, if it was part of this attempt.
+    pub fn graded(&self, question_id: &Thing) -> Option<GradedAnswer> {
+        self.answers
+            .iter()
+            .find(|ga| &ga.question_id == question_id)
+            .cloned()
+    }
+}
+
+/// Returned by the backend when an attempt starts.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttemptStart {
+    pub attempt_id: Thing,
+    pub questions: Vec<ExamQuestion>,
+    // seconds the whole attempt may take, missing when untimed
+    #[serde(default)]
+    pub time_allowed_secs: Option<i64>,
+    // set when questions are served one at a time, each with this long to answer
+    #[serde(default)]
+    pub time_per_question_secs: Option<u32>,
+    #[serde(default)]
+    pub hint_penalty: f64,
+}
+
+/// Answers the open question of an attempt timed per question, asking for the next.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NextQuestionJsonPkg {
+    pub answer: Option<AttemptAnswer>,
+}
+
+/// The next question of an attempt timed per question, missing once all have been served.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ServedQuestion {
+    pub question: Option<ExamQuestion>,
+    // seconds left to answer it
+    pub time_allowed_secs: Option<i64>,
+    // the previous answer arrived too late to count
+    pub late: bool,
+}
+
+/// Asks for the hint to one question of an attempt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HintJsonPkg {
+    pub question_id: Thing,
+}
+
+/// A revealed hint, counted against the attempt when graded.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevealedHint {
+    pub question_id: Thing,
+    pub hint: String,
+}
+
+/// A response to one question when submitting an attempt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerJsonPkg {
+    pub question_id: Thing,
+    pub answer: AttemptAnswer,
+}
+
+/// Every response of an attempt, submitted at once.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttemptSubmission {
+    pub answers: Vec<AnswerJsonPkg>,
+}
+
+/// Self-marking a long answer question of a submitted attempt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SelfMarkJsonPkg {
+    pub question_id: Thing,
+    pub criteria_met: Vec<usize>,
+}
+
+/// One past attempt in a user's history.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttemptSummary {
+    pub attempt_id: Thing,
+    pub submitted_at: Option<String>,
+    pub score: f64,
+    pub max_score: f64,
+    pub percent: f64,
+}
+
+/// How often a single question has been answered correctly across attempts.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuestionStat {
+    pub question_id: Thing,
+    pub question: String,
+    pub times_answered: u32,
+    pub success_rate: f64,
+}
+
+/// A user's progress on a single quiz, summarised by the backend.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuizProgress {
+    pub quiz_id: Thing,
+    pub quiz_name: String,
+    pub attempts: Vec<AttemptSummary>,
+    pub best: f64,
+    pub latest: f64,
+    pub average: f64,
+    pub question_stats: Vec<QuestionStat>,
+}
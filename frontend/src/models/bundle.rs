@@ -0,0 +1,33 @@
+//! frontend/src/models/bundle.rs
+//! Models for importing quiz bundles, mirroring `models::bundle`.
+use crate::models::mimic_surreal::SurrealQuiz;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of checking a single question of a bundle.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportItem {
+    pub index: usize,
+    pub table: String,
+    pub question: String,
+    pub error: Option<String>,
+}
+
+/// Part of a GIFT or Moodle XML question with no equivalent here.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Unmapped {
+    pub item: usize,
+    pub name: Option<String>,
+    pub construct: String,
+    pub skipped: bool,
+}
+
+/// What an import did, or in a dry run would do.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub quiz_error: Option<String>,
+    pub items: Vec<ImportItem>,
+    #[serde(default)]
+    pub unmapped: Vec<Unmapped>,
+    pub quiz: Option<SurrealQuiz>,
+}
@@ -0,0 +1,17 @@
+//! frontend/src/models/csv_upload.rs
+//! Models for uploading CSV questions, mirroring `models::csv_upload`.
+use serde::{Deserialize, Serialize};
+
+/// A problem with one row of an upload, the header is row 1.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CsvRowError {
+    pub row: u64,
+    pub error: String,
+}
+
+/// Body of a rejected upload, nothing from the batch is saved.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CsvRejection {
+    pub msg: String,
+    pub rows: Vec<CsvRowError>,
+}
@@ -0,0 +1,82 @@
+//! frontend/src/models/groups.rs
+//! Models for groups and quiz assignments, mirroring `models::groups`.
+//! Times are kept as the RFC 3339 strings the backend sends.
+use crate::{
+    models::mimic_surreal::{SurrealQuiz, Thing},
+    utils::PartialUser,
+};
+use serde::{Deserialize, Serialize};
+
+/// For creating groups.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupJsonPkg {
+    pub name: String,
+}
+
+/// For an owner adding a student by username.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InvitePkg {
+    pub username: String,
+}
+
+/// For a student joining with a code.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JoinPkg {
+    pub join_code: String,
+}
+
+/// For assigning a quiz to a group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssignmentJsonPkg {
+    pub quiz_id: Thing,
+    pub opens_at: String,
+    pub closes_at: String,
+}
+
+/// A quiz given to a group, open between two times.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SurrealAssignment {
+    pub id: Thing,
+    pub group_id: Thing,
+    pub quiz_id: Thing,
+    pub opens_at: String,
+    pub closes_at: String,
+}
+
+/// A group the user owns or belongs to, only owners get the code, members and assignments.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupSummary {
+    pub id: Thing,
+    pub name: String,
+    pub owner_id: String,
+    pub join_code: String,
+    pub owned: bool,
+    pub members: Vec<PartialUser>,
+    pub assignments: Vec<SurrealAssignment>,
+}
+
+/// An assignment for a student, with its quiz and the name of its group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssignedQuiz {
+    pub id: Thing,
+    pub opens_at: String,
+    pub closes_at: String,
+    pub quiz: SurrealQuiz,
+    pub group_name: String,
+}
+
+/// How one member is doing on an assignment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MemberProgress {
+    pub member: PartialUser,
+    pub attempts: u32,
+    pub completed: bool,
+    pub best_score: Option<f64>,
+}
+
+/// Every member's progress on an assignment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssignmentProgress {
+    pub assignment: SurrealAssignment,
+    pub members: Vec<MemberProgress>,
+}
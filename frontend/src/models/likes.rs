@@ -0,0 +1,19 @@
+//! frontend/src/models/likes.rs
+//! Models for liking quizzes and questions, mirroring `models::likes`.
+use crate::models::mimic_surreal::Thing;
+use serde::{Deserialize, Serialize};
+
+/// Sets whether the user likes the target.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LikeJsonPkg {
+    pub target: Thing,
+    pub liked: bool,
+}
+
+/// Returned by the backend once a like is set, or unset.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LikeStatus {
+    pub target: Thing,
+    pub liked: bool,
+    pub likes: u64,
+}
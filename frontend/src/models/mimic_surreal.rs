@@ -2,6 +2,10 @@
 //! Having trouble compiling surrealdb sdk to web assembly,
 //! but this application only needs a few structs.
 //! Replicating them here for compatibility.
+use crate::models::{
+    questions::RubricCriterion,
+    quizzes::{PoolDraw, Visibility},
+};
 use serde::{Deserialize, Serialize};
 
 /// The record ID of records returned from SurrealDB.
@@ -28,6 +32,14 @@ pub enum Id {
     String(String),
 }
 
+/// A user's tag, for organising quizzes and questions
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SurrealTag {
+    pub id: Thing,
+    pub name: String,
+    pub author_id: String,
+}
+
 /// Multiple Choice question type
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SurrealQuestionMC {
@@ -38,6 +50,77 @@ pub struct SurrealQuestionMC {
     pub parent_quiz: Thing,
     pub answer: String,
     pub choices: Vec<String>,
+    // like counts are only sent when listing
+    #[serde(default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub liked: bool,
+}
+
+/// Short Answer question type
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SurrealQuestionSA {
+    pub id: Thing,
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub answers: Vec<String>,
+    // like counts are only sent when listing
+    #[serde(default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub liked: bool,
+}
+
+/// Long Answer question type
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SurrealQuestionLA {
+    pub id: Thing,
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub model_answer: String,
+    pub rubric: Vec<RubricCriterion>,
+    // like counts are only sent when listing
+    #[serde(default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub liked: bool,
+}
+
+/// True or False question type
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SurrealQuestionTF {
+    pub id: Thing,
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub answer: bool,
+    // like counts are only sent when listing
+    #[serde(default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub liked: bool,
+}
+
+/// Multi-Select question type
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SurrealQuestionMS {
+    pub id: Thing,
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub answers: Vec<String>,
+    pub choices: Vec<String>,
+    // like counts are only sent when listing
+    #[serde(default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub liked: bool,
 }
 
 /// For holding Quiz data provided by backend
@@ -47,4 +130,24 @@ pub struct SurrealQuiz {
     pub name: String,
     pub description: String,
     pub author_id: String,
+    #[serde(default)]
+    pub visibility: Visibility,
+    // untimed when missing or 0
+    #[serde(default)]
+    pub time_limit_secs: Option<u32>,
+    #[serde(default)]
+    pub time_per_question_secs: Option<u32>,
+    // every question is handed out when there are no pools
+    #[serde(default)]
+    pub pools: Vec<PoolDraw>,
+    #[serde(default)]
+    pub hint_penalty: f64,
+    // like counts are only sent when listing
+    #[serde(default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub liked: bool,
+    // tags are only sent when listing the user's own quizzes
+    #[serde(default)]
+    pub tags: Vec<SurrealTag>,
 }
@@ -1,5 +1,13 @@
 //! frontend/src/models/mod.rs
 //! File to track models
+pub mod attempts;
+pub mod bundle;
+pub mod csv_upload;
+pub mod groups;
+pub mod likes;
 pub mod mimic_surreal;
 pub mod questions;
 pub mod quizzes;
+pub mod search;
+pub mod study;
+pub mod tags;
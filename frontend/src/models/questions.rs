@@ -1,6 +1,9 @@
 //! frontend/src/models/questions.rs
 //! Models for questions
-use crate::models::mimic_surreal::{SurrealQuestionMC, Thing};
+use crate::models::mimic_surreal::{
+    SurrealQuestionLA, SurrealQuestionMC, SurrealQuestionMS, SurrealQuestionSA, SurrealQuestionTF,
+    Thing,
+};
 use serde::{Deserialize, Serialize};
 
 /// Struct from Models for transporting all questions for a quiz
@@ -8,6 +11,18 @@ use serde::{Deserialize, Serialize};
 pub struct AllQuestions {
     // mc = multiple choice
     pub mc: Vec<SurrealQuestionMC>,
+    // sa = short answer
+    #[serde(default)]
+    pub sa: Vec<SurrealQuestionSA>,
+    // la = long answer
+    #[serde(default)]
+    pub la: Vec<SurrealQuestionLA>,
+    // tf = true or false
+    #[serde(default)]
+    pub tf: Vec<SurrealQuestionTF>,
+    // ms = multi-select
+    #[serde(default)]
+    pub ms: Vec<SurrealQuestionMS>,
 }
 
 /// Existing Questions are now Quests
@@ -15,15 +30,20 @@ pub struct AllQuestions {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum QuestType {
     MC(SurrealQuestionMC),
-    LA,
-    SA,
+    LA(SurrealQuestionLA),
+    SA(SurrealQuestionSA),
+    TF(SurrealQuestionTF),
+    MS(SurrealQuestionMS),
 }
 
 impl QuestType {
     pub fn get_id(&self) -> Thing {
         match &self {
             QuestType::MC(quest) => quest.id.clone(),
-            _ => unimplemented!(),
+            QuestType::SA(quest) => quest.id.clone(),
+            QuestType::LA(quest) => quest.id.clone(),
+            QuestType::TF(quest) => quest.id.clone(),
+            QuestType::MS(quest) => quest.id.clone(),
         }
     }
 }
@@ -47,6 +67,10 @@ pub struct QuestionJsonPkg {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum JsonQuestion {
     MultipleChoice(JsonQuestionMC),
+    ShortAnswer(JsonQuestionSA),
+    LongAnswer(JsonQuestionLA),
+    TrueFalse(JsonQuestionTF),
+    MultiSelect(JsonQuestionMS),
 }
 
 impl Default for JsonQuestion {
@@ -64,6 +88,47 @@ pub struct JsonQuestionMC {
     pub choices: Vec<String>,
 }
 
+/// Specific short answer question JSON format for sending information to backend
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct JsonQuestionSA {
+    pub question: String,
+    pub hint: Option<String>,
+    pub answers: Vec<String>,
+}
+
+/// Specific long answer question JSON format for sending information to backend
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct JsonQuestionLA {
+    pub question: String,
+    pub hint: Option<String>,
+    pub model_answer: String,
+    pub rubric: Vec<RubricCriterion>,
+}
+
+/// Specific true or false question JSON format for sending information to backend
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct JsonQuestionTF {
+    pub question: String,
+    pub hint: Option<String>,
+    pub answer: bool,
+}
+
+/// Specific multi-select question JSON format for sending information to backend
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct JsonQuestionMS {
+    pub question: String,
+    pub hint: Option<String>,
+    pub answers: Vec<String>,
+    pub choices: Vec<String>,
+}
+
+/// A single line of a marking rubric for long answer questions.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct RubricCriterion {
+    pub criterion: String,
+    pub weight: u32,
+}
+
 /// Question List Internals, Used to track and
 #[derive(Clone, Debug)]
 pub struct QLInternals {
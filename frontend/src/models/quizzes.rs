@@ -1,5 +1,6 @@
 //! frontend/src/models/quizzes.rs
 //! Models for questions
+use crate::models::mimic_surreal::{SurrealQuiz, Thing};
 use serde::{Deserialize, Serialize};
 
 /// For sending and recieving quiz data
@@ -9,6 +10,56 @@ pub struct QuizJsonPkg {
     pub description: String,
 }
 
+/// Who can find and take a quiz, besides its author.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub enum Visibility {
+    #[default]
+    Private,
+    Unlisted,
+    Public,
+}
+
+impl Visibility {
+    pub const ALL: [Visibility; 3] = [
+        Visibility::Private,
+        Visibility::Unlisted,
+        Visibility::Public,
+    ];
+
+    /// Matches how the backend names each variant.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Private => "Private",
+            Visibility::Unlisted => "Unlisted",
+            Visibility::Public => "Public",
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Visibility::Private => "Private - only me",
+            Visibility::Unlisted => "Unlisted - anyone with the link",
+            Visibility::Public => "Public - listed for everyone",
+        }
+    }
+}
+
+/// Draw `count` questions tagged with `tag`, from any of the author's quizzes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PoolDraw {
+    pub tag: Thing,
+    pub count: u32,
+}
+
+/// A page of public quizzes from browsing, `total` counts every match.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublicQuizPage {
+    pub quizzes: Vec<SurrealQuiz>,
+    pub page: u32,
+    pub per_page: u32,
+    pub total: u64,
+}
+
 /// For sending and recieving quiz data in Action
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UpdateQuizActionPkg {
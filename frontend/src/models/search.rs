@@ -0,0 +1,37 @@
+//! frontend/src/models/search.rs
+//! Models for full-text search results, mirroring `models::search`.
+use crate::models::mimic_surreal::Thing;
+use serde::{Deserialize, Serialize};
+
+/// What kind of record a search hit is.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SearchKind {
+    Quiz,
+    Question,
+}
+
+/// A piece of a snippet, `hit` is true for the words that matched.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SnippetPart {
+    pub text: String,
+    pub hit: bool,
+}
+
+/// A single quiz or question that matched a search.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SearchHit {
+    pub id: Thing,
+    pub kind: SearchKind,
+    pub quiz_id: Thing,
+    pub quiz_name: String,
+    pub title: Vec<SnippetPart>,
+    pub snippet: Vec<SnippetPart>,
+    pub score: f64,
+}
+
+/// Every hit for a search, best match first.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SearchResults {
+    pub query: String,
+    pub hits: Vec<SearchHit>,
+}
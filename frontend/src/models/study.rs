@@ -0,0 +1,36 @@
+//! frontend/src/models/study.rs
+//! Models for spaced repetition study mode, mirroring `models::study`.
+//! Scheduling is done by the backend, these only carry data back and forth.
+use crate::models::{
+    attempts::{AnswerKey, AttemptAnswer},
+    mimic_surreal::Thing,
+};
+use serde::{Deserialize, Serialize};
+
+/// How well a question was recalled during a review.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ReviewRating {
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+/// The result of a single review, sent to the backend.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReviewJsonPkg {
+    pub question_id: Thing,
+    pub answer: AttemptAnswer,
+    pub rating: ReviewRating,
+}
+
+/// Returned once a review is recorded, timestamps are kept as strings.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReviewOutcome {
+    pub question_id: Thing,
+    pub earned: f64,
+    pub key: AnswerKey,
+    pub rating: ReviewRating,
+    pub interval_days: u32,
+    pub due: String,
+}
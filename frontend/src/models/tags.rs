@@ -0,0 +1,28 @@
+//! frontend/src/models/tags.rs
+//! Models for tagging quizzes and questions, mirroring `models::tags`.
+use crate::models::mimic_surreal::Thing;
+use serde::{Deserialize, Serialize};
+
+/// For creating and renaming tags.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TagJsonPkg {
+    pub name: String,
+}
+
+/// Sets whether the target has the tag.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TagAssignPkg {
+    pub tag: Thing,
+    pub target: Thing,
+    pub tagged: bool,
+}
+
+/// A user's tag with how many quizzes and questions have it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TagSummary {
+    pub id: Thing,
+    pub name: String,
+    pub author_id: String,
+    pub quizzes: u64,
+    pub questions: u64,
+}
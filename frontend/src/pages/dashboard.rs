@@ -5,7 +5,10 @@ use web_sys::{Headers, RequestMode, Response};
 
 use crate::{
     components::{
-        dashboard::{ExamRoom, MakeQuiz, QuestionForge, QuizShowCase, UpdateQuiz},
+        dashboard::{
+            ExamRoom, GroupHall, ImportQuiz, MakeQuiz, ProgressReport, PublicShowCase,
+            QuestionForge, QuizShowCase, SearchBox, SearchResultList, StudyHall, UpdateQuiz,
+        },
         Card, Footer, TodoCard,
     },
     models::mimic_surreal::SurrealQuiz,
@@ -63,6 +66,7 @@ pub fn Dashboard() -> impl IntoView {
         WriteSignal<Option<SurrealQuiz>>,
     ) = create_signal(None);
     let quiz_list = create_rw_signal(Vec::new());
+    let search_rw: RwSignal<String> = create_rw_signal(String::new());
 
     // -- Use Context --
     let user: PartialUser = use_context().expect("PartialUser Context not set");
@@ -73,15 +77,34 @@ pub fn Dashboard() -> impl IntoView {
     let set_display_my_quizzes = Callback::new(move |_click: ev::MouseEvent| {
         write_display.set(DashDisplay::MyQuizzes);
     });
+    let set_display_public_quizzes = Callback::new(move |_click: ev::MouseEvent| {
+        write_display.set(DashDisplay::PublicQuizzes);
+    });
+    let set_display_progress = Callback::new(move |_click: ev::MouseEvent| {
+        write_display.set(DashDisplay::Progress);
+    });
+    let set_display_groups = Callback::new(move |_click: ev::MouseEvent| {
+        write_display.set(DashDisplay::Groups);
+    });
+    let set_display_search = Callback::new(move |_: ()| {
+        write_display.set(DashDisplay::Search);
+    });
     let set_display_make_quiz = Callback::new(move |_click: ev::MouseEvent| {
         current_quiz_rw.set(None);
         write_display.set(DashDisplay::MakeQuizzes);
     });
+    let set_display_import_quiz = Callback::new(move |_click: ev::MouseEvent| {
+        write_display.set(DashDisplay::ImportQuiz);
+    });
     // Callback to setup quiz to take
     let choose_quiz_to_take = Callback::new(move |quiz: SurrealQuiz| {
         current_quiz_rw.set(Some(quiz));
         write_display.set(DashDisplay::TakeQuiz);
     });
+    let choose_quiz_to_study = Callback::new(move |quiz: SurrealQuiz| {
+        current_quiz_rw.set(Some(quiz));
+        write_display.set(DashDisplay::StudyQuiz);
+    });
     let choose_quiz_to_update = Callback::new(move |qz: SurrealQuiz| {
         current_quiz_rw.set(Some(qz));
         write_display.set(DashDisplay::UpdateQuiz);
@@ -143,11 +166,15 @@ pub fn Dashboard() -> impl IntoView {
             <QuizShowCase
                 quiz_list=quiz_list
                 quiz_selector=choose_quiz_to_take
+                quiz_studier=choose_quiz_to_study
                 pop_quiz=remove_quiz
                 quiz_updater=choose_quiz_to_update
                 quest_calibrate=reforge_questions
             />
         },
+        DashDisplay::PublicQuizzes => view! {
+            <PublicShowCase quiz_selector=choose_quiz_to_take />
+        },
         DashDisplay::MakeQuizzes => view! {
             <MakeQuiz
                 display_settings=write_display
@@ -164,6 +191,9 @@ pub fn Dashboard() -> impl IntoView {
         DashDisplay::TakeQuiz => view! {
             <ExamRoom some_quiz=current_quiz_rw.get()/>
         },
+        DashDisplay::StudyQuiz => view! {
+            <StudyHall some_quiz=current_quiz_rw.get()/>
+        },
         DashDisplay::UpdateQuiz => view! {
             <UpdateQuiz
                 display_settings=write_display
@@ -172,6 +202,18 @@ pub fn Dashboard() -> impl IntoView {
                 quiz_rw=current_quiz_rw
             />
         },
+        DashDisplay::Progress => view! {
+            <ProgressReport />
+        },
+        DashDisplay::Search => view! {
+            <SearchResultList search_rw=search_rw />
+        },
+        DashDisplay::ImportQuiz => view! {
+            <ImportQuiz push_quiz=add_quiz />
+        },
+        DashDisplay::Groups => view! {
+            <GroupHall quiz_list=quiz_list quiz_selector=choose_quiz_to_take />
+        },
     };
 
     // -- Render View --
@@ -197,17 +239,28 @@ pub fn Dashboard() -> impl IntoView {
                     <Card on_click=Some(set_display_my_quizzes)>
                         "To Main Page - My Quizzes!"
                     </Card>
-                    <Card on_click=Some(set_display_make_quiz)>
-                        "Make a New Quiz"
+                    <Card on_click=Some(set_display_public_quizzes)>
+                        "Browse Public Quizzes"
                     </Card>
+                    <Card on_click=Some(set_display_progress)>
+                        "My Progress"
+                    </Card>
+                    <Card on_click=Some(set_display_groups)>
+                        "My Groups"
+                    </Card>
+                    // Students take quizzes, they do not write them
+                    {user.role.can_author().then(|| view! {
+                        <Card on_click=Some(set_display_make_quiz)>
+                            "Make a New Quiz"
+                        </Card>
+                        <Card on_click=Some(set_display_import_quiz)>
+                            "Import a Quiz"
+                        </Card>
+                    })}
                     <TodoCard on_click=None>
                         "Saved Quizzes"
                     </TodoCard>
-                    <TodoCard on_click=None>
-                        <div
-                            class:unimplemented-button=true
-                        >"Search Quizzes"</div>
-                    </TodoCard>
+                    <SearchBox search_rw=search_rw on_search=set_display_search />
                 </aside>
                 <section
                     class:main-content=true
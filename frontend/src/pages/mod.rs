@@ -4,10 +4,12 @@ mod dashboard;
 mod home;
 mod login;
 mod new_user;
+mod shared_quiz;
 mod tos;
 
 pub use dashboard::*;
 pub use home::*;
 pub use login::*;
 pub use new_user::*;
+pub use shared_quiz::*;
 pub use tos::*;
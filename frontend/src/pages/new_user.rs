@@ -162,6 +162,7 @@ pub fn CreateNewUser() -> impl IntoView {
                         <input type=move || { show_password.get().input_type } id="password" node_ref=password_input_elm placeholder="Password" required/>
                         <span id="togglePassword" class=move || {show_password.get().span_class} on:click=toggle_password>Show</span>
                     </div>
+                    <p>"New accounts take the quizzes shared with them. Ask an admin to make you an instructor to write your own."</p>
                     <div
                         style="margin-bottom: 0.5rem"
                     >
@@ -0,0 +1,107 @@
+//! frontend/src/pages/shared_quiz.rs
+//! Page for a quiz shared by link, such as `/quiz/quizzes:abc123`.
+//! Users need to be logged in to take it, but the author does not need to share anything else.
+use leptos::*;
+use leptos_router::{use_params_map, ParamsMap, A};
+use web_sys::{Headers, RequestMode, Response};
+
+use crate::{
+    components::{dashboard::ExamRoom, Card, Footer, LikeButton},
+    models::mimic_surreal::SurrealQuiz,
+    store::AppSettings,
+    utils::{Fetcher, JsonMsg},
+};
+
+/// Shows a shared quiz, which can then be taken in place.
+#[component]
+pub fn SharedQuizPage() -> impl IntoView {
+    // -- Create Signals --
+    let quiz_rw: RwSignal<Option<SurrealQuiz>> = create_rw_signal(None);
+    let taking: RwSignal<bool> = create_rw_signal(false);
+    let err_msg: RwSignal<Option<String>> = create_rw_signal(None);
+    let logged_out: RwSignal<bool> = create_rw_signal(false);
+
+    // -- Use Context --
+    let params: Memo<ParamsMap> = use_params_map();
+    let app_settings: AppSettings =
+        use_context::<AppSettings>().expect("AppSettings context not found");
+
+    // -- Create Resource --
+    let quiz_resource = create_resource(
+        move || params.with(|ps| ps.get("id").cloned().unwrap_or_default()),
+        move |quiz_id: String| {
+            let headers: Headers = Headers::new().unwrap();
+            headers
+                .set("Content-Type", "application/json;charset=UTF-8")
+                .unwrap();
+            let fetcher: Fetcher = Fetcher::init()
+                .set_url(app_settings.backend_url.clone() + "quiz-nexus/shared")
+                .add_query_param("quiz", &quiz_id)
+                .set_method("GET")
+                .set_headers(headers)
+                .set_mode(RequestMode::Cors)
+                .build();
+            async move {
+                let response: Response = fetcher.fetch(None).await;
+                match response.status() {
+                    200 => {
+                        let data: SurrealQuiz = Fetcher::response_to_struct(&response).await;
+                        quiz_rw.set(Some(data));
+                    }
+                    401 => logged_out.set(true),
+                    _ => {
+                        let deserialized: JsonMsg = Fetcher::response_to_struct(&response).await;
+                        err_msg.set(deserialized.msg);
+                    }
+                }
+            }
+        },
+    );
+
+    // -- Create Effect --
+    create_effect(move |_| {
+        quiz_resource.get();
+    });
+
+    let quiz_view = move || {
+        quiz_rw.get().map(|qz| {
+            if taking.get() {
+                view! { <ExamRoom some_quiz=Some(qz)/> }.into_view()
+            } else {
+                view! {
+                    <Card on_click=None>
+                        <h3>"Name: "{qz.name.clone()}</h3>
+                        <p>{qz.description.clone()}</p>
+                        <div class:horizontal-even=true>
+                            <button on:click=move |_| taking.set(true)>"Take Quiz"</button>
+                            <LikeButton target=qz.id.clone() likes=qz.likes liked=qz.liked />
+                        </div>
+                    </Card>
+                }
+                .into_view()
+            }
+        })
+    };
+
+    // -- Render View --
+    view! {
+        <div
+            class:fill-screen=true
+        >
+            <header>
+                <h1>"Kev's Quiz App"</h1>
+                <A href="/">"Back to my dashboard"</A>
+            </header>
+            <main class:main-content=true>
+                <div class:main-content-container=true>
+                    <Show when=move || logged_out.get()>
+                        <p>"Please "<A href="/">"log in"</A>" to take this quiz, then open the link again."</p>
+                    </Show>
+                    <h4>{move || err_msg.get()}</h4>
+                    {quiz_view}
+                </div>
+            </main>
+            <Footer />
+        </div>
+    }
+}
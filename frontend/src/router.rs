@@ -15,6 +15,7 @@ pub fn AppRouter() -> impl IntoView {
                 <Route path="/" view=HomePage/>
                 <Route path="/home" view=HomePage/>
                 <Route path="/new-user" view=CreateNewUser />
+                <Route path="/quiz/:id" view=SharedQuizPage />
                 // <Route path="/test" view=HomePage>
                 //     <Route path=":id" view=|| view! { <p>"{id}"</p> } />
                 // </Route>
@@ -148,16 +148,39 @@ pub struct PartialUser {
     pub uuid: String,
     pub name: String,
     pub username: String,
+    #[serde(default)]
+    pub role: Role,
+}
+
+/// What a user may do, the backend still checks every request.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, PartialOrd, Clone, Copy)]
+pub enum Role {
+    Student,
+    #[default]
+    Instructor,
+    Admin,
+}
+
+impl Role {
+    pub fn can_author(&self) -> bool {
+        *self != Role::Student
+    }
 }
 
 #[derive(Debug, Default, Clone)]
 pub enum DashDisplay {
     #[default]
     MyQuizzes,
+    PublicQuizzes,
     MakeQuizzes,
     MakeQuestions,
     TakeQuiz,
+    StudyQuiz,
     UpdateQuiz,
+    Progress,
+    Search,
+    ImportQuiz,
+    Groups,
 }
 
 /// To generate random strings for unique and random IDs
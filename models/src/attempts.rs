@@ -0,0 +1,1072 @@
+//! models/src/attempts.rs
+//! To hold structs for taking a quiz, an "attempt", which is graded by the server.
+use crate::model_errors::ModelErrors;
+use crate::questions::{
+    multi_select_score, rubric_score, short_answer_matches, AllQuestions, JsonQuestion,
+    RubricCriterion,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Thing};
+use surrealize_macro::Surrealize;
+
+/// How late a timed attempt may arrive and still be graded, to allow for slow connections.
+pub const DEADLINE_GRACE_SECS: i64 = 10;
+
+/// How long an unsubmitted attempt without a deadline counts as being taken, after that it was abandoned.
+pub const ABANDONED_AFTER_HOURS: i64 = 24;
+
+/// A question as shown to someone taking a quiz.
+/// Nothing here may reveal the answer.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExamQuestion {
+    pub id: Thing,
+    pub question: String,
+    pub hint: Option<String>,
+    // attempts hold the hint back until it is asked for
+    #[serde(default)]
+    pub has_hint: bool,
+    pub kind: ExamQuestionKind,
+}
+
+impl ExamQuestion {
+    /// Drops the hint text, keeping whether there is one to reveal.
+    pub fn withhold_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+}
+
+/// What kind of response a question expects.
+/// Choices are sorted so the position of the answer gives nothing away.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ExamQuestionKind {
+    MultipleChoice { choices: Vec<String> },
+    ShortAnswer,
+    LongAnswer,
+    TrueFalse,
+    MultiSelect { choices: Vec<String> },
+}
+
+/// The correct answer to a question, copied into the attempt when it is graded
+/// so results still make sense if the question is edited later.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum AnswerKey {
+    MultipleChoice(String),
+    ShortAnswer(Vec<String>),
+    LongAnswer {
+        model_answer: String,
+        rubric: Vec<RubricCriterion>,
+    },
+    TrueFalse(bool),
+    MultiSelect(Vec<String>),
+}
+
+impl AnswerKey {
+    /// Marks a response, from 0.0 to 1.0.
+    /// A response of the wrong shape earns nothing, and long answers earn nothing
+    /// until they are self-marked against the rubric.
+    pub fn grade(&self, response: &AttemptAnswer) -> f64 {
+        match (self, response) {
+            (AnswerKey::MultipleChoice(answer), AttemptAnswer::Choice(choice))
+                if answer == choice =>
+            {
+                1.0
+            }
+            (AnswerKey::ShortAnswer(answers), AttemptAnswer::Text(text))
+                if short_answer_matches(answers, text) =>
+            {
+                1.0
+            }
+            (AnswerKey::TrueFalse(answer), AttemptAnswer::TrueFalse(choice))
+                if answer == choice =>
+            {
+                1.0
+            }
+            (AnswerKey::MultiSelect(answers), AttemptAnswer::Choices(choices)) => {
+                multi_select_score(answers, choices)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// A test-taker's response to a single question.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum AttemptAnswer {
+    Choice(String),
+    Text(String),
+    TrueFalse(bool),
+    Choices(Vec<String>),
+}
+
+/// One question of a submitted attempt, with its response and mark.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GradedAnswer {
+    pub question_id: Thing,
+    pub question: String,
+    pub response: Option<AttemptAnswer>,
+    pub key: AnswerKey,
+    pub earned: f64,
+    // Only used by long answer questions
+    pub criteria_met: Vec<usize>,
+    #[serde(default)]
+    pub hint_used: bool,
+    // taken off `earned` for revealing the hint
+    #[serde(default)]
+    pub deducted: f64,
+}
+
+impl GradedAnswer {
+    /// Sets the mark, less the penalty if the hint was revealed. Marks never go below 0.
+    fn mark(&mut self, marked: f64, hint_penalty: f64) {
+        self.deducted = if self.hint_used {
+            hint_penalty.min(marked)
+        } else {
+            0.0
+        };
+        self.earned = marked - self.deducted;
+    }
+}
+
+/// When a question of an attempt timed per question was served, and what came back for it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuestionTiming {
+    pub question_id: Thing,
+    pub served_at: Datetime,
+    pub answered_at: Option<Datetime>,
+    // only kept when it arrived in time
+    pub response: Option<AttemptAnswer>,
+    #[serde(default)]
+    pub late: bool,
+}
+
+/// A single attempt at a quiz, stored in the `attempts` table.
+/// The questions are fixed when the attempt starts.
+#[derive(Serialize, Deserialize, Debug, Surrealize, Clone)]
+pub struct Attempt {
+    pub quiz_id: Thing,
+    pub user_id: String,
+    pub question_ids: Vec<Thing>,
+    pub started_at: Datetime,
+    pub submitted_at: Option<Datetime>,
+    pub answers: Vec<GradedAnswer>,
+    pub score: f64,
+    pub max_score: f64,
+    // only timed quizzes have a deadline
+    #[serde(default)]
+    pub deadline: Option<Datetime>,
+    // set when the attempt arrived too late and was submitted without its answers
+    #[serde(default)]
+    pub timed_out: bool,
+    // the seed the questions were drawn with, missing on attempts from before pools
+    #[serde(default)]
+    pub seed: Option<u64>,
+    // questions whose hints were revealed, each costing `hint_penalty` when graded
+    #[serde(default)]
+    pub hints_used: Vec<Thing>,
+    // copied from the quiz when the attempt starts
+    #[serde(default)]
+    pub hint_penalty: f64,
+    // the quiz and question revisions the attempt was taken against, missing on attempts from before revisions
+    #[serde(default)]
+    pub revisions: Vec<Thing>,
+    // started through an assignment, so it is only submitted while one is open
+    #[serde(default)]
+    pub assigned: bool,
+    // copied from the quiz, questions are then served one at a time and each is due this long after
+    #[serde(default)]
+    pub time_per_question_secs: Option<u32>,
+    // the questions served so far, in order
+    #[serde(default)]
+    pub timings: Vec<QuestionTiming>,
+}
+
+impl Attempt {
+    /// A fresh, ungraded attempt at the given questions, due `time_allowed` from now if timed.
+    /// `seed` is what the questions were drawn with, see [`crate::pools::draw_questions`].
+    pub fn start(
+        quiz_id: Thing,
+        user_id: String,
+        question_ids: Vec<Thing>,
+        time_allowed: Option<Duration>,
+        seed: u64,
+        hint_penalty: f64,
+    ) -> Self {
+        let started_at: DateTime<Utc> = Utc::now();
+        Self {
+            quiz_id,
+            user_id,
+            max_score: question_ids.len() as f64,
+            question_ids,
+            started_at: Datetime::from(started_at),
+            submitted_at: None,
+            answers: Vec::new(),
+            score: 0.0,
+            deadline: time_allowed.map(|allowed| Datetime::from(started_at + allowed)),
+            timed_out: false,
+            seed: Some(seed),
+            hints_used: Vec::new(),
+            hint_penalty,
+            revisions: Vec::new(),
+            assigned: false,
+            time_per_question_secs: None,
+            timings: Vec::new(),
+        }
+    }
+
+    /// Pins the attempt to the revisions of the quiz and questions it is taken against.
+    pub fn pinned_to(mut self, revisions: Vec<Thing>) -> Self {
+        self.revisions = revisions;
+        self
+    }
+
+    /// Marks the attempt as taken through an assignment to one of the user's groups.
+    pub fn through_assignment(mut self) -> Self {
+        self.assigned = true;
+        self
+    }
+
+    /// Times every question on its own, see [`Attempt::next_question`].
+    pub fn timed_per_question(mut self, secs: u32) -> Self {
+        self.time_per_question_secs = Some(secs);
+        self
+    }
+
+    /// Whether questions are served one at a time, each with its own time limit.
+    pub fn is_paced(&self) -> bool {
+        self.time_per_question_secs.is_some()
+    }
+
+    /// The question served last, while it is still waiting for an answer.
+    pub fn open_question(&self) -> Option<&QuestionTiming> {
+        self.timings.last().filter(|qt| qt.answered_at.is_none())
+    }
+
+    /// When the open question is due, grace period not included.
+    pub fn question_due(&self) -> Option<DateTime<Utc>> {
+        let secs: u32 = self.time_per_question_secs?;
+        self.open_question()
+            .map(|qt| qt.served_at.0 + Duration::seconds(i64::from(secs)))
+    }
+
+    /// Answers the open question, keeping the answer only if it arrived in time.
+    fn answer_open(&mut self, answer: Option<AttemptAnswer>, now: DateTime<Utc>) {
+        let late: bool = self
+            .question_due()
+            .is_some_and(|due| now > due + Duration::seconds(DEADLINE_GRACE_SECS));
+        if let Some(open) = self
+            .timings
+            .last_mut()
+            .filter(|qt| qt.answered_at.is_none())
+        {
+            open.answered_at = Some(Datetime::from(now));
+            open.response = if late { None } else { answer };
+            open.late = late;
+        }
+    }
+
+    /// Answers the open question and serves the next, in the order the questions were drawn.
+    /// Returns the next question, or `None` once every question has been served.
+    /// An answer arriving after its question's time and grace period is recorded as late and thrown away.
+    pub fn next_question(
+        &mut self,
+        answer: Option<AttemptAnswer>,
+        now: DateTime<Utc>,
+    ) -> Option<Thing> {
+        self.answer_open(answer, now);
+        let next: Thing = self.question_ids.get(self.timings.len())?.clone();
+        self.timings.push(QuestionTiming {
+            question_id: next.clone(),
+            served_at: Datetime::from(now),
+            answered_at: None,
+            response: None,
+            late: false,
+        });
+        Some(next)
+    }
+
+    /// The responses an attempt timed per question is graded on, closing the open question.
+    /// Only answers that arrived in time count, and of the submitted responses only the open question's.
+    pub fn paced_responses(
+        &mut self,
+        submitted: &[AnswerJsonPkg],
+        now: DateTime<Utc>,
+    ) -> Vec<AnswerJsonPkg> {
+        let open_answer: Option<AttemptAnswer> = self.open_question().and_then(|open| {
+            submitted
+                .iter()
+                .find(|res| res.question_id == open.question_id)
+                .map(|res| res.answer.clone())
+        });
+        self.answer_open(open_answer, now);
+        self.timings
+            .iter()
+            .filter_map(|qt| {
+                qt.response.clone().map(|answer| AnswerJsonPkg {
+                    question_id: qt.question_id.clone(),
+                    answer,
+                })
+            })
+            .collect()
+    }
+
+    /// Records that the hint to a question was revealed.
+    /// Returns `false` if the question is not part of this attempt.
+    pub fn reveal_hint(&mut self, question_id: &Thing) -> bool {
+        if !self.question_ids.contains(question_id) {
+            return false;
+        }
+        if !self.hints_used.contains(question_id) {
+            self.hints_used.push(question_id.clone());
+        }
+        true
+    }
+
+    /// Whether the attempt is past its deadline, grace period included.
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.deadline
+            .as_ref()
+            .is_some_and(|due| now > due.0 + Duration::seconds(DEADLINE_GRACE_SECS))
+    }
+
+    /// Whether the attempt is still being taken, neither submitted, overdue nor abandoned.
+    pub fn in_progress(&self, now: DateTime<Utc>) -> bool {
+        self.submitted_at.is_none()
+            && !self.is_overdue(now)
+            && now < self.started_at.0 + Duration::hours(ABANDONED_AFTER_HOURS)
+    }
+
+    /// Submits an overdue attempt, ignoring whatever arrived late so every question earns nothing.
+    pub fn time_out(&mut self, keyed: Vec<(ExamQuestion, AnswerKey)>) {
+        self.grade(keyed, &[]);
+        self.timed_out = true;
+    }
+
+    /// Grades responses against the answer keys of the questions this attempt started with.
+    /// Questions removed from the quiz since are dropped, and unanswered questions earn nothing.
+    /// Questions whose hints were revealed lose the attempt's hint penalty.
+    pub fn grade(&mut self, keyed: Vec<(ExamQuestion, AnswerKey)>, responses: &[AnswerJsonPkg]) {
+        self.answers = keyed
+            .into_iter()
+            .filter(|(eq, _)| self.question_ids.contains(&eq.id))
+            .map(|(eq, key)| {
+                let response: Option<AttemptAnswer> = responses
+                    .iter()
+                    .find(|res| res.question_id == eq.id)
+                    .map(|res| res.answer.clone());
+                let marked: f64 = response.as_ref().map_or(0.0, |res| key.grade(res));
+                let mut graded: GradedAnswer = GradedAnswer {
+                    hint_used: self.hints_used.contains(&eq.id),
+                    question_id: eq.id,
+                    question: eq.question,
+                    response,
+                    key,
+                    earned: 0.0,
+                    criteria_met: Vec::new(),
+                    deducted: 0.0,
+                };
+                graded.mark(marked, self.hint_penalty);
+                graded
+            })
+            .collect();
+        self.max_score = self.answers.len() as f64;
+        self.score = self.answers.iter().map(|ga| ga.earned).sum();
+        self.submitted_at = Some(Datetime::default());
+    }
+
+    /// Records which rubric criteria a long answer met and updates the score.
+    /// Refused unless the question is a written long answer in this attempt
+    /// and every criterion is part of its rubric. Timed out attempts keep their 0.
+    pub fn self_mark(
+        &mut self,
+        question_id: &Thing,
+        criteria_met: Vec<usize>,
+    ) -> Result<(), ModelErrors> {
+        if self.timed_out {
+            return Err(ModelErrors::JsonValidation(String::from(
+                "Attempts submitted after their deadline cannot be self-marked",
+            )));
+        }
+        let Some(graded) = self
+            .answers
+            .iter_mut()
+            .find(|ga| &ga.question_id == question_id)
+        else {
+            return Err(ModelErrors::JsonValidation(String::from(
+                "Question is not part of this attempt",
+            )));
+        };
+        let AnswerKey::LongAnswer { rubric, .. } = &graded.key else {
+            return Err(ModelErrors::JsonValidation(String::from(
+                "Only long answer questions can be self-marked",
+            )));
+        };
+        match &graded.response {
+            Some(AttemptAnswer::Text(text)) if !text.trim().is_empty() => {}
+            _ => {
+                return Err(ModelErrors::JsonValidation(String::from(
+                    "A long answer with no response cannot be self-marked",
+                )));
+            }
+        }
+        if criteria_met.iter().any(|idx| *idx >= rubric.len()) {
+            return Err(ModelErrors::JsonValidation(String::from(
+                "Criteria must be part of the question's rubric",
+            )));
+        }
+        let marked: f64 = rubric_score(rubric, &criteria_met);
+        graded.mark(marked, self.hint_penalty);
+        graded.criteria_met = criteria_met;
+        self.score = self.answers.iter().map(|ga| ga.earned).sum();
+        Ok(())
+    }
+}
+
+impl JsonQuestion {
+    /// Splits a question into what the test-taker sees and the key to grade it.
+    pub fn into_exam(self, id: Thing) -> (ExamQuestion, AnswerKey) {
+        let (question, hint, kind, key) = match self {
+            JsonQuestion::MultipleChoice(qmc) => {
+                let mut choices: Vec<String> = qmc.choices;
+                choices.push(qmc.answer.clone());
+                choices.sort();
+                (
+                    qmc.question,
+                    qmc.hint,
+                    ExamQuestionKind::MultipleChoice { choices },
+                    AnswerKey::MultipleChoice(qmc.answer),
+                )
+            }
+            JsonQuestion::ShortAnswer(qsa) => (
+                qsa.question,
+                qsa.hint,
+                ExamQuestionKind::ShortAnswer,
+                AnswerKey::ShortAnswer(qsa.answers),
+            ),
+            JsonQuestion::LongAnswer(qla) => (
+                qla.question,
+                qla.hint,
+                ExamQuestionKind::LongAnswer,
+                AnswerKey::LongAnswer {
+                    model_answer: qla.model_answer,
+                    rubric: qla.rubric,
+                },
+            ),
+            JsonQuestion::TrueFalse(qtf) => (
+                qtf.question,
+                qtf.hint,
+                ExamQuestionKind::TrueFalse,
+                AnswerKey::TrueFalse(qtf.answer),
+            ),
+            JsonQuestion::MultiSelect(qms) => {
+                let mut choices: Vec<String> = qms.choices;
+                choices.extend(qms.answers.iter().cloned());
+                choices.sort();
+                (
+                    qms.question,
+                    qms.hint,
+                    ExamQuestionKind::MultiSelect { choices },
+                    AnswerKey::MultiSelect(qms.answers),
+                )
+            }
+        };
+        (
+            ExamQuestion {
+                id,
+                question,
+                has_hint: hint.is_some(),
+                hint,
+                kind,
+            },
+            key,
+        )
+    }
+}
+
+impl AllQuestions {
+    /// Splits every question into what the test-taker sees and the key to grade it.
+    pub fn into_exam(self) -> Vec<(ExamQuestion, AnswerKey)> {
+        self.written()
+            .into_iter()
+            .map(|wq| wq.question.into_exam(wq.id))
+            .collect()
+    }
+}
+
+/// Returned when an attempt starts, the questions have no answers.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttemptStart {
+    pub attempt_id: Thing,
+    pub questions: Vec<ExamQuestion>,
+    // seconds until the deadline, so the countdown does not depend on the client's clock
+    pub time_allowed_secs: Option<i64>,
+    // set when questions are served one at a time through `/exam-room/next`, each due this long after
+    pub time_per_question_secs: Option<u32>,
+    // taken off a question's mark when its hint is revealed
+    #[serde(default)]
+    pub hint_penalty: f64,
+}
+
+/// Asks for the hint to one question of an attempt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HintJsonPkg {
+    pub question_id: Thing,
+}
+
+/// Answers the open question of an attempt timed per question, asking for the next.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NextQuestionJsonPkg {
+    #[serde(default)]
+    pub answer: Option<AttemptAnswer>,
+}
+
+/// The next question of an attempt timed per question, `None` once every question has been served.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ServedQuestion {
+    pub question: Option<ExamQuestion>,
+    // seconds until the question is due, so the countdown does not depend on the client's clock
+    pub time_allowed_secs: Option<i64>,
+    // the answer just given arrived too late and was thrown away
+    pub late: bool,
+}
+
+/// A revealed hint, the attempt now counts it as used.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevealedHint {
+    pub question_id: Thing,
+    pub hint: String,
+}
+
+/// A response to one question when submitting an attempt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerJsonPkg {
+    pub question_id: Thing,
+    pub answer: AttemptAnswer,
+}
+
+/// Every response of an attempt, submitted at once.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttemptSubmission {
+    pub answers: Vec<AnswerJsonPkg>,
+}
+
+/// Self-marking a long answer question of a submitted attempt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SelfMarkJsonPkg {
+    pub question_id: Thing,
+    pub criteria_met: Vec<usize>,
+}
+
+/// One past attempt in a user's history.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttemptSummary {
+    pub attempt_id: Thing,
+    pub submitted_at: Option<Datetime>,
+    pub score: f64,
+    pub max_score: f64,
+    // score as a percentage, so attempts compare even if questions were added
+    pub percent: f64,
+}
+
+/// How often a single question has been answered correctly across attempts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuestionStat {
+    pub question_id: Thing,
+    pub question: String,
+    pub times_answered: u32,
+    // mean mark, from 0.0 to 1.0, partial credit counts
+    pub success_rate: f64,
+}
+
+/// A user's progress on a single quiz, built from their submitted attempts.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuizProgress {
+    pub quiz_id: Thing,
+    pub quiz_name: String,
+    pub attempts: Vec<AttemptSummary>,
+    pub best: f64,
+    pub latest: f64,
+    pub average: f64,
+    pub question_stats: Vec<QuestionStat>,
+}
+
+impl QuizProgress {
+    /// Summarises attempts at one quiz, expected oldest first.
+    /// Attempts that were never submitted are skipped.
+    pub fn from_attempts(quiz_id: Thing, quiz_name: String, attempts: &[SurrealAttempt]) -> Self {
+        let summaries: Vec<AttemptSummary> = attempts
+            .iter()
+            .filter(|att| att.submitted_at.is_some())
+            .map(|att| AttemptSummary {
+                attempt_id: att.id.clone(),
+                submitted_at: att.submitted_at.clone(),
+                score: att.score,
+                max_score: att.max_score,
+                percent: if att.max_score > 0.0 {
+                    att.score / att.max_score * 100.0
+                } else {
+                    0.0
+                },
+            })
+            .collect();
+
+        let best: f64 = summaries.iter().map(|sm| sm.percent).fold(0.0, f64::max);
+        let latest: f64 = summaries.last().map_or(0.0, |sm| sm.percent);
+        let average: f64 = if summaries.is_empty() {
+            0.0
+        } else {
+            summaries.iter().map(|sm| sm.percent).sum::<f64>() / summaries.len() as f64
+        };
+
+        // Keeps the order questions were first seen in
+        let mut question_stats: Vec<QuestionStat> = Vec::new();
+        for graded in attempts
+            .iter()
+            .filter(|att| att.submitted_at.is_some())
+            .flat_map(|att| att.answers.iter())
+        {
+            match question_stats
+                .iter_mut()
+                .find(|qs| qs.question_id == graded.question_id)
+            {
+                Some(stat) => {
+                    stat.success_rate = (stat.success_rate * f64::from(stat.times_answered)
+                        + graded.earned)
+                        / f64::from(stat.times_answered + 1);
+                    stat.times_answered += 1;
+                }
+                None => question_stats.push(QuestionStat {
+                    question_id: graded.question_id.clone(),
+                    question: graded.question.clone(),
+                    times_answered: 1,
+                    success_rate: graded.earned,
+                }),
+            }
+        }
+
+        Self {
+            quiz_id,
+            quiz_name,
+            attempts: summaries,
+            best,
+            latest,
+            average,
+            question_stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exam_question(id: &str, kind: ExamQuestionKind) -> ExamQuestion {
+        ExamQuestion {
+            id: Thing::from(("questions", id)),
+            question: String::from("A question"),
+            hint: None,
+            has_hint: false,
+            kind,
+        }
+    }
+
+    #[test]
+    fn grading_ignores_unanswered_and_unknown_questions() {
+        // Assign
+        let keyed: Vec<(ExamQuestion, AnswerKey)> = vec![
+            (
+                exam_question("tf", ExamQuestionKind::TrueFalse),
+                AnswerKey::TrueFalse(true),
+            ),
+            (
+                exam_question("sa", ExamQuestionKind::ShortAnswer),
+                AnswerKey::ShortAnswer(vec![String::from("Rust")]),
+            ),
+            (
+                exam_question("new", ExamQuestionKind::TrueFalse),
+                AnswerKey::TrueFalse(false),
+            ),
+        ];
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![
+                Thing::from(("questions", "tf")),
+                Thing::from(("questions", "sa")),
+            ],
+            None,
+            0,
+            0.0,
+        );
+        let responses: Vec<AnswerJsonPkg> = vec![AnswerJsonPkg {
+            question_id: Thing::from(("questions", "tf")),
+            answer: AttemptAnswer::TrueFalse(true),
+        }];
+
+        // Act
+        attempt.grade(keyed, &responses);
+
+        // Assert
+        assert_eq!(attempt.answers.len(), 2);
+        assert_eq!(attempt.score, 1.0);
+        assert_eq!(attempt.max_score, 2.0);
+        assert!(attempt.submitted_at.is_some());
+    }
+
+    #[test]
+    fn self_mark_only_applies_to_long_answers() {
+        // Assign
+        let keyed: Vec<(ExamQuestion, AnswerKey)> = vec![
+            (
+                exam_question("la", ExamQuestionKind::LongAnswer),
+                AnswerKey::LongAnswer {
+                    model_answer: String::from("An essay"),
+                    rubric: vec![
+                        RubricCriterion {
+                            criterion: String::from("Intro"),
+                            weight: 1,
+                        },
+                        RubricCriterion {
+                            criterion: String::from("Body"),
+                            weight: 1,
+                        },
+                    ],
+                },
+            ),
+            (
+                exam_question("tf", ExamQuestionKind::TrueFalse),
+                AnswerKey::TrueFalse(true),
+            ),
+        ];
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![
+                Thing::from(("questions", "la")),
+                Thing::from(("questions", "tf")),
+            ],
+            None,
+            0,
+            0.0,
+        );
+        let responses: Vec<AnswerJsonPkg> = vec![AnswerJsonPkg {
+            question_id: Thing::from(("questions", "la")),
+            answer: AttemptAnswer::Text(String::from("An essay of sorts")),
+        }];
+        attempt.grade(keyed, &responses);
+
+        // Act
+        let marked_la = attempt.self_mark(&Thing::from(("questions", "la")), vec![1]);
+        let marked_tf = attempt.self_mark(&Thing::from(("questions", "tf")), vec![0]);
+        let outside_rubric = attempt.self_mark(&Thing::from(("questions", "la")), vec![0, 2]);
+
+        // Assert
+        assert!(marked_la.is_ok());
+        assert!(marked_tf.is_err());
+        assert!(outside_rubric.is_err());
+        assert_eq!(attempt.score, 0.5);
+        assert_eq!(attempt.answers[0].criteria_met, vec![1]);
+    }
+
+    #[test]
+    fn blank_long_answers_cannot_be_self_marked() {
+        // Assign
+        let keyed: Vec<(ExamQuestion, AnswerKey)> = vec![(
+            exam_question("la", ExamQuestionKind::LongAnswer),
+            AnswerKey::LongAnswer {
+                model_answer: String::from("An essay"),
+                rubric: vec![RubricCriterion {
+                    criterion: String::from("Intro"),
+                    weight: 1,
+                }],
+            },
+        )];
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![Thing::from(("questions", "la"))],
+            None,
+            0,
+            0.0,
+        );
+        attempt.grade(keyed, &[]);
+
+        // Act
+        let marked = attempt.self_mark(&Thing::from(("questions", "la")), vec![0]);
+
+        // Assert
+        assert!(marked.is_err());
+        assert_eq!(attempt.score, 0.0);
+    }
+
+    #[test]
+    fn progress_tracks_best_latest_and_average() {
+        // Assign
+        let graded = |earned: f64| GradedAnswer {
+            question_id: Thing::from(("questions", "tf")),
+            question: String::from("A question"),
+            response: Some(AttemptAnswer::TrueFalse(true)),
+            key: AnswerKey::TrueFalse(true),
+            earned,
+            criteria_met: Vec::new(),
+            hint_used: false,
+            deducted: 0.0,
+        };
+        let attempt = |id: &str, score: f64, submitted: bool| SurrealAttempt {
+            id: Thing::from(("attempts", id)),
+            quiz_id: Thing::from(("quizzes", "quiz")),
+            user_id: String::from("user"),
+            question_ids: vec![Thing::from(("questions", "tf"))],
+            started_at: Datetime::default(),
+            submitted_at: if submitted {
+                Some(Datetime::default())
+            } else {
+                None
+            },
+            answers: vec![graded(score)],
+            score,
+            max_score: 1.0,
+            deadline: None,
+            timed_out: false,
+            seed: None,
+            hints_used: Vec::new(),
+            hint_penalty: 0.0,
+            revisions: Vec::new(),
+            assigned: false,
+            time_per_question_secs: None,
+            timings: Vec::new(),
+        };
+        let attempts: Vec<SurrealAttempt> = vec![
+            attempt("a", 1.0, true),
+            attempt("b", 0.0, true),
+            attempt("c", 1.0, false),
+            attempt("d", 0.5, true),
+        ];
+
+        // Act
+        let progress: QuizProgress = QuizProgress::from_attempts(
+            Thing::from(("quizzes", "quiz")),
+            String::from("Algorithms"),
+            &attempts,
+        );
+
+        // Assert
+        assert_eq!(progress.attempts.len(), 3);
+        assert_eq!(progress.best, 100.0);
+        assert_eq!(progress.latest, 50.0);
+        assert_eq!(progress.average, 50.0);
+        assert_eq!(progress.question_stats.len(), 1);
+        assert_eq!(progress.question_stats[0].times_answered, 3);
+        assert_eq!(progress.question_stats[0].success_rate, 0.5);
+    }
+
+    #[test]
+    fn overdue_attempts_time_out_without_answers() {
+        // Assign
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![Thing::from(("questions", "tf"))],
+            Some(Duration::seconds(60)),
+            0,
+            0.0,
+        );
+        let due: DateTime<Utc> = attempt.deadline.clone().unwrap().0;
+        let keyed: Vec<(ExamQuestion, AnswerKey)> = vec![(
+            exam_question("tf", ExamQuestionKind::TrueFalse),
+            AnswerKey::TrueFalse(true),
+        )];
+
+        // Act
+        let in_grace: bool = attempt.is_overdue(due + Duration::seconds(DEADLINE_GRACE_SECS));
+        let late: bool = attempt.is_overdue(due + Duration::seconds(DEADLINE_GRACE_SECS + 1));
+        attempt.time_out(keyed);
+
+        // Assert
+        assert!(!in_grace);
+        assert!(late);
+        assert!(attempt.timed_out);
+        assert!(attempt.submitted_at.is_some());
+        assert_eq!(attempt.score, 0.0);
+        assert_eq!(attempt.max_score, 1.0);
+    }
+
+    #[test]
+    fn questions_timed_one_by_one_drop_late_answers() {
+        // Assign
+        let keyed: Vec<(ExamQuestion, AnswerKey)> = vec![
+            (
+                exam_question("tf", ExamQuestionKind::TrueFalse),
+                AnswerKey::TrueFalse(true),
+            ),
+            (
+                exam_question("sa", ExamQuestionKind::ShortAnswer),
+                AnswerKey::ShortAnswer(vec![String::from("Rust")]),
+            ),
+        ];
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![
+                Thing::from(("questions", "tf")),
+                Thing::from(("questions", "sa")),
+            ],
+            None,
+            0,
+            0.0,
+        )
+        .timed_per_question(30);
+        let start: DateTime<Utc> = Utc::now();
+        let too_late: DateTime<Utc> = start + Duration::seconds(20 + 30 + DEADLINE_GRACE_SECS + 1);
+
+        // Act
+        let first: Option<Thing> = attempt.next_question(None, start);
+        let second: Option<Thing> = attempt.next_question(
+            Some(AttemptAnswer::TrueFalse(true)),
+            start + Duration::seconds(20),
+        );
+        let done: Option<Thing> =
+            attempt.next_question(Some(AttemptAnswer::Text(String::from("Rust"))), too_late);
+        // Changing an answered question when submitting does nothing
+        let responses: Vec<AnswerJsonPkg> = attempt.paced_responses(
+            &[AnswerJsonPkg {
+                question_id: Thing::from(("questions", "tf")),
+                answer: AttemptAnswer::TrueFalse(false),
+            }],
+            too_late,
+        );
+        attempt.grade(keyed, &responses);
+
+        // Assert
+        assert_eq!(first, Some(Thing::from(("questions", "tf"))));
+        assert_eq!(second, Some(Thing::from(("questions", "sa"))));
+        assert_eq!(done, None);
+        assert!(!attempt.timings[0].late);
+        assert!(attempt.timings[1].late);
+        assert!(attempt.open_question().is_none());
+        assert_eq!(responses.len(), 1);
+        assert_eq!(attempt.score, 1.0);
+    }
+
+    #[test]
+    fn timed_out_attempts_cannot_be_self_marked() {
+        // Assign
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![Thing::from(("questions", "la"))],
+            Some(Duration::seconds(60)),
+            0,
+            0.0,
+        );
+        let keyed: Vec<(ExamQuestion, AnswerKey)> = vec![(
+            exam_question("la", ExamQuestionKind::LongAnswer),
+            AnswerKey::LongAnswer {
+                model_answer: String::from("An essay"),
+                rubric: vec![RubricCriterion {
+                    criterion: String::from("Intro"),
+                    weight: 1,
+                }],
+            },
+        )];
+        attempt.time_out(keyed);
+
+        // Act
+        let marked = attempt.self_mark(&Thing::from(("questions", "la")), vec![0]);
+
+        // Assert
+        assert!(marked.is_err());
+        assert_eq!(attempt.score, 0.0);
+        assert!(attempt.answers[0].criteria_met.is_empty());
+    }
+
+    #[test]
+    fn attempts_stop_being_in_progress() {
+        // Assign
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![Thing::from(("questions", "tf"))],
+            None,
+            0,
+            0.0,
+        );
+        let now: DateTime<Utc> = Utc::now();
+
+        // Act
+        let taking: bool = attempt.in_progress(now);
+        let abandoned: bool = attempt.in_progress(now + Duration::hours(ABANDONED_AFTER_HOURS));
+        attempt.grade(Vec::new(), &[]);
+        let submitted: bool = attempt.in_progress(now);
+
+        // Assert
+        assert!(taking);
+        assert!(!abandoned);
+        assert!(!submitted);
+    }
+
+    #[test]
+    fn revealed_hints_cost_the_penalty() {
+        // Assign
+        let keyed = || -> Vec<(ExamQuestion, AnswerKey)> {
+            vec![
+                (
+                    exam_question("tf", ExamQuestionKind::TrueFalse),
+                    AnswerKey::TrueFalse(true),
+                ),
+                (
+                    exam_question("sa", ExamQuestionKind::ShortAnswer),
+                    AnswerKey::ShortAnswer(vec![String::from("Rust")]),
+                ),
+            ]
+        };
+        let mut attempt: Attempt = Attempt::start(
+            Thing::from(("quizzes", "quiz")),
+            String::from("user"),
+            vec![
+                Thing::from(("questions", "tf")),
+                Thing::from(("questions", "sa")),
+            ],
+            None,
+            0,
+            0.25,
+        );
+        let responses: Vec<AnswerJsonPkg> = vec![AnswerJsonPkg {
+            question_id: Thing::from(("questions", "tf")),
+            answer: AttemptAnswer::TrueFalse(true),
+        }];
+
+        // Act
+        let revealed_tf: bool = attempt.reveal_hint(&Thing::from(("questions", "tf")));
+        let revealed_sa: bool = attempt.reveal_hint(&Thing::from(("questions", "sa")));
+        let revealed_other: bool = attempt.reveal_hint(&Thing::from(("questions", "other")));
+        attempt.reveal_hint(&Thing::from(("questions", "tf")));
+        attempt.grade(keyed(), &responses);
+
+        // Assert
+        assert!(revealed_tf && revealed_sa && !revealed_other);
+        assert_eq!(attempt.hints_used.len(), 2);
+        assert_eq!(attempt.score, 0.75);
+        assert_eq!(attempt.answers[0].deducted, 0.25);
+        // a wrong answer has nothing to take the penalty from
+        assert!(attempt.answers[1].hint_used);
+        assert_eq!(attempt.answers[1].earned, 0.0);
+    }
+
+    #[test]
+    fn wrong_response_shape_earns_nothing() {
+        // Assign
+        let key: AnswerKey = AnswerKey::MultipleChoice(String::from("Merge Sort"));
+
+        // Act & Assert
+        assert_eq!(
+            key.grade(&AttemptAnswer::Choice(String::from("Merge Sort"))),
+            1.0
+        );
+        assert_eq!(
+            key.grade(&AttemptAnswer::Text(String::from("Merge Sort"))),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! models/src/bundle.rs
+//! Portable quiz bundles, a quiz and all of its questions in one versioned document.
+//! Bundles carry no record IDs or authors, so importing one always makes a new quiz
+//! owned by whoever imports it.
+use crate::{
+    interchange::Unmapped,
+    model_errors::ModelErrors,
+    questions::{AllQuestions, JsonQuestion, QuestionJsonPkg},
+    quiz::{QuizJsonPkg, SurrealQuiz},
+};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+/// Version written into exported bundles.
+/// Bump it when the bundle layout changes in a way older readers cannot follow.
+pub const BUNDLE_VERSION: u32 = 1;
+/// Most questions a single bundle may import.
+pub const MAX_BUNDLE_QUESTIONS: usize = 500;
+
+/// How a bundle is written out.
+/// GIFT and Moodle XML only hold questions, see `interchange` for what they cannot express.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleFormat {
+    #[default]
+    Json,
+    Yaml,
+    Gift,
+    #[serde(rename = "xml")]
+    MoodleXml,
+}
+
+impl BundleFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            BundleFormat::Json => "json",
+            BundleFormat::Yaml => "yaml",
+            BundleFormat::Gift => "gift",
+            BundleFormat::MoodleXml => "xml",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            BundleFormat::Json => "application/json",
+            BundleFormat::Yaml => "application/yaml",
+            BundleFormat::Gift => "text/plain; charset=utf-8",
+            BundleFormat::MoodleXml => "application/xml",
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            BundleFormat::Json => "JSON",
+            BundleFormat::Yaml => "YAML",
+            BundleFormat::Gift => "GIFT",
+            BundleFormat::MoodleXml => "Moodle XML",
+        }
+    }
+
+    /// Guesses the format from a file extension.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "json" => Some(BundleFormat::Json),
+            "yaml" | "yml" => Some(BundleFormat::Yaml),
+            "gift" | "txt" => Some(BundleFormat::Gift),
+            "xml" => Some(BundleFormat::MoodleXml),
+            _ => None,
+        }
+    }
+}
+
+/// A quiz with every one of its questions.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuizBundle {
+    pub version: u32,
+    pub quiz: QuizJsonPkg,
+    #[serde(default)]
+    pub questions: Vec<JsonQuestion>,
+}
+
+impl QuizBundle {
+    /// Bundles a quiz, questions are kept in the same order as `AllQuestions` lists them.
+    pub fn new(quiz: &SurrealQuiz, all_questions: AllQuestions) -> Self {
+        let questions: Vec<JsonQuestion> = all_questions
+            .written()
+            .into_iter()
+            .map(|written| written.question)
+            .collect();
+
+        Self {
+            version: BUNDLE_VERSION,
+            quiz: QuizJsonPkg {
+                name: quiz.name.clone(),
+                description: quiz.description.clone(),
+                visibility: Some(quiz.visibility),
+                time_limit_secs: quiz.time_limit_secs,
+                time_per_question_secs: quiz.time_per_question_secs,
+                // pools point at the author's tags, which do not travel with the bundle
+                pools: None,
+                hint_penalty: Some(quiz.hint_penalty).filter(|penalty| *penalty > 0.0),
+            },
+            questions,
+        }
+    }
+
+    /// Problems with the bundle as a whole, which stop any item from being checked.
+    pub fn validate_header(&self) -> Result<(), ModelErrors> {
+        if self.version == 0 || self.version > BUNDLE_VERSION {
+            Err(ModelErrors::JsonValidation(format!(
+                "Unsupported bundle version {}, newest supported is {}",
+                self.version, BUNDLE_VERSION
+            )))
+        } else if self.questions.len() > MAX_BUNDLE_QUESTIONS {
+            Err(ModelErrors::JsonValidation(format!(
+                "Bundles cannot have more than {} questions",
+                MAX_BUNDLE_QUESTIONS
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Splits the bundle into the quiz and a question package per question,
+    /// with every question pointing at `quiz_id`.
+    pub fn into_packages(self, quiz_id: &Thing) -> (QuizJsonPkg, Vec<QuestionJsonPkg>) {
+        let packages: Vec<QuestionJsonPkg> = self
+            .questions
+            .into_iter()
+            .map(|question| QuestionJsonPkg {
+                quiz_id: quiz_id.clone(),
+                question,
+            })
+            .collect();
+        (self.quiz, packages)
+    }
+}
+
+/// The outcome of checking a single question of a bundle.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportItem {
+    // position in the bundle, starting at 0
+    pub index: usize,
+    pub table: String,
+    pub question: String,
+    pub error: Option<String>,
+}
+
+/// What an import did, or in a dry run would do.
+/// Nothing is saved unless the quiz and every item are valid.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub quiz_error: Option<String>,
+    pub items: Vec<ImportItem>,
+    // parts of a GIFT or Moodle XML file that had no equivalent
+    #[serde(default)]
+    pub unmapped: Vec<Unmapped>,
+    // only set once the quiz has been saved
+    pub quiz: Option<SurrealQuiz>,
+}
+
+impl ImportReport {
+    /// Checks the quiz and each question package, reporting every problem found.
+    pub fn check(dry_run: bool, quiz: &QuizJsonPkg, packages: &[QuestionJsonPkg]) -> Self {
+        let items: Vec<ImportItem> = packages
+            .iter()
+            .enumerate()
+            .map(|(index, pkg)| ImportItem {
+                index,
+                table: pkg.question.table_name().to_string(),
+                question: pkg.question.question().to_string(),
+                error: pkg.validate_fields().err().map(|err| err.to_string()),
+            })
+            .collect();
+
+        Self {
+            dry_run,
+            quiz_error: quiz.validate_field().err().map(|err| err.to_string()),
+            items,
+            unmapped: Vec::new(),
+            quiz: None,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.quiz_error.is_none() && self.items.iter().all(|item| item.error.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::questions::{JsonQuestionSA, SurrealQuestionMC, SurrealQuestionTF};
+    use crate::quiz::Visibility;
+
+    fn surreal_quiz() -> SurrealQuiz {
+        SurrealQuiz {
+            id: Thing::from(("quizzes", "quiz")),
+            name: String::from("Algorithms"),
+            description: String::from("Sorting and searching"),
+            author_id: String::from("user"),
+            visibility: Visibility::Unlisted,
+            time_limit_secs: None,
+            time_per_question_secs: None,
+            pools: Vec::new(),
+            hint_penalty: 0.0,
+        }
+    }
+
+    fn all_questions() -> AllQuestions {
+        AllQuestions {
+            mc: vec![SurrealQuestionMC {
+                id: Thing::from(("questions_mc", "mc")),
+                question: String::from("Fastest average sort?"),
+                hint: None,
+                author_id: String::from("user"),
+                parent_quiz: Thing::from(("quizzes", "quiz")),
+                answer: String::from("Quick sort"),
+                choices: vec![String::from("Bubble sort")],
+            }],
+            sa: Vec::new(),
+            la: Vec::new(),
+            tf: vec![SurrealQuestionTF {
+                id: Thing::from(("questions_tf", "tf")),
+                question: String::from("Binary search needs sorted input"),
+                hint: Some(String::from("Think halves")),
+                author_id: String::from("user"),
+                parent_quiz: Thing::from(("quizzes", "quiz")),
+                answer: true,
+            }],
+            ms: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn bundle_round_trips_without_ids() {
+        // Assign
+        let bundle: QuizBundle = QuizBundle::new(&surreal_quiz(), all_questions());
+
+        // Act
+        let json: String = serde_json::to_string(&bundle).unwrap();
+        let read_back: QuizBundle = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert!(!json.contains("author_id"));
+        assert!(!json.contains("parent_quiz"));
+        assert_eq!(read_back.version, BUNDLE_VERSION);
+        assert_eq!(read_back.quiz.visibility, Some(Visibility::Unlisted));
+        let tables: Vec<&str> = read_back
+            .questions
+            .iter()
+            .map(|qs| qs.table_name())
+            .collect();
+        assert_eq!(tables, vec!["questions_mc", "questions_tf"]);
+        assert!(read_back.validate_header().is_ok());
+    }
+
+    #[test]
+    fn report_marks_each_invalid_item() {
+        // Assign
+        let mut bundle: QuizBundle = QuizBundle::new(&surreal_quiz(), all_questions());
+        bundle
+            .questions
+            .push(JsonQuestion::ShortAnswer(JsonQuestionSA {
+                question: String::from("Name a stable sort"),
+                hint: None,
+                answers: vec![String::from("  ")],
+            }));
+        let quiz_id: Thing = Thing::from(("quizzes", "new"));
+
+        // Act
+        let (quiz, packages) = bundle.into_packages(&quiz_id);
+        let report: ImportReport = ImportReport::check(true, &quiz, &packages);
+
+        // Assert
+        assert!(!report.is_valid());
+        assert!(report.quiz_error.is_none());
+        assert_eq!(report.items.len(), 3);
+        assert!(report.items[0].error.is_none());
+        assert!(report.items[1].error.is_none());
+        assert_eq!(report.items[2].index, 2);
+        assert_eq!(report.items[2].table, "questions_sa");
+        assert!(report.items[2].error.is_some());
+        assert!(packages.iter().all(|pkg| pkg.quiz_id == quiz_id));
+    }
+
+    #[test]
+    fn unsupported_versions_rejected() {
+        let mut bundle: QuizBundle = QuizBundle::new(&surreal_quiz(), all_questions());
+        bundle.version = BUNDLE_VERSION + 1;
+        assert!(bundle.validate_header().is_err());
+        bundle.version = 0;
+        assert!(bundle.validate_header().is_err());
+    }
+}
@@ -0,0 +1,30 @@
+//! models/src/csv_upload.rs
+//! Multiple choice questions uploaded as a CSV spreadsheet.
+//! A batch is all or nothing, so every problem is reported against its spreadsheet row.
+use serde::{Deserialize, Serialize};
+
+/// Most rows a single upload may hold.
+pub const MAX_CSV_ROWS: usize = 500;
+/// Columns an upload may use, plus any number of `choice1`, `choice2`, ... columns.
+pub const CSV_COLUMNS: [&str; 3] = ["question", "answer", "hint"];
+
+/// A problem with one row of an upload.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CsvRowError {
+    // line in the spreadsheet, the header is row 1
+    pub row: u64,
+    pub error: String,
+}
+
+impl std::fmt::Display for CsvRowError {
+    fn fmt(&self, fm: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fm, "Row {}: {}", self.row, self.error)
+    }
+}
+
+/// Body of a rejected upload, nothing from the batch is saved.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CsvRejection {
+    pub msg: String,
+    pub rows: Vec<CsvRowError>,
+}
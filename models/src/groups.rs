@@ -0,0 +1,341 @@
+//! models/src/groups.rs
+//! Groups (classes) let an instructor hand quizzes to a set of students.
+//! Students are invited by username or join with the group's code,
+//! and each assignment gives a quiz to the group between an open and close time.
+use crate::{attempts::SurrealAttempt, model_errors::ModelErrors, quiz::SurrealQuiz, PartialUser};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Thing};
+use surrealize_macro::Surrealize;
+
+/// Longest group name allowed, in characters.
+pub const MAX_GROUP_NAME_LEN: usize = 50;
+/// Length of the codes students join with.
+pub const JOIN_CODE_LEN: usize = 8;
+
+/// A group of students, stored in the `groups` table.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Surrealize)]
+pub struct Group {
+    pub name: String,
+    pub owner_id: String,
+    pub join_code: String,
+}
+
+impl Group {
+    pub fn new(name: String, owner_id: String, join_code: String) -> Self {
+        Self {
+            name,
+            owner_id,
+            join_code,
+        }
+    }
+}
+
+/// For creating groups.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupJsonPkg {
+    pub name: String,
+}
+
+impl GroupJsonPkg {
+    pub fn validate_field(&self) -> Result<(), ModelErrors> {
+        let name: &str = self.name.trim();
+        if name.is_empty() {
+            Err(ModelErrors::JsonValidation(String::from(
+                "Group name cannot be blank or white space",
+            )))
+        } else if name.chars().count() > MAX_GROUP_NAME_LEN {
+            Err(ModelErrors::JsonValidation(format!(
+                "Group name cannot be longer than {} characters",
+                MAX_GROUP_NAME_LEN
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A group the user owns or belongs to.
+/// Only owners are sent the join code, members and assignments.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupSummary {
+    #[serde(flatten)]
+    pub group: SurrealGroup,
+    pub owned: bool,
+    pub members: Vec<PartialUser>,
+    pub assignments: Vec<SurrealAssignment>,
+}
+
+/// A student in a group, stored in the `memberships` table.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Surrealize)]
+pub struct Membership {
+    pub group_id: Thing,
+    pub user_id: String,
+    pub joined_at: Datetime,
+}
+
+impl Membership {
+    pub fn new(group_id: Thing, user_id: String) -> Self {
+        Self {
+            group_id,
+            user_id,
+            joined_at: Datetime::default(),
+        }
+    }
+}
+
+/// For an owner adding a student by username.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InvitePkg {
+    pub username: String,
+}
+
+/// For a student joining with a code.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JoinPkg {
+    pub join_code: String,
+}
+
+/// A quiz given to a group, stored in the `assignments` table.
+/// Attempts are only accepted between `opens_at` and `closes_at`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Surrealize)]
+pub struct Assignment {
+    pub group_id: Thing,
+    pub quiz_id: Thing,
+    pub opens_at: Datetime,
+    pub closes_at: Datetime,
+}
+
+impl SurrealAssignment {
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.opens_at.0 <= now && now <= self.closes_at.0
+    }
+}
+
+/// Checks an attempt can be submitted, given every assignment of the quiz to the user's groups.
+/// Quizzes that are not assigned are always open, assigned ones need one window open.
+pub fn check_windows(assignments: &[SurrealAssignment], now: DateTime<Utc>) -> Result<(), String> {
+    if assignments.is_empty() || assignments.iter().any(|asg| asg.is_open(now)) {
+        return Ok(());
+    }
+    // Report the next window if there is one, otherwise the last to close
+    let next: Option<&SurrealAssignment> = assignments
+        .iter()
+        .filter(|asg| asg.opens_at.0 > now)
+        .min_by_key(|asg| asg.opens_at.0);
+    match next {
+        Some(asg) => Err(format!(
+            "Assignment opens at {}",
+            asg.opens_at.0.to_rfc3339()
+        )),
+        None => {
+            let closed: DateTime<Utc> = assignments
+                .iter()
+                .map(|asg| asg.closes_at.0)
+                .max()
+                .unwrap_or(now);
+            Err(format!("Assignment closed at {}", closed.to_rfc3339()))
+        }
+    }
+}
+
+/// For assigning a quiz to a group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssignmentJsonPkg {
+    pub quiz_id: Thing,
+    pub opens_at: Datetime,
+    pub closes_at: Datetime,
+}
+
+impl AssignmentJsonPkg {
+    pub fn validate_fields(&self) -> Result<(), ModelErrors> {
+        if self.closes_at.0 <= self.opens_at.0 {
+            Err(ModelErrors::JsonValidation(String::from(
+                "Assignment must close after it opens",
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn into_record(self, group_id: Thing) -> Assignment {
+        Assignment {
+            group_id,
+            quiz_id: self.quiz_id,
+            opens_at: self.opens_at,
+            closes_at: self.closes_at,
+        }
+    }
+}
+
+/// An assignment for a student, with its quiz and the name of its group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssignedQuiz {
+    #[serde(flatten)]
+    pub assignment: SurrealAssignment,
+    pub quiz: SurrealQuiz,
+    pub group_name: String,
+}
+
+/// How one member is doing on an assignment, counting attempts submitted in its window.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MemberProgress {
+    pub member: PartialUser,
+    pub attempts: u32,
+    pub completed: bool,
+    // as a fraction of the attempt's max score, from 0.0 to 1.0
+    pub best_score: Option<f64>,
+}
+
+/// Every member's progress on an assignment, for the group owner.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssignmentProgress {
+    pub assignment: SurrealAssignment,
+    pub members: Vec<MemberProgress>,
+}
+
+impl AssignmentProgress {
+    /// Tallies submitted attempts at the assigned quiz inside the window, sorted by username.
+    pub fn tally(
+        assignment: SurrealAssignment,
+        mut members: Vec<PartialUser>,
+        attempts: &[SurrealAttempt],
+    ) -> Self {
+        members.sort_by(|a, b| a.username.cmp(&b.username));
+        let members: Vec<MemberProgress> = members
+            .into_iter()
+            .map(|member| {
+                let scores: Vec<f64> = attempts
+                    .iter()
+                    .filter(|att| att.user_id == member.uuid && att.quiz_id == assignment.quiz_id)
+                    .filter(|att| {
+                        att.submitted_at.as_ref().is_some_and(|at| {
+                            assignment.opens_at.0 <= at.0 && at.0 <= assignment.closes_at.0
+                        })
+                    })
+                    .map(|att| {
+                        if att.max_score > 0.0 {
+                            att.score / att.max_score
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect();
+                MemberProgress {
+                    member,
+                    attempts: scores.len() as u32,
+                    completed: !scores.is_empty(),
+                    best_score: scores.into_iter().reduce(f64::max),
+                }
+            })
+            .collect();
+        Self {
+            assignment,
+            members,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::roles::Role;
+    use chrono::Duration;
+
+    fn assignment(now: DateTime<Utc>, opens_in: i64, closes_in: i64) -> SurrealAssignment {
+        SurrealAssignment {
+            id: Thing::from(("assignments", "asg")),
+            group_id: Thing::from(("groups", "grp")),
+            quiz_id: Thing::from(("quizzes", "quiz")),
+            opens_at: Datetime::from(now + Duration::hours(opens_in)),
+            closes_at: Datetime::from(now + Duration::hours(closes_in)),
+        }
+    }
+
+    fn member(uuid: &str) -> PartialUser {
+        PartialUser {
+            uuid: String::from(uuid),
+            name: String::from(uuid),
+            username: String::from(uuid),
+            role: Role::Student,
+        }
+    }
+
+    fn attempt(user_id: &str, submitted_at: Option<DateTime<Utc>>, score: f64) -> SurrealAttempt {
+        SurrealAttempt {
+            id: Thing::from(("attempts", user_id)),
+            quiz_id: Thing::from(("quizzes", "quiz")),
+            user_id: String::from(user_id),
+            question_ids: Vec::new(),
+            started_at: Datetime::default(),
+            submitted_at: submitted_at.map(Datetime::from),
+            answers: Vec::new(),
+            score,
+            max_score: 4.0,
+            deadline: None,
+            timed_out: false,
+            seed: None,
+            hints_used: Vec::new(),
+            hint_penalty: 0.0,
+            revisions: Vec::new(),
+            assigned: false,
+            time_per_question_secs: None,
+            timings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn windows_checked() {
+        // Assign
+        let now: DateTime<Utc> = Utc::now();
+
+        // Act & Assert
+        assert!(check_windows(&[], now).is_ok());
+        assert!(check_windows(&[assignment(now, -1, 1)], now).is_ok());
+        let early: String = check_windows(&[assignment(now, 1, 2)], now).unwrap_err();
+        assert!(early.starts_with("Assignment opens"));
+        let late: String = check_windows(&[assignment(now, -2, -1)], now).unwrap_err();
+        assert!(late.starts_with("Assignment closed"));
+        // Another group's window being open is enough
+        assert!(check_windows(&[assignment(now, -2, -1), assignment(now, -1, 1)], now).is_ok());
+    }
+
+    #[test]
+    fn progress_counts_attempts_inside_window() {
+        // Assign
+        let now: DateTime<Utc> = Utc::now();
+        let attempts: Vec<SurrealAttempt> = vec![
+            attempt("bea", Some(now), 2.0),
+            attempt("bea", Some(now), 3.0),
+            // submitted too late
+            attempt("abe", Some(now + Duration::hours(2)), 4.0),
+            // never submitted
+            attempt("abe", None, 0.0),
+        ];
+
+        // Act
+        let progress: AssignmentProgress = AssignmentProgress::tally(
+            assignment(now, -1, 1),
+            vec![member("bea"), member("abe")],
+            &attempts,
+        );
+
+        // Assert
+        assert_eq!(progress.members[0].member.username, "abe");
+        assert!(!progress.members[0].completed);
+        assert_eq!(progress.members[0].best_score, None);
+        assert_eq!(progress.members[1].attempts, 2);
+        assert_eq!(progress.members[1].best_score, Some(0.75));
+    }
+
+    #[test]
+    fn assignment_must_close_after_opening() {
+        let now: DateTime<Utc> = Utc::now();
+        let pkg: AssignmentJsonPkg = AssignmentJsonPkg {
+            quiz_id: Thing::from(("quizzes", "quiz")),
+            opens_at: Datetime::from(now),
+            closes_at: Datetime::from(now),
+        };
+        assert!(pkg.validate_fields().is_err());
+    }
+}
@@ -0,0 +1,508 @@
+//! models/src/interchange/gift.rs
+//! Moodle's GIFT text format, one question per block of lines with its answers in braces.
+//! See <https://docs.moodle.org/en/GIFT_format> for the full format.
+use super::{category_name, share_weight, QuestionBank, Unmapped, WrittenBank};
+use crate::{
+    model_errors::ModelErrors,
+    questions::{JsonQuestion, JsonQuestionMC, JsonQuestionMS, JsonQuestionSA, JsonQuestionTF},
+};
+
+/// Characters with a meaning in GIFT, escaped with `\` in text.
+const SPECIAL: [char; 7] = ['\\', '~', '=', '#', '{', '}', ':'];
+/// Formats a question text can start with, like `[html]`.
+const TEXT_FORMATS: [&str; 4] = ["[html]", "[moodle]", "[plain]", "[markdown]"];
+
+/// Reads every question of a GIFT file.
+pub fn read(text: &str) -> Result<QuestionBank, ModelErrors> {
+    let mut bank: QuestionBank = QuestionBank::default();
+    let mut item: usize = 0;
+    for block in blocks(text) {
+        if let Some(path) = block.strip_prefix("$CATEGORY:") {
+            bank.category = category_name(path);
+            continue;
+        }
+        if let Some(question) = read_question(&block, item, &mut bank.unmapped) {
+            bank.questions.push(question);
+        }
+        item += 1;
+    }
+    Ok(bank)
+}
+
+/// Writes questions as GIFT, with a comment above each question that lost something.
+pub fn write(name: &str, questions: &[JsonQuestion]) -> WrittenBank {
+    let mut text: String = format!("$CATEGORY: {}\n\n", name.replace(['/', '\n'], "-"));
+    let mut unmapped: Vec<Unmapped> = Vec::new();
+
+    for (item, question) in questions.iter().enumerate() {
+        let mut lost: Vec<Unmapped> = Vec::new();
+        if question_hint(question).is_some_and(|hint| !hint.trim().is_empty()) {
+            lost.push(Unmapped::part(item, None, "hint"));
+        }
+        if let JsonQuestion::LongAnswer(_) = question {
+            lost.push(Unmapped::part(item, None, "model answer and rubric"));
+        }
+        for part in lost.iter() {
+            text.push_str(&format!("// Not exported: {}\n", part.construct));
+        }
+        unmapped.append(&mut lost);
+
+        text.push_str(&escape(question.question()));
+        let answers: String = match question {
+            JsonQuestion::MultipleChoice(qmc) => {
+                let mut lines: Vec<String> = vec![format!("={}", escape(&qmc.answer))];
+                lines.extend(qmc.choices.iter().map(|ch| format!("~{}", escape(ch))));
+                lines.join("\n")
+            }
+            JsonQuestion::ShortAnswer(qsa) => qsa
+                .answers
+                .iter()
+                .map(|ans| format!("={}", escape(ans)))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            JsonQuestion::LongAnswer(_) => String::new(),
+            JsonQuestion::TrueFalse(qtf) => String::from(if qtf.answer { "TRUE" } else { "FALSE" }),
+            JsonQuestion::MultiSelect(qms) => {
+                let right: String = share_weight(qms.answers.len(), false);
+                let wrong: String = share_weight(qms.answers.len(), true);
+                let mut lines: Vec<String> = qms
+                    .answers
+                    .iter()
+                    .map(|ans| format!("~%{}%{}", right, escape(ans)))
+                    .collect();
+                lines.extend(
+                    qms.choices
+                        .iter()
+                        .map(|ch| format!("~%{}%{}", wrong, escape(ch))),
+                );
+                lines.join("\n")
+            }
+        };
+        if answers.contains('\n') {
+            text.push_str(&format!(" {{\n{}\n}}\n\n", answers));
+        } else {
+            text.push_str(&format!(" {{{}}}\n\n", answers));
+        }
+    }
+
+    WrittenBank { text, unmapped }
+}
+
+fn question_hint(question: &JsonQuestion) -> Option<&str> {
+    match question {
+        JsonQuestion::MultipleChoice(qmc) => qmc.hint.as_deref(),
+        JsonQuestion::ShortAnswer(qsa) => qsa.hint.as_deref(),
+        JsonQuestion::LongAnswer(qla) => qla.hint.as_deref(),
+        JsonQuestion::TrueFalse(qtf) => qtf.hint.as_deref(),
+        JsonQuestion::MultiSelect(qms) => qms.hint.as_deref(),
+    }
+}
+
+/// Questions are separated by blank lines, lines starting with `//` are comments.
+fn blocks(text: &str) -> Vec<String> {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if line.trim_start().starts_with("//") {
+            continue;
+        }
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(current.join("\n").trim().to_string());
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current.join("\n").trim().to_string());
+    }
+    blocks
+}
+
+/// Position of the first `pattern` not escaped with `\`, starting at `from`.
+fn find_unescaped(chars: &[char], pattern: &str, from: usize) -> Option<usize> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let mut idx: usize = from;
+    while idx < chars.len() {
+        if chars[idx] == '\\' {
+            idx += 2;
+        } else if chars[idx..].starts_with(&pattern) {
+            return Some(idx);
+        } else {
+            idx += 1;
+        }
+    }
+    None
+}
+
+fn unescape(chars: &[char]) -> String {
+    let mut text: String = String::new();
+    let mut iter = chars.iter();
+    while let Some(ch) = iter.next() {
+        if *ch != '\\' {
+            text.push(*ch);
+            continue;
+        }
+        match iter.next() {
+            Some('n') => text.push('\n'),
+            Some(escaped) => text.push(*escaped),
+            None => text.push('\\'),
+        }
+    }
+    text
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped: String = String::new();
+    for ch in text.trim().chars() {
+        if ch == '\n' {
+            escaped.push_str("\\n");
+        } else {
+            if SPECIAL.contains(&ch) {
+                escaped.push('\\');
+            }
+            escaped.push(ch);
+        }
+    }
+    escaped
+}
+
+fn strip_text_format(text: &str) -> &str {
+    TEXT_FORMATS
+        .iter()
+        .find_map(|format| text.strip_prefix(format))
+        .unwrap_or(text)
+        .trim()
+}
+
+/// A single `=` or `~` answer inside the braces.
+struct Entry {
+    correct_marker: bool,
+    weight: Option<f64>,
+    text: String,
+    feedback: bool,
+}
+
+impl Entry {
+    fn is_correct(&self) -> bool {
+        match self.weight {
+            Some(weight) => weight > 0.0,
+            None => self.correct_marker,
+        }
+    }
+}
+
+fn read_entries(chars: &[char]) -> Vec<Entry> {
+    // Split at each unescaped marker
+    let mut starts: Vec<usize> = Vec::new();
+    let mut idx: usize = 0;
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' => idx += 2,
+            '=' | '~' => {
+                starts.push(idx);
+                idx += 1;
+            }
+            _ => idx += 1,
+        }
+    }
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(pos, &start)| {
+            let end: usize = starts.get(pos + 1).copied().unwrap_or(chars.len());
+            let mut body: &[char] = &chars[start + 1..end];
+            let mut weight: Option<f64> = None;
+            if body.first() == Some(&'%') {
+                if let Some(close) = find_unescaped(body, "%", 1) {
+                    weight = body[1..close].iter().collect::<String>().parse().ok();
+                    body = &body[close + 1..];
+                }
+            }
+            let feedback_at: Option<usize> = find_unescaped(body, "#", 0);
+            Entry {
+                correct_marker: chars[start] == '=',
+                weight,
+                text: unescape(&body[..feedback_at.unwrap_or(body.len())])
+                    .trim()
+                    .to_string(),
+                feedback: feedback_at.is_some(),
+            }
+        })
+        .collect()
+}
+
+fn read_question(block: &str, item: usize, unmapped: &mut Vec<Unmapped>) -> Option<JsonQuestion> {
+    let chars: Vec<char> = block.chars().collect();
+
+    // Optional title, `::Title:: Question text {...}`
+    let mut start: usize = 0;
+    let mut title: Option<String> = None;
+    if block.starts_with("::") {
+        if let Some(end) = find_unescaped(&chars, "::", 2) {
+            title = Some(unescape(&chars[2..end]).trim().to_string());
+            start = end + 2;
+        }
+    }
+    let name: Option<&str> = title.as_deref();
+
+    let Some(open) = find_unescaped(&chars, "{", start) else {
+        unmapped.push(Unmapped::question(
+            item,
+            name,
+            "description without answers",
+        ));
+        return None;
+    };
+    let Some(close) = find_unescaped(&chars, "}", open + 1) else {
+        unmapped.push(Unmapped::question(
+            item,
+            name,
+            "answers without a closing brace",
+        ));
+        return None;
+    };
+
+    // Text after the answers makes a "missing word" question
+    let before: String = unescape(&chars[start..open]);
+    let after: String = unescape(&chars[close + 1..]);
+    let mut question: String = strip_text_format(before.trim()).to_string();
+    if !after.trim().is_empty() {
+        question = format!("{} _____ {}", question, after.trim())
+            .trim()
+            .to_string();
+    }
+
+    let mut inner: &[char] = &chars[open + 1..close];
+    let inner_text: String = inner.iter().collect::<String>().trim().to_string();
+    if inner_text.is_empty() {
+        unmapped.push(Unmapped::question(
+            item,
+            name,
+            "essay without a model answer",
+        ));
+        return None;
+    }
+    if inner_text.starts_with('#') {
+        unmapped.push(Unmapped::question(item, name, "numerical question"));
+        return None;
+    }
+    if find_unescaped(inner, "->", 0).is_some() {
+        unmapped.push(Unmapped::question(item, name, "matching question"));
+        return None;
+    }
+
+    // True or false, with optional feedback after `#`
+    let head: String = unescape(&inner[..find_unescaped(inner, "#", 0).unwrap_or(inner.len())]);
+    let truth: Option<bool> = match head.trim().to_uppercase().as_str() {
+        "T" | "TRUE" => Some(true),
+        "F" | "FALSE" => Some(false),
+        _ => None,
+    };
+    if let Some(answer) = truth {
+        if find_unescaped(inner, "#", 0).is_some() {
+            unmapped.push(Unmapped::part(item, name, "answer feedback"));
+        }
+        return Some(JsonQuestion::TrueFalse(JsonQuestionTF {
+            question,
+            hint: None,
+            answer,
+        }));
+    }
+
+    if let Some(general) = find_unescaped(inner, "####", 0) {
+        unmapped.push(Unmapped::part(item, name, "general feedback"));
+        inner = &inner[..general];
+    }
+    let entries: Vec<Entry> = read_entries(inner);
+    if entries.iter().any(|entry| entry.feedback) {
+        unmapped.push(Unmapped::part(item, name, "answer feedback"));
+    }
+
+    // Only `=` answers is a short answer question
+    if entries.iter().all(|entry| entry.correct_marker) {
+        let (full, partial): (Vec<&Entry>, Vec<&Entry>) = entries
+            .iter()
+            .partition(|entry| entry.weight.is_none_or(|weight| weight >= 100.0));
+        if !partial.is_empty() {
+            unmapped.push(Unmapped::part(item, name, "partial credit answers"));
+        }
+        return Some(JsonQuestion::ShortAnswer(JsonQuestionSA {
+            question,
+            hint: None,
+            answers: full.into_iter().map(|entry| entry.text.clone()).collect(),
+        }));
+    }
+
+    let (right, wrong): (Vec<&Entry>, Vec<&Entry>) =
+        entries.iter().partition(|entry| entry.is_correct());
+    let right: Vec<String> = right.into_iter().map(|entry| entry.text.clone()).collect();
+    let wrong: Vec<String> = wrong.into_iter().map(|entry| entry.text.clone()).collect();
+    let single_marker: bool = entries.iter().filter(|entry| entry.correct_marker).count() == 1;
+
+    match right.len() {
+        0 => {
+            unmapped.push(Unmapped::question(item, name, "no correct answer"));
+            None
+        }
+        1 if single_marker => Some(JsonQuestion::MultipleChoice(JsonQuestionMC {
+            question,
+            hint: None,
+            answer: right.into_iter().next().unwrap_or_default(),
+            choices: wrong,
+        })),
+        _ => Some(JsonQuestion::MultiSelect(JsonQuestionMS {
+            question,
+            hint: None,
+            answers: right,
+            choices: wrong,
+        })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_each_question_type() {
+        // Assign
+        let gift: &str = r#"// Exported from a question bank
+$CATEGORY: $course$/top/Algorithms
+
+::Sorts:: Which sort is O(n log(n)) in the worst case? {
+=Merge sort
+~Bubble sort # too slow
+~Quick sort
+}
+
+Name a stable sort {=merge sort =insertion sort =%50%bubble}
+
+Binary search needs sorted input {T}
+
+Select the graph algorithms {
+~%50%Dijkstra
+~%50%Prim
+~%-50%Bubble sort
+}
+
+Match the pairs {=a -> b =c -> d}
+
+Describe recursion {}
+
+The time complexity of binary search is {~O(n) =O(log n)} in the worst case.
+
+Escaped \{braces\} and a colon\: {TRUE}
+"#;
+
+        // Act
+        let bank: QuestionBank = read(gift).unwrap();
+
+        // Assert
+        assert_eq!(bank.category, Some(String::from("Algorithms")));
+        let tables: Vec<&str> = bank.questions.iter().map(|qs| qs.table_name()).collect();
+        assert_eq!(
+            tables,
+            vec![
+                "questions_mc",
+                "questions_sa",
+                "questions_tf",
+                "questions_ms",
+                "questions_mc",
+                "questions_tf"
+            ]
+        );
+        match &bank.questions[0] {
+            JsonQuestion::MultipleChoice(qmc) => {
+                assert_eq!(qmc.answer, "Merge sort");
+                assert_eq!(qmc.choices, vec!["Bubble sort", "Quick sort"]);
+            }
+            other => panic!("Expected multiple choice, got {:?}", other),
+        }
+        match &bank.questions[3] {
+            JsonQuestion::MultiSelect(qms) => {
+                assert_eq!(qms.answers, vec!["Dijkstra", "Prim"]);
+                assert_eq!(qms.choices, vec!["Bubble sort"]);
+            }
+            other => panic!("Expected multi-select, got {:?}", other),
+        }
+        assert_eq!(
+            bank.questions[4].question(),
+            "The time complexity of binary search is _____ in the worst case."
+        );
+        assert_eq!(
+            bank.questions[5].question(),
+            "Escaped {braces} and a colon:"
+        );
+
+        let constructs: Vec<(usize, &str, bool)> = bank
+            .unmapped
+            .iter()
+            .map(|un| (un.item, un.construct.as_str(), un.skipped))
+            .collect();
+        assert_eq!(
+            constructs,
+            vec![
+                (0, "answer feedback", false),
+                (1, "partial credit answers", false),
+                (4, "matching question", true),
+                (5, "essay without a model answer", true),
+            ]
+        );
+        assert_eq!(bank.unmapped[0].name, Some(String::from("Sorts")));
+    }
+
+    #[test]
+    fn written_gift_reads_back() {
+        // Assign
+        let questions: Vec<JsonQuestion> = vec![
+            JsonQuestion::MultipleChoice(JsonQuestionMC {
+                question: String::from("Cost of a hash map lookup: average or worst?"),
+                hint: Some(String::from("Collisions")),
+                answer: String::from("O(1) average"),
+                choices: vec![String::from("O(n) = always")],
+            }),
+            JsonQuestion::ShortAnswer(JsonQuestionSA {
+                question: String::from("Name a {balanced} tree"),
+                hint: None,
+                answers: vec![String::from("AVL"), String::from("red-black")],
+            }),
+            JsonQuestion::TrueFalse(JsonQuestionTF {
+                question: String::from("Heaps are complete\nbinary trees"),
+                hint: None,
+                answer: false,
+            }),
+            JsonQuestion::MultiSelect(JsonQuestionMS {
+                question: String::from("Which are stable?"),
+                hint: None,
+                answers: vec![String::from("Merge"), String::from("Insertion")],
+                choices: vec![String::from("Heap")],
+            }),
+        ];
+
+        // Act
+        let written: WrittenBank = write("Data Structures", &questions);
+        let bank: QuestionBank = read(&written.text).unwrap();
+
+        // Assert
+        assert!(written.text.contains("// Not exported: hint"));
+        assert_eq!(written.unmapped, vec![Unmapped::part(0, None, "hint")]);
+        assert!(bank.unmapped.is_empty());
+        assert_eq!(bank.category, Some(String::from("Data Structures")));
+        assert_eq!(bank.questions.len(), questions.len());
+        for (read_back, original) in bank.questions.iter().zip(questions.iter()) {
+            assert_eq!(read_back.table_name(), original.table_name());
+            assert_eq!(read_back.question(), original.question());
+        }
+        match &bank.questions[3] {
+            JsonQuestion::MultiSelect(qms) => {
+                assert_eq!(qms.answers, vec!["Merge", "Insertion"]);
+                assert_eq!(qms.choices, vec!["Heap"]);
+            }
+            other => panic!("Expected multi-select, got {:?}", other),
+        }
+    }
+}
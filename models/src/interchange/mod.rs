@@ -0,0 +1,115 @@
+//! models/src/interchange/mod.rs
+//! Converters between our questions and other quiz formats, Moodle's GIFT text format
+//! and Moodle XML. Anything without an equivalent on the other side is reported as
+//! `Unmapped` rather than silently dropped.
+use crate::questions::JsonQuestion;
+use serde::{Deserialize, Serialize};
+
+pub mod gift;
+pub mod moodle_xml;
+mod xml;
+
+/// Something in a question bank or quiz that could not be carried over.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Unmapped {
+    // position of the question in the source, starting at 0
+    pub item: usize,
+    // question name or title, when the source has one
+    pub name: Option<String>,
+    pub construct: String,
+    // true when the whole question was left out, not just part of it
+    pub skipped: bool,
+}
+
+impl Unmapped {
+    pub fn part(item: usize, name: Option<&str>, construct: &str) -> Self {
+        Self {
+            item,
+            name: name.map(String::from),
+            construct: construct.to_string(),
+            skipped: false,
+        }
+    }
+
+    pub fn question(item: usize, name: Option<&str>, construct: &str) -> Self {
+        Self {
+            skipped: true,
+            ..Self::part(item, name, construct)
+        }
+    }
+}
+
+impl std::fmt::Display for Unmapped {
+    fn fmt(&self, fm: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fm, "question {}", self.item + 1)?;
+        if let Some(name) = &self.name {
+            write!(fm, " ({})", name)?;
+        }
+        if self.skipped {
+            write!(fm, " skipped: {}", self.construct)
+        } else {
+            write!(fm, " lost {}", self.construct)
+        }
+    }
+}
+
+/// Questions read from another format.
+#[derive(Debug, Default)]
+pub struct QuestionBank {
+    // GIFT `$CATEGORY` or Moodle XML category, the closest thing to a quiz name
+    pub category: Option<String>,
+    pub questions: Vec<JsonQuestion>,
+    pub unmapped: Vec<Unmapped>,
+}
+
+/// A quiz written out in another format.
+#[derive(Debug)]
+pub struct WrittenBank {
+    pub text: String,
+    pub unmapped: Vec<Unmapped>,
+}
+
+/// Last part of a Moodle category path, `$course$/top/Algorithms` is `Algorithms`.
+fn category_name(path: &str) -> Option<String> {
+    path.rsplit('/')
+        .map(str::trim)
+        .find(|part| !part.is_empty())
+        .filter(|part| !part.starts_with('$') && *part != "top")
+        .map(String::from)
+}
+
+/// Moodle percentage for one of `count` equal shares, `-` for distractors.
+fn share_weight(count: usize, negative: bool) -> String {
+    let share: f64 = 100.0 / count.max(1) as f64;
+    let text: String = format!("{:.5}", share)
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string();
+    if negative {
+        format!("-{}", text)
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn category_paths_reduced_to_name() {
+        assert_eq!(
+            category_name("$course$/top/Algorithms"),
+            Some(String::from("Algorithms"))
+        );
+        assert_eq!(category_name("Graphs/"), Some(String::from("Graphs")));
+        assert_eq!(category_name("$course$/top"), None);
+    }
+
+    #[test]
+    fn shares_match_moodle_percentages() {
+        assert_eq!(share_weight(1, false), "100");
+        assert_eq!(share_weight(3, false), "33.33333");
+        assert_eq!(share_weight(2, true), "-50");
+    }
+}
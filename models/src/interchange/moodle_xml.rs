@@ -0,0 +1,497 @@
+//! models/src/interchange/moodle_xml.rs
+//! Moodle XML, the question bank export format of Moodle.
+//! See <https://docs.moodle.org/en/Moodle_XML_format> for the full format.
+use super::{
+    category_name, share_weight,
+    xml::{self, encode_entities, Element},
+    QuestionBank, Unmapped, WrittenBank,
+};
+use crate::{
+    model_errors::ModelErrors,
+    questions::{
+        JsonQuestion, JsonQuestionLA, JsonQuestionMC, JsonQuestionMS, JsonQuestionSA,
+        JsonQuestionTF, RubricCriterion,
+    },
+};
+
+/// Rubric given to imported essays, Moodle essays are marked against grader information only.
+pub const ESSAY_CRITERION: &str = "Matches the model answer";
+
+/// Reads every question of a Moodle XML file.
+pub fn read(text: &str) -> Result<QuestionBank, ModelErrors> {
+    let root: Element = xml::parse(text)?;
+    if root.name != "quiz" {
+        return Err(ModelErrors::JsonValidation(String::from(
+            "Moodle XML must have a <quiz> root element",
+        )));
+    }
+
+    let mut bank: QuestionBank = QuestionBank::default();
+    let mut item: usize = 0;
+    for question in root.children_named("question") {
+        let kind: &str = question.attribute("type").unwrap_or_default();
+        if kind == "category" {
+            bank.category = question
+                .child("category")
+                .and_then(|cat| category_name(&text_of(cat)));
+            continue;
+        }
+        if let Some(read) = read_question(question, kind, item, &mut bank.unmapped) {
+            bank.questions.push(read);
+        }
+        item += 1;
+    }
+    Ok(bank)
+}
+
+/// Writes questions as Moodle XML, with a comment above each question that lost something.
+pub fn write(name: &str, questions: &[JsonQuestion]) -> WrittenBank {
+    let mut text: String = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<quiz>\n");
+    let mut unmapped: Vec<Unmapped> = Vec::new();
+    text.push_str(&format!(
+        "  <question type=\"category\">\n    <category><text>$course$/top/{}</text></category>\n  </question>\n",
+        encode_entities(&name.replace('/', "-"))
+    ));
+
+    for (item, question) in questions.iter().enumerate() {
+        let mut body: Vec<String> = Vec::new();
+        let (kind, hint): (&str, Option<&str>) = match question {
+            JsonQuestion::MultipleChoice(qmc) => {
+                body.push(answer_xml("100", &qmc.answer));
+                body.extend(qmc.choices.iter().map(|ch| answer_xml("0", ch)));
+                body.push(String::from("<single>true</single>"));
+                ("multichoice", qmc.hint.as_deref())
+            }
+            JsonQuestion::ShortAnswer(qsa) => {
+                body.extend(qsa.answers.iter().map(|ans| answer_xml("100", ans)));
+                body.push(String::from("<usecase>0</usecase>"));
+                ("shortanswer", qsa.hint.as_deref())
+            }
+            JsonQuestion::LongAnswer(qla) => {
+                // Moodle essays have no rubric, the criteria go into the grader information
+                let mut info: String = qla.model_answer.clone();
+                if !qla.rubric.is_empty() {
+                    unmapped.push(Unmapped::part(item, None, "rubric weights"));
+                    info.push_str("\n\nRubric:");
+                    for criterion in qla.rubric.iter() {
+                        info.push_str(&format!("\n- {}", criterion.criterion));
+                    }
+                }
+                body.push(format!(
+                    "<graderinfo format=\"plain_text\"><text>{}</text></graderinfo>",
+                    encode_entities(&info)
+                ));
+                ("essay", qla.hint.as_deref())
+            }
+            JsonQuestion::TrueFalse(qtf) => {
+                body.push(answer_xml(if qtf.answer { "100" } else { "0" }, "true"));
+                body.push(answer_xml(if qtf.answer { "0" } else { "100" }, "false"));
+                ("truefalse", qtf.hint.as_deref())
+            }
+            JsonQuestion::MultiSelect(qms) => {
+                let right: String = share_weight(qms.answers.len(), false);
+                let wrong: String = share_weight(qms.answers.len(), true);
+                body.extend(qms.answers.iter().map(|ans| answer_xml(&right, ans)));
+                body.extend(qms.choices.iter().map(|ch| answer_xml(&wrong, ch)));
+                body.push(String::from("<single>false</single>"));
+                ("multichoice", qms.hint.as_deref())
+            }
+        };
+        if let Some(hint) = hint.filter(|hint| !hint.trim().is_empty()) {
+            body.push(format!(
+                "<hint format=\"plain_text\"><text>{}</text></hint>",
+                encode_entities(hint)
+            ));
+        }
+
+        if let Some(lost) = unmapped.last().filter(|lost| lost.item == item) {
+            text.push_str(&format!("  <!-- Not exported: {} -->\n", lost.construct));
+        }
+        text.push_str(&format!("  <question type=\"{}\">\n", kind));
+        text.push_str(&format!(
+            "    <name><text>{}</text></name>\n",
+            encode_entities(&question_name(question.question()))
+        ));
+        text.push_str(&format!(
+            "    <questiontext format=\"plain_text\"><text>{}</text></questiontext>\n",
+            encode_entities(question.question())
+        ));
+        for line in body {
+            text.push_str(&format!("    {}\n", line));
+        }
+        text.push_str("  </question>\n");
+    }
+    text.push_str("</quiz>\n");
+
+    WrittenBank { text, unmapped }
+}
+
+fn answer_xml(fraction: &str, text: &str) -> String {
+    format!(
+        "<answer fraction=\"{}\" format=\"plain_text\"><text>{}</text></answer>",
+        fraction,
+        encode_entities(text)
+    )
+}
+
+/// Moodle needs a name for each question, the start of the question text will do.
+fn question_name(question: &str) -> String {
+    let first_line: &str = question.lines().next().unwrap_or_default().trim();
+    if first_line.chars().count() > 50 {
+        format!(
+            "{}…",
+            first_line.chars().take(50).collect::<String>().trim_end()
+        )
+    } else {
+        first_line.to_string()
+    }
+}
+
+/// Text of an element's `<text>` child, as plain text.
+fn text_of(elem: &Element) -> String {
+    let text: String = elem.child("text").map(Element::text).unwrap_or_default();
+    match elem.attribute("format") {
+        Some("html") | Some("moodle_auto_format") | None => html_to_text(&text),
+        _ => text.trim().to_string(),
+    }
+}
+
+/// Drops HTML tags, keeping line breaks from `<br>` and paragraphs.
+fn html_to_text(html: &str) -> String {
+    let mut text: String = String::new();
+    let mut rest: &str = html;
+    while let Some(open) = rest.find('<') {
+        text.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('>') else {
+            rest = &rest[open..];
+            break;
+        };
+        let tag: String = rest[open + 1..open + close].to_lowercase();
+        if tag.starts_with("br") || tag == "/p" || tag == "/div" || tag == "/li" {
+            text.push('\n');
+        }
+        rest = &rest[open + close + 1..];
+    }
+    text.push_str(rest);
+    xml::decode_entities(&text)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// Answers with their fraction, in percent.
+type Answers = Vec<(f64, String)>;
+
+/// Each `<answer>` with its fraction, in percent.
+fn answers(question: &Element) -> Answers {
+    question
+        .children_named("answer")
+        .map(|ans| {
+            let fraction: f64 = ans
+                .attribute("fraction")
+                .and_then(|frac| frac.trim().parse().ok())
+                .unwrap_or(0.0);
+            (fraction, text_of(ans))
+        })
+        .collect()
+}
+
+fn read_question(
+    question: &Element,
+    kind: &str,
+    item: usize,
+    unmapped: &mut Vec<Unmapped>,
+) -> Option<JsonQuestion> {
+    let name: Option<String> = question
+        .child("name")
+        .map(|nm| nm.child("text").map(Element::text).unwrap_or_default())
+        .map(|nm| nm.trim().to_string())
+        .filter(|nm| !nm.is_empty());
+    let name: Option<&str> = name.as_deref();
+    let text: String = question
+        .child("questiontext")
+        .map(text_of)
+        .unwrap_or_default();
+
+    let hints: Vec<String> = question.children_named("hint").map(text_of).collect();
+    if hints.len() > 1 {
+        unmapped.push(Unmapped::part(item, name, "extra hints"));
+    }
+    let hint: Option<String> = hints.into_iter().next().filter(|hint| !hint.is_empty());
+    if question
+        .child("generalfeedback")
+        .is_some_and(|fb| !text_of(fb).is_empty())
+    {
+        unmapped.push(Unmapped::part(item, name, "general feedback"));
+    }
+    if question
+        .children_named("answer")
+        .filter_map(|ans| ans.child("feedback"))
+        .any(|fb| !text_of(fb).is_empty())
+    {
+        unmapped.push(Unmapped::part(item, name, "answer feedback"));
+    }
+
+    let answers: Answers = answers(question);
+    match kind {
+        "multichoice" => {
+            let single: bool = question
+                .child("single")
+                .map(|sg| sg.text().trim().to_lowercase())
+                .is_none_or(|sg| sg == "true" || sg == "1");
+            let (right, wrong): (Answers, Answers) =
+                answers.into_iter().partition(|(frac, _)| *frac > 0.0);
+            if right.is_empty() {
+                unmapped.push(Unmapped::question(item, name, "no correct answer"));
+                return None;
+            }
+            let wrong: Vec<String> = wrong.into_iter().map(|(_, ans)| ans).collect();
+            if single {
+                // Only the best answer is kept, others become distractors
+                let best: usize = right
+                    .iter()
+                    .enumerate()
+                    .max_by(|a, b| a.1 .0.total_cmp(&b.1 .0))
+                    .map(|(idx, _)| idx)
+                    .unwrap_or_default();
+                if right.len() > 1 {
+                    unmapped.push(Unmapped::part(item, name, "partial credit answers"));
+                }
+                let mut choices: Vec<String> = Vec::new();
+                let mut answer: String = String::new();
+                for (idx, (_, ans)) in right.into_iter().enumerate() {
+                    if idx == best {
+                        answer = ans;
+                    } else {
+                        choices.push(ans);
+                    }
+                }
+                choices.extend(wrong);
+                Some(JsonQuestion::MultipleChoice(JsonQuestionMC {
+                    question: text,
+                    hint,
+                    answer,
+                    choices,
+                }))
+            } else {
+                Some(JsonQuestion::MultiSelect(JsonQuestionMS {
+                    question: text,
+                    hint,
+                    answers: right.into_iter().map(|(_, ans)| ans).collect(),
+                    choices: wrong,
+                }))
+            }
+        }
+        "truefalse" => {
+            let answer: bool = answers
+                .iter()
+                .find(|(frac, _)| *frac > 0.0)
+                .is_some_and(|(_, ans)| ans.to_lowercase() == "true");
+            Some(JsonQuestion::TrueFalse(JsonQuestionTF {
+                question: text,
+                hint,
+                answer,
+            }))
+        }
+        "shortanswer" => {
+            if question
+                .child("usecase")
+                .is_some_and(|uc| uc.text().trim() == "1")
+            {
+                unmapped.push(Unmapped::part(item, name, "case sensitive matching"));
+            }
+            let (full, partial): (Answers, Answers) =
+                answers.into_iter().partition(|(frac, _)| *frac >= 100.0);
+            if partial.iter().any(|(frac, _)| *frac > 0.0) {
+                unmapped.push(Unmapped::part(item, name, "partial credit answers"));
+            }
+            Some(JsonQuestion::ShortAnswer(JsonQuestionSA {
+                question: text,
+                hint,
+                answers: full.into_iter().map(|(_, ans)| ans).collect(),
+            }))
+        }
+        "essay" => {
+            let model_answer: String = question
+                .child("graderinfo")
+                .map(text_of)
+                .unwrap_or_default();
+            if model_answer.is_empty() {
+                unmapped.push(Unmapped::question(
+                    item,
+                    name,
+                    "essay without grader information",
+                ));
+                return None;
+            }
+            Some(JsonQuestion::LongAnswer(JsonQuestionLA {
+                question: text,
+                hint,
+                model_answer,
+                rubric: vec![RubricCriterion {
+                    criterion: String::from(ESSAY_CRITERION),
+                    weight: 1,
+                }],
+            }))
+        }
+        other => {
+            unmapped.push(Unmapped::question(
+                item,
+                name,
+                &format!(
+                    "{} question",
+                    if other.is_empty() { "untyped" } else { other }
+                ),
+            ));
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_moodle_questions() {
+        // Assign
+        let doc: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<quiz>
+  <question type="category">
+    <category><text>$course$/top/Graphs</text></category>
+  </question>
+  <question type="multichoice">
+    <name><text>Shortest path</text></name>
+    <questiontext format="html"><text><![CDATA[<p>Which handles <b>negative</b> edges?</p>]]></text></questiontext>
+    <answer fraction="100"><text>Bellman-Ford</text><feedback><text>Yes</text></feedback></answer>
+    <answer fraction="0"><text>Dijkstra</text></answer>
+    <single>true</single>
+    <hint format="html"><text>Think relaxation</text></hint>
+  </question>
+  <question type="multichoice">
+    <name><text>Spanning trees</text></name>
+    <questiontext format="plain_text"><text>Pick the MST algorithms</text></questiontext>
+    <answer fraction="50"><text>Prim</text></answer>
+    <answer fraction="50"><text>Kruskal</text></answer>
+    <answer fraction="-50"><text>DFS</text></answer>
+    <single>false</single>
+  </question>
+  <question type="truefalse">
+    <questiontext format="plain_text"><text>Trees are graphs</text></questiontext>
+    <answer fraction="100"><text>true</text></answer>
+    <answer fraction="0"><text>false</text></answer>
+  </question>
+  <question type="matching">
+    <name><text>Pairs</text></name>
+  </question>
+  <question type="essay">
+    <questiontext format="plain_text"><text>Explain BFS</text></questiontext>
+    <graderinfo format="html"><text>Visits by &lt;b&gt;layers&lt;/b&gt;</text></graderinfo>
+  </question>
+</quiz>"#;
+
+        // Act
+        let bank: QuestionBank = read(doc).unwrap();
+
+        // Assert
+        assert_eq!(bank.category, Some(String::from("Graphs")));
+        assert_eq!(bank.questions.len(), 4);
+        match &bank.questions[0] {
+            JsonQuestion::MultipleChoice(qmc) => {
+                assert_eq!(qmc.question, "Which handles negative edges?");
+                assert_eq!(qmc.answer, "Bellman-Ford");
+                assert_eq!(qmc.choices, vec!["Dijkstra"]);
+                assert_eq!(qmc.hint.as_deref(), Some("Think relaxation"));
+            }
+            other => panic!("Expected multiple choice, got {:?}", other),
+        }
+        match &bank.questions[1] {
+            JsonQuestion::MultiSelect(qms) => {
+                assert_eq!(qms.answers, vec!["Prim", "Kruskal"]);
+                assert_eq!(qms.choices, vec!["DFS"]);
+            }
+            other => panic!("Expected multi-select, got {:?}", other),
+        }
+        match &bank.questions[3] {
+            JsonQuestion::LongAnswer(qla) => assert_eq!(qla.model_answer, "Visits by layers"),
+            other => panic!("Expected long answer, got {:?}", other),
+        }
+        assert_eq!(
+            bank.unmapped,
+            vec![
+                Unmapped::part(0, Some("Shortest path"), "answer feedback"),
+                Unmapped::question(3, Some("Pairs"), "matching question"),
+            ]
+        );
+    }
+
+    #[test]
+    fn written_xml_reads_back() {
+        // Assign
+        let questions: Vec<JsonQuestion> = vec![
+            JsonQuestion::ShortAnswer(JsonQuestionSA {
+                question: String::from("Name a <stable> sort & why"),
+                hint: Some(String::from("Ties")),
+                answers: vec![String::from("Merge")],
+            }),
+            JsonQuestion::TrueFalse(JsonQuestionTF {
+                question: String::from("Quick sort is stable"),
+                hint: None,
+                answer: false,
+            }),
+            JsonQuestion::LongAnswer(JsonQuestionLA {
+                question: String::from("Explain recursion"),
+                hint: None,
+                model_answer: String::from("A function calling itself"),
+                rubric: vec![RubricCriterion {
+                    criterion: String::from("Mentions a base case"),
+                    weight: 2,
+                }],
+            }),
+            JsonQuestion::MultiSelect(JsonQuestionMS {
+                question: String::from("Which are O(1)?"),
+                hint: None,
+                answers: vec![String::from("Array index")],
+                choices: vec![String::from("List search")],
+            }),
+        ];
+
+        // Act
+        let written: WrittenBank = write("Sorting / Searching", &questions);
+        let bank: QuestionBank = read(&written.text).unwrap();
+
+        // Assert
+        assert!(written
+            .text
+            .contains("<!-- Not exported: rubric weights -->"));
+        assert_eq!(
+            written.unmapped,
+            vec![Unmapped::part(2, None, "rubric weights")]
+        );
+        assert!(bank.unmapped.is_empty());
+        assert_eq!(bank.category, Some(String::from("Sorting - Searching")));
+        let tables: Vec<&str> = bank.questions.iter().map(|qs| qs.table_name()).collect();
+        assert_eq!(
+            tables,
+            vec![
+                "questions_sa",
+                "questions_tf",
+                "questions_la",
+                "questions_ms"
+            ]
+        );
+        match &bank.questions[0] {
+            JsonQuestion::ShortAnswer(qsa) => {
+                assert_eq!(qsa.question, "Name a <stable> sort & why");
+                assert_eq!(qsa.hint.as_deref(), Some("Ties"));
+            }
+            other => panic!("Expected short answer, got {:?}", other),
+        }
+        match &bank.questions[1] {
+            JsonQuestion::TrueFalse(qtf) => assert!(!qtf.answer),
+            other => panic!("Expected true or false, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! models/src/interchange/xml.rs
+//! Just enough XML for Moodle question files: elements, attributes, text, CDATA,
+//! comments and the standard entities. DTDs and namespaces are not supported.
+use crate::model_errors::ModelErrors;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Child elements with the given name.
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter_map(move |node| match node {
+            Node::Element(elem) if elem.name == name => Some(elem),
+            _ => None,
+        })
+    }
+
+    pub fn child<'a>(&'a self, name: &'a str) -> Option<&'a Element> {
+        self.children_named(name).next()
+    }
+
+    /// All text inside the element, including nested elements.
+    pub fn text(&self) -> String {
+        let mut text: String = String::new();
+        for node in self.children.iter() {
+            match node {
+                Node::Text(part) => text.push_str(part),
+                Node::Element(elem) => text.push_str(&elem.text()),
+            }
+        }
+        text
+    }
+}
+
+/// How deep elements may nest. Moodle files need about five levels, and each level
+/// is a recursive call, so without a limit a deeply nested upload overflows the stack.
+const MAX_DEPTH: usize = 64;
+
+fn xml_error(msg: &str) -> ModelErrors {
+    ModelErrors::JsonValidation(format!("Invalid XML: {}", msg))
+}
+
+/// Parses a document, returning its root element.
+pub fn parse(text: &str) -> Result<Element, ModelErrors> {
+    let mut parser: Parser = Parser {
+        rest: text,
+        depth: 0,
+    };
+    parser.skip_misc()?;
+    let root: Element = parser.element()?;
+    parser.skip_misc()?;
+    if parser.rest.is_empty() {
+        Ok(root)
+    } else {
+        Err(xml_error("content after the root element"))
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+    // elements open around the one being parsed
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn take_until(&mut self, end: &str) -> Result<&'a str, ModelErrors> {
+        let at: usize = self
+            .rest
+            .find(end)
+            .ok_or_else(|| xml_error(&format!("missing {}", end)))?;
+        let taken: &'a str = &self.rest[..at];
+        self.rest = &self.rest[at + end.len()..];
+        Ok(taken)
+    }
+
+    /// Skips whitespace, the XML declaration, processing instructions, comments and doctypes.
+    fn skip_misc(&mut self) -> Result<(), ModelErrors> {
+        loop {
+            self.rest = self.rest.trim_start();
+            if self.rest.starts_with("<?") {
+                self.take_until("?>")?;
+            } else if self.rest.starts_with("<!--") {
+                self.take_until("-->")?;
+            } else if self.rest.starts_with("<!DOCTYPE") {
+                self.take_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, ModelErrors> {
+        let end: usize = self
+            .rest
+            .find(|ch: char| ch.is_whitespace() || ch == '>' || ch == '/' || ch == '=')
+            .unwrap_or(self.rest.len());
+        if end == 0 {
+            return Err(xml_error("expected a name"));
+        }
+        let name: String = self.rest[..end].to_string();
+        self.rest = &self.rest[end..];
+        Ok(name)
+    }
+
+    fn element(&mut self) -> Result<Element, ModelErrors> {
+        self.rest = self
+            .rest
+            .strip_prefix('<')
+            .ok_or_else(|| xml_error("expected an element"))?;
+        let mut elem: Element = Element {
+            name: self.name()?,
+            ..Element::default()
+        };
+
+        // Attributes, up to `>` or `/>`
+        loop {
+            self.rest = self.rest.trim_start();
+            if let Some(rest) = self.rest.strip_prefix("/>") {
+                self.rest = rest;
+                return Ok(elem);
+            }
+            if let Some(rest) = self.rest.strip_prefix('>') {
+                self.rest = rest;
+                break;
+            }
+            let key: String = self.name()?;
+            self.rest = self
+                .rest
+                .trim_start()
+                .strip_prefix('=')
+                .ok_or_else(|| xml_error("attribute without a value"))?
+                .trim_start();
+            let quote: char = self
+                .rest
+                .chars()
+                .next()
+                .filter(|ch| *ch == '"' || *ch == '\'')
+                .ok_or_else(|| xml_error("attribute value without quotes"))?;
+            self.rest = &self.rest[1..];
+            let value: &str = self.take_until(&quote.to_string())?;
+            elem.attributes.push((key, decode_entities(value)));
+        }
+
+        // Children, up to the closing tag
+        loop {
+            if let Some(rest) = self.rest.strip_prefix("</") {
+                self.rest = rest;
+                let closing: String = self.name()?;
+                if closing != elem.name {
+                    return Err(xml_error(&format!(
+                        "<{}> closed by </{}>",
+                        elem.name, closing
+                    )));
+                }
+                self.take_until(">")?;
+                return Ok(elem);
+            } else if let Some(rest) = self.rest.strip_prefix("<![CDATA[") {
+                self.rest = rest;
+                let data: &str = self.take_until("]]>")?;
+                elem.children.push(Node::Text(data.to_string()));
+            } else if self.rest.starts_with("<!--") {
+                self.take_until("-->")?;
+            } else if self.rest.starts_with('<') {
+                if self.depth == MAX_DEPTH {
+                    return Err(xml_error(&format!(
+                        "elements nested more than {} deep",
+                        MAX_DEPTH
+                    )));
+                }
+                self.depth += 1;
+                let child: Element = self.element()?;
+                self.depth -= 1;
+                elem.children.push(Node::Element(child));
+            } else if self.rest.is_empty() {
+                return Err(xml_error(&format!("<{}> is never closed", elem.name)));
+            } else {
+                let end: usize = self.rest.find('<').unwrap_or(self.rest.len());
+                elem.children
+                    .push(Node::Text(decode_entities(&self.rest[..end])));
+                self.rest = &self.rest[end..];
+            }
+        }
+    }
+}
+
+/// Replaces the XML entities and character references, plus `&nbsp;` which HTML
+/// question text often has. Unknown entities are left as they are.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded: String = String::new();
+    let mut rest: &str = text;
+    while let Some(at) = rest.find('&') {
+        decoded.push_str(&rest[..at]);
+        rest = &rest[at..];
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity: &str = &rest[1..end];
+        let replacement: Option<char> = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match replacement {
+            Some(ch) => {
+                decoded.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+pub fn encode_entities(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_elements_text_and_cdata() {
+        // Assign
+        let doc: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- a comment -->
+<quiz>
+  <question type='multichoice'>
+    <name><text>Sorts &amp; searches</text></name>
+    <questiontext format="html"><text><![CDATA[<p>Fastest?</p>]]></text></questiontext>
+    <single>true</single>
+    <hidden/>
+  </question>
+</quiz>"#;
+
+        // Act
+        let root: Element = parse(doc).unwrap();
+
+        // Assert
+        let question: &Element = root.child("question").unwrap();
+        assert_eq!(question.attribute("type"), Some("multichoice"));
+        assert_eq!(question.child("name").unwrap().text(), "Sorts & searches");
+        assert_eq!(
+            question.child("questiontext").unwrap().text().trim(),
+            "<p>Fastest?</p>"
+        );
+        assert!(question.child("hidden").unwrap().children.is_empty());
+    }
+
+    #[test]
+    fn rejects_mismatched_tags() {
+        assert!(parse("<quiz><question></quiz>").is_err());
+        assert!(parse("<quiz>").is_err());
+        assert!(parse("<quiz/><quiz/>").is_err());
+    }
+
+    #[test]
+    fn rejects_deep_nesting() {
+        // Assign
+        let nested = |depth: usize| format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+
+        // Act
+        let shallow = parse(&nested(MAX_DEPTH + 1));
+        let deep = parse(&nested(30_000));
+
+        // Assert
+        assert!(shallow.is_ok());
+        assert!(deep.is_err());
+    }
+
+    #[test]
+    fn entities_round_trip() {
+        let text: &str = "a < b && \"c\" > d";
+        assert_eq!(decode_entities(&encode_entities(text)), text);
+        assert_eq!(
+            decode_entities("&#65;&#x42; &unknown; & done"),
+            "AB &unknown; & done"
+        );
+    }
+}
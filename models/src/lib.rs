@@ -1,10 +1,24 @@
+use roles::Role;
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::Thing;
 use surrealize_macro::Surrealize;
 
+pub mod attempts;
+pub mod bundle;
+pub mod csv_upload;
+pub mod groups;
+pub mod interchange;
+pub mod likes;
 pub mod model_errors;
+pub mod pools;
 pub mod questions;
 pub mod quiz;
+pub mod revisions;
+pub mod roles;
+pub mod search;
+pub mod study;
+pub mod tags;
+pub mod trash;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Clone)]
 pub struct SurrealRecord {
@@ -21,16 +35,26 @@ pub struct GeneralUser {
     pub name: String,
     pub username: String,
     pub password_hash: String,
+    // accounts from before roles were added are instructors
+    #[serde(default)]
+    pub role: Role,
 }
 
 impl GeneralUser {
-    pub fn new(uuid: String, name: String, username: String, password_hash: String) -> Self {
+    pub fn new(
+        uuid: String,
+        name: String,
+        username: String,
+        password_hash: String,
+        role: Role,
+    ) -> Self {
         // Validation?
         Self {
             uuid,
             name,
             username,
             password_hash,
+            role,
         }
     }
 }
@@ -40,6 +64,8 @@ pub struct PartialUser {
     pub uuid: String,
     pub name: String,
     pub username: String,
+    #[serde(default)]
+    pub role: Role,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -71,12 +97,14 @@ mod tests {
             name.clone(),
             username.clone(),
             password_hash.clone(),
+            Role::Student,
         );
         let expected = GeneralUser {
             uuid: id,
             name: name,
             username: username,
             password_hash: password_hash,
+            role: Role::Student,
         };
         assert_eq!(actual, expected);
     }
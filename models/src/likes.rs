@@ -0,0 +1,164 @@
+//! models/src/likes.rs
+//! Likes are stored as `general_user->likes->target` relations,
+//! where the target is a quiz or a question.
+use crate::questions::{
+    AllQuestions, SurrealQuestionLA, SurrealQuestionMC, SurrealQuestionMS, SurrealQuestionSA,
+    SurrealQuestionTF, QUESTION_TABLES,
+};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+/// Tables whose records can be liked.
+pub fn likeable_table(table: &str) -> bool {
+    table == "quizzes" || QUESTION_TABLES.contains(&table)
+}
+
+/// Sets whether the user likes the target, sending the same package twice changes nothing.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LikeJsonPkg {
+    pub target: Thing,
+    pub liked: bool,
+}
+
+/// How many users like a record.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LikeCount {
+    #[serde(alias = "out")]
+    pub target: Thing,
+    pub likes: u64,
+}
+
+/// Returned once a like is set, or unset.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LikeStatus {
+    pub target: Thing,
+    pub liked: bool,
+    pub likes: u64,
+}
+
+/// Like counts for a set of records, along with which of them the current user likes.
+#[derive(Debug, Default, Clone)]
+pub struct LikeTally {
+    pub counts: Vec<LikeCount>,
+    pub mine: Vec<Thing>,
+}
+
+impl LikeTally {
+    pub fn count(&self, target: &Thing) -> u64 {
+        self.counts
+            .iter()
+            .find(|lc| &lc.target == target)
+            .map_or(0, |lc| lc.likes)
+    }
+
+    pub fn wrap<T>(&self, target: &Thing, record: T) -> Liked<T> {
+        Liked {
+            likes: self.count(target),
+            liked: self.mine.contains(target),
+            record,
+        }
+    }
+}
+
+/// A record as sent to the frontend, with its like count alongside its own fields.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Liked<T> {
+    #[serde(flatten)]
+    pub record: T,
+    pub likes: u64,
+    // whether the current user likes it
+    pub liked: bool,
+}
+
+/// `AllQuestions` with like counts, in the same shape so clients can ignore the extra fields.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LikedQuestions {
+    pub mc: Vec<Liked<SurrealQuestionMC>>,
+    pub sa: Vec<Liked<SurrealQuestionSA>>,
+    pub la: Vec<Liked<SurrealQuestionLA>>,
+    pub tf: Vec<Liked<SurrealQuestionTF>>,
+    pub ms: Vec<Liked<SurrealQuestionMS>>,
+}
+
+impl AllQuestions {
+    /// Ids of every question, across all question types.
+    pub fn ids(&self) -> Vec<Thing> {
+        self.mc
+            .iter()
+            .map(|qs| qs.id.clone())
+            .chain(self.sa.iter().map(|qs| qs.id.clone()))
+            .chain(self.la.iter().map(|qs| qs.id.clone()))
+            .chain(self.tf.iter().map(|qs| qs.id.clone()))
+            .chain(self.ms.iter().map(|qs| qs.id.clone()))
+            .collect()
+    }
+
+    pub fn with_likes(self, tally: &LikeTally) -> LikedQuestions {
+        LikedQuestions {
+            mc: self
+                .mc
+                .into_iter()
+                .map(|qs| tally.wrap(&qs.id.clone(), qs))
+                .collect(),
+            sa: self
+                .sa
+                .into_iter()
+                .map(|qs| tally.wrap(&qs.id.clone(), qs))
+                .collect(),
+            la: self
+                .la
+                .into_iter()
+                .map(|qs| tally.wrap(&qs.id.clone(), qs))
+                .collect(),
+            tf: self
+                .tf
+                .into_iter()
+                .map(|qs| tally.wrap(&qs.id.clone(), qs))
+                .collect(),
+            ms: self
+                .ms
+                .into_iter()
+                .map(|qs| tally.wrap(&qs.id.clone(), qs))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quiz::{SurrealQuiz, Visibility};
+
+    #[test]
+    fn liked_records_keep_their_fields() {
+        // Assign
+        let quiz_id: Thing = Thing::from(("quizzes", "quiz"));
+        let tally: LikeTally = LikeTally {
+            counts: vec![LikeCount {
+                target: quiz_id.clone(),
+                likes: 3,
+            }],
+            mine: vec![quiz_id.clone()],
+        };
+        let quiz: SurrealQuiz = SurrealQuiz {
+            id: quiz_id.clone(),
+            name: String::from("Algorithms"),
+            description: String::from("An algorithms quiz"),
+            author_id: String::from("user"),
+            visibility: Visibility::Public,
+            time_limit_secs: None,
+            time_per_question_secs: None,
+            pools: Vec::new(),
+            hint_penalty: 0.0,
+        };
+
+        // Act
+        let json: serde_json::Value = serde_json::to_value(tally.wrap(&quiz_id, quiz)).unwrap();
+
+        // Assert
+        assert_eq!(json["name"], "Algorithms");
+        assert_eq!(json["likes"], 3);
+        assert_eq!(json["liked"], true);
+        assert_eq!(tally.count(&Thing::from(("quizzes", "other"))), 0);
+    }
+}
@@ -0,0 +1,148 @@
+//! models/src/pools.rs
+//! Question pools let a quiz hand each attempt a random paper instead of every question.
+//! A pool is one of the author's tags, and the quiz draws a number of questions from each.
+//! Draws are seeded so the server can regenerate exactly which questions an attempt saw.
+use crate::{model_errors::ModelErrors, tags::SurrealTag};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+/// Most pools a quiz can draw from.
+pub const MAX_POOLS: usize = 10;
+/// Most questions a single pool can hand out.
+pub const MAX_POOL_DRAW: u32 = 100;
+
+/// Draw `count` questions tagged with `tag`, from any of the author's quizzes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PoolDraw {
+    pub tag: Thing,
+    pub count: u32,
+}
+
+/// Checks the pools of a quiz, `tags` being every tag its author has made.
+pub fn validate_pools(pools: &[PoolDraw], tags: &[SurrealTag]) -> Result<(), ModelErrors> {
+    if pools.len() > MAX_POOLS {
+        return Err(ModelErrors::JsonValidation(format!(
+            "A quiz cannot draw from more than {} pools",
+            MAX_POOLS
+        )));
+    }
+    for (at, pool) in pools.iter().enumerate() {
+        if pool.count == 0 || pool.count > MAX_POOL_DRAW {
+            return Err(ModelErrors::JsonValidation(format!(
+                "Pools draw between 1 and {} questions",
+                MAX_POOL_DRAW
+            )));
+        }
+        if !tags.iter().any(|tg| tg.id == pool.tag) {
+            return Err(ModelErrors::JsonValidation(String::from(
+                "Pools can only use your own tags",
+            )));
+        }
+        if pools[..at].iter().any(|other| other.tag == pool.tag) {
+            return Err(ModelErrors::JsonValidation(String::from(
+                "A tag can only be used by one pool",
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Picks the questions for an attempt, in the order they are handed out.
+/// Each entry is how many to draw and the questions to draw from, a question already
+/// drawn by an earlier pool is not drawn again. The same seed and candidates give the same paper,
+/// whatever order the candidates arrive in.
+pub fn draw_questions(pools: &[(u32, Vec<Thing>)], seed: u64) -> Vec<Thing> {
+    let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut paper: Vec<Thing> = Vec::new();
+    for (count, candidates) in pools {
+        let mut candidates: Vec<Thing> = candidates
+            .iter()
+            .filter(|id| !paper.contains(id))
+            .cloned()
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+        candidates.shuffle(&mut rng);
+        candidates.truncate(*count as usize);
+        paper.extend(candidates);
+    }
+    paper.shuffle(&mut rng);
+    paper
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(prefix: &str, count: usize) -> Vec<Thing> {
+        (0..count)
+            .map(|n| Thing::from(("questions_mc", format!("{}{}", prefix, n).as_str())))
+            .collect()
+    }
+
+    fn tag(id: &str) -> SurrealTag {
+        SurrealTag {
+            id: Thing::from(("tags", id)),
+            name: String::from(id),
+            author_id: String::from("author"),
+        }
+    }
+
+    #[test]
+    fn same_seed_draws_same_paper() {
+        // Assign
+        let sorting: Vec<Thing> = ids("sort", 10);
+        let graphs: Vec<Thing> = ids("graph", 10);
+        let mut reversed: Vec<Thing> = sorting.clone();
+        reversed.reverse();
+
+        // Act
+        let paper: Vec<Thing> = draw_questions(&[(3, sorting.clone()), (2, graphs.clone())], 42);
+        let again: Vec<Thing> = draw_questions(&[(3, reversed), (2, graphs.clone())], 42);
+        let other: Vec<Thing> = draw_questions(&[(3, sorting.clone()), (2, graphs.clone())], 7);
+
+        // Assert
+        assert_eq!(paper.len(), 5);
+        assert_eq!(paper, again);
+        assert_ne!(paper, other);
+        assert_eq!(paper.iter().filter(|id| sorting.contains(id)).count(), 3);
+        assert_eq!(paper.iter().filter(|id| graphs.contains(id)).count(), 2);
+    }
+
+    #[test]
+    fn overlapping_pools_draw_each_question_once() {
+        // Assign
+        let shared: Vec<Thing> = ids("shared", 3);
+
+        // Act
+        let paper: Vec<Thing> = draw_questions(&[(2, shared.clone()), (5, shared.clone())], 1);
+
+        // Assert
+        // the second pool only has the one question left
+        assert_eq!(paper.len(), 3);
+        let mut sorted: Vec<Thing> = paper.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 3);
+    }
+
+    #[test]
+    fn pools_checked() {
+        // Assign
+        let tags: Vec<SurrealTag> = vec![tag("sorting"), tag("graphs")];
+        let pool = |id: &str, count: u32| PoolDraw {
+            tag: Thing::from(("tags", id)),
+            count,
+        };
+
+        // Act & Assert
+        assert!(validate_pools(&[], &tags).is_ok());
+        assert!(validate_pools(&[pool("sorting", 3), pool("graphs", 2)], &tags).is_ok());
+        assert!(validate_pools(&[pool("sorting", 0)], &tags).is_err());
+        assert!(validate_pools(&[pool("trees", 1)], &tags).is_err());
+        assert!(validate_pools(&[pool("sorting", 1), pool("sorting", 2)], &tags).is_err());
+    }
+}
@@ -5,6 +5,16 @@ use serde::{Deserialize, Serialize};
 use surrealdb::sql::Thing;
 use surrealize_macro::Surrealize;
 
+/// Every table that holds questions, one per question type.
+/// Quiz wide operations, like deleting a quiz, must visit each of these.
+pub const QUESTION_TABLES: [&str; 5] = [
+    "questions_mc",
+    "questions_sa",
+    "questions_la",
+    "questions_tf",
+    "questions_ms",
+];
+
 /// Currently for deleting questions generically
 /// All questions must have this Generic Data to be processed correctly
 /// in all endpoints.
@@ -16,10 +26,188 @@ pub struct GenericQuestionData {
 }
 
 /// To allow for the easy transporation of data
-/// If adding another type, be sure to update the `JsonPkg::validate_fields()` method.
+/// If adding another type, be sure to update the `JsonQuestion::validate_fields()` method.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum JsonQuestion {
     MultipleChoice(JsonQuestionMC),
+    ShortAnswer(JsonQuestionSA),
+    LongAnswer(JsonQuestionLA),
+    TrueFalse(JsonQuestionTF),
+    MultiSelect(JsonQuestionMS),
+}
+
+impl JsonQuestion {
+    /// Gives the question an author and quiz, ready to be saved in its table.
+    pub fn into_record(self, author_id: String, parent_quiz: Thing) -> QuestionRecord {
+        match self {
+            JsonQuestion::MultipleChoice(what) => QuestionRecord::MultipleChoice(QuestionMC {
+                question: what.question,
+                hint: what.hint,
+                author_id,
+                parent_quiz,
+                answer: what.answer,
+                choices: what.choices,
+            }),
+            JsonQuestion::ShortAnswer(what) => QuestionRecord::ShortAnswer(QuestionSA {
+                question: what.question,
+                hint: what.hint,
+                author_id,
+                parent_quiz,
+                answers: what.answers,
+            }),
+            JsonQuestion::LongAnswer(what) => QuestionRecord::LongAnswer(QuestionLA {
+                question: what.question,
+                hint: what.hint,
+                author_id,
+                parent_quiz,
+                model_answer: what.model_answer,
+                rubric: what.rubric,
+            }),
+            JsonQuestion::TrueFalse(what) => QuestionRecord::TrueFalse(QuestionTF {
+                question: what.question,
+                hint: what.hint,
+                author_id,
+                parent_quiz,
+                answer: what.answer,
+            }),
+            JsonQuestion::MultiSelect(what) => QuestionRecord::MultiSelect(QuestionMS {
+                question: what.question,
+                hint: what.hint,
+                author_id,
+                parent_quiz,
+                answers: what.answers,
+                choices: what.choices,
+            }),
+        }
+    }
+
+    /// The question text, whatever the type.
+    pub fn question(&self) -> &str {
+        match self {
+            JsonQuestion::MultipleChoice(what) => &what.question,
+            JsonQuestion::ShortAnswer(what) => &what.question,
+            JsonQuestion::LongAnswer(what) => &what.question,
+            JsonQuestion::TrueFalse(what) => &what.question,
+            JsonQuestion::MultiSelect(what) => &what.question,
+        }
+    }
+
+    /// The table questions of this type are stored in.
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            JsonQuestion::MultipleChoice(_) => "questions_mc",
+            JsonQuestion::ShortAnswer(_) => "questions_sa",
+            JsonQuestion::LongAnswer(_) => "questions_la",
+            JsonQuestion::TrueFalse(_) => "questions_tf",
+            JsonQuestion::MultiSelect(_) => "questions_ms",
+        }
+    }
+
+    /// Shared validation for creating and editing questions, so each
+    /// question type only needs its rules written once.
+    pub fn validate_fields(&self) -> Result<(), ModelErrors> {
+        use JsonQuestion::*;
+        match self {
+            MultipleChoice(qmc) => {
+                if qmc.question.trim().len() < 1 {
+                    return Err(ModelErrors::JsonValidation(format!(
+                        "Question cannot be empty",
+                    )));
+                } else if qmc.answer.trim().len() < 1 {
+                    return Err(ModelErrors::JsonValidation(format!(
+                        "Question needs valid answer",
+                    )));
+                } else if qmc.choices.len() < 1 {
+                    return Err(ModelErrors::JsonValidation(format!(
+                        "Question needs at least one additional choice",
+                    )));
+                }
+                // Could loop through choices to ensure they are also not blank
+            }
+            ShortAnswer(qsa) => {
+                if qsa.question.trim().is_empty() {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question cannot be empty",
+                    )));
+                } else if qsa.answers.iter().all(|ans| ans.trim().is_empty()) {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question needs at least one accepted answer",
+                    )));
+                }
+            }
+            LongAnswer(qla) => {
+                if qla.question.trim().is_empty() {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question cannot be empty",
+                    )));
+                } else if qla.model_answer.trim().is_empty() {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question needs a model answer",
+                    )));
+                } else if qla.rubric.is_empty() {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question needs at least one rubric criterion",
+                    )));
+                } else if qla
+                    .rubric
+                    .iter()
+                    .any(|rc| rc.criterion.trim().is_empty() || rc.weight == 0)
+                {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Rubric criteria need a description and a weight above zero",
+                    )));
+                }
+            }
+            TrueFalse(qtf) => {
+                // The answer is a bool, so only the statement can be invalid
+                if qtf.question.trim().is_empty() {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question cannot be empty",
+                    )));
+                }
+            }
+            MultiSelect(qms) => {
+                if qms.question.trim().is_empty() {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question cannot be empty",
+                    )));
+                } else if qms.answers.is_empty() {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question needs at least one correct choice",
+                    )));
+                } else if qms.answers.len() + qms.choices.len() < 2 {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Question needs at least two choices in total",
+                    )));
+                } else if qms
+                    .answers
+                    .iter()
+                    .chain(qms.choices.iter())
+                    .any(|choice| choice.trim().is_empty())
+                {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "Choices cannot be empty",
+                    )));
+                } else if qms.answers.iter().any(|ans| qms.choices.contains(ans)) {
+                    return Err(ModelErrors::JsonValidation(String::from(
+                        "A choice cannot be both correct and incorrect",
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A question with its author and quiz, serialized as the record for its own table.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum QuestionRecord {
+    MultipleChoice(QuestionMC),
+    ShortAnswer(QuestionSA),
+    LongAnswer(QuestionLA),
+    TrueFalse(QuestionTF),
+    MultiSelect(QuestionMS),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -30,9 +218,58 @@ pub struct JsonQuestionMC {
     pub choices: Vec<String>,
 }
 
+/// Short Answer question in JSON format.
+/// Any of the `answers` is accepted when taking the quiz.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JsonQuestionSA {
+    pub question: String,
+    pub hint: Option<String>,
+    pub answers: Vec<String>,
+}
+
+/// Long Answer (essay) question in JSON format.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JsonQuestionLA {
+    pub question: String,
+    pub hint: Option<String>,
+    pub model_answer: String,
+    pub rubric: Vec<RubricCriterion>,
+}
+
+/// True or False question in JSON format.
+/// The `question` is a statement and `answer` is whether it is true.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JsonQuestionTF {
+    pub question: String,
+    pub hint: Option<String>,
+    pub answer: bool,
+}
+
+/// Multi-Select ("select all that apply") question in JSON format.
+/// Every entry of `answers` is correct, every entry of `choices` is a distractor.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JsonQuestionMS {
+    pub question: String,
+    pub hint: Option<String>,
+    pub answers: Vec<String>,
+    pub choices: Vec<String>,
+}
+
+/// A single line of a marking rubric.
+/// Weights are relative to the other criteria of the same question.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RubricCriterion {
+    pub criterion: String,
+    pub weight: u32,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum QuestionType {
     MultipleChoice(QuestionMC),
+    ShortAnswer(QuestionSA),
+    LongAnswer(QuestionLA),
+    TrueFalse(QuestionTF),
+    MultiSelect(QuestionMS),
 }
 
 /// Multiple Choice question type
@@ -46,6 +283,123 @@ pub struct QuestionMC {
     pub choices: Vec<String>,
 }
 
+/// Short Answer question type, stored in the `questions_sa` table.
+#[derive(Serialize, Deserialize, Debug, Surrealize, Clone)]
+pub struct QuestionSA {
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub answers: Vec<String>,
+}
+
+impl QuestionSA {
+    /// Checks a response against the accepted answers.
+    pub fn is_correct(&self, response: &str) -> bool {
+        short_answer_matches(&self.answers, response)
+    }
+}
+
+impl SurrealQuestionSA {
+    /// Checks a response against the accepted answers.
+    pub fn is_correct(&self, response: &str) -> bool {
+        short_answer_matches(&self.answers, response)
+    }
+}
+
+/// Lower cases and collapses all whitespace so "  Merge   sort" and "merge sort"
+/// are treated as the same answer.
+pub fn normalize_answer(answer: &str) -> String {
+    answer
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// True if the response matches any accepted answer, ignoring case and whitespace.
+pub fn short_answer_matches(accepted: &[String], response: &str) -> bool {
+    let response: String = normalize_answer(response);
+    if response.is_empty() {
+        return false;
+    }
+    accepted.iter().any(|ans| normalize_answer(ans) == response)
+}
+
+/// Long Answer question type, stored in the `questions_la` table.
+/// These are self-graded against the rubric after the quiz is submitted.
+#[derive(Serialize, Deserialize, Debug, Surrealize, Clone)]
+pub struct QuestionLA {
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub model_answer: String,
+    pub rubric: Vec<RubricCriterion>,
+}
+
+/// Fraction of a long answer question earned, from 0.0 to 1.0, given the
+/// indices of the rubric criteria the test-taker marked as met.
+/// Unknown or repeated indices are ignored.
+pub fn rubric_score(rubric: &[RubricCriterion], criteria_met: &[usize]) -> f64 {
+    let total: u32 = rubric.iter().map(|rc| rc.weight).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let earned: u32 = rubric
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| criteria_met.contains(idx))
+        .map(|(_, rc)| rc.weight)
+        .sum();
+    f64::from(earned) / f64::from(total)
+}
+
+/// True or False question type, stored in the `questions_tf` table.
+#[derive(Serialize, Deserialize, Debug, Surrealize, Clone)]
+pub struct QuestionTF {
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub answer: bool,
+}
+
+/// Multi-Select question type, stored in the `questions_ms` table.
+#[derive(Serialize, Deserialize, Debug, Surrealize, Clone)]
+pub struct QuestionMS {
+    pub question: String,
+    pub hint: Option<String>,
+    pub author_id: String,
+    pub parent_quiz: Thing,
+    pub answers: Vec<String>,
+    pub choices: Vec<String>,
+}
+
+/// Partial credit for a multi-select question, from 0.0 to 1.0.
+/// Each correct choice selected earns an equal share, each distractor selected
+/// takes one share away, and the result never drops below zero.
+/// This stops "select everything" from earning full marks.
+pub fn multi_select_score(answers: &[String], selected: &[String]) -> f64 {
+    if answers.is_empty() {
+        return 0.0;
+    }
+    let mut hits: i32 = 0;
+    let mut misses: i32 = 0;
+    for (idx, choice) in selected.iter().enumerate() {
+        // Ignore repeated selections
+        if selected[..idx].contains(choice) {
+            continue;
+        }
+        if answers.contains(choice) {
+            hits += 1;
+        } else {
+            misses += 1;
+        }
+    }
+    (f64::from(hits - misses) / answers.len() as f64).max(0.0)
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QuestionJsonPkg {
     pub quiz_id: Thing,
@@ -55,26 +409,7 @@ pub struct QuestionJsonPkg {
 impl QuestionJsonPkg {
     pub fn validate_fields(&self) -> Result<(), ModelErrors> {
         // The type system ensures quiz_id isn't empty
-        use JsonQuestion::*;
-        match &self.question {
-            MultipleChoice(qmc) => {
-                if qmc.question.trim().len() < 1 {
-                    return Err(ModelErrors::JsonValidation(format!(
-                        "Question cannot be empty",
-                    )));
-                } else if qmc.answer.trim().len() < 1 {
-                    return Err(ModelErrors::JsonValidation(format!(
-                        "Question needs valid answer",
-                    )));
-                } else if qmc.choices.len() < 1 {
-                    return Err(ModelErrors::JsonValidation(format!(
-                        "Question needs at least one additional choice",
-                    )));
-                }
-                // Could loop through choices to ensure they are also not blank
-            }
-        }
-        Ok(())
+        self.question.validate_fields()
     }
 }
 
@@ -85,27 +420,7 @@ pub struct EditQuestionJsonPkg {
 
 impl EditQuestionJsonPkg {
     pub fn validate_fields(&self) -> Result<(), ModelErrors> {
-        // The type system ensures quiz_id isn't empty
-        use JsonQuestion::*;
-        match &self.question {
-            MultipleChoice(qmc) => {
-                if qmc.question.trim().len() < 1 {
-                    return Err(ModelErrors::JsonValidation(format!(
-                        "Question cannot be empty",
-                    )));
-                } else if qmc.answer.trim().len() < 1 {
-                    return Err(ModelErrors::JsonValidation(format!(
-                        "Question needs valid answer",
-                    )));
-                } else if qmc.choices.len() < 1 {
-                    return Err(ModelErrors::JsonValidation(format!(
-                        "Question needs at least one additional choice",
-                    )));
-                }
-                // Could loop through choices to ensure they are also not blank
-            }
-        }
-        Ok(())
+        self.question.validate_fields()
     }
 }
 
@@ -115,5 +430,112 @@ impl EditQuestionJsonPkg {
 pub struct AllQuestions {
     // mc = Multiple Choice
     pub mc: Vec<SurrealQuestionMC>,
-    // To Come: sa = Short Answer; la = Long Answer
+    // sa = Short Answer
+    #[serde(default)]
+    pub sa: Vec<SurrealQuestionSA>,
+    // la = Long Answer
+    #[serde(default)]
+    pub la: Vec<SurrealQuestionLA>,
+    // tf = True or False
+    #[serde(default)]
+    pub tf: Vec<SurrealQuestionTF>,
+    // ms = Multi-Select
+    #[serde(default)]
+    pub ms: Vec<SurrealQuestionMS>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_answer_ignores_case_and_whitespace() {
+        // Assign
+        let accepted: Vec<String> = vec![String::from("Merge Sort"), String::from("mergesort")];
+
+        // Act & Assert
+        assert!(short_answer_matches(&accepted, "merge sort"));
+        assert!(short_answer_matches(&accepted, "  MERGE    sort "));
+        assert!(short_answer_matches(&accepted, "MergeSort"));
+        assert!(!short_answer_matches(&accepted, "quick sort"));
+        assert!(!short_answer_matches(&accepted, "   "));
+    }
+
+    #[test]
+    fn rubric_score_is_weighted() {
+        // Assign
+        let rubric: Vec<RubricCriterion> = vec![
+            RubricCriterion {
+                criterion: String::from("Defines the term"),
+                weight: 1,
+            },
+            RubricCriterion {
+                criterion: String::from("Gives an example"),
+                weight: 3,
+            },
+        ];
+
+        // Act & Assert
+        assert_eq!(rubric_score(&rubric, &[]), 0.0);
+        assert_eq!(rubric_score(&rubric, &[1]), 0.75);
+        assert_eq!(rubric_score(&rubric, &[0, 1, 1, 7]), 1.0);
+    }
+
+    #[test]
+    fn multi_select_gives_partial_credit() {
+        // Assign
+        let answers: Vec<String> = vec![String::from("Rust"), String::from("C")];
+
+        // Act & Assert
+        assert_eq!(multi_select_score(&answers, &[String::from("Rust")]), 0.5);
+        assert_eq!(
+            multi_select_score(&answers, &[String::from("C"), String::from("Rust")]),
+            1.0
+        );
+        assert_eq!(
+            multi_select_score(
+                &answers,
+                &[
+                    String::from("C"),
+                    String::from("Rust"),
+                    String::from("Python")
+                ]
+            ),
+            0.5
+        );
+        assert_eq!(multi_select_score(&answers, &[String::from("Python")]), 0.0);
+    }
+
+    #[test]
+    fn multi_select_rejects_overlapping_choices() {
+        // Assign
+        let question = JsonQuestion::MultiSelect(JsonQuestionMS {
+            question: String::from("Which are compiled languages?"),
+            hint: None,
+            answers: vec![String::from("Rust")],
+            choices: vec![String::from("Rust"), String::from("Python")],
+        });
+
+        // Act
+        let actual = question.validate_fields();
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn short_answer_requires_accepted_answer() {
+        // Assign
+        let question = JsonQuestion::ShortAnswer(JsonQuestionSA {
+            question: String::from("Name a stable sorting algorithm"),
+            hint: None,
+            answers: vec![String::from(" "), String::new()],
+        });
+
+        // Act
+        let actual = question.validate_fields();
+
+        // Assert
+        assert!(actual.is_err());
+    }
 }
@@ -1,39 +1,248 @@
 //! models/src/quiz.rs
-use crate::model_errors::ModelErrors;
+use crate::{likes::Liked, model_errors::ModelErrors, pools::PoolDraw};
+use chrono::Duration;
 use serde::{Deserialize, Serialize};
 use surrealize_macro::Surrealize;
 
+/// Who can find and take a quiz, besides its author.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    // only the author
+    #[default]
+    Private,
+    // anyone with a link
+    Unlisted,
+    // anyone, and listed when browsing
+    Public,
+}
+
 #[derive(Serialize, Deserialize, Debug, Surrealize)]
 pub struct Quiz {
     pub name: String,
     pub description: String,
     pub author_id: String,
+    // quizzes made before sharing existed are private
+    #[serde(default)]
+    pub visibility: Visibility,
+    // untimed when missing or 0
+    #[serde(default)]
+    pub time_limit_secs: Option<u32>,
+    // each question is served on its own and must be answered within this long
+    #[serde(default)]
+    pub time_per_question_secs: Option<u32>,
+    // every question of the quiz is handed out when there are no pools
+    #[serde(default)]
+    pub pools: Vec<PoolDraw>,
+    // fraction of a question's mark lost by revealing its hint, from 0.0 to 1.0
+    #[serde(default)]
+    pub hint_penalty: f64,
 }
 
 impl Quiz {
-    pub fn new(name: String, description: String, author_id: String) -> Self {
+    pub fn new(
+        name: String,
+        description: String,
+        author_id: String,
+        visibility: Visibility,
+        time_limit_secs: Option<u32>,
+        time_per_question_secs: Option<u32>,
+    ) -> Self {
         Self {
             name,
             description,
             author_id,
+            visibility,
+            time_limit_secs,
+            time_per_question_secs,
+            pools: Vec::new(),
+            hint_penalty: 0.0,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Longest time limit allowed, for a whole quiz or a single question.
+pub const MAX_TIME_LIMIT_SECS: u32 = 24 * 60 * 60;
+
+impl SurrealQuiz {
+    /// Whether a user may see and take this quiz.
+    pub fn viewable_by(&self, user_id: &str) -> bool {
+        self.author_id == user_id || self.visibility != Visibility::Private
+    }
+
+    /// How long a whole attempt may take, `None` when untimed.
+    pub fn time_allowed(&self) -> Option<Duration> {
+        self.time_limit_secs
+            .filter(|secs| *secs > 0)
+            .map(|secs| Duration::seconds(i64::from(secs)))
+    }
+
+    /// How long each question may take once it is served, `None` when questions are not timed one by one.
+    pub fn time_per_question(&self) -> Option<u32> {
+        self.time_per_question_secs.filter(|secs| *secs > 0)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QuizJsonPkg {
     pub name: String,
     pub description: String,
+    // left out when editing keeps the current visibility
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    // time limits are kept the same way, 0 removes a limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_limit_secs: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_per_question_secs: Option<u32>,
+    // pools are kept too, an empty list removes them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pools: Option<Vec<PoolDraw>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint_penalty: Option<f64>,
 }
 
 impl QuizJsonPkg {
     pub fn validate_field(&self) -> Result<(), ModelErrors> {
+        let limits = [self.time_limit_secs, self.time_per_question_secs];
         if self.name.trim().len() < 1 {
             Err(ModelErrors::JsonValidation(String::from(
                 "Quiz name cannot be blank or white space",
             )))
+        } else if limits
+            .into_iter()
+            .flatten()
+            .any(|secs| secs > MAX_TIME_LIMIT_SECS)
+        {
+            Err(ModelErrors::JsonValidation(format!(
+                "Time limits cannot be longer than {} hours",
+                MAX_TIME_LIMIT_SECS / 3600
+            )))
+        } else if self
+            .hint_penalty
+            .is_some_and(|penalty| !(0.0..=1.0).contains(&penalty))
+        {
+            Err(ModelErrors::JsonValidation(String::from(
+                "Hint penalty must be between 0 and 1",
+            )))
         } else {
             Ok(())
         }
     }
+
+    /// A new quiz by `author_id`. Pools are left out, they need checking against the author's tags.
+    pub fn into_record(self, author_id: String) -> Quiz {
+        let mut quiz: Quiz = Quiz::new(
+            self.name,
+            self.description,
+            author_id,
+            self.visibility.unwrap_or_default(),
+            self.time_limit_secs,
+            self.time_per_question_secs,
+        );
+        quiz.hint_penalty = self.hint_penalty.unwrap_or_default();
+        quiz
+    }
+}
+
+/// Largest page allowed when browsing public quizzes.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// One page of public quizzes, `total` counts every match, not just this page.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PublicQuizPage {
+    pub quizzes: Vec<Liked<SurrealQuiz>>,
+    pub page: u32,
+    pub per_page: u32,
+    pub total: u64,
+}
+
+/// What deleting a quiz removed, the quiz itself along with how many records pointed at it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuizDeletion {
+    pub quiz: SurrealQuiz,
+    // across every question table
+    pub questions: usize,
+    pub attempts: usize,
+    pub review_cards: usize,
+    // likes of the quiz and of its questions
+    pub likes: usize,
+    // tags stay, only their links to the quiz and its questions go
+    pub tag_links: usize,
+    pub assignments: usize,
+    // of the quiz and of its questions
+    #[serde(default)]
+    pub revisions: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealdb::sql::Thing;
+
+    #[test]
+    fn private_quizzes_only_viewable_by_author() {
+        // Assign
+        let mut quiz: SurrealQuiz = SurrealQuiz {
+            id: Thing::from(("quizzes", "quiz")),
+            name: String::from("Algorithms"),
+            description: String::from("An algorithms quiz"),
+            author_id: String::from("author"),
+            visibility: Visibility::Private,
+            time_limit_secs: None,
+            time_per_question_secs: None,
+            pools: Vec::new(),
+            hint_penalty: 0.0,
+        };
+
+        // Act
+        let private: (bool, bool) = (quiz.viewable_by("author"), quiz.viewable_by("someone"));
+        quiz.visibility = Visibility::Unlisted;
+        let unlisted: bool = quiz.viewable_by("someone");
+
+        // Assert
+        assert_eq!(private, (true, false));
+        assert!(unlisted);
+    }
+
+    #[test]
+    fn old_quizzes_default_to_private() {
+        // Assign
+        let json: &str =
+            r#"{"name": "Old", "description": "Made before sharing", "author_id": "author"}"#;
+
+        // Act
+        let quiz: Quiz = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(quiz.visibility, Visibility::Private);
+        assert_eq!(quiz.time_limit_secs, None);
+    }
+
+    #[test]
+    fn zero_time_limits_are_untimed() {
+        // Assign
+        let mut quiz: SurrealQuiz = SurrealQuiz {
+            id: Thing::from(("quizzes", "quiz")),
+            name: String::from("Algorithms"),
+            description: String::from("An algorithms quiz"),
+            author_id: String::from("author"),
+            visibility: Visibility::Public,
+            time_limit_secs: Some(600),
+            time_per_question_secs: Some(30),
+            pools: Vec::new(),
+            hint_penalty: 0.0,
+        };
+
+        // Act
+        let total: Option<Duration> = quiz.time_allowed();
+        let per_question: Option<u32> = quiz.time_per_question();
+        quiz.time_limit_secs = Some(0);
+        quiz.time_per_question_secs = Some(0);
+
+        // Assert
+        assert_eq!(total, Some(Duration::seconds(600)));
+        assert_eq!(per_question, Some(30));
+        assert_eq!(quiz.time_allowed(), None);
+        assert_eq!(quiz.time_per_question(), None);
+    }
 }
@@ -0,0 +1,362 @@
+//! models/src/revisions.rs
+//! Quizzes and questions keep every version they are edited into, so what students saw is never lost.
+//! Revisions are numbered from 1 for each quiz or question, and never change once saved.
+use crate::{
+    attempts::{AnswerKey, ExamQuestion},
+    questions::{
+        AllQuestions, JsonQuestion, JsonQuestionLA, JsonQuestionMC, JsonQuestionMS, JsonQuestionSA,
+        JsonQuestionTF,
+    },
+    quiz::{QuizJsonPkg, SurrealQuiz},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use surrealdb::sql::{Datetime, Thing};
+use surrealize_macro::Surrealize;
+
+/// What a quiz or question held at one revision, in the shape it is edited in.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum Snapshot {
+    Quiz(QuizJsonPkg),
+    Question(JsonQuestion),
+}
+
+impl Snapshot {
+    /// Every field an author can edit on a quiz, none left out, so rolling back restores all of them.
+    pub fn of_quiz(quiz: &SurrealQuiz) -> Self {
+        Snapshot::Quiz(QuizJsonPkg {
+            name: quiz.name.clone(),
+            description: quiz.description.clone(),
+            visibility: Some(quiz.visibility),
+            // 0 removes a limit when rolled back
+            time_limit_secs: Some(quiz.time_limit_secs.unwrap_or_default()),
+            time_per_question_secs: Some(quiz.time_per_question_secs.unwrap_or_default()),
+            pools: Some(quiz.pools.clone()),
+            hint_penalty: Some(quiz.hint_penalty),
+        })
+    }
+
+    /// The quiz as it will be once `changes` are merged in, fields left out of `changes` keep their value.
+    pub fn of_edited_quiz(quiz: &SurrealQuiz, changes: &QuizJsonPkg) -> Self {
+        Snapshot::Quiz(QuizJsonPkg {
+            name: changes.name.clone(),
+            description: changes.description.clone(),
+            visibility: Some(changes.visibility.unwrap_or(quiz.visibility)),
+            time_limit_secs: Some(
+                changes
+                    .time_limit_secs
+                    .or(quiz.time_limit_secs)
+                    .unwrap_or_default(),
+            ),
+            time_per_question_secs: Some(
+                changes
+                    .time_per_question_secs
+                    .or(quiz.time_per_question_secs)
+                    .unwrap_or_default(),
+            ),
+            pools: Some(changes.pools.clone().unwrap_or_else(|| quiz.pools.clone())),
+            hint_penalty: Some(changes.hint_penalty.unwrap_or(quiz.hint_penalty)),
+        })
+    }
+
+    /// Top level fields by name.
+    fn fields(&self) -> Map<String, Value> {
+        let value: serde_json::Result<Value> = match self {
+            Snapshot::Quiz(quiz) => serde_json::to_value(quiz),
+            // the fields sit under the name of the question type
+            Snapshot::Question(question) => serde_json::to_value(question).map(|tagged| {
+                tagged
+                    .as_object()
+                    .and_then(|types| types.values().next().cloned())
+                    .unwrap_or_default()
+            }),
+        };
+        match value {
+            Ok(Value::Object(fields)) => fields,
+            _ => Map::new(),
+        }
+    }
+}
+
+/// One saved version of a quiz or question.
+#[derive(Serialize, Deserialize, Debug, Surrealize)]
+pub struct Revision {
+    // the quiz or question this is a version of
+    pub target: Thing,
+    pub number: u32,
+    // who made the edit, or wrote the original for a first revision
+    pub author_id: String,
+    pub created_at: Datetime,
+    pub snapshot: Snapshot,
+}
+
+/// A quiz or question to pin an attempt to, with what it holds now in case it has no revisions yet.
+#[derive(Debug, Clone)]
+pub struct Pin {
+    pub target: Thing,
+    pub author_id: String,
+    pub current: Snapshot,
+}
+
+/// An edit to save as the next revision of a quiz or question, along with the change itself.
+#[derive(Debug, Clone)]
+pub struct Edit {
+    // who made the edit
+    pub author_id: String,
+    pub snapshot: Snapshot,
+}
+
+/// The questions among `revisions` as they were then, split for grading like live questions.
+/// Quiz revisions have nothing to grade and are skipped.
+pub fn pinned_exam(revisions: Vec<SurrealRevision>) -> Vec<(ExamQuestion, AnswerKey)> {
+    revisions
+        .into_iter()
+        .filter_map(|revision| match revision.snapshot {
+            Snapshot::Question(question) => Some(question.into_exam(revision.target)),
+            Snapshot::Quiz(_) => None,
+        })
+        .collect()
+}
+
+/// A field that differs between two revisions, `None` where a side does not have it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// What changed going from one revision of a quiz or question to another.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevisionDiff {
+    pub target: Thing,
+    pub from: u32,
+    pub to: u32,
+    // sorted by field name
+    pub changes: Vec<FieldChange>,
+}
+
+impl RevisionDiff {
+    pub fn new(from: &SurrealRevision, to: &SurrealRevision) -> Self {
+        Self {
+            target: to.target.clone(),
+            from: from.number,
+            to: to.number,
+            changes: diff_snapshots(&from.snapshot, &to.snapshot),
+        }
+    }
+}
+
+/// Compares the top level fields of two snapshots, lists and nested values are compared whole.
+pub fn diff_snapshots(before: &Snapshot, after: &Snapshot) -> Vec<FieldChange> {
+    let before: Map<String, Value> = before.fields();
+    let after: Map<String, Value> = after.fields();
+    let names: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    names
+        .into_iter()
+        .filter(|name| before.get(*name) != after.get(*name))
+        .map(|name| FieldChange {
+            field: name.clone(),
+            before: before.get(name).cloned(),
+            after: after.get(name).cloned(),
+        })
+        .collect()
+}
+
+/// A stored question in the shape it is written in, with where it lives and who wrote it.
+#[derive(Debug, Clone)]
+pub struct WrittenQuestion {
+    pub id: Thing,
+    pub author_id: String,
+    pub question: JsonQuestion,
+}
+
+impl AllQuestions {
+    /// Every question as written, in the same order the lists are in.
+    pub fn written(&self) -> Vec<WrittenQuestion> {
+        let mut written: Vec<WrittenQuestion> = Vec::new();
+        written.extend(self.mc.iter().map(|qmc| WrittenQuestion {
+            id: qmc.id.clone(),
+            author_id: qmc.author_id.clone(),
+            question: JsonQuestion::MultipleChoice(JsonQuestionMC {
+                question: qmc.question.clone(),
+                hint: qmc.hint.clone(),
+                answer: qmc.answer.clone(),
+                choices: qmc.choices.clone(),
+            }),
+        }));
+        written.extend(self.sa.iter().map(|qsa| WrittenQuestion {
+            id: qsa.id.clone(),
+            author_id: qsa.author_id.clone(),
+            question: JsonQuestion::ShortAnswer(JsonQuestionSA {
+                question: qsa.question.clone(),
+                hint: qsa.hint.clone(),
+                answers: qsa.answers.clone(),
+            }),
+        }));
+        written.extend(self.la.iter().map(|qla| WrittenQuestion {
+            id: qla.id.clone(),
+            author_id: qla.author_id.clone(),
+            question: JsonQuestion::LongAnswer(JsonQuestionLA {
+                question: qla.question.clone(),
+                hint: qla.hint.clone(),
+                model_answer: qla.model_answer.clone(),
+                rubric: qla.rubric.clone(),
+            }),
+        }));
+        written.extend(self.tf.iter().map(|qtf| WrittenQuestion {
+            id: qtf.id.clone(),
+            author_id: qtf.author_id.clone(),
+            question: JsonQuestion::TrueFalse(JsonQuestionTF {
+                question: qtf.question.clone(),
+                hint: qtf.hint.clone(),
+                answer: qtf.answer,
+            }),
+        }));
+        written.extend(self.ms.iter().map(|qms| WrittenQuestion {
+            id: qms.id.clone(),
+            author_id: qms.author_id.clone(),
+            question: JsonQuestion::MultiSelect(JsonQuestionMS {
+                question: qms.question.clone(),
+                hint: qms.hint.clone(),
+                answers: qms.answers.clone(),
+                choices: qms.choices.clone(),
+            }),
+        }));
+        written
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quiz::Visibility;
+
+    fn quiz(name: &str, time_limit_secs: Option<u32>) -> SurrealQuiz {
+        SurrealQuiz {
+            id: Thing::from(("quizzes", "sorting")),
+            name: String::from(name),
+            description: String::from("Comparison sorts"),
+            author_id: String::from("author"),
+            visibility: Visibility::Private,
+            time_limit_secs,
+            time_per_question_secs: None,
+            pools: Vec::new(),
+            hint_penalty: 0.0,
+        }
+    }
+
+    #[test]
+    fn diff_lists_changed_fields_only() {
+        // Assign
+        let before: Snapshot = Snapshot::of_quiz(&quiz("Sorting", None));
+        let after: Snapshot = Snapshot::of_quiz(&quiz("Sorting, again", Some(600)));
+
+        // Act
+        let changes: Vec<FieldChange> = diff_snapshots(&before, &after);
+        let unchanged: Vec<FieldChange> = diff_snapshots(&after, &after);
+
+        // Assert
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    field: String::from("name"),
+                    before: Some(Value::from("Sorting")),
+                    after: Some(Value::from("Sorting, again")),
+                },
+                FieldChange {
+                    field: String::from("time_limit_secs"),
+                    before: Some(Value::from(0)),
+                    after: Some(Value::from(600)),
+                },
+            ]
+        );
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn edited_quiz_keeps_fields_left_out() {
+        // Assign
+        let before: SurrealQuiz = quiz("Sorting", Some(600));
+        let changes: QuizJsonPkg = QuizJsonPkg {
+            name: String::from("Sorting, again"),
+            description: String::from("Comparison sorts"),
+            visibility: None,
+            time_limit_secs: None,
+            time_per_question_secs: Some(30),
+            pools: None,
+            hint_penalty: None,
+        };
+        let mut after: SurrealQuiz = quiz("Sorting, again", Some(600));
+        after.time_per_question_secs = Some(30);
+
+        // Act
+        let edited: Snapshot = Snapshot::of_edited_quiz(&before, &changes);
+
+        // Assert
+        assert!(diff_snapshots(&edited, &Snapshot::of_quiz(&after)).is_empty());
+        assert_eq!(
+            diff_snapshots(&Snapshot::of_quiz(&before), &edited).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn pinned_exam_keys_questions_by_target() {
+        // Assign
+        let revision = |target: Thing, snapshot: Snapshot| SurrealRevision {
+            id: Thing::from(("revisions", target.id.to_raw().as_str())),
+            target,
+            number: 1,
+            author_id: String::from("author"),
+            created_at: Datetime::default(),
+            snapshot,
+        };
+        let question_id: Thing = Thing::from(("questions_tf", "stable"));
+        let revisions: Vec<SurrealRevision> = vec![
+            revision(
+                Thing::from(("quizzes", "sorting")),
+                Snapshot::of_quiz(&quiz("Sorting", None)),
+            ),
+            revision(
+                question_id.clone(),
+                Snapshot::Question(JsonQuestion::TrueFalse(JsonQuestionTF {
+                    question: String::from("Merge sort is stable"),
+                    hint: None,
+                    answer: true,
+                })),
+            ),
+        ];
+
+        // Act
+        let exam: Vec<(ExamQuestion, AnswerKey)> = pinned_exam(revisions);
+
+        // Assert
+        assert_eq!(exam.len(), 1);
+        assert_eq!(exam[0].0.id, question_id);
+        assert_eq!(exam[0].1, AnswerKey::TrueFalse(true));
+    }
+
+    #[test]
+    fn question_diff_skips_type_wrapper() {
+        // Assign
+        let question = |hint: Option<&str>| {
+            Snapshot::Question(JsonQuestion::TrueFalse(JsonQuestionTF {
+                question: String::from("Merge sort is stable"),
+                hint: hint.map(String::from),
+                answer: true,
+            }))
+        };
+
+        // Act
+        let changes: Vec<FieldChange> =
+            diff_snapshots(&question(None), &question(Some("Think of ties")));
+
+        // Assert
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "hint");
+        assert_eq!(changes[0].before, Some(Value::Null));
+    }
+}
@@ -0,0 +1,108 @@
+//! models/src/roles.rs
+//! Roles decide what a user may do, checked through the permissions each role grants.
+use serde::{Deserialize, Serialize};
+
+/// A user's role, stored on `general_user`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
+pub enum Role {
+    // takes the quizzes they are given
+    Student,
+    // writes quizzes, the role every account had before roles existed, so kept as the default for them
+    #[default]
+    Instructor,
+    // moderates everything
+    Admin,
+}
+
+/// Something a role can be allowed to do.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    TakeQuizzes,
+    AuthorQuizzes,
+    ModerateContent,
+    ManageUsers,
+}
+
+impl Role {
+    /// The role every sign up gets. Instructors and admins are promoted by an admin,
+    /// through `quizctl users set-role` or `/users/role`.
+    pub const SIGN_UP: Role = Role::Student;
+
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Role::Student => &[Permission::TakeQuizzes],
+            Role::Instructor => &[Permission::TakeQuizzes, Permission::AuthorQuizzes],
+            Role::Admin => &[
+                Permission::TakeQuizzes,
+                Permission::AuthorQuizzes,
+                Permission::ModerateContent,
+                Permission::ManageUsers,
+            ],
+        }
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.permissions().contains(&permission)
+    }
+
+    /// Whether a user with this role may change or delete what `author_id` made.
+    /// Authors can manage their own content while they can still author, moderators anyone's.
+    pub fn may_manage(&self, user_id: &str, author_id: &str) -> bool {
+        (user_id == author_id && self.can(Permission::AuthorQuizzes))
+            || self.can(Permission::ModerateContent)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "student" => Some(Role::Student),
+            "instructor" => Some(Role::Instructor),
+            "admin" => Some(Role::Admin),
+            _ => None,
+        }
+    }
+}
+
+/// For an admin to change someone's role, found by username.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoleJsonPkg {
+    pub username: String,
+    pub role: Role,
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name: &str = match self {
+            Role::Student => "student",
+            Role::Instructor => "instructor",
+            Role::Admin => "admin",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn students_take_but_do_not_author() {
+        assert!(Role::Student.can(Permission::TakeQuizzes));
+        assert!(!Role::Student.can(Permission::AuthorQuizzes));
+        assert!(!Role::Student.may_manage("abc", "abc"));
+    }
+
+    #[test]
+    fn admins_manage_anything() {
+        assert!(Role::Admin.may_manage("abc", "xyz"));
+        assert!(!Role::Instructor.may_manage("abc", "xyz"));
+        assert!(Role::Instructor.may_manage("abc", "abc"));
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for role in [Role::Student, Role::Instructor, Role::Admin] {
+            assert_eq!(Role::from_name(&role.to_string()), Some(role));
+        }
+        assert_eq!(Role::from_name("Teacher"), None);
+    }
+}
@@ -0,0 +1,228 @@
+//! models/src/search.rs
+//! Full-text search over quizzes and questions, backed by SurrealDB search indexes.
+//! SurrealDB wraps matched words in highlight markers, those are split into snippet parts here
+//! so clients can style matches without rendering user content as HTML.
+use crate::model_errors::ModelErrors;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+/// Markers passed to `search::highlight()`.
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_END: &str = "</mark>";
+
+/// Longest search allowed, in characters.
+pub const MAX_QUERY_LEN: usize = 100;
+/// Most results returned by a single search.
+pub const MAX_SEARCH_RESULTS: usize = 50;
+/// Characters of text kept either side of the highlighted words.
+pub const SNIPPET_CONTEXT: usize = 40;
+
+/// Checks a search, returning it trimmed.
+pub fn validate_query(query: &str) -> Result<String, ModelErrors> {
+    let trimmed: &str = query.trim();
+    if trimmed.is_empty() {
+        Err(ModelErrors::JsonValidation(String::from(
+            "Search cannot be blank or white space",
+        )))
+    } else if trimmed.chars().count() > MAX_QUERY_LEN {
+        Err(ModelErrors::JsonValidation(format!(
+            "Search cannot be longer than {} characters",
+            MAX_QUERY_LEN
+        )))
+    } else {
+        Ok(trimmed.to_string())
+    }
+}
+
+/// What kind of record a search hit is.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchKind {
+    Quiz,
+    Question,
+}
+
+/// A piece of a snippet, `hit` is true for the words that matched.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SnippetPart {
+    pub text: String,
+    pub hit: bool,
+}
+
+impl SnippetPart {
+    fn new(text: &str, hit: bool) -> Self {
+        Self {
+            text: text.to_string(),
+            hit,
+        }
+    }
+}
+
+/// Splits highlighted text into parts.
+/// Text before the first match and after the last is cut down to `context` characters.
+pub fn snippet(highlighted: &str, context: usize) -> Vec<SnippetPart> {
+    let mut parts: Vec<SnippetPart> = Vec::new();
+    let mut pieces = highlighted.split(HIGHLIGHT_START);
+
+    if let Some(lead) = pieces.next() {
+        parts.push(SnippetPart::new(lead, false));
+    }
+    for piece in pieces {
+        // An unclosed marker highlights the rest of the text
+        let (hit, rest) = piece.split_once(HIGHLIGHT_END).unwrap_or((piece, ""));
+        parts.push(SnippetPart::new(hit, true));
+        parts.push(SnippetPart::new(rest, false));
+    }
+    parts.retain(|part| !part.text.is_empty());
+
+    // Nothing matched, so the start of the text is the best snippet
+    if parts.iter().all(|part| !part.hit) {
+        return parts
+            .first()
+            .map(|part| {
+                vec![SnippetPart::new(
+                    &keep_start(&part.text, context * 2),
+                    false,
+                )]
+            })
+            .unwrap_or_default();
+    }
+
+    if let Some(first) = parts.first_mut().filter(|part| !part.hit) {
+        first.text = keep_end(&first.text, context);
+    }
+    if let Some(last) = parts.last_mut().filter(|part| !part.hit) {
+        last.text = keep_start(&last.text, context);
+    }
+    parts
+}
+
+fn keep_start(text: &str, count: usize) -> String {
+    if text.chars().count() <= count {
+        text.to_string()
+    } else {
+        let kept: String = text.chars().take(count).collect();
+        format!("{}…", kept.trim_end())
+    }
+}
+
+fn keep_end(text: &str, count: usize) -> String {
+    let length: usize = text.chars().count();
+    if length <= count {
+        text.to_string()
+    } else {
+        let kept: String = text.chars().skip(length - count).collect();
+        format!("…{}", kept.trim_start())
+    }
+}
+
+/// A single quiz or question that matched a search.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SearchHit {
+    pub id: Thing,
+    pub kind: SearchKind,
+    // the quiz itself, or the quiz the question belongs to
+    pub quiz_id: Thing,
+    pub quiz_name: String,
+    // quiz name or question text
+    pub title: Vec<SnippetPart>,
+    // quiz description or question choices
+    pub snippet: Vec<SnippetPart>,
+    pub score: f64,
+}
+
+/// Every hit for a search, best match first.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchResults {
+    pub query: String,
+    pub hits: Vec<SearchHit>,
+}
+
+impl SearchResults {
+    /// Merges hits from every table, keeping the `limit` highest scores.
+    pub fn ranked(query: String, mut hits: Vec<SearchHit>, limit: usize) -> Self {
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(limit);
+        Self { query, hits }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(name: &str, score: f64) -> SearchHit {
+        SearchHit {
+            id: Thing::from(("quizzes", name)),
+            kind: SearchKind::Quiz,
+            quiz_id: Thing::from(("quizzes", name)),
+            quiz_name: name.to_string(),
+            title: vec![SnippetPart::new(name, false)],
+            snippet: Vec::new(),
+            score,
+        }
+    }
+
+    #[test]
+    fn snippet_marks_highlighted_words() {
+        // Assign
+        let highlighted: &str = "Sorting <mark>graphs</mark> and <mark>trees</mark>";
+
+        // Act
+        let parts: Vec<SnippetPart> = snippet(highlighted, SNIPPET_CONTEXT);
+
+        // Assert
+        assert_eq!(
+            parts,
+            vec![
+                SnippetPart::new("Sorting ", false),
+                SnippetPart::new("graphs", true),
+                SnippetPart::new(" and ", false),
+                SnippetPart::new("trees", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn snippet_trims_text_around_matches() {
+        // Assign
+        let highlighted: &str = "A very long introduction to <mark>graph</mark> theory and more";
+
+        // Act
+        let parts: Vec<SnippetPart> = snippet(highlighted, 8);
+
+        // Assert
+        assert_eq!(parts[0].text, "…tion to ");
+        assert_eq!(parts[1], SnippetPart::new("graph", true));
+        assert_eq!(parts[2].text, " theory…");
+    }
+
+    #[test]
+    fn snippet_without_match_keeps_start() {
+        // Act
+        let parts: Vec<SnippetPart> = snippet("Nothing matched here", 4);
+
+        // Assert
+        assert_eq!(parts, vec![SnippetPart::new("Nothing…", false)]);
+        assert!(snippet("", 4).is_empty());
+    }
+
+    #[test]
+    fn results_ranked_by_score() {
+        // Assign
+        let hits: Vec<SearchHit> = vec![hit("low", 0.5), hit("high", 2.0), hit("mid", 1.0)];
+
+        // Act
+        let results: SearchResults = SearchResults::ranked(String::from("query"), hits, 2);
+
+        // Assert
+        let names: Vec<&str> = results.hits.iter().map(|h| h.quiz_name.as_str()).collect();
+        assert_eq!(names, vec!["high", "mid"]);
+    }
+
+    #[test]
+    fn blank_or_long_queries_rejected() {
+        assert!(validate_query("   ").is_err());
+        assert!(validate_query(&"a".repeat(MAX_QUERY_LEN + 1)).is_err());
+        assert_eq!(validate_query(" graphs ").unwrap(), "graphs");
+    }
+}
@@ -0,0 +1,196 @@
+//! models/src/study.rs
+//! Spaced repetition study mode, scheduled with the SM-2 algorithm.
+//! Every user has a review card per question they have studied.
+use crate::attempts::{AnswerKey, AttemptAnswer};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Thing};
+use surrealize_macro::Surrealize;
+
+/// Ease factor new cards start with, and the lowest it can drop to.
+pub const STARTING_EASE: f64 = 2.5;
+pub const MINIMUM_EASE: f64 = 1.3;
+
+/// How well a question was recalled during a review.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReviewRating {
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl ReviewRating {
+    /// The SM-2 response quality, from 0 to 5.
+    pub fn quality(&self) -> f64 {
+        match self {
+            ReviewRating::Again => 1.0,
+            ReviewRating::Hard => 3.0,
+            ReviewRating::Good => 4.0,
+            ReviewRating::Easy => 5.0,
+        }
+    }
+
+    /// Caps the user's rating by how the answer was marked, a wrong answer cannot be "easy".
+    /// Long answers are not marked automatically, so their rating is trusted.
+    pub fn capped_by(self, key: &AnswerKey, earned: f64) -> Self {
+        match key {
+            AnswerKey::LongAnswer { .. } => self,
+            _ if earned <= 0.0 => ReviewRating::Again,
+            _ if earned < 1.0 => self.min(ReviewRating::Hard),
+            _ => self,
+        }
+    }
+}
+
+/// A user's schedule for reviewing a single question.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Surrealize)]
+pub struct ReviewCard {
+    pub user_id: String,
+    pub quiz_id: Thing,
+    pub question_id: Thing,
+    pub ease_factor: f64,
+    pub interval_days: u32,
+    // successful reviews in a row
+    pub repetitions: u32,
+    pub due: Datetime,
+    pub reviewed_at: Option<Datetime>,
+}
+
+impl ReviewCard {
+    /// A card that has never been reviewed, due straight away.
+    pub fn new(user_id: String, quiz_id: Thing, question_id: Thing) -> Self {
+        Self {
+            user_id,
+            quiz_id,
+            question_id,
+            ease_factor: STARTING_EASE,
+            interval_days: 0,
+            repetitions: 0,
+            due: Datetime::default(),
+            reviewed_at: None,
+        }
+    }
+
+    /// Whether the card should be reviewed at any point on the day of `now`.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        let end_of_day: DateTime<Utc> = now
+            .date_naive()
+            .and_hms_opt(23, 59, 59)
+            .expect("valid time")
+            .and_utc();
+        self.due.0 <= end_of_day
+    }
+
+    /// Reschedules the card after a review, following SM-2.
+    /// A failed review starts the card over, but its ease factor is kept (lowered).
+    pub fn review(&mut self, rating: ReviewRating, now: DateTime<Utc>) {
+        let quality: f64 = rating.quality();
+
+        if quality < 3.0 {
+            self.repetitions = 0;
+            self.interval_days = 1;
+        } else {
+            self.interval_days = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (f64::from(self.interval_days) * self.ease_factor).round() as u32,
+            };
+            self.repetitions += 1;
+        }
+
+        self.ease_factor = (self.ease_factor
+            + (0.1 - (5.0 - quality) * (0.08 + (5.0 - quality) * 0.02)))
+            .max(MINIMUM_EASE);
+        self.due = Datetime::from(now + Duration::days(i64::from(self.interval_days)));
+        self.reviewed_at = Some(Datetime::from(now));
+    }
+}
+
+/// The result of a single review, sent by the user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReviewJsonPkg {
+    pub question_id: Thing,
+    pub answer: AttemptAnswer,
+    pub rating: ReviewRating,
+}
+
+/// Returned once a review is recorded, with the answer so the user can check themselves.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReviewOutcome {
+    pub question_id: Thing,
+    pub earned: f64,
+    pub key: AnswerKey,
+    pub rating: ReviewRating,
+    pub interval_days: u32,
+    pub due: Datetime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_card() -> ReviewCard {
+        ReviewCard::new(
+            String::from("user"),
+            Thing::from(("quizzes", "quiz")),
+            Thing::from(("questions_tf", "tf")),
+        )
+    }
+
+    #[test]
+    fn good_reviews_grow_the_interval() {
+        // Assign
+        let mut card: ReviewCard = new_card();
+        let now: DateTime<Utc> = Utc::now();
+
+        // Act
+        card.review(ReviewRating::Good, now);
+        let first: u32 = card.interval_days;
+        card.review(ReviewRating::Good, now);
+        let second: u32 = card.interval_days;
+        card.review(ReviewRating::Good, now);
+
+        // Assert
+        assert_eq!(first, 1);
+        assert_eq!(second, 6);
+        assert_eq!(card.interval_days, 15);
+        assert_eq!(card.repetitions, 3);
+        assert!(!card.is_due(now));
+        assert!(card.is_due(now + Duration::days(15)));
+    }
+
+    #[test]
+    fn failed_review_starts_over_with_lower_ease() {
+        // Assign
+        let mut card: ReviewCard = new_card();
+        let now: DateTime<Utc> = Utc::now();
+        card.review(ReviewRating::Easy, now);
+        card.review(ReviewRating::Easy, now);
+
+        // Act
+        card.review(ReviewRating::Again, now);
+
+        // Assert
+        assert_eq!(card.repetitions, 0);
+        assert_eq!(card.interval_days, 1);
+        assert!(card.ease_factor < STARTING_EASE + 0.2);
+        assert!(card.ease_factor >= MINIMUM_EASE);
+    }
+
+    #[test]
+    fn wrong_answers_cannot_be_rated_easy() {
+        // Assign
+        let key: AnswerKey = AnswerKey::TrueFalse(true);
+
+        // Act
+        let wrong: ReviewRating = ReviewRating::Easy.capped_by(&key, 0.0);
+        let partial: ReviewRating = ReviewRating::Easy.capped_by(&key, 0.5);
+        let right: ReviewRating = ReviewRating::Easy.capped_by(&key, 1.0);
+
+        // Assert
+        assert_eq!(wrong, ReviewRating::Again);
+        assert_eq!(partial, ReviewRating::Hard);
+        assert_eq!(right, ReviewRating::Easy);
+    }
+}
@@ -0,0 +1,185 @@
+//! models/src/tags.rs
+//! Tags let users organise their own quizzes and questions.
+//! Each user has their own tags, attached with `target->tagged->tag` relations,
+//! so a quiz can have many tags and a tag many quizzes.
+use crate::{model_errors::ModelErrors, questions::QUESTION_TABLES};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+use surrealize_macro::Surrealize;
+
+/// Longest tag name allowed, in characters.
+pub const MAX_TAG_LEN: usize = 30;
+
+/// Tables whose records can be tagged.
+pub fn taggable_table(table: &str) -> bool {
+    table == "quizzes" || QUESTION_TABLES.contains(&table)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Surrealize)]
+pub struct Tag {
+    pub name: String,
+    pub author_id: String,
+}
+
+impl Tag {
+    pub fn new(name: String, author_id: String) -> Self {
+        Self { name, author_id }
+    }
+}
+
+impl SurrealTag {
+    /// Tag names are unique per user, ignoring case.
+    pub fn same_name(&self, name: &str) -> bool {
+        self.name.to_lowercase() == name.trim().to_lowercase()
+    }
+}
+
+/// For creating and renaming tags.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TagJsonPkg {
+    pub name: String,
+}
+
+impl TagJsonPkg {
+    pub fn validate_field(&self) -> Result<(), ModelErrors> {
+        let name: &str = self.name.trim();
+        if name.is_empty() {
+            Err(ModelErrors::JsonValidation(String::from(
+                "Tag name cannot be blank or white space",
+            )))
+        } else if name.chars().count() > MAX_TAG_LEN {
+            Err(ModelErrors::JsonValidation(format!(
+                "Tag name cannot be longer than {} characters",
+                MAX_TAG_LEN
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Sets whether the target has the tag, sending the same package twice changes nothing.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TagAssignPkg {
+    pub tag: Thing,
+    pub target: Thing,
+    pub tagged: bool,
+}
+
+/// A user's tag with how many quizzes and questions have it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TagSummary {
+    #[serde(flatten)]
+    pub tag: SurrealTag,
+    pub quizzes: u64,
+    pub questions: u64,
+}
+
+/// A single `tagged` relation, with the tag fetched.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TagLink {
+    pub target: Thing,
+    // the tag can be deleted while the relation is read
+    pub tag: Option<SurrealTag>,
+}
+
+/// Tags for a set of records.
+#[derive(Debug, Default, Clone)]
+pub struct TagIndex {
+    pub links: Vec<TagLink>,
+}
+
+impl TagIndex {
+    /// Tags on the target, sorted by name.
+    pub fn tags_for(&self, target: &Thing) -> Vec<SurrealTag> {
+        let mut tags: Vec<SurrealTag> = self
+            .links
+            .iter()
+            .filter(|link| &link.target == target)
+            .filter_map(|link| link.tag.clone())
+            .collect();
+        tags.sort_by_key(|tag| tag.name.to_lowercase());
+        tags
+    }
+
+    pub fn wrap<T>(&self, target: &Thing, record: T) -> Tagged<T> {
+        Tagged {
+            tags: self.tags_for(target),
+            record,
+        }
+    }
+}
+
+/// A record as sent to the frontend, with its tags alongside its own fields.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Tagged<T> {
+    #[serde(flatten)]
+    pub record: T,
+    pub tags: Vec<SurrealTag>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn surreal_tag(id: &str, name: &str) -> SurrealTag {
+        SurrealTag {
+            id: Thing::from(("tags", id)),
+            name: String::from(name),
+            author_id: String::from("user"),
+        }
+    }
+
+    #[test]
+    fn tag_names_validated() {
+        let blank: TagJsonPkg = TagJsonPkg {
+            name: String::from("  "),
+        };
+        let long: TagJsonPkg = TagJsonPkg {
+            name: "a".repeat(MAX_TAG_LEN + 1),
+        };
+        let good: TagJsonPkg = TagJsonPkg {
+            name: String::from("Algorithms"),
+        };
+        assert!(blank.validate_field().is_err());
+        assert!(long.validate_field().is_err());
+        assert!(good.validate_field().is_ok());
+    }
+
+    #[test]
+    fn tag_index_finds_tags_per_target() {
+        // Assign
+        let quiz: Thing = Thing::from(("quizzes", "quiz"));
+        let other: Thing = Thing::from(("quizzes", "other"));
+        let index: TagIndex = TagIndex {
+            links: vec![
+                TagLink {
+                    target: quiz.clone(),
+                    tag: Some(surreal_tag("b", "graphs")),
+                },
+                TagLink {
+                    target: other.clone(),
+                    tag: Some(surreal_tag("c", "sorting")),
+                },
+                TagLink {
+                    target: quiz.clone(),
+                    tag: Some(surreal_tag("a", "Exam")),
+                },
+                TagLink {
+                    target: quiz.clone(),
+                    tag: None,
+                },
+            ],
+        };
+
+        // Act
+        let tagged: Tagged<&str> = index.wrap(&quiz, "quiz");
+        let json: serde_json::Value = serde_json::to_value(index.tags_for(&other)).unwrap();
+
+        // Assert
+        let names: Vec<&str> = tagged.tags.iter().map(|tg| tg.name.as_str()).collect();
+        assert_eq!(names, vec!["Exam", "graphs"]);
+        assert_eq!(json[0]["name"], "sorting");
+        assert!(surreal_tag("a", "Exam").same_name(" exam "));
+    }
+}
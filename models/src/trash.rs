@@ -0,0 +1,32 @@
+//! models/src/trash.rs
+//! Deleted quizzes and questions wait in the trash, where they can be restored
+//! until they are purged for good.
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Thing};
+
+/// A trashed quiz or question, as listed in the trash.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TrashItem {
+    pub id: Thing,
+    // the quiz name, or the question itself
+    pub title: String,
+    pub author_id: String,
+    // the quiz a question came from, `None` for quizzes
+    #[serde(default)]
+    pub parent_quiz: Option<Thing>,
+    pub deleted_at: Datetime,
+}
+
+impl TrashItem {
+    pub fn is_quiz(&self) -> bool {
+        self.id.tb == "quizzes"
+    }
+}
+
+/// What a purge deleted for good.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrashPurge {
+    pub quizzes: usize,
+    // questions trashed on their own, not those of purged quizzes
+    pub questions: usize,
+}
@@ -26,19 +26,24 @@ pub fn surrealize_macro(input: TokenStream) -> TokenStream {
     };
 
     // Generate fields for new struct - does not include "id" yet.
+    // Serde attributes are kept, so things like `#[serde(default)]` apply to both structs.
     let surreal_fields = struct_fields.iter().map(|fld| {
         let name = &fld.ident;
         let ty = &fld.ty; // type
-        quote! { #name: #ty }
+        let serde_attrs = fld
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde"));
+        quote! { #(#serde_attrs)* pub #name: #ty }
     });
 
     let field_names = struct_fields.iter().map(|f| &f.ident);
 
     let expanded: proc_macro2::TokenStream = quote! {
-        #[derive(serde::Serialize, serde::Deserialize, Debug)]
+        #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
         pub struct #surreal_name {
             pub id: surrealdb::sql::Thing,
-            #(pub #surreal_fields,)*
+            #(#surreal_fields,)*
         }
 
         impl From<#surreal_name> for #struct_name {